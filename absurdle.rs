@@ -0,0 +1,84 @@
+// Find a short win against Absurdle, whose host keeps changing the answer to whichever leaves
+// the most candidates.  Opening guesses may be given, and the search continues from them.
+use anyhow::{Result, anyhow, bail};
+use std::path::PathBuf;
+use wordle::{Pattern, absurdle_solve, adversary_result, check_guess_n, combine_lists, fmt_result, load_lists};
+
+// Positions kept each round; the builtin lists take seconds, and are won in 4 rounds.
+const DEFAULT_WIDTH: usize = 100;
+
+struct Args {
+    width: usize,
+    // Answer and extra guess lists to use instead of the builtin ones.
+    answers: Option<PathBuf>,
+    guesses: Option<PathBuf>,
+    // Letters per word; other than 5, the answers must be given with -a.
+    length: usize,
+    first: Vec<String>,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut res = Args { width: DEFAULT_WIDTH, answers: None, guesses: None, length: 5, first: Vec::new() };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--width" => res.width = args.next().and_then(|w| w.parse().ok()).filter(|&w| w > 0)
+                .ok_or_else(|| anyhow!("--width needs a positive number"))?,
+            "-a" => res.answers = Some(args.next().ok_or_else(|| anyhow!("-a needs a word list file"))?.into()),
+            "-g" => res.guesses = Some(args.next().ok_or_else(|| anyhow!("-g needs a word list file"))?.into()),
+            "--length" => res.length = args.next().and_then(|s| s.parse().ok()).filter(|n| (4..=7).contains(n))
+                .ok_or_else(|| anyhow!("--length needs a number from 4 to 7"))?,
+            _ if !arg.starts_with('-') => res.first.push(arg),
+            _ => bail!("Usage: absurdle [--width n] [-a answers] [-g guesses] [--length n] [guess...]"),
+        }
+    }
+    Ok(res)
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    // Results are printed for their word length, so each length is run as its own.
+    match args.length {
+        4 => run::<4>(args),
+        5 => run::<5>(args),
+        6 => run::<6>(args),
+        7 => run::<7>(args),
+        _ => unreachable!("parse_args() checks the length"),
+    }
+}
+
+fn run<const N: usize>(args: Args) -> Result<()> {
+    let (answ_list, guess_list) = load_lists(args.answers.as_deref(), args.guesses.as_deref(), N)?;
+    let (guesses, _) = combine_lists(guess_list, answ_list);
+
+    let mut answers = answ_list.to_vec();
+    let mut round = 0;
+    let mut play = |word: &str, answers: &mut Vec<&str>| {
+        let (result, left) = adversary_result(answers, word);
+        round += 1;
+        let colors = fmt_result(&result.colors::<N>());
+        if result == Pattern::solved(N) {
+            println!("{}. {} {}", round, word, colors);
+        } else {
+            println!("{}. {} {}: {} candidates left", round, word, colors, left.len());
+        }
+        *answers = left;
+    };
+    for word in &args.first {
+        if let Err(e) = check_guess_n::<N>(word, Some(&guesses)) {
+            bail!("'{}' {}", word, e);
+        }
+        play(word, &mut answers);
+    }
+
+    match absurdle_solve(&answers, &guesses, args.width) {
+        Some(line) => {
+            for word in line {
+                play(word, &mut answers);
+            }
+            println!("Solved in {} rounds", round);
+        }
+        None => println!("No guesses narrow the {} candidates left to one", answers.len()),
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::{Pattern, PatternTable, partition_by_pattern};
+use crate::par::*;
+use rustc_hash::FxHashSet as HashSet;
+
+/// The result Absurdle's host gives for 'guess': it has no answer in mind, and keeps whichever
+/// result leaves the most candidates.  Ties go to the lowest pattern, so a guess is only solved
+/// once it is the last candidate.  Returns the result and the candidates it leaves; the guess
+/// must be lowercase letters, as many as the answers have.
+pub fn adversary_result<'a>(answers: &[&'a str], guess: &str) -> (Pattern, Vec<&'a str>) {
+    let mut buckets = partition_by_pattern(guess, answers);
+    let best = (0..buckets.len()).rev().max_by_key(|&p| buckets[p].len()).unwrap();
+    (Pattern(best as u16), std::mem::take(&mut buckets[best]))
+}
+
+// As adversary_result, for answers and a guess given by their indices in 'table'.
+fn host_bucket(table: &PatternTable, answers: &[u16], guess: usize) -> Vec<u16> {
+    let mut buckets = table.partition(guess, answers);
+    let best = (0..buckets.len()).rev().max_by_key(|&p| buckets[p].len()).unwrap();
+    std::mem::take(&mut buckets[best])
+}
+
+/// The shortest guess sequence found to win Absurdle, ending with the guess that solves it.
+/// Each round keeps the 'width' positions with the fewest candidates, so the search is only
+/// certain to be shortest if no round reaches more than 'width' distinct positions.  None if the
+/// candidates can't be narrowed to one which is also a guess.
+pub fn absurdle_solve<'a>(answers: &[&'a str], guesses: &[&'a str], width: usize) -> Option<Vec<&'a str>> {
+    let table = PatternTable::new(answers, guesses);
+    let guess_index = |word: &str| guesses.iter().position(|&g| g == word);
+    let mut positions = vec![((0..answers.len() as u16).collect::<Vec<_>>(), Vec::new())];
+    loop {
+        // Positions in a round are equally deep, so any one down to a guessable answer wins.
+        for (left, path) in &positions {
+            if let [a] = left[..] {
+                if let Some(g) = guess_index(answers[a as usize]) {
+                    let mut res = path.iter().map(|&g| guesses[g]).collect::<Vec<_>>();
+                    res.push(guesses[g]);
+                    return Some(res);
+                }
+            }
+        }
+
+        let mut seen = HashSet::default();
+        let mut next = positions.iter().flat_map(|(left, path)| {
+            (0..guesses.len()).collect::<Vec<_>>().par_iter()
+                .map(|&g| (host_bucket(&table, left, g), g))
+                .filter(|(bucket, _)| bucket.len() < left.len())
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(move |(bucket, g)| (bucket, path.iter().copied().chain([g]).collect::<Vec<_>>()))
+        }).filter(|(bucket, _)| seen.insert(bucket.clone())).collect::<Vec<_>>();
+        if next.is_empty() {
+            return None;
+        }
+        next.sort_by_key(|(bucket, _)| bucket.len());
+        next.truncate(width.max(1));
+        positions = next;
+    }
+}
+
+#[cfg(test)]
+mod test_adversary {
+    use super::*;
+
+    #[test]
+    fn test_adversary() {
+        let answers = ["hatch", "watch", "latch", "patch", "cigar"];
+        // The "-atch" words all leave "hatch" four greens.
+        let (result, left) = adversary_result(&answers, "hatch");
+        assert_eq!(result.colors(), crate::score("watch", "hatch"));
+        assert_eq!(adversary_result(&["plan", "clan", "flan"], "plot"), (Pattern::of("clan", "plot"), vec!["clan", "flan"]));
+        assert_eq!(left, ["watch", "latch", "patch"]);
+        assert_eq!(adversary_result(&["cigar"], "cigar").0, Pattern::solved(5));
+
+        // "whelp" tells every answer apart, so the host can only keep one of them.
+        let guesses = ["hatch", "watch", "latch", "patch", "cigar", "whelp"];
+        assert_eq!(absurdle_solve(&answers, &guesses, 100), Some(vec!["whelp", "cigar"]));
+        // Without it, each guess among them makes the host give up only itself.
+        assert_eq!(absurdle_solve(&answers, &guesses[..5], 100).map(|s| s.len()), Some(4));
+        assert_eq!(absurdle_solve(&answers, &["cigar"], 100), None);
+    }
+}
@@ -0,0 +1,137 @@
+// How good a player's guesses were, next to the best they could have made, and how lucky the
+// results they got.
+use crate::{BestGuess, Color, evaluate_all, parse_guess_n, pattern_counts, prune_n};
+
+// Entropies of guesses splitting the candidates alike may differ in the last bits.
+const EPSILON: f64 = 1e-9;
+
+/// Where a guess stands among those which could have been played instead, by the bits of
+/// information each was expected to give.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Analysis<'a> {
+    pub guess: BestGuess<'a>,
+    /// The guess expected to give the most.
+    pub best: BestGuess<'a>,
+    /// The guess's place from 1, among 'of' guesses; tied guesses share a place.
+    pub rank: usize,
+    pub of: usize,
+    /// The share of the other guesses expected to give less, from 0 to 100.
+    pub percentile: f64,
+}
+
+impl Analysis<'_> {
+    /// The bits the guess was expected to give, as a share of the best guess's, from 0 to 100.
+    pub fn skill(&self) -> f64 {
+        if self.best.entropy <= EPSILON {
+            100.0
+        } else {
+            100.0 * self.guess.entropy / self.best.entropy
+        }
+    }
+}
+
+/// How 'guess' compares with 'guesses', when 'candidates' were left.  The guess needn't be one of
+/// them, as when hard mode rules it out.  None if there are no guesses.
+pub fn analyze<'a>(guess: &'a str, candidates: &[&str], guesses: &[&'a str]) -> Option<Analysis<'a>> {
+    let evaluated = evaluate_all(candidates, guesses);
+    let best = *evaluated.iter().reduce(|a, b| if b.entropy > a.entropy { b } else { a })?;
+    let played = BestGuess::evaluate(candidates, guess, candidates.contains(&guess));
+    let better = evaluated.iter().filter(|g| g.entropy > played.entropy + EPSILON).count();
+    let worse = evaluated.iter().filter(|g| g.entropy < played.entropy - EPSILON).count();
+    let others = evaluated.len() - guesses.contains(&guess) as usize;
+    Some(Analysis {
+        guess: played,
+        best,
+        rank: better + 1,
+        of: evaluated.len(),
+        percentile: if others == 0 { 100.0 } else { 100.0 * worse as f64 / others as f64 },
+    })
+}
+
+/// One guess of a game, graded for skill and for luck.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Round<'a> {
+    pub analysis: Analysis<'a>,
+    /// The candidates before and after the guess.
+    pub before: usize,
+    pub after: usize,
+    /// Of the candidates before the guess, each as likely to be the answer, the share whose
+    /// result would have left more, counting half of those which would have left as many: 50 for
+    /// an average result, from 0 to 100.
+    pub luck: f64,
+}
+
+impl Round<'_> {
+    /// The candidates the guess was expected to leave.
+    pub fn expected(&self) -> f64 {
+        self.analysis.guess.expected_remaining
+    }
+}
+
+/// Grade each guess of a game played from 'answers', of 'guesses' and the results they got.
+/// A guess which isn't one, or leaves no candidates after a mistyped result, ends the grading.
+pub fn analyze_game<'a, const N: usize>(answers: &[&str], rounds: &[(&'a str, [Color; N])], guesses: &[&'a str]) -> Vec<Round<'a>> {
+    let mut candidates = answers.to_vec();
+    let mut res = Vec::new();
+    for &(guess, result) in rounds {
+        let left = match parse_guess_n(guess) {
+            Some(bguess) => prune_n(&candidates, bguess, result),
+            None => break,
+        };
+        let analysis = match analyze(guess, &candidates, guesses) {
+            Some(analysis) if !left.is_empty() => analysis,
+            _ => break,
+        };
+        let counts = pattern_counts(&candidates, guess);
+        let more = counts.iter().filter(|&&c| c > left.len()).sum::<usize>();
+        let same = counts.iter().filter(|&&c| c == left.len()).sum::<usize>();
+        let luck = 100.0 * (more as f64 + same as f64 / 2.0) / candidates.len() as f64;
+        res.push(Round { analysis, before: candidates.len(), after: left.len(), luck });
+        candidates = left;
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_analysis {
+    use super::*;
+    use crate::score;
+
+    #[test]
+    fn test_analyze() {
+        let candidates = ["hatch", "watch", "latch", "match", "batch"];
+        let guesses = ["hatch", "whelm", "cigar", "batty"];
+        let a = analyze("cigar", &candidates, &guesses).unwrap();
+        assert_eq!(a.best.word, "whelm");
+        assert_eq!((a.rank, a.of), (4, 4));
+        assert_eq!(a.percentile, 0.0);
+        assert_eq!(a.skill(), 0.0);
+
+        let a = analyze("whelm", &candidates, &guesses).unwrap();
+        assert_eq!((a.rank, a.percentile, a.skill()), (1, 100.0, 100.0));
+        // A guess outside the pool is ranked among it all the same.
+        let a = analyze("hatch", &candidates, &guesses[1..]).unwrap();
+        assert_eq!((a.rank, a.of), (2, 3));
+        assert!((a.percentile - 100.0 / 3.0).abs() < 1e-9);
+        assert!(analyze("cigar", &candidates, &[]).is_none());
+    }
+
+    #[test]
+    fn test_analyze_game() {
+        let answers = ["hatch", "watch", "latch", "match", "batch"];
+        let guesses = ["hatch", "whelm", "cigar", "batty", "watch"];
+        let rounds = [("batty", score("watch", "batty")), ("watch", score("watch", "watch"))];
+        let graded = analyze_game(&answers, &rounds, &guesses);
+        assert_eq!(graded.len(), 2);
+        // batty leaves 4 unless the answer is batch, so leaving 4 is unlucky.
+        assert_eq!((graded[0].before, graded[0].after), (5, 4));
+        assert_eq!(graded[0].luck, 100.0 * (4.0 / 2.0) / 5.0);
+        assert_eq!(graded[0].expected(), (1.0 + 16.0) / 5.0);
+        assert_eq!((graded[1].before, graded[1].after), (4, 1));
+        // Solving it was lucky: the other three would have been left together.
+        assert_eq!(graded[1].luck, 100.0 * (3.0 + 1.0 / 2.0) / 4.0);
+
+        let rounds = [("batty", [Color::GREEN; 5])];
+        assert!(analyze_game(&answers, &rounds, &guesses).is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::paths::xdg_path;
+use crate::{Minimax, Preferences, Strategy, best_opener, is_builtin, opener_for, worst_case};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const OPENERS: &str = "openers";
+
+/// Checksum of the word lists a computed artifact depends on.  FNV-1a, so it is stable across
+/// builds and platforms.
+pub fn wordlist_checksum(answers: &[&str], guesses: &[&str]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for list in [answers, guesses] {
+        for word in list {
+            for &b in word.as_bytes().iter().chain(b"\n") {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        }
+        hash ^= 0xff;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// What the cache knows about one stored artifact.
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+    pub name: String,
+    pub size: u64,
+    pub age: Option<Duration>,
+    /// Checksum of the word lists the artifact was computed for, if it has a valid header.
+    pub checksum: Option<u64>,
+}
+
+/// Directory of computed artifacts, each a text file whose first line records the word list
+/// checksum it was computed for.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// '$WORDLE_CACHE', else 'wordle' under '$XDG_CACHE_HOME' (default '~/.cache').
+    pub fn default_path() -> Option<PathBuf> {
+        xdg_path("WORDLE_CACHE", "XDG_CACHE_HOME", ".cache", "")
+    }
+
+    pub fn open(dir: &Path) -> Self {
+        Self { dir: dir.to_path_buf() }
+    }
+
+    pub fn open_default() -> Option<Self> {
+        Self::default_path().map(|dir| Self::open(&dir))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The stored artifacts, sorted by name.  A missing directory is an empty cache.
+    pub fn entries(&self) -> io::Result<Vec<CacheEntry>> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut res = Vec::new();
+        for ent in dir {
+            let ent = ent?;
+            let meta = ent.metadata()?;
+            if !meta.is_file() {
+                continue;
+            }
+            let age = meta.modified().ok().and_then(|m| SystemTime::now().duration_since(m).ok());
+            let checksum = fs::read_to_string(ent.path()).ok().and_then(|s| parse_header(&s).map(|(c, _)| c));
+            res.push(CacheEntry { name: ent.file_name().to_string_lossy().into_owned(), size: meta.len(), age, checksum });
+        }
+        res.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(res)
+    }
+
+    /// Remove every stored artifact, returning how many there were.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = self.entries()?;
+        for ent in &entries {
+            fs::remove_file(self.dir.join(&ent.name))?;
+        }
+        Ok(entries.len())
+    }
+
+    /// The body of artifact 'name', if it was computed for word lists with 'checksum'.
+    pub fn load(&self, name: &str, checksum: u64) -> Option<String> {
+        let text = fs::read_to_string(self.dir.join(name)).ok()?;
+        match parse_header(&text) {
+            Some((c, body)) if c == checksum => Some(body.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn store(&self, name: &str, checksum: u64, body: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write and rename, so a concurrent reader never sees half an artifact.
+        let tmp = self.dir.join(format!(".{}.tmp", name));
+        fs::write(&tmp, format!("wordlist {:016x}\n{}", checksum, body))?;
+        fs::rename(&tmp, self.dir.join(name))
+    }
+
+    /// Search for and store the best openers for one and two boards.
+    pub fn rebuild_openers(&self, answers: &[&str], guesses: &[&str]) -> io::Result<()> {
+        let mut body = String::new();
+        for boards in 1..=2 {
+            if let Some((word, worst)) = best_opener(answers, guesses, boards) {
+                body.push_str(&format!("{}\t{}\t{}\n", boards, word, worst));
+            }
+        }
+        self.store(OPENERS, wordlist_checksum(answers, guesses), &body)
+    }
+
+    fn load_opener<'a>(&self, answers: &[&str], guesses: &[&'a str], boards: usize) -> Option<(&'a str, usize)> {
+        self.load_opener_from(OPENERS, answers, guesses, boards)
+    }
+
+    fn load_opener_from<'a>(&self, name: &str, answers: &[&str], guesses: &[&'a str], boards: usize)
+        -> Option<(&'a str, usize)> {
+        let body = self.load(name, wordlist_checksum(answers, guesses))?;
+        body.lines().find_map(|line| {
+            let mut fields = line.split('\t');
+            if fields.next()?.parse::<usize>().ok()? != boards {
+                return None;
+            }
+            let word = fields.next()?;
+            let worst = fields.next()?.parse().ok()?;
+            guesses.iter().copied().find(|&g| g == word).map(|g| (g, worst))
+        })
+    }
+}
+
+fn parse_header(text: &str) -> Option<(u64, &str)> {
+    let (header, body) = text.split_once('\n')?;
+    let checksum = u64::from_str_radix(header.strip_prefix("wordlist ")?, 16).ok()?;
+    Some((checksum, body))
+}
+
+/// As opener_for, but preferring a cached opener for these lists, and caching a searched one.
+pub fn cached_opener<'a>(cache: Option<&Cache>, answers: &[&str], guesses: &[&'a str], boards: usize)
+    -> Option<(&'a str, usize)> {
+    if let Some(hit) = cache.and_then(|c| c.load_opener(answers, guesses, boards)) {
+        return Some(hit);
+    }
+    match cache {
+        Some(cache) if !is_builtin(answers, guesses) && (1..=2).contains(&boards) => {
+            // Best effort; a searched opener is still good if it can't be saved.
+            cache.rebuild_openers(answers, guesses).ok();
+            cache.load_opener(answers, guesses, boards).or_else(|| best_opener(answers, guesses, boards))
+        }
+        _ => opener_for(answers, guesses, boards),
+    }
+}
+
+/// The best one board opener under 'strategy'.  Minimax openers are those of cached_opener; other
+/// strategies have no precomputed openers, so theirs are searched once for each pair of word
+/// lists and cached as 'openers-<strategy>'.
+pub fn strategy_opener<'a>(cache: Option<&Cache>, answers: &[&str], guesses: &[&'a str], strategy: &dyn Strategy)
+    -> Option<(&'a str, usize)> {
+    if strategy.name() == Minimax.name() {
+        return cached_opener(cache, answers, guesses, 1);
+    }
+    let name = format!("{}-{}", OPENERS, strategy.name());
+    if let Some(hit) = cache.and_then(|c| c.load_opener_from(&name, answers, guesses, 1)) {
+        return Some(hit);
+    }
+    let best = strategy.best_guess(answers, guesses, &Preferences::default())?;
+    if let Some(cache) = cache {
+        // Best effort, as in cached_opener.
+        let body = format!("1\t{}\t{}\n", best.word, best.worst_case);
+        cache.store(&name, wordlist_checksum(answers, guesses), &body).ok();
+    }
+    Some((best.word, best.worst_case))
+}
+
+/// The opener named by a '--first' option: a word from 'guesses', or for "auto" or no option, the
+/// cached or precomputed opener for the default cache.
+pub fn choose_opener<'a>(first: Option<&str>, answers: &[&str], guesses: &[&'a str], boards: usize)
+    -> Result<(&'a str, usize), String> {
+    match first {
+        None | Some("auto") => cached_opener(Cache::open_default().as_ref(), answers, guesses, boards)
+            .ok_or_else(|| "no guesses to open with".to_string()),
+        Some(word) => {
+            let word = guesses.iter().copied().find(|&g| g == word)
+                .ok_or_else(|| format!("'{}' is not in the word list", word))?;
+            Ok((word, boards * worst_case(answers, word)))
+        }
+    }
+}
+
+/// A fixed opening sequence, like "salet,courd": words from 'guesses' separated by commas or
+/// spaces, played in order before the solver takes over.
+pub fn parse_opening<'a>(s: &str, guesses: &[&'a str]) -> Result<Vec<&'a str>, String> {
+    let res = s.split(|c: char| c == ',' || c.is_whitespace()).filter(|w| !w.is_empty())
+        .map(|word| guesses.iter().copied().find(|&g| g == word).ok_or_else(|| format!("'{}' is not in the word list", word)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if res.is_empty() {
+        return Err("no opening guesses given".to_string());
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test_cache {
+    use super::*;
+
+    #[test]
+    fn test_store_load() {
+        let dir = std::env::temp_dir().join(format!("wordle-cache-test-{}", std::process::id()));
+        let cache = Cache::open(&dir);
+        assert!(cache.entries().unwrap().is_empty());
+
+        let sum = wordlist_checksum(&["cigar"], &["cigar", "rebut"]);
+        assert_ne!(sum, wordlist_checksum(&["cigar", "rebut"], &["cigar"]));
+        cache.store("x", sum, "body\n").unwrap();
+        assert_eq!(cache.load("x", sum).as_deref(), Some("body\n"));
+        assert_eq!(cache.load("x", sum + 1), None);
+
+        let entries = cache.entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "x");
+        assert_eq!(entries[0].checksum, Some(sum));
+
+        cache.rebuild_openers(&["cigar", "rebut"], &["cigar", "rebut"]).unwrap();
+        assert_eq!(cache.load_opener(&["cigar", "rebut"], &["cigar", "rebut"], 2), Some(("cigar", 2)));
+        // "naval" tells these apart best on average, though "cigar" has the better worst case.
+        let answers = ["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve"];
+        let guesses = ["cigar", "naval"];
+        assert_eq!(strategy_opener(Some(&cache), &answers, &guesses, &crate::Entropy), Some(("naval", 3)));
+        assert!(cache.load("openers-entropy", wordlist_checksum(&answers, &guesses)).is_some());
+        assert_eq!(strategy_opener(Some(&cache), &answers, &guesses, &Minimax).map(|(w, _)| w), Some("cigar"));
+        assert_eq!(cache.clear().unwrap(), 3);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_choose_opener() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        assert_eq!(choose_opener(Some("hatch"), &answers, &guesses, 2), Ok(("hatch", 6)));
+        assert!(choose_opener(Some("zzzzz"), &answers, &guesses, 1).is_err());
+        assert_eq!(parse_opening("whelp,hatch", &guesses), Ok(vec!["whelp", "hatch"]));
+        assert_eq!(parse_opening(" cigar ", &guesses), Ok(vec!["cigar"]));
+        assert!(parse_opening("whelp,zzzzz", &guesses).is_err());
+        assert!(parse_opening(",", &guesses).is_err());
+    }
+}
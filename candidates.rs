@@ -0,0 +1,228 @@
+use crate::{BestGuess, Pattern, PatternTable, Preferences, rank};
+use crate::par::*;
+use std::ops::BitAnd;
+
+/// A set of answers, by their indices in an answer list of 'universe' words, as a bitset.
+/// Intersecting two sets is an AND of their words.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CandidateSet {
+    bits: Vec<u64>,
+    universe: usize,
+}
+
+impl CandidateSet {
+    pub fn empty(universe: usize) -> Self {
+        Self { bits: vec![0; universe.div_ceil(64)], universe }
+    }
+
+    /// Every answer.
+    pub fn full(universe: usize) -> Self {
+        let mut res = Self::empty(universe);
+        res.bits.fill(!0);
+        if !universe.is_multiple_of(64) {
+            *res.bits.last_mut().unwrap() = (1 << (universe % 64)) - 1;
+        }
+        res
+    }
+
+    pub fn from_indices<I: IntoIterator<Item = usize>>(universe: usize, indices: I) -> Self {
+        let mut res = Self::empty(universe);
+        for i in indices {
+            res.insert(i);
+        }
+        res
+    }
+
+    pub fn universe(&self) -> usize {
+        self.universe
+    }
+
+    pub fn insert(&mut self, i: usize) {
+        assert!(i < self.universe, "answer {} out of range", i);
+        self.bits[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn remove(&mut self, i: usize) {
+        if i < self.universe {
+            self.bits[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.universe && self.bits[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&w| w == 0)
+    }
+
+    /// How many answers both sets contain, without building their intersection.
+    pub fn intersection_len(&self, other: &CandidateSet) -> usize {
+        self.bits.iter().zip(&other.bits).map(|(a, b)| (a & b).count_ones() as usize).sum()
+    }
+
+    pub fn intersect_with(&mut self, other: &CandidateSet) {
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a &= b;
+        }
+    }
+
+    /// The indices of the answers, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(k, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                if w == 0 {
+                    return None;
+                }
+                let bit = w.trailing_zeros() as usize;
+                w &= w - 1;
+                Some(k * 64 + bit)
+            })
+        })
+    }
+
+    /// The answers themselves, from the list the indices are into.
+    pub fn words<'a>(&self, answers: &[&'a str]) -> Vec<&'a str> {
+        self.iter().map(|i| answers[i]).collect()
+    }
+}
+
+impl BitAnd for &CandidateSet {
+    type Output = CandidateSet;
+
+    fn bitand(self, other: &CandidateSet) -> CandidateSet {
+        let mut res = self.clone();
+        res.intersect_with(other);
+        res
+    }
+}
+
+/// A bitmask of the answers giving each result of each guess in a table, so pruning a
+/// CandidateSet is one AND and evaluating a guess is a popcount per result.  Only results some
+/// answer gives are kept, but for the builtin lists that is still several hundred MB; build it
+/// for smaller pools, like the answers alone, or the candidates left, as best_guess_depth()
+/// does.
+pub struct PruneTable<'t, 'a> {
+    table: &'t PatternTable<'a>,
+    // Per guess, the results it gives, sorted, and the answers giving each.
+    masks: Vec<Vec<(Pattern, CandidateSet)>>,
+}
+
+impl<'t, 'a> PruneTable<'t, 'a> {
+    pub fn new(table: &'t PatternTable<'a>) -> Self {
+        let n = table.answers().len();
+        let masks = (0..table.guesses().len()).collect::<Vec<_>>().par_iter().map(|&g| {
+            let mut by_pattern = vec![None; table.pattern_count()];
+            for a in 0..n {
+                by_pattern[table.pattern(g, a).index()].get_or_insert_with(|| CandidateSet::empty(n)).insert(a);
+            }
+            by_pattern.into_iter().enumerate()
+                .filter_map(|(p, set)| set.map(|set| (Pattern(p as u16), set)))
+                .collect()
+        }).collect();
+        Self { table, masks }
+    }
+
+    pub fn table(&self) -> &'t PatternTable<'a> {
+        self.table
+    }
+
+    /// Every answer of the table.
+    pub fn full(&self) -> CandidateSet {
+        CandidateSet::full(self.table.answers().len())
+    }
+
+    /// The set of 'words', if they are all answers of the table.
+    pub fn set(&self, words: &[&str]) -> Option<CandidateSet> {
+        let indices = words.iter().map(|w| self.table.answer_index(w)).collect::<Option<Vec<_>>>()?;
+        Some(CandidateSet::from_indices(self.table.answers().len(), indices))
+    }
+
+    /// The candidates of 'set' which give 'pattern' for guess 'guess', by its index in the table.
+    pub fn prune(&self, set: &CandidateSet, guess: usize, pattern: Pattern) -> CandidateSet {
+        let masks = &self.masks[guess];
+        match masks.binary_search_by_key(&pattern, |&(p, _)| p) {
+            Ok(i) => set & &masks[i].1,
+            Err(_) => CandidateSet::empty(set.universe()),
+        }
+    }
+
+    /// How many candidates of 'set' give each result of guess 'guess', for the results some
+    /// answer of the table gives.
+    pub fn counts<'s>(&'s self, set: &'s CandidateSet, guess: usize)
+        -> impl Iterator<Item = (Pattern, usize)> + Clone + 's {
+        self.masks[guess].iter().map(move |(p, mask)| (*p, set.intersection_len(mask)))
+    }
+
+    /// The candidates of 'set' giving each result of guess 'guess', for the results some of
+    /// them give.
+    pub fn partition<'s>(&'s self, set: &'s CandidateSet, guess: usize)
+        -> impl Iterator<Item = (Pattern, CandidateSet)> + 's {
+        self.masks[guess].iter().map(move |(p, mask)| (*p, set & mask)).filter(|(_, left)| !left.is_empty())
+    }
+
+    /// As BestGuess::evaluate(), for the candidates of 'set'.
+    pub fn evaluate(&self, set: &CandidateSet, guess: usize) -> BestGuess<'a> {
+        let is_candidate = self.table.answer_index(self.table.guesses()[guess]).is_some_and(|a| set.contains(a));
+        let buckets = self.counts(set, guess).map(|(_, n)| n);
+        BestGuess::from_buckets(self.table.guesses()[guess], is_candidate, buckets)
+    }
+
+    /// Every guess of the table evaluated against 'set', from best to worst.
+    pub fn rank_guesses(&self, set: &CandidateSet, prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        let guesses = (0..self.table.guesses().len()).collect::<Vec<_>>();
+        rank(guesses.par_iter().map(|&g| self.evaluate(set, g)).collect(), prefs)
+    }
+
+    pub fn best_guess(&self, set: &CandidateSet, prefs: &Preferences) -> Option<BestGuess<'a>> {
+        self.rank_guesses(set, prefs).into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod test_candidates {
+    use super::*;
+    use crate::{Minimax, Strategy, score};
+
+    #[test]
+    fn test_candidate_set() {
+        let mut set = CandidateSet::from_indices(130, [0, 64, 129]);
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 64, 129]);
+        assert!(set.contains(64) && !set.contains(65) && !set.contains(500));
+        set.remove(64);
+        assert_eq!(set.len(), 2);
+        assert_eq!(CandidateSet::full(130).len(), 130);
+        assert_eq!((&CandidateSet::full(130) & &set), set);
+        assert!((&set & &CandidateSet::empty(130)).is_empty());
+    }
+
+    #[test]
+    fn test_prune_table() {
+        let answers = ["hatch", "watch", "latch", "patch", "cigar"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        let table = PatternTable::new(&answers, &guesses);
+        let prune = PruneTable::new(&table);
+        let full = prune.full();
+
+        let result = Pattern::from(score("watch", "hatch"));
+        let left = prune.prune(&full, 0, result);
+        assert_eq!(left.words(&answers), ["watch", "latch", "patch"]);
+        assert!(prune.prune(&left, 2, Pattern::solved(5)).is_empty());
+        let parts = prune.partition(&left, 1).map(|(_, set)| set.words(&answers)).collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|words| words.len() == 1));
+        assert_eq!(prune.counts(&left, 1).map(|(_, n)| n).sum::<usize>(), 3);
+        assert_eq!(prune.set(&["latch", "cigar"]), Some(CandidateSet::from_indices(5, [2, 4])));
+        assert_eq!(prune.set(&["whelp"]), None);
+
+        let prefs = Preferences::default();
+        assert_eq!(prune.rank_guesses(&left, &prefs), Minimax.rank_guesses(&left.words(&answers), &guesses, &prefs));
+        assert_eq!(prune.best_guess(&full, &prefs).map(|g| g.word), Some("whelp"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::paths::xdg_path;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+
+/// User settings, stored as "key = value" lines with '#' comments.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// Describe results in words rather than by color or symbol alone, for screen readers.
+    pub accessible: bool,
+    /// Ask before starting a best guess search estimated to take longer than this many seconds.
+    pub confirm_seconds: Option<u64>,
+    /// Show guesses as colored tiles; by default, only when output is a terminal.
+    pub color: Option<bool>,
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" | "yes" | "on" => Some(true),
+        "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+impl Config {
+    /// Default location: $WORDLE_CONFIG, else $XDG_CONFIG_HOME/wordle/config.
+    pub fn default_path() -> Option<PathBuf> {
+        xdg_path("WORDLE_CONFIG", "XDG_CONFIG_HOME", ".config", "config")
+    }
+
+    /// Load the default config file, if there is one.
+    pub fn load_default() -> io::Result<Self> {
+        match Self::default_path() {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// A missing file is the default config.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}:{}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (lineno, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let bad = || format!("{}: bad setting '{}'", lineno + 1, line);
+            let (key, value) = line.split_once('=').ok_or_else(bad)?;
+            let value = value.trim();
+            match key.trim() {
+                "accessible" => config.accessible = parse_bool(value).ok_or_else(bad)?,
+                "confirm_seconds" => config.confirm_seconds = Some(value.parse().map_err(|_| bad())?),
+                "color" => config.color = Some(parse_bool(value).ok_or_else(bad)?),
+                _ => return Err(bad()),
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn use_color(&self) -> bool {
+        self.color.unwrap_or_else(|| io::stdout().is_terminal())
+    }
+}
+
+#[cfg(test)]
+mod test_config {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        let config = Config::parse("# For my screen reader\naccessible = yes\n").unwrap();
+        assert!(config.accessible);
+        assert_eq!(config.confirm_seconds, None);
+        assert_eq!(Config::parse("confirm_seconds = 30").unwrap().confirm_seconds, Some(30));
+        assert!(Config::parse("confirm_seconds = soon").is_err());
+        assert!(Config::parse("accessible = maybe").is_err());
+        assert!(Config::parse("colour = on").is_err());
+        assert_eq!(Config::parse("color = off").unwrap().color, Some(false));
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{Color, LETTERS, Observation, alphabet, letter_index};
+
+fn bit(letter: u8) -> u32 {
+    1 << letter_index(letter)
+}
+
+/// Letter knowledge accumulated from a game's observations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints<const N: usize = 5> {
+    greens: [Option<u8>; N],
+    // Letters known not to be at each position.
+    excluded: [u32; N],
+    // Bounds on how many of each letter the answer has.
+    min: [u8; LETTERS],
+    max: [u8; LETTERS],
+    // Set when observations contradict each other outright, e.g. two greens in one position.
+    conflict: bool,
+}
+
+impl<const N: usize> Default for Constraints<N> {
+    fn default() -> Self {
+        Self {
+            greens: [None; N],
+            excluded: [0; N],
+            min: [0; LETTERS],
+            max: [N as u8; LETTERS],
+            conflict: false,
+        }
+    }
+}
+
+impl<const N: usize> Constraints<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_history(history: &[Observation<N>]) -> Self {
+        let mut res = Self::new();
+        for &(guess, result) in history {
+            res.add(guess, result);
+        }
+        res
+    }
+
+    pub fn add(&mut self, guess: [u8; N], result: [Color; N]) {
+        for i in 0..N {
+            let g = guess[i];
+            if result[i] == Color::GREEN {
+                self.set_green(i, g);
+            } else {
+                self.excluded[i] |= bit(g);
+            }
+
+            // Every green or yellow tile for a letter is another copy of it in the answer; a grey
+            // tile means there are no more copies than that.
+            let present = (0..N).filter(|&j| guess[j] == g && result[j] != Color::GREY).count() as u8;
+            let l = letter_index(g);
+            self.min[l] = self.min[l].max(present);
+            if result[i] == Color::GREY {
+                self.max[l] = self.max[l].min(present);
+            }
+
+            // Yellows are assigned left to right, so no grey tile precedes a yellow one for the
+            // same letter.
+            if result[i] == Color::YELLOW && (0..i).any(|j| guess[j] == g && result[j] == Color::GREY) {
+                self.conflict = true;
+            }
+        }
+    }
+
+    fn set_green(&mut self, i: usize, letter: u8) {
+        if self.greens[i].is_some_and(|g| g != letter) {
+            self.conflict = true;
+        }
+        self.greens[i] = Some(letter);
+    }
+
+    /// Combine with the constraints from other observations of the same answer.
+    pub fn merge(&mut self, other: &Self) {
+        for i in 0..N {
+            if let Some(g) = other.greens[i] {
+                self.set_green(i, g);
+            }
+            self.excluded[i] |= other.excluded[i];
+        }
+        for l in 0..LETTERS {
+            self.min[l] = self.min[l].max(other.min[l]);
+            self.max[l] = self.max[l].min(other.max[l]);
+        }
+        self.conflict |= other.conflict;
+    }
+
+    /// Would the constraints remain satisfiable after observing 'result' for 'guess'?
+    pub fn compatible(&self, guess: [u8; N], result: [Color; N]) -> bool {
+        let mut c = *self;
+        c.add(guess, result);
+        c.is_satisfiable()
+    }
+
+    /// Is there any string of N letters (word or not) consistent with every observation?
+    pub fn is_satisfiable(&self) -> bool {
+        if self.conflict || (0..LETTERS).any(|l| self.min[l] > self.max[l]) {
+            return false;
+        }
+        let mut allowed = [0; N];
+        for (i, a) in allowed.iter_mut().enumerate() {
+            *a = self.greens[i].map_or(alphabet().mask(), bit) & !self.excluded[i];
+        }
+        self.search(&allowed, 0, &mut [0; LETTERS])
+    }
+
+    // Depth first search for a string satisfying the constraints, filling in position 'pos'
+    // onwards given the letter 'counts' of the positions before it.
+    fn search(&self, allowed: &[u32; N], pos: usize, counts: &mut [u8; LETTERS]) -> bool {
+        // The copies still owed to reach each letter's minimum must fit in the remaining positions.
+        let mut owed = 0;
+        for (l, (&min, &count)) in self.min.iter().zip(counts.iter()).enumerate() {
+            let need = min.saturating_sub(count) as usize;
+            if need > (pos..N).filter(|&p| allowed[p] & (1 << l) != 0).count() {
+                return false;
+            }
+            owed += need;
+        }
+        if owed > N - pos {
+            return false;
+        }
+        if pos == N {
+            return true;
+        }
+
+        for l in 0..LETTERS {
+            if allowed[pos] & (1 << l) != 0 && counts[l] < self.max[l] {
+                counts[l] += 1;
+                if self.search(allowed, pos + 1, counts) {
+                    return true;
+                }
+                counts[l] -= 1;
+            }
+        }
+        false
+    }
+
+    /// Bitmask (bit 0 is 'a') of the letters proven absent from the answer.
+    pub fn dead_letters(&self) -> u32 {
+        (0..LETTERS).filter(|&l| self.max[l] == 0).fold(0, |mask, l| mask | 1 << l)
+    }
+
+    /// Bitmask of the letters proven present in the answer; see min_count() for how many.
+    pub fn required_letters(&self) -> u32 {
+        (0..LETTERS).filter(|&l| self.min[l] > 0).fold(0, |mask, l| mask | 1 << l)
+    }
+
+    /// The letter known to be at each position.
+    pub fn green_mask(&self) -> [Option<u8>; N] {
+        self.greens
+    }
+
+    /// Bitmask of the letters known not to be at position 'i', other than by it being green.
+    pub fn excluded(&self, i: usize) -> u32 {
+        self.excluded[i]
+    }
+
+    pub fn min_count(&self, letter: u8) -> usize {
+        self.min[letter_index(letter)] as usize
+    }
+
+    /// How many of 'letter' the answer may have, at most.
+    pub fn max_count(&self, letter: u8) -> usize {
+        self.max[letter_index(letter)] as usize
+    }
+
+    /// Whether hard mode allows playing 'word': it keeps every green in place, and has at least
+    /// as many of each letter as are known to be present.
+    pub fn allows_hard(&self, word: &str) -> bool {
+        let word = word.as_bytes();
+        if self.greens.iter().zip(word).any(|(g, &w)| g.is_some_and(|g| g != w)) {
+            return false;
+        }
+        let mut counts = [0u8; LETTERS];
+        for &w in word {
+            counts[letter_index(w)] += 1;
+        }
+        counts.iter().zip(&self.min).all(|(count, min)| count >= min)
+    }
+}
+
+impl<const N: usize> Constraints<N> {
+    /// What is known, a line per kind of knowledge: the greens, the letters present and how many
+    /// of each, the letters absent, and the present letters ruled out of each position.
+    pub fn summary(&self) -> Vec<String> {
+        let upper = |l: usize| alphabet().upper(b'a' + l as u8);
+        let letters = |mask: u32| (0..LETTERS).filter(|l| mask & (1 << l) != 0).collect::<Vec<_>>();
+        let mut res = Vec::new();
+        if self.greens.iter().any(Option::is_some) {
+            res.push(format!("Greens: {}", self.greens.iter()
+                .map(|g| g.map_or('_', |g| alphabet().upper(g)).to_string()).collect::<Vec<_>>().join(" ")));
+        }
+        let present = letters(self.required_letters()).into_iter().map(|l| {
+            let c = upper(l);
+            match (self.min[l], self.max[l]) {
+                (min, max) if min == max => format!("{} (exactly {})", c, min),
+                (min, max) if max as usize == N && min > 1 => format!("{} (at least {})", c, min),
+                (_, max) if max as usize == N => c.to_string(),
+                (min, max) => format!("{} ({} to {})", c, min, max),
+            }
+        }).collect::<Vec<_>>();
+        if !present.is_empty() {
+            res.push(format!("Present: {}", present.join(", ")));
+        }
+        let dead = self.dead_letters();
+        if dead != 0 {
+            res.push(format!("Absent: {}", letters(dead).into_iter().map(upper).collect::<String>()));
+        }
+        // Absent letters are ruled out everywhere, and a green position rules out all but one.
+        let not_at = (0..N).filter(|&i| self.greens[i].is_none() && self.excluded[i] & !dead != 0)
+            .map(|i| format!("{} {}", i + 1, letters(self.excluded[i] & !dead).into_iter().map(upper).collect::<String>()))
+            .collect::<Vec<_>>();
+        if !not_at.is_empty() {
+            res.push(format!("Not at: {}", not_at.join(", ")));
+        }
+        if res.is_empty() {
+            res.push("Nothing known yet".to_string());
+        }
+        res
+    }
+}
+
+/// Could both observations have been made of the same answer?
+pub fn consistent<const N: usize>(a: &Observation<N>, b: &Observation<N>) -> bool {
+    Constraints::from_history(&[*a, *b]).is_satisfiable()
+}
+
+/// Why an observation can't have been made of the same answer as the ones before it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Conflict {
+    /// The earlier observation it contradicts, from 0; None if it contradicts itself, or only the
+    /// earlier observations together.
+    pub index: Option<usize>,
+    pub reason: String,
+}
+
+/// The earliest of 'history' that 'obs' contradicts, and which of its clues; None if every
+/// observation could have been made of one answer.
+pub fn find_conflict<const N: usize>(history: &[Observation<N>], obs: Observation<N>) -> Option<Conflict> {
+    let new = Constraints::from_history(&[obs]);
+    if !new.is_satisfiable() {
+        return Some(Conflict { index: None, reason: self_clash(obs, &new) });
+    }
+    if let Some((i, earlier)) = history.iter().enumerate().find(|(_, earlier)| !consistent(earlier, &obs)) {
+        let reason = clash(&Constraints::from_history(&[*earlier]), &new).unwrap_or_else(|| {
+            format!("no {} letters fit both", N)
+        });
+        return Some(Conflict { index: Some(i), reason: format!("guess {} {}", i + 1, reason) });
+    }
+    if Constraints::from_history(history).compatible(obs.0, obs.1) {
+        return None;
+    }
+    Some(Conflict { index: None, reason: format!("no {} letters fit it and the earlier guesses together", N) })
+}
+
+// Why 'obs', whose constraints are 'c', contradicts itself.
+fn self_clash<const N: usize>((guess, result): Observation<N>, c: &Constraints<N>) -> String {
+    let alphabet = alphabet();
+    for i in 0..N {
+        let g = guess[i];
+        if result[i] != Color::YELLOW {
+            continue;
+        }
+        if let Some(j) = (0..i).find(|&j| guess[j] == g && result[j] == Color::GREY) {
+            return format!("it has {} yellow at {} after a grey one at {}, which the game never shows",
+                           alphabet.upper(g), i + 1, j + 1);
+        }
+    }
+    for l in 0..LETTERS {
+        let letter = b'a' + l as u8;
+        let places = (0..N).filter(|&i| match c.greens[i] {
+            Some(g) => g == letter,
+            None => c.excluded[i] & (1 << l) == 0,
+        }).count();
+        if c.min[l] as usize > places {
+            let left = match places {
+                0 => "no place".to_string(),
+                1 => "only 1 place".to_string(),
+                n => format!("only {} places", n),
+            };
+            return format!("it shows {} {}, and leaves {} for them", c.min[l], alphabet.upper(letter), left);
+        }
+    }
+    format!("no {} letters fit it", N)
+}
+
+// The first clue of 'earlier' which 'new' goes against, said as what follows "guess n".
+fn clash<const N: usize>(earlier: &Constraints<N>, new: &Constraints<N>) -> Option<String> {
+    let alphabet = alphabet();
+    for i in 0..N {
+        match (earlier.greens[i], new.greens[i]) {
+            (Some(e), Some(n)) if e != n => return Some(format!("had {} green at {}, and this has {} there",
+                                                                alphabet.upper(e), i + 1, alphabet.upper(n))),
+            (Some(e), None) if new.excluded[i] & bit(e) != 0 => return Some(format!(
+                "had {} green at {}, and this rules it out there", alphabet.upper(e), i + 1)),
+            (None, Some(n)) if earlier.excluded[i] & bit(n) != 0 => return Some(format!(
+                "ruled {} out at {}, and this has it green there", alphabet.upper(n), i + 1)),
+            _ => {}
+        }
+    }
+    for l in 0..LETTERS {
+        let c = alphabet.upper(b'a' + l as u8);
+        if new.min[l] > earlier.max[l] {
+            return Some(match earlier.max[l] {
+                0 => format!("showed no {}, and this shows {}", c, new.min[l]),
+                max => format!("showed at most {} {}, and this shows {}", max, c, new.min[l]),
+            });
+        }
+        if earlier.min[l] > new.max[l] {
+            return Some(match new.max[l] {
+                0 => format!("showed {} {}, and this shows none", earlier.min[l], c),
+                max => format!("showed {} {}, and this at most {}", earlier.min[l], c, max),
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test_constraints {
+    use super::*;
+    use crate::{parse_guess, parse_result, score};
+
+    #[test]
+    fn test_constraints() {
+        let mut c = Constraints::new();
+        assert_eq!((c.dead_letters(), c.required_letters()), (0, 0));
+
+        let guess = parse_guess("geese").unwrap();
+        // The extra e's are grey, but the green e keeps 'e' alive.
+        c.add(guess, score("those", "geese"));
+        assert_eq!(c.dead_letters(), bit(b'g'));
+        assert_eq!(c.required_letters(), bit(b'e') | bit(b's'));
+        assert_eq!((c.min_count(b'e'), c.max_count(b'e')), (1, 1));
+        assert_eq!(c.max_count(b's'), 5);
+        assert_eq!(c.green_mask(), [None, None, None, Some(b's'), Some(b'e')]);
+        assert_eq!(c.excluded(1), bit(b'e'));
+
+        let c2 = Constraints::from_history(&[(guess, score("those", "geese")),
+                                             (parse_guess("shoot").unwrap(), score("those", "shoot"))]);
+        assert_eq!(c2.required_letters(), bit(b'e') | bit(b's') | bit(b'h') | bit(b'o') | bit(b't'));
+        assert_eq!(c2.max_count(b'o'), 1);
+        assert_eq!(c2.excluded(0), bit(b'g') | bit(b's'));
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(Constraints::<5>::new().summary(), ["Nothing known yet"]);
+        let c = Constraints::from_history(&[obs("geese", "00022")]);
+        assert_eq!(c.summary(), ["Greens: _ _ _ S E", "Present: E (exactly 1), S", "Absent: G", "Not at: 2 E, 3 E"]);
+        let c = Constraints::from_history(&[obs("geese", "00022"), obs("shoot", "12201")]);
+        assert_eq!(c.summary(), ["Greens: _ H O S E", "Present: E (exactly 1), H, O (exactly 1), S, T", "Absent: G",
+                                 "Not at: 1 S"]);
+        let c = Constraints::from_history(&[obs("error", "11112")]);
+        assert_eq!(c.summary(), ["Greens: _ _ _ _ R", "Present: E, O, R (at least 3)", "Not at: 1 E, 2 R, 3 R, 4 O"]);
+    }
+
+    #[test]
+    fn test_allows_hard() {
+        let c = Constraints::from_history(&[obs("geese", "00022")]);
+        assert!(c.allows_hard("those"));
+        assert!(c.allows_hard("sense"));
+        // Yellows may move, and greys may be reused.
+        assert!(!c.allows_hard("esses"));
+        let c = Constraints::from_history(&[obs("rebut", "11000")]);
+        assert!(c.allows_hard("error"));
+        assert!(c.allows_hard("rerun") && c.allows_hard("greed"));
+        assert!(!c.allows_hard("cigar"));
+    }
+
+    fn obs(guess: &str, result: &str) -> Observation {
+        (parse_guess(guess).unwrap(), parse_result(result).unwrap())
+    }
+
+    #[test]
+    fn test_consistent() {
+        let those = |guess| (parse_guess(guess).unwrap(), score("those", guess));
+        assert!(consistent(&those("geese"), &those("shoot")));
+        // 's' can't be both absent and green.
+        assert!(!consistent(&obs("salty", "00000"), &obs("sissy", "20000")));
+        // Two different greens in one position.
+        assert!(!consistent(&obs("crane", "20000"), &obs("brine", "20000")));
+        // Six distinct required letters.
+        assert!(!consistent(&obs("abcde", "11111"), &obs("fghij", "10000")));
+        // 'a' is required, but ruled out of every position.
+        assert!(!consistent(&obs("abcde", "10000"), &obs("xaaaa", "01111")));
+    }
+
+    #[test]
+    fn test_find_conflict() {
+        let history = [obs("crane", "00102"), obs("sloth", "00000")];
+        assert_eq!(find_conflict(&history, obs("abide", "22222")), None);
+        let conflict = find_conflict(&history, obs("width", "00001")).unwrap();
+        assert_eq!(conflict.index, Some(1));
+        assert_eq!(conflict.reason, "guess 2 showed no H, and this shows 1");
+        assert_eq!(find_conflict(&history, obs("plane", "00001")).unwrap().reason,
+                   "guess 1 had E green at 5, and this rules it out there");
+        assert_eq!(find_conflict(&history, obs("edict", "00000")).unwrap().reason,
+                   "guess 1 showed 1 E, and this shows none");
+        let conflict = find_conflict(&history, obs("geese", "00100")).unwrap();
+        assert_eq!(conflict.index, None);
+        assert_eq!(conflict.reason, "it has E yellow at 3 after a grey one at 2, which the game never shows");
+        assert_eq!(find_conflict(&[], obs("aaaaa", "11111")).unwrap().reason,
+                   "it shows 5 A, and leaves no place for them");
+        assert_eq!(find_conflict(&[], obs("bbbxx", "11100")).unwrap().reason,
+                   "it shows 3 B, and leaves only 2 places for them");
+        // Six letters required, two by each of three observations which could each pair up.
+        let history = [obs("abqqq", "11000"), obs("cdvvv", "11000")];
+        assert_eq!(find_conflict(&history, obs("efwww", "11000")).map(|c| c.index), Some(None));
+    }
+
+    #[test]
+    fn test_satisfiable() {
+        assert!(Constraints::<5>::new().is_satisfiable());
+        // A grey 'e' can't precede a yellow one.
+        assert!(!Constraints::from_history(&[obs("geese", "00100")]).is_satisfiable());
+        assert!(Constraints::from_history(&[obs("geese", "01000")]).is_satisfiable());
+
+        let mut c = Constraints::from_history(&[obs("crane", "00102")]);
+        assert!(c.compatible(parse_guess("aside").unwrap(), parse_result("22222").unwrap()));
+        assert!(!c.compatible(parse_guess("crane").unwrap(), parse_result("22222").unwrap()));
+        c.merge(&Constraints::from_history(&[obs("aside", "22222")]));
+        assert!(c.is_satisfiable());
+        assert_eq!(c.green_mask(), [Some(b'a'), Some(b's'), Some(b'i'), Some(b'd'), Some(b'e')]);
+        c.merge(&Constraints::from_history(&[obs("amend", "20000")]));
+        assert!(!c.is_satisfiable());
+    }
+}
@@ -0,0 +1,105 @@
+// Wordle's daily puzzles.  The builtin answer list is the original game's, in the order it
+// used them: one a day, from puzzle 0 on 2021-06-19.  The New York Times has since edited and
+// reordered its list, so recent days may differ from its site.
+use crate::ANSW_LIST;
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::SystemTime;
+
+/// A calendar date, as days since 1970-01-01.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    days: i64,
+}
+
+impl Date {
+    /// The date of puzzle 0.
+    pub const FIRST_PUZZLE: Date = Date { days: 18797 };
+
+    /// The date 'day' 'month' 'year', if there is one.
+    pub fn from_ymd(year: i64, month: u32, day: u32) -> Option<Self> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let month_days = [31, if leap { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        if !(1..=12).contains(&month) || day < 1 || day > month_days[month as usize - 1] {
+            return None;
+        }
+        // Counted in years from March, so the leap day ends the year.
+        let (y, m) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+        let era = y.div_euclid(400);
+        let year_of_era = y.rem_euclid(400);
+        let day_of_year = (153 * m as i64 + 2) / 5 + day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Some(Self { days: era * 146097 + day_of_era - 719468 })
+    }
+
+    /// Parse "YYYY-MM-DD".
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.splitn(3, '-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+        Self::from_ymd(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Today, in UTC.
+    pub fn today() -> Self {
+        let secs = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Self { days: (secs / 86400) as i64 }
+    }
+
+    /// The year, month and day.
+    pub fn ymd(self) -> (i64, u32, u32) {
+        let days = self.days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let m = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * m + 2) / 5 + 1) as u32;
+        let month = if m < 10 { m + 3 } else { m - 9 } as u32;
+        let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+        (year, month, day)
+    }
+
+    /// The number of the day's puzzle, if it came after the first.
+    pub fn puzzle(self) -> Option<u32> {
+        u32::try_from(self.days - Self::FIRST_PUZZLE.days).ok()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.ymd();
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// The answer to puzzle 'puzzle', from the builtin list, which starts over after its last word.
+pub fn daily_answer(puzzle: u32) -> &'static str {
+    ANSW_LIST[puzzle as usize % ANSW_LIST.len()]
+}
+
+#[cfg(test)]
+mod test_daily {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(Date::from_ymd(1970, 1, 1), Some(Date { days: 0 }));
+        assert_eq!(Date::parse("2021-06-19"), Some(Date::FIRST_PUZZLE));
+        assert_eq!(Date::parse("2024-02-29").map(|d| d.to_string()).as_deref(), Some("2024-02-29"));
+        assert_eq!(Date::parse("2023-02-29"), None);
+        assert_eq!(Date::parse("2021-6-19"), None);
+        assert_eq!(Date::parse("1969-12-31").map(Date::ymd), Some((1969, 12, 31)));
+        assert_eq!(Date::parse("2022-01-01").and_then(Date::puzzle), Some(196));
+        assert_eq!(Date::parse("2021-06-18").and_then(Date::puzzle), None);
+    }
+
+    #[test]
+    fn test_daily_answer() {
+        assert_eq!(daily_answer(0), "cigar");
+        assert_eq!(daily_answer(1), "rebut");
+        assert_eq!(daily_answer(ANSW_LIST.len() as u32), "cigar");
+    }
+}
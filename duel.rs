@@ -0,0 +1,120 @@
+use crate::{Color, Game, MAX_GUESSES, Mode, Strategy, score_n, word_bytes_n};
+use std::cmp::Ordering;
+
+/// Who found the answer in fewer guesses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuelOutcome {
+    Player,
+    Solver,
+    Tie,
+}
+
+/// A race between a player and the solver to find the same answer, taking turns a guess at a
+/// time, player first.  Each side has MAX_GUESSES guesses.
+#[derive(Clone, Debug)]
+pub struct Duel<'a, const N: usize = 5> {
+    answer: &'a str,
+    player: Game<'a, N>,
+    solver: Game<'a, N>,
+}
+
+impl<'a, const N: usize> Duel<'a, N> {
+    pub fn new(answers: &'a [&'a str], answer: &'a str, mode: Mode) -> Self {
+        let mut player = Game::new(answers);
+        player.set_mode(mode);
+        let solver = player.clone();
+        Self { answer, player, solver }
+    }
+
+    pub fn answer(&self) -> &'a str {
+        self.answer
+    }
+
+    pub fn player(&self) -> &Game<'a, N> {
+        &self.player
+    }
+
+    pub fn solver(&self) -> &Game<'a, N> {
+        &self.solver
+    }
+
+    /// Whether the side playing 'game' may still guess.
+    fn in_play(game: &Game<N>) -> bool {
+        !game.is_solved() && game.history().len() < MAX_GUESSES
+    }
+
+    /// Whether it is the player's turn: the player guesses first each round, and a side which
+    /// has finished is skipped.
+    pub fn player_to_move(&self) -> bool {
+        Self::in_play(&self.player)
+            && (!Self::in_play(&self.solver) || self.player.history().len() <= self.solver.history().len())
+    }
+
+    pub fn is_over(&self) -> bool {
+        !Self::in_play(&self.player) && !Self::in_play(&self.solver)
+    }
+
+    /// Record the player's guess, which the caller has checked is a word of N letters, and return
+    /// its result.
+    pub fn play(&mut self, guess: &str) -> [Color; N] {
+        let result = score_n(self.answer, guess);
+        self.player.apply(word_bytes_n(guess), result);
+        result
+    }
+
+    /// The solver's turn: it opens with 'opener', then plays 'strategy' from 'guesses'.  Returns
+    /// its guess and result, or None if it has finished.
+    pub fn solver_turn(&mut self, strategy: &dyn Strategy, guesses: &[&'a str], opener: &'a str)
+        -> Option<(&'a str, [Color; N])> {
+        if !Self::in_play(&self.solver) {
+            return None;
+        }
+        let guess = if self.solver.is_new() {
+            opener
+        } else {
+            let pool = self.solver.guess_pool(guesses);
+            strategy.best_guess(self.solver.candidates(), &pool, &Default::default())?.word
+        };
+        let result = score_n(self.answer, guess);
+        self.solver.apply(word_bytes_n(guess), result);
+        Some((guess, result))
+    }
+
+    /// Who won, once the duel is over.  A side which didn't find the answer loses to one which did.
+    pub fn outcome(&self) -> Option<DuelOutcome> {
+        if !self.is_over() {
+            return None;
+        }
+        let taken = |game: &Game<N>| if game.is_solved() { game.history().len() } else { MAX_GUESSES + 1 };
+        Some(match taken(&self.player).cmp(&taken(&self.solver)) {
+            Ordering::Less => DuelOutcome::Player,
+            Ordering::Greater => DuelOutcome::Solver,
+            Ordering::Equal => DuelOutcome::Tie,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_duel {
+    use super::*;
+    use crate::{Minimax, score};
+
+    const ANSWERS: &[&str] = &["hatch", "watch", "latch", "patch", "cigar"];
+
+    #[test]
+    fn test_duel() {
+        let guesses = ["hatch", "watch", "latch", "patch", "cigar", "whelp"];
+        let mut duel = Duel::new(ANSWERS, "latch", Mode::Normal);
+        assert!(duel.player_to_move());
+        assert_eq!(duel.play("hatch"), score("latch", "hatch"));
+        assert!(!duel.player_to_move());
+        // "whelp" tells every answer apart, so the solver needs one more guess.
+        assert_eq!(duel.solver_turn(&Minimax, &guesses, "whelp").map(|(g, _)| g), Some("whelp"));
+        duel.play("latch");
+        assert_eq!(duel.outcome(), None);
+        assert_eq!(duel.solver_turn(&Minimax, &guesses, "whelp"), Some(("latch", [Color::GREEN; 5])));
+        assert!(duel.is_over());
+        assert_eq!(duel.outcome(), Some(DuelOutcome::Tie));
+        assert_eq!(duel.solver_turn(&Minimax, &guesses, "whelp"), None);
+    }
+}
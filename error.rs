@@ -0,0 +1,32 @@
+// Why the library turned down a guess, a result or an observation.
+use std::fmt;
+
+/// What's wrong with some input.  Displayed to follow the input it's about, as in
+/// "'cigars' has 6 letters, not 5".
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum WordleError {
+    /// A guess has 'found' letters, where the game's words have 'expected'.
+    InvalidLength { expected: usize, found: usize },
+    /// A guess has a character which is no lowercase letter of the alphabet().
+    InvalidCharacter(char),
+    /// A result isn't one, in any notation parse_result() takes.
+    InvalidPattern,
+    /// A guess is letters, but not a word of the guess list.
+    NotInWordList,
+    /// A guess and result no candidate left could give.
+    ContradictoryFeedback,
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WordleError::InvalidLength { expected, found } => write!(f, "has {} letters, not {}", found, expected),
+            WordleError::InvalidCharacter(c) => write!(f, "contains invalid character '{}'", c),
+            WordleError::InvalidPattern => f.write_str("is not a result, like 01202 or byg-g"),
+            WordleError::NotInWordList => f.write_str("is not in the word list"),
+            WordleError::ContradictoryFeedback => f.write_str("contradicts earlier feedback"),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
@@ -0,0 +1,249 @@
+// The solver for C, and anything which can call C: Python's ctypes, Swift and so on.  wordle.h,
+// which mkheader generates from this file, declares these functions.  Build a library to link
+// with:
+//   cargo rustc --lib --release --no-default-features --features wordle-ffi --crate-type cdylib
+// Strings are NUL-terminated, and results are written as digits, as everywhere else: 0 for grey,
+// 1 for yellow, 2 for green.  Nothing is allocated; output goes to buffers the caller provides.
+// Functions return a negative number for invalid input, or a buffer too small.
+use crate::{builtin_guesses, check_guess, check_result, fmt_result};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+// The string 's', if it's UTF-8.
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+// The 'n' strings of 'words', if they're all 5 letter words.
+unsafe fn to_words<'a>(words: *const *const c_char, n: usize) -> Option<Vec<&'a str>> {
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    if words.is_null() {
+        return None;
+    }
+    std::slice::from_raw_parts(words, n).iter().map(|&w| to_str(w).filter(|w| check_guess(w, None).is_ok())).collect()
+}
+
+// Copy 's' to the 'len' bytes at 'out', NUL-terminated, returning its length.
+unsafe fn write_str(s: &str, out: *mut c_char, len: usize) -> c_int {
+    if out.is_null() || s.len() >= len {
+        return -1;
+    }
+    std::ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, out, s.len());
+    *out.add(s.len()) = 0;
+    s.len() as c_int
+}
+
+/// Write the result of 'guess' when the answer is 'answer' to 'result', which has room for 6
+/// bytes.  Returns 0, or -1 if either isn't a 5 letter word.
+///
+/// # Safety
+/// 'answer' and 'guess' must be NUL-terminated strings, and 'result' must point to 6 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_score(answer: *const c_char, guess: *const c_char, result: *mut c_char) -> c_int {
+    match (to_str(answer), to_str(guess)) {
+        (Some(answer), Some(guess)) if check_guess(answer, None).is_ok() && check_guess(guess, None).is_ok() => {
+            write_str(&fmt_result(&crate::score(answer, guess)), result, 6).min(0)
+        }
+        _ => -1,
+    }
+}
+
+/// Write those of the 'ncandidates' strings at 'candidates' still possible after 'guess' gave
+/// 'result' to 'out', which has room for 'ncandidates' pointers.  'result' may be in any
+/// notation parse_result() takes.  Returns how many were written, or -1 if any candidate isn't
+/// a 5 letter word.
+///
+/// # Safety
+/// 'candidates' must point to 'ncandidates' NUL-terminated strings, and 'out' to room for as
+/// many pointers.  The pointers written are those of 'candidates'.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_prune(candidates: *const *const c_char, ncandidates: usize, guess: *const c_char,
+                                      result: *const c_char, out: *mut *const c_char) -> c_int {
+    let words = match to_words(candidates, ncandidates) {
+        Some(words) if ncandidates == 0 || !out.is_null() => words,
+        _ => return -1,
+    };
+    let (bguess, result) = match (to_str(guess).map(|g| check_guess(g, None)), to_str(result).map(check_result)) {
+        (Some(Ok(bguess)), Some(Ok(result))) => (bguess, result),
+        _ => return -1,
+    };
+    let mut n = 0;
+    for (i, word) in words.iter().enumerate() {
+        if !crate::prune(&[word], bguess, result).is_empty() {
+            *out.add(n) = *candidates.add(i);
+            n += 1;
+        }
+    }
+    n as c_int
+}
+
+/// Write the minimax best guess for the 'ncandidates' strings at 'candidates', from the
+/// 'nguesses' at 'guesses' or from the builtin lists if there are none, to the 'len' bytes at
+/// 'out'.  Returns its length, 0 if there are no candidates, or -1 if any word isn't a 5 letter
+/// word.
+///
+/// # Safety
+/// 'candidates' and 'guesses' must point to as many NUL-terminated strings as they're said to
+/// have, and 'out' to 'len' bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_best_guess(candidates: *const *const c_char, ncandidates: usize,
+                                           guesses: *const *const c_char, nguesses: usize,
+                                           out: *mut c_char, len: usize) -> c_int {
+    let (candidates, guesses) = match (to_words(candidates, ncandidates), to_words(guesses, nguesses)) {
+        (Some(candidates), Some(guesses)) => (candidates, guesses),
+        _ => return -1,
+    };
+    if candidates.is_empty() {
+        return 0;
+    }
+    let guesses = if guesses.is_empty() { builtin_guesses() } else { guesses };
+    match crate::best_guess(&candidates, &guesses, &Default::default()) {
+        Some(best) => write_str(best.word, out, len),
+        None => 0,
+    }
+}
+
+// The C type of 'ty', one of the Rust types the functions here take or return.
+fn c_type(ty: &str) -> String {
+    let (pointee, constant) = match (ty.strip_prefix("*const "), ty.strip_prefix("*mut ")) {
+        (Some(pointee), _) => (c_type(pointee), true),
+        (_, Some(pointee)) => (c_type(pointee), false),
+        _ => return match ty {
+            "()" => "void",
+            "c_char" => "char",
+            "c_int" => "int",
+            "usize" => "size_t",
+            _ => panic!("no C type for {}", ty),
+        }.to_string(),
+    };
+    match (pointee.ends_with('*'), constant) {
+        (true, true) => pointee + "const *",
+        (true, false) => pointee + "*",
+        (false, true) => format!("const {} *", pointee),
+        (false, false) => pointee + " *",
+    }
+}
+
+// 'name' declared as of Rust type 'ty', spaced as C is.
+fn c_decl(ty: &str, name: &str) -> String {
+    let ty = c_type(ty);
+    if ty.ends_with('*') { ty + name } else { format!("{} {}", ty, name) }
+}
+
+// The declaration of the function whose Rust signature, from its name to its return type, is
+// 'sig', wrapped at HEADER_WIDTH.
+fn c_function(sig: &str) -> String {
+    let (name, rest) = sig.split_once('(').unwrap();
+    let (params, ret) = rest.rsplit_once(')').unwrap();
+    let ret = ret.trim().strip_prefix("->").map_or("()", str::trim);
+    let mut res = c_decl(ret, name.trim()) + "(";
+    let indent = res.len();
+    let mut width = indent;
+    let params = params.split(',').map(str::trim).filter(|p| !p.is_empty()).collect::<Vec<_>>();
+    for (i, param) in params.iter().enumerate() {
+        let (name, ty) = param.split_once(':').unwrap();
+        let end = if i + 1 == params.len() { ");" } else { "," };
+        let decl = c_decl(ty.trim(), name.trim()) + end;
+        if i > 0 && width + 1 + decl.len() > HEADER_WIDTH {
+            res += &format!("\n{}", " ".repeat(indent));
+            width = indent;
+        } else if i > 0 {
+            res.push(' ');
+            width += 1;
+        }
+        width += decl.len();
+        res += &decl;
+    }
+    if params.is_empty() {
+        res += "void);";
+    }
+    res
+}
+
+// Lines of wordle.h go no wider than this.
+const HEADER_WIDTH: usize = 100;
+
+/// wordle.h: the declarations of the extern "C" functions of 'source', the text of ffi.rs, each
+/// with its doc comment up to the first blank line, as mkheader writes it.
+pub fn c_header(source: &str) -> String {
+    let mut res = String::from("/* The solver's C interface.  Generated from ffi.rs, which says how to build the library, by\n \
+                                 *   cargo run --features wordle-ffi --bin mkheader > wordle.h\n \
+                                 * ffi.rs tests that it is up to date. */\n\
+                                #ifndef WORDLE_H\n#define WORDLE_H\n\n#include <stddef.h>\n\n\
+                                #ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+    let mut doc = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if let Some(text) = line.strip_prefix("///") {
+            doc.push(text.trim());
+            continue;
+        }
+        let sig = match line.strip_prefix("pub unsafe extern \"C\" fn ").or_else(|| line.strip_prefix("pub extern \"C\" fn ")) {
+            Some(sig) => sig,
+            None if line.starts_with("#[") => continue,
+            None => {
+                doc.clear();
+                continue;
+            }
+        };
+        // The signature runs to the opening of the body.
+        let mut sig = sig.to_string();
+        while !sig.ends_with('{') {
+            sig = sig + " " + lines.next().unwrap();
+        }
+        let doc = doc.drain(..).take_while(|line| !line.is_empty()).collect::<Vec<_>>();
+        res += "\n/* ";
+        res += &doc.join("\n * ");
+        res += " */\n";
+        res += &c_function(sig.trim_end_matches('{'));
+        res.push('\n');
+    }
+    res + "\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n"
+}
+
+#[cfg(test)]
+mod test_ffi {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_ffi() {
+        let words = ["hatch", "watch", "cigar"].iter().map(|w| CString::new(*w).unwrap()).collect::<Vec<_>>();
+        let ptrs = words.iter().map(|w| w.as_ptr()).collect::<Vec<_>>();
+        let (guess, result) = (CString::new("watch").unwrap(), CString::new("02222").unwrap());
+        let mut buf = [0 as c_char; 6];
+        unsafe {
+            assert_eq!(wordle_score(ptrs[0], guess.as_ptr(), buf.as_mut_ptr()), 0);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str(), Ok("02222"));
+            assert_eq!(wordle_score(ptrs[0], result.as_ptr(), buf.as_mut_ptr()), -1);
+
+            let mut out = [std::ptr::null(); 3];
+            assert_eq!(wordle_prune(ptrs.as_ptr(), 3, guess.as_ptr(), result.as_ptr(), out.as_mut_ptr()), 1);
+            assert_eq!(out[0], ptrs[0]);
+            let short = [ptrs[0], result.as_ptr(), CString::new("cat").unwrap().into_raw()];
+            assert_eq!(wordle_prune(short.as_ptr(), 3, guess.as_ptr(), result.as_ptr(), out.as_mut_ptr()), -1);
+            assert_eq!(wordle_best_guess(short.as_ptr(), 3, std::ptr::null(), 0, buf.as_mut_ptr(), 6), -1);
+            drop(CString::from_raw(short[2] as *mut c_char));
+
+            assert_eq!(wordle_best_guess(ptrs.as_ptr(), 2, std::ptr::null(), 0, buf.as_mut_ptr(), 6), 5);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str(), Ok("hatch"));
+            assert_eq!(wordle_best_guess(ptrs.as_ptr(), 2, std::ptr::null(), 0, buf.as_mut_ptr(), 5), -1);
+            assert_eq!(wordle_best_guess(ptrs.as_ptr(), 0, ptrs.as_ptr(), 3, buf.as_mut_ptr(), 6), 0);
+        }
+    }
+
+    #[test]
+    fn test_header() {
+        assert_eq!(c_type("*const *const c_char"), "const char *const *");
+        assert_eq!(c_type("*mut *const c_char"), "const char **");
+        assert_eq!(c_function("f() -> c_int"), "int f(void);");
+        assert_eq!(c_function("g(n: usize, out: *mut c_char)"), "void g(size_t n, char *out);");
+        // Regenerate it with mkheader after changing the functions here.
+        assert!(c_header(include_str!("ffi.rs")) == include_str!("wordle.h"), "wordle.h is out of date");
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Color, Constraints, GameHistory, Mode, Observation, Pool, WordleError, guess_pool, is_eligible, narrow_pool,
+            word_list_bytes};
+use std::borrow::Cow;
+
+/// One board's game in progress, with words of 'N' letters: the candidates left, the
+/// observations which left them, and the mode and pool guesses are chosen in.
+#[derive(Clone, Debug)]
+pub struct Game<'a, const N: usize = 5> {
+    // Every answer, which the candidates are recomputed from on undo.
+    answers: &'a [&'a str],
+    // Borrowed from the answers, and their byte arrays, before the first guess.
+    candidates: Cow<'a, [&'a str]>,
+    // The candidates as byte arrays, which they are pruned by.
+    words: Cow<'a, [[u8; N]]>,
+    history: GameHistory<Observation<N>>,
+    mode: Mode,
+    pool: Pool,
+}
+
+impl<'a, const N: usize> Game<'a, N> {
+    pub fn new(answers: &'a [&'a str]) -> Self {
+        Self::resume(answers, GameHistory::new())
+    }
+
+    /// A game with the observations of 'history' already made.
+    pub fn resume(answers: &'a [&'a str], history: GameHistory<Observation<N>>) -> Self {
+        let mut res = Self { answers, candidates: Cow::Borrowed(&[]), words: Cow::Borrowed(&[]), history, mode: Mode::Normal, pool: Pool::All };
+        res.refresh();
+        res
+    }
+
+    // Recompute the candidates from the observations in effect.
+    fn refresh(&mut self) {
+        self.candidates = Cow::Borrowed(self.answers);
+        self.words = word_list_bytes(self.answers);
+        for &(guess, result) in self.history.entries() {
+            (self.candidates, self.words) = self.prune(guess, result);
+        }
+    }
+
+    // The candidates, and their byte arrays, consistent with 'guess' and its 'result'.
+    fn prune(&self, guess: [u8; N], result: [Color; N]) -> (Cow<'a, [&'a str]>, Cow<'a, [[u8; N]]>) {
+        let (candidates, words): (Vec<_>, Vec<_>) = self.candidates.iter().zip(self.words.iter())
+            .filter(|&(_, word)| is_eligible(word, guess, result)).map(|(&candidate, &word)| (candidate, word)).unzip();
+        (Cow::Owned(candidates), Cow::Owned(words))
+    }
+
+    /// Record a guess and its result, pruning the candidates.
+    pub fn apply(&mut self, guess: [u8; N], result: [Color; N]) {
+        (self.candidates, self.words) = self.prune(guess, result);
+        self.history.push(guess, result);
+    }
+
+    /// As apply(), unless no candidate could give 'result', when the game is left as it was.
+    pub fn try_apply(&mut self, guess: [u8; N], result: [Color; N]) -> Result<(), WordleError> {
+        let (candidates, words) = self.prune(guess, result);
+        if candidates.is_empty() {
+            return Err(WordleError::ContradictoryFeedback);
+        }
+        (self.candidates, self.words) = (candidates, words);
+        self.history.push(guess, result);
+        Ok(())
+    }
+
+    /// Take back the last observation, if there is one.
+    pub fn undo(&mut self) -> Option<Observation<N>> {
+        let obs = self.history.undo()?;
+        self.refresh();
+        Some(obs)
+    }
+
+    /// Make again the last observation undone, if there is one and none has been made since.
+    pub fn redo(&mut self) -> Option<Observation<N>> {
+        let (guess, result) = self.history.redo()?;
+        (self.candidates, self.words) = self.prune(guess, result);
+        Some((guess, result))
+    }
+
+    /// Start over, in the same mode and pool.
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.refresh();
+    }
+
+    pub fn candidates(&self) -> &[&'a str] {
+        &self.candidates
+    }
+
+    pub fn history(&self) -> &GameHistory<Observation<N>> {
+        &self.history
+    }
+
+    /// Whether no guess has been made, so every answer is a candidate.
+    pub fn is_new(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Whether the last guess was the answer.
+    pub fn is_solved(&self) -> bool {
+        self.history.entries().last().is_some_and(|(_, result)| *result == [Color::GREEN; N])
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    pub fn pool(&self) -> Pool {
+        self.pool
+    }
+
+    pub fn set_pool(&mut self, pool: Pool) {
+        self.pool = pool;
+    }
+
+    pub fn constraints(&self) -> Constraints<N> {
+        self.history.constraints()
+    }
+
+    /// The subset of 'guesses' which may be played next in the game's mode, narrowed to the
+    /// game's pool.
+    pub fn guess_pool<'g>(&self, guesses: &[&'g str]) -> Vec<&'g str> {
+        narrow_pool(guess_pool(guesses, self.mode, &self.constraints()), &self.candidates, self.pool)
+    }
+}
+
+#[cfg(test)]
+mod test_game {
+    use super::*;
+    use crate::{parse_guess, parse_guess_n, score, score_n};
+
+    const ANSWERS: &[&str] = &["hatch", "watch", "latch", "cigar"];
+
+    #[test]
+    fn test_apply_undo() {
+        let mut game = Game::new(ANSWERS);
+        assert!(game.is_new());
+        game.apply(parse_guess("batch").unwrap(), score("watch", "batch"));
+        assert_eq!(game.candidates(), ["hatch", "watch", "latch"]);
+        game.apply(parse_guess("watch").unwrap(), score("watch", "watch"));
+        assert_eq!(game.candidates(), ["watch"]);
+        assert!(game.is_solved());
+
+        assert_eq!(game.undo().map(|(_, result)| result), Some([Color::GREEN; 5]));
+        assert_eq!(game.candidates(), ["hatch", "watch", "latch"]);
+        assert!(!game.is_solved());
+        assert!(game.redo().is_some() && game.is_solved());
+        assert_eq!(game.redo(), None);
+        assert_eq!(game.candidates(), ["watch"]);
+        game.undo();
+
+        game.set_mode(Mode::Hard);
+        assert_eq!(game.guess_pool(&["patch", "cigar"]), ["patch"]);
+        game.set_pool(Pool::Candidates);
+        assert_eq!(game.guess_pool(&["patch", "latch", "hatch"]), ["latch", "hatch"]);
+        game.reset();
+        assert_eq!(game.candidates(), ANSWERS);
+        assert_eq!(game.undo(), None);
+        assert_eq!(game.mode(), Mode::Hard);
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut game = Game::new(ANSWERS);
+        assert_eq!(game.try_apply(parse_guess("cigar").unwrap(), [Color::YELLOW; 5]),
+                   Err(WordleError::ContradictoryFeedback));
+        assert!(game.is_new());
+        assert_eq!(game.try_apply(parse_guess("cigar").unwrap(), score("cigar", "cigar")), Ok(()));
+        assert_eq!(game.candidates(), ["cigar"]);
+    }
+
+    #[test]
+    fn test_word_length() {
+        let answers = ["coat", "boat", "moat", "cost"];
+        let mut game = Game::<4>::new(&answers);
+        game.apply(parse_guess_n("goat").unwrap(), score_n("boat", "goat"));
+        assert_eq!(game.candidates(), ["coat", "boat", "moat"]);
+        assert_eq!(game.constraints().summary()[0], "Greens: _ O A T");
+        game.set_mode(Mode::Hard);
+        assert_eq!(game.guess_pool(&["cost", "moat"]), ["moat"]);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{Color, Constraints, LETTERS, MAX_GUESSES, alphabet, fmt_colored, fmt_count, fmt_result, fmt_squares, fmt_tile, letter_index, prune_n, score_n};
+
+/// A guess of 'N' letters and its result.
+pub type Observation<const N: usize = 5> = ([u8; N], [Color; N]);
+
+/// Results of a game which may have been mistyped: with those of 'entries' (indices into the
+/// history) changed to what each of 'answers' gives, the history fits that answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Correction<'a> {
+    pub entries: Vec<usize>,
+    pub answers: Vec<&'a str>,
+}
+
+/// The ordered observations of a game, with undo and redo.  Candidates are always recomputed
+/// from the observations in effect, so undo cannot leave stale state behind.  Other records of
+/// a game, such as MultiBoard's moves, may be kept in place of observations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameHistory<T = Observation> {
+    entries: Vec<T>,
+    // How many entries are in effect; those past it were undone and may be redone.
+    len: usize,
+}
+
+impl<T> Default for GameHistory<T> {
+    fn default() -> Self {
+        Self { entries: Vec::new(), len: 0 }
+    }
+}
+
+impl<T: Copy> GameHistory<T> {
+    /// Record an entry, discarding any undone ones.
+    pub fn record(&mut self, entry: T) {
+        self.entries.truncate(self.len);
+        self.entries.push(entry);
+        self.len += 1;
+    }
+
+    pub fn undo(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.entries[self.len])
+    }
+
+    pub fn redo(&mut self) -> Option<T> {
+        let entry = *self.entries.get(self.len)?;
+        self.len += 1;
+        Some(entry)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The entries in effect, in order.
+    pub fn entries(&self) -> &[T] {
+        &self.entries[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> GameHistory<Observation<N>> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an observation, discarding any undone ones.
+    pub fn push(&mut self, guess: [u8; N], result: [Color; N]) {
+        self.record((guess, result));
+    }
+
+    /// The 'answers' consistent with every observation in effect.
+    pub fn candidates<'a>(&self, answers: &[&'a str]) -> Vec<&'a str> {
+        let mut res = answers.to_vec();
+        for &(guess, result) in self.entries() {
+            res = prune_n(&res, guess, result);
+        }
+        res
+    }
+
+    /// The game as the board it is played on, a line per row: the guesses so far, then empty
+    /// rows up to MAX_GUESSES.  Without 'color', a guess is its word and 0/1/2 result.
+    pub fn board(&self, color: bool) -> Vec<String> {
+        let mut rows = self.entries().iter().map(|(guess, result)| if color {
+            fmt_colored(guess, result)
+        } else {
+            format!("{} {}", String::from_utf8_lossy(guess), fmt_result(result))
+        }).collect::<Vec<_>>();
+        // Unplayed tiles are unlit, so they can't be mistaken for greys.
+        let empty = if color { " \u{b7} ".repeat(N) } else { "_".repeat(N) };
+        rows.resize(rows.len().max(MAX_GUESSES), empty);
+        rows
+    }
+
+    /// The best color each letter has shown, indexed from 'a', or None for letters not played.
+    pub fn keyboard(&self) -> [Option<Color>; LETTERS] {
+        let mut res = [None; LETTERS];
+        for (guess, result) in self.entries() {
+            for (&g, &c) in guess.iter().zip(result) {
+                let key = &mut res[letter_index(g)];
+                if key.is_none_or(|k: Color| c as u8 > k as u8) {
+                    *key = Some(c);
+                }
+            }
+        }
+        res
+    }
+
+    /// The letters laid out as the game's keyboard, a line per row, each key showing the best
+    /// color it has shown.  Without 'color', greens are [a], yellows (a), greys -, and letters
+    /// not played are themselves.  Letters an alphabet adds to a to z get a row of their own.
+    pub fn keyboard_rows(&self, color: bool) -> Vec<String> {
+        let keys = self.keyboard();
+        let alphabet = alphabet();
+        let mut rows = ["qwertyuiop", "asdfghjkl", "zxcvbnm"].iter().map(|r| r.bytes().collect()).collect::<Vec<Vec<_>>>();
+        rows.push(alphabet.extra_letters().collect());
+        rows.iter().filter(|letters| !letters.is_empty()).enumerate().map(|(row, letters)| {
+            let indent = " ".repeat(row * 2);
+            indent + &letters.iter().map(|&l| {
+                let key = keys[letter_index(l)];
+                match key {
+                    _ if color => fmt_tile(l, key),
+                    Some(Color::GREEN) => format!("[{}]", alphabet.letter(l)),
+                    Some(Color::YELLOW) => format!("({})", alphabet.letter(l)),
+                    Some(Color::GREY) => " - ".to_string(),
+                    None => format!(" {} ", alphabet.letter(l)),
+                }
+            }).collect::<String>()
+        }).collect()
+    }
+
+    /// The game as the emoji grid Wordle shares: a "Wordle <day> <guesses>/6" header, with X for
+    /// a game not solved and * for hard mode, then a row of squares per guess.
+    pub fn share_grid(&self, day: Option<u32>, hard: bool) -> String {
+        let solved = self.entries().last().is_some_and(|(_, result)| *result == [Color::GREEN; N]);
+        let score = if solved { self.len.to_string() } else { "X".to_string() };
+        let mut res = match day {
+            Some(day) => format!("Wordle {} {}/{}", fmt_count(day as usize), score, MAX_GUESSES),
+            None => format!("Wordle {}/{}", score, MAX_GUESSES),
+        };
+        if hard {
+            res.push('*');
+        }
+        res.push_str("\n\n");
+        for (_, result) in self.entries() {
+            res.push_str(&fmt_squares(result));
+            res.push('\n');
+        }
+        res
+    }
+
+    /// The fewest results to change for some of 'answers' to fit every observation in effect, as
+    /// a Correction per set of entries which would do, most answers first.  Empty if an answer
+    /// fits already.
+    pub fn corrections<'a>(&self, answers: &[&'a str]) -> Vec<Correction<'a>> {
+        let misfits = answers.iter().map(|&answ| {
+            let misfit = self.entries().iter().enumerate()
+                .filter(|(_, (guess, result))| score_n(answ, std::str::from_utf8(guess).unwrap()) != *result)
+                .map(|(i, _)| i).collect::<Vec<_>>();
+            (answ, misfit)
+        }).collect::<Vec<_>>();
+        let fewest = match misfits.iter().map(|(_, m)| m.len()).min() {
+            Some(fewest) if fewest > 0 => fewest,
+            _ => return Vec::new(),
+        };
+        let mut res: Vec<Correction> = Vec::new();
+        for (answ, entries) in misfits.into_iter().filter(|(_, m)| m.len() == fewest) {
+            match res.iter_mut().find(|c| c.entries == entries) {
+                Some(c) => c.answers.push(answ),
+                None => res.push(Correction { entries, answers: vec![answ] }),
+            }
+        }
+        res.sort_by_key(|c| std::cmp::Reverse(c.answers.len()));
+        res
+    }
+
+    /// What the observations in effect tell us about the answer's letters.
+    pub fn constraints(&self) -> Constraints<N> {
+        Constraints::from_history(self.entries())
+    }
+}
+
+#[cfg(test)]
+mod test_history {
+    use super::*;
+    use crate::{parse_guess, score};
+
+    const ANSWERS: &[&str] = &["hatch", "watch", "latch", "cigar"];
+
+    fn obs(answ: &str, guess: &str) -> Observation {
+        (parse_guess(guess).unwrap(), score(answ, guess))
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut h = GameHistory::new();
+        assert_eq!(h.undo(), None);
+        let (g1, r1) = obs("watch", "batch");
+        let (g2, r2) = obs("watch", "whelp");
+        h.push(g1, r1);
+        h.push(g2, r2);
+        assert_eq!(h.candidates(ANSWERS), ["watch"]);
+
+        assert_eq!(h.undo(), Some((g2, r2)));
+        assert_eq!(h.entries(), [(g1, r1)]);
+        assert_eq!(h.candidates(ANSWERS), ["hatch", "watch", "latch"]);
+        assert_eq!(h.redo(), Some((g2, r2)));
+        assert_eq!(h.redo(), None);
+        assert_eq!(h.len(), 2);
+
+        // A new observation discards the undone ones.
+        h.undo();
+        let (g3, r3) = obs("latch", "whelp");
+        h.push(g3, r3);
+        assert_eq!(h.redo(), None);
+        assert_eq!(h.candidates(ANSWERS), ["latch"]);
+        assert_eq!(h.constraints(), Constraints::from_history(&[(g1, r1), (g3, r3)]));
+
+        h.clear();
+        assert!(h.is_empty());
+        assert_eq!(h.candidates(ANSWERS), ANSWERS);
+    }
+
+    #[test]
+    fn test_corrections() {
+        let mut h = GameHistory::new();
+        let (g, r) = obs("watch", "batch");
+        h.push(g, r);
+        assert_eq!(h.corrections(ANSWERS), []);
+        h.push(parse_guess("cigar").unwrap(), [Color::GREEN; 5]);
+        assert!(h.candidates(ANSWERS).is_empty());
+        assert_eq!(h.corrections(ANSWERS), [Correction { entries: vec![1], answers: vec!["hatch", "watch", "latch"] },
+                                            Correction { entries: vec![0], answers: vec!["cigar"] }]);
+    }
+
+    #[test]
+    fn test_board() {
+        let mut h = GameHistory::new();
+        let (g, r) = obs("watch", "batch");
+        h.push(g, r);
+        assert_eq!(h.board(false), ["batch 02222", "_____", "_____", "_____", "_____", "_____"]);
+        assert!(h.board(true)[0].contains("\x1b[1;97;42m A \x1b[0m"));
+        h.push(parse_guess("watch").unwrap(), [Color::GREEN; 5]);
+        assert_eq!(h.share_grid(Some(1234), true),
+                   "Wordle 1,234 2/6*\n\n\u{2b1b}\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}\n\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}\n");
+        h.undo();
+        assert!(h.share_grid(None, false).starts_with("Wordle X/6\n"));
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let mut h = GameHistory::new();
+        let (g, r) = obs("those", "geese");
+        h.push(g, r);
+        let (g, r) = obs("those", "hoist");
+        h.push(g, r);
+        let keys = h.keyboard();
+        // The e's of "geese" are grey but for the last, so its best is green.
+        assert_eq!((keys[letter_index(b'e')], keys[letter_index(b'g')]), (Some(Color::GREEN), Some(Color::GREY)));
+        assert_eq!((keys[letter_index(b's')], keys[letter_index(b'z')]), (Some(Color::GREEN), None));
+        assert_eq!(h.keyboard_rows(false), [
+            " q  w [e] r (t) y  u  - (o) p ",
+            "   a [s] d  f  - (h) j  k  l ",
+            "     z  x  c  v  b  n  m ",
+        ]);
+        assert!(h.keyboard_rows(true)[2].contains("\x1b[1;30;47m Z \x1b[0m"));
+    }
+}
@@ -0,0 +1,429 @@
+// The one JSON form of the crate's types, which the REPL's save and load, --json, the server and
+// other tools all read and write: Json values, parsed and printed, and ToJson and FromJson in
+// place of serde's derives, which this crate does without.  Types with private state, such as
+// DecisionTree and SimReport, implement them in their own modules.
+use crate::{Color, Game, GameHistory, Observation, alphabet, check_guess_n, check_result_n, fmt_result, json_string};
+use std::convert::TryInto;
+use std::fmt;
+
+/// A JSON value.  Objects keep their fields in order.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+// Arrays and objects nested deeper than this are refused, so a request can't exhaust the stack.
+const MAX_DEPTH: usize = 128;
+
+impl Json {
+    /// The value 'text' holds, with nothing but whitespace after it.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut parser = Parser { text, pos: 0 };
+        let value = parser.value(0)?;
+        parser.skip_space();
+        if parser.pos < text.len() {
+            return Err(parser.error("the end"));
+        }
+        Ok(value)
+    }
+
+    /// The value of field 'key', if this is an object with one.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Field 'key' as a T, or why it can't be.
+    pub fn field<T: FromJson>(&self, key: &str) -> Result<T, String> {
+        T::from_json(self.get(key).ok_or_else(|| format!("no '{}'", key))?).map_err(|e| format!("'{}' {}", key, e))
+    }
+
+    /// As field(), but None for a field not there.
+    pub fn opt_field<T: FromJson>(&self, key: &str) -> Result<Option<T>, String> {
+        self.get(key).map(|_| self.field(key)).transpose()
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The number, if it's a whole one a u64 holds exactly.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Json::Num(n) if n >= 0.0 && n.fract() == 0.0 && n < (1u64 << 53) as f64 => Some(n as u64),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// An object of 'kind', as JsonObject::new() starts them, with 'fields' after its "type".
+    pub fn object(kind: &str, fields: Vec<(&str, Json)>) -> Self {
+        let kind = ("type".to_string(), Json::Str(kind.to_string()));
+        Json::Object(std::iter::once(kind).chain(fields.into_iter().map(|(k, v)| (k.to_string(), v))).collect())
+    }
+}
+
+impl fmt::Display for Json {
+    /// The value as one line of JSON, written as JsonObject writes them.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Num(n) if !n.is_finite() => f.write_str("null"),
+            Json::Num(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Json::Num(n) => write!(f, "{}", n),
+            Json::Str(s) => f.write_str(&json_string(s)),
+            Json::Array(values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    write!(f, "{}{}", if i > 0 { "," } else { "" }, value)?;
+                }
+                f.write_str("]")
+            }
+            Json::Object(fields) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    write!(f, "{}{}:{}", if i > 0 { "," } else { "" }, json_string(key), value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+struct Parser<'t> {
+    text: &'t str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &str) -> String {
+        format!("expected {} at byte {}", expected, self.pos)
+    }
+
+    fn skip_space(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_space();
+        let found = self.text[self.pos..].starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Json, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("nothing nested over {} deep at byte {}", MAX_DEPTH, self.pos));
+        }
+        self.skip_space();
+        match self.text[self.pos..].chars().next() {
+            Some('{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                if self.eat("}") {
+                    return Ok(Json::Object(fields));
+                }
+                loop {
+                    self.skip_space();
+                    let key = self.string()?;
+                    if !self.eat(":") {
+                        return Err(self.error("':'"));
+                    }
+                    fields.push((key, self.value(depth + 1)?));
+                    if self.eat("}") {
+                        return Ok(Json::Object(fields));
+                    }
+                    if !self.eat(",") {
+                        return Err(self.error("',' or '}'"));
+                    }
+                }
+            }
+            Some('[') => {
+                self.pos += 1;
+                let mut values = Vec::new();
+                if self.eat("]") {
+                    return Ok(Json::Array(values));
+                }
+                loop {
+                    values.push(self.value(depth + 1)?);
+                    if self.eat("]") {
+                        return Ok(Json::Array(values));
+                    }
+                    if !self.eat(",") {
+                        return Err(self.error("',' or ']'"));
+                    }
+                }
+            }
+            Some('"') => Ok(Json::Str(self.string()?)),
+            Some('-' | '0'..='9') => {
+                let rest = &self.text[self.pos..];
+                let len = rest.find(|c: char| !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9')).unwrap_or(rest.len());
+                let n = rest[..len].parse().map_err(|_| self.error("a number"))?;
+                self.pos += len;
+                Ok(Json::Num(n))
+            }
+            _ if self.eat("null") => Ok(Json::Null),
+            _ if self.eat("true") => Ok(Json::Bool(true)),
+            _ if self.eat("false") => Ok(Json::Bool(false)),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    // A string, from its opening quote.
+    fn string(&mut self) -> Result<String, String> {
+        if !self.text[self.pos..].starts_with('"') {
+            return Err(self.error("a string"));
+        }
+        let mut res = String::new();
+        let mut chars = self.text[self.pos + 1..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 2;
+                    return Ok(res);
+                }
+                '\\' => res.push(match chars.next().map(|(_, c)| c) {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('u') => {
+                        let unit = hex_unit(&mut chars).ok_or_else(|| self.error("4 hex digits"))?;
+                        // A character past the BMP is a surrogate pair, of which this is the first.
+                        let code = match unit {
+                            0xd800..=0xdbff if chars.next().map(|(_, c)| c) == Some('\\')
+                                && chars.next().map(|(_, c)| c) == Some('u') => {
+                                let low = hex_unit(&mut chars).filter(|low| (0xdc00..=0xdfff).contains(low))
+                                    .ok_or_else(|| self.error("a low surrogate"))?;
+                                0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00)
+                            }
+                            _ => unit,
+                        };
+                        char::from_u32(code).ok_or_else(|| self.error("a character"))?
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => c,
+                    _ => return Err(self.error("an escape")),
+                }),
+                c if (c as u32) < 0x20 => return Err(self.error("no control characters in a string")),
+                c => res.push(c),
+            }
+        }
+        Err(self.error("the end of the string"))
+    }
+}
+
+// The UTF-16 code unit of a \u escape's 4 hex digits.
+fn hex_unit(chars: &mut std::str::CharIndices) -> Option<u32> {
+    let hex = (0..4).map(|_| chars.next().map(|(_, c)| c)).collect::<Option<String>>()?;
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+/// A value with a JSON form.
+pub trait ToJson {
+    fn to_json(&self) -> Json;
+}
+
+/// A value which can be read back from its JSON form, or says why not.
+pub trait FromJson: Sized {
+    fn from_json(json: &Json) -> Result<Self, String>;
+}
+
+impl ToJson for Json {
+    fn to_json(&self) -> Json {
+        self.clone()
+    }
+}
+
+impl FromJson for Json {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        Ok(json.clone())
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> Json {
+        Json::Str(self.to_string())
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> Json {
+        Json::Str(self.clone())
+    }
+}
+
+impl FromJson for String {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        json.as_str().map(str::to_string).ok_or_else(|| "must be a string".to_string())
+    }
+}
+
+impl ToJson for usize {
+    fn to_json(&self) -> Json {
+        Json::Num(*self as f64)
+    }
+}
+
+impl FromJson for usize {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        json.as_u64().and_then(|n| n.try_into().ok()).ok_or_else(|| "must be a whole number".to_string())
+    }
+}
+
+impl FromJson for u64 {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        json.as_u64().ok_or_else(|| "must be a whole number".to_string())
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> Json {
+        Json::Array(self.iter().map(T::to_json).collect())
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        json.as_array().ok_or_else(|| "must be an array".to_string())?.iter().map(T::from_json).collect()
+    }
+}
+
+/// A color is its digit: 0 for grey, 1 for yellow, 2 for green.
+impl ToJson for Color {
+    fn to_json(&self) -> Json {
+        Json::Num(*self as u8 as f64)
+    }
+}
+
+impl FromJson for Color {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        match json.as_u64() {
+            Some(0) => Ok(Color::GREY),
+            Some(1) => Ok(Color::YELLOW),
+            Some(2) => Ok(Color::GREEN),
+            _ => Err("must be 0, 1 or 2".to_string()),
+        }
+    }
+}
+
+/// A result is a string of digits, as fmt_result() writes it.
+impl<const N: usize> ToJson for [Color; N] {
+    fn to_json(&self) -> Json {
+        Json::Str(fmt_result(self))
+    }
+}
+
+impl<const N: usize> FromJson for [Color; N] {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        check_result_n(json.as_str().ok_or("must be a result")?).map_err(|e| e.to_string())
+    }
+}
+
+/// A game's observations are its "guesses", as their letters, and their "results".
+impl<const N: usize> ToJson for GameHistory<Observation<N>> {
+    fn to_json(&self) -> Json {
+        Json::object("history", history_fields(self))
+    }
+}
+
+fn history_fields<const N: usize>(history: &GameHistory<Observation<N>>) -> Vec<(&'static str, Json)> {
+    let alphabet = alphabet();
+    let guesses = history.entries().iter().map(|(g, _)| alphabet.decode(&String::from_utf8_lossy(g))).collect::<Vec<_>>();
+    let results = history.entries().iter().map(|&(_, r)| r).collect::<Vec<_>>();
+    vec![("guesses", guesses.to_json()), ("results", results.to_json())]
+}
+
+impl<const N: usize> FromJson for GameHistory<Observation<N>> {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        let (guesses, results) = (json.field::<Vec<String>>("guesses")?, json.field::<Vec<String>>("results")?);
+        if guesses.len() != results.len() {
+            return Err("a different number of guesses and results".to_string());
+        }
+        let mut res = GameHistory::new();
+        for (guess, result) in guesses.iter().zip(&results) {
+            let bguess = check_guess_n(guess, None).map_err(|e| format!("'{}' {}", guess, e))?;
+            res.push(bguess, check_result_n(result).map_err(|e| format!("'{}' {}", result, e))?);
+        }
+        Ok(res)
+    }
+}
+
+/// A game is its history, with its mode and pool before it and the candidates left after it.
+/// Reading one back takes its answer list, so parse_game_json() does.
+impl<const N: usize> ToJson for Game<'_, N> {
+    fn to_json(&self) -> Json {
+        let alphabet = alphabet();
+        let candidates = self.candidates().iter().map(|c| alphabet.decode(c)).collect::<Vec<_>>();
+        let mut fields = vec![("mode", self.mode().to_string().to_json()), ("pool", self.pool().to_string().to_json())];
+        fields.extend(history_fields(self.history()));
+        fields.push(("candidates", candidates.to_json()));
+        Json::object("game", fields)
+    }
+}
+
+#[cfg(test)]
+mod test_json {
+    use super::*;
+    use crate::{parse_guess, score, score_n};
+
+    #[test]
+    fn test_parse() {
+        let text = r#" {"a": [1, -2.5e1, "x\"é😀", true, null], "b" : {}, "c":[]} "#;
+        let json = Json::parse(text).unwrap();
+        assert_eq!(json.get("a").unwrap().as_array().unwrap(),
+                   [Json::Num(1.0), Json::Num(-25.0), Json::Str("x\"é\u{1f600}".to_string()), Json::Bool(true), Json::Null]);
+        assert_eq!(json.to_string(), r#"{"a":[1,-25,"x\"é😀",true,null],"b":{},"c":[]}"#);
+        assert_eq!(Json::parse(&json.to_string()), Ok(json.clone()));
+        assert_eq!(json.field::<Vec<String>>("c"), Ok(vec![]));
+        assert_eq!(json.field::<Vec<String>>("a").unwrap_err(), "'a' must be a string");
+        assert_eq!(json.opt_field::<usize>("z"), Ok(None));
+        assert_eq!(Json::Num(0.5).to_string(), "0.5");
+        assert_eq!(Json::Num(f64::NAN).to_string(), "null");
+
+        assert_eq!(Json::parse(r#"{"a" 1}"#).unwrap_err(), "expected ':' at byte 5");
+        assert!(Json::parse("[1,]").is_err() && Json::parse("[1] 2").is_err() && Json::parse("\"a").is_err());
+        assert!(Json::parse(&"[".repeat(MAX_DEPTH + 2)).unwrap_err().starts_with("nothing nested"));
+    }
+
+    #[test]
+    fn test_types() {
+        assert_eq!(Color::YELLOW.to_json(), Json::Num(1.0));
+        assert_eq!(Color::from_json(&Json::Num(2.0)), Ok(Color::GREEN));
+        assert!(Color::from_json(&Json::Num(3.0)).is_err());
+        let result = score("watch", "batch");
+        assert_eq!(result.to_json(), Json::Str("02222".to_string()));
+        assert_eq!(<[Color; 5]>::from_json(&result.to_json()), Ok(result));
+        assert!(<[Color; 5]>::from_json(&Json::Str("0222".to_string())).is_err());
+        assert_eq!(<[Color; 4]>::from_json(&Json::Str("0222".to_string())), Ok(score_n("bash", "cash")));
+
+        let mut history = GameHistory::new();
+        history.push(parse_guess("batch").unwrap(), score("watch", "batch"));
+        let json = history.to_json();
+        assert_eq!(json.to_string(), r#"{"type":"history","guesses":["batch"],"results":["02222"]}"#);
+        assert_eq!(GameHistory::from_json(&json), Ok(history));
+        let bad = Json::parse(r#"{"guesses":["bat"],"results":["022"]}"#).unwrap();
+        assert_eq!(GameHistory::<Observation>::from_json(&bad).unwrap_err(), "'bat' has 3 letters, not 5");
+        assert_eq!(GameHistory::<Observation<3>>::from_json(&bad).unwrap().entries().len(), 1);
+    }
+}
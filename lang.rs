@@ -0,0 +1,229 @@
+// Wordle in other languages.  Words are kept a byte a letter, so the letters an alphabet has
+// beyond a to z are stored as the bytes after 'z', and only shown as themselves.
+use crate::paths::xdg_path;
+use crate::{WordList, load_lists, load_lists_with};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// Set once from the command line, before any output.
+static ALPHABET: OnceLock<Alphabet> = OnceLock::new();
+
+/// The letters of a language: a to z, and up to MAX_EXTRA more, such as ñ or ü.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alphabet {
+    extra: &'static [char],
+}
+
+impl Alphabet {
+    /// How many letters past z an alphabet may have: the bytes from '{' to the end of ASCII.
+    pub const MAX_EXTRA: usize = 5;
+    /// Just a to z.
+    pub const LATIN: Alphabet = Alphabet { extra: &[] };
+
+    /// The alphabet of a to z and the lowercase letters 'extra', in their stored order.
+    pub const fn new(extra: &'static [char]) -> Self {
+        assert!(extra.len() <= Self::MAX_EXTRA, "too many extra letters");
+        Self { extra }
+    }
+
+    /// 'word' as stored, if every letter of it is a lowercase letter of the alphabet.
+    pub fn encode(&self, word: &str) -> Option<String> {
+        word.chars().map(|c| match c {
+            'a'..='z' => Some(c),
+            _ => self.extra.iter().position(|&e| e == c).map(|i| (b'{' + i as u8) as char),
+        }).collect()
+    }
+
+    /// 'text' with the alphabet's letters past z stored as bytes, and any other character beyond
+    /// ASCII as '?', so the result is a byte a character.
+    pub fn encode_text(&self, text: &str) -> String {
+        text.chars().map(|c| match self.extra.iter().position(|&e| e == c) {
+            Some(i) => (b'{' + i as u8) as char,
+            None if c.is_ascii() => c,
+            None => '?',
+        }).collect()
+    }
+
+    /// Whether 'byte' is a letter of the alphabet, as stored.
+    pub fn is_letter(&self, byte: u8) -> bool {
+        byte.is_ascii_lowercase() || self.extra_letters().contains(&byte)
+    }
+
+    /// The letter stored as 'byte', or '?' for a byte past z which is none.  Other ASCII
+    /// characters are themselves, so decode() undoes encode_text().
+    pub fn letter(&self, byte: u8) -> char {
+        match byte {
+            b'{'..=0x7f => self.extra.get((byte - b'{') as usize).copied().unwrap_or('?'),
+            _ if byte.is_ascii() => byte as char,
+            _ => '?',
+        }
+    }
+
+    /// As letter(), in capitals, where the letter has a single capital.
+    pub fn upper(&self, byte: u8) -> char {
+        let letter = self.letter(byte);
+        let mut upper = letter.to_uppercase();
+        match (upper.next(), upper.next()) {
+            (Some(c), None) => c,
+            _ => letter,
+        }
+    }
+
+    /// A stored word, as its letters.
+    pub fn decode(&self, word: &str) -> String {
+        word.bytes().map(|b| self.letter(b)).collect()
+    }
+
+    /// The bytes the letters past z are stored as.
+    pub fn extra_letters(&self) -> std::ops::Range<u8> {
+        b'{'..b'{' + self.extra.len() as u8
+    }
+
+    /// Bitmask of the letters, with bit 0 for 'a', as in Constraints.
+    pub fn mask(&self) -> u32 {
+        (1 << (26 + self.extra.len())) - 1
+    }
+}
+
+/// The alphabet words are shown in: the one given to set_alphabet(), or a to z.
+pub fn alphabet() -> Alphabet {
+    ALPHABET.get().copied().unwrap_or(Alphabet::LATIN)
+}
+
+/// Show words in 'alphabet' from now on.  Only the first call has any effect.
+pub fn set_alphabet(alphabet: Alphabet) {
+    ALPHABET.set(alphabet).ok();
+}
+
+/// A language Wordle is played in, and where its word lists are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Language {
+    /// Its ISO 639-1 code, as --lang takes.
+    pub code: &'static str,
+    pub name: &'static str,
+    pub alphabet: Alphabet,
+    /// The answer and guess lists built in, a word a line, if any.
+    pub bundled: Option<(&'static str, &'static str)>,
+}
+
+// 'answers' and 'guesses', if their feature is enabled.
+const fn bundle(enabled: bool, answers: &'static str, guesses: &'static str) -> Option<(&'static str, &'static str)> {
+    if enabled { Some((answers, guesses)) } else { None }
+}
+
+/// The languages --lang knows.  English has the builtin lists.  The others have answer lists of
+/// a few hundred common words, and guess lists of a few hundred more, from
+/// lang/<code>/answers.txt and guesses.txt, built in with the lang-<code> features; lists
+/// installed under the same names in the language's directory are used instead.  The French
+/// clones drop accents, so their words are a to z; Spanish keeps ñ but has no room for accented
+/// vowels, so words spelt with them are left out.
+pub const LANGUAGES: &[Language] = &[
+    Language { code: "en", name: "English", alphabet: Alphabet::LATIN, bundled: None },
+    Language { code: "de", name: "German", alphabet: Alphabet::new(&['ä', 'ö', 'ü', 'ß']),
+               bundled: bundle(cfg!(feature = "lang-de"), include_str!("lang/de/answers.txt"),
+                               include_str!("lang/de/guesses.txt")) },
+    Language { code: "es", name: "Spanish", alphabet: Alphabet::new(&['ñ']),
+               bundled: bundle(cfg!(feature = "lang-es"), include_str!("lang/es/answers.txt"),
+                               include_str!("lang/es/guesses.txt")) },
+    Language { code: "fr", name: "French", alphabet: Alphabet::LATIN,
+               bundled: bundle(cfg!(feature = "lang-fr"), include_str!("lang/fr/answers.txt"),
+                               include_str!("lang/fr/guesses.txt")) },
+];
+
+impl Language {
+    pub fn named(code: &str) -> Option<&'static Language> {
+        LANGUAGES.iter().find(|l| l.code == code)
+    }
+
+    /// Where the language's lists are installed: $WORDLE_LANG_DIR/<code>, else
+    /// $XDG_DATA_HOME/wordle/lang/<code>.
+    pub fn dir(&self) -> Option<PathBuf> {
+        Some(xdg_path("WORDLE_LANG_DIR", "XDG_DATA_HOME", ".local/share", "lang")?.join(self.code))
+    }
+
+    /// As load_lists(), with the language's lists instead of the builtin ones for files not given:
+    /// those installed in its directory, else its bundled ones.
+    pub fn lists(&self, answers: Option<&Path>, guesses: Option<&Path>, word_len: usize)
+        -> io::Result<(&'static [&'static str], &'static [&'static str])> {
+        if self.code == "en" {
+            return load_lists(answers, guesses, word_len);
+        }
+        let dir = self.dir();
+        let installed = |name| dir.as_ref().map(|dir| dir.join(name)).filter(|p: &PathBuf| p.exists());
+        let answers = answers.map(Path::to_path_buf).or_else(|| installed("answers.txt"));
+        // Without a guess list, only answers may be guessed.
+        let guesses = guesses.map(Path::to_path_buf).or_else(|| installed("guesses.txt"));
+        let read = |list: &str, given: bool| -> io::Result<&'static [&'static str]> {
+            Ok(if given { &[] } else { WordList::from_reader_in(list.as_bytes(), self.alphabet)?.leak() })
+        };
+        let default = match (&answers, self.bundled) {
+            (_, Some((answ, guess))) if word_len == 5 => (read(answ, answers.is_some())?, read(guess, guesses.is_some())?),
+            (Some(_), _) => (&[][..], &[][..]),
+            (None, Some(_)) => return Err(io::Error::new(io::ErrorKind::NotFound,
+                format!("the bundled {} answers are 5 letter words", self.name))),
+            (None, None) => return Err(io::Error::new(io::ErrorKind::NotFound, format!(
+                "no {} word lists; build with --features lang-{}, or install answers.txt in {}", self.name, self.code,
+                dir.map_or_else(|| "$WORDLE_LANG_DIR/".to_string() + self.code, |d| d.display().to_string())))),
+        };
+        load_lists_with(answers.as_deref(), guesses.as_deref(), word_len, self.alphabet, default)
+    }
+}
+
+#[cfg(test)]
+mod test_lang {
+    use super::*;
+
+    #[test]
+    fn test_alphabet() {
+        let es = Language::named("es").unwrap().alphabet;
+        let stored = es.encode("niños").unwrap();
+        assert_eq!(stored, "ni{os");
+        assert_eq!(es.decode(&stored), "niños");
+        assert_eq!(es.upper(b'{'), 'Ñ');
+        assert_eq!(es.encode("Niños"), None);
+        assert_eq!(Alphabet::LATIN.encode("niños"), None);
+        assert_eq!(Alphabet::LATIN.letter(b'{'), '?');
+        assert_eq!(es.mask(), (1 << 27) - 1);
+        assert_eq!(es.encode_text("g niño 2ü"), "g ni{o 2?");
+        assert_eq!(es.decode(&es.encode_text("g niño 22")), "g niño 22");
+        assert!(es.is_letter(b'{') && !es.is_letter(b'|') && !Alphabet::LATIN.is_letter(b'{'));
+
+        let de = Language::named("de").unwrap().alphabet;
+        assert_eq!(de.decode(&de.encode("straße").unwrap()), "straße");
+        assert_eq!(de.upper(de.encode("ß").unwrap().as_bytes()[0]), 'ß');
+        assert!(Language::named("xx").is_none());
+    }
+
+    #[test]
+    fn test_bundled() {
+        for lang in LANGUAGES.iter().filter(|l| l.bundled.is_some()) {
+            let (answers, guesses) = lang.bundled.unwrap();
+            let answers = WordList::from_reader_in(answers.as_bytes(), lang.alphabet).unwrap();
+            let guesses = WordList::from_reader_in(guesses.as_bytes(), lang.alphabet).unwrap();
+            assert_eq!(answers.word_len(), Some(5), "{}", lang.name);
+            assert_eq!(guesses.word_len(), Some(5), "{}", lang.name);
+            let listed = answers.words();
+            assert!(guesses.words().iter().all(|w| !listed.contains(w)), "{}", lang.name);
+        }
+        #[cfg(feature = "lang-es")]
+        {
+            let es = Language::named("es").unwrap();
+            let (answers, guesses) = es.lists(None, None, 5).unwrap();
+            assert!(guesses.contains(&es.alphabet.encode("puñal").unwrap().as_str()));
+            // Árbol may not be spelt arbol.
+            assert!(!answers.contains(&"arbol") && !guesses.contains(&"arbol"));
+            let (sueno, senor) = (es.alphabet.encode("sueño").unwrap(), es.alphabet.encode("señor").unwrap());
+            assert!(answers.contains(&sueno.as_str()));
+            assert_eq!(crate::fmt_result(&crate::score(&sueno, &senor)), "21110");
+        }
+        #[cfg(feature = "lang-de")]
+        {
+            let de = Language::named("de").unwrap();
+            let (answers, _) = de.lists(None, None, 5).unwrap();
+            let (hugel, kuste) = (de.alphabet.encode("hügel").unwrap(), de.alphabet.encode("küste").unwrap());
+            assert!(answers.contains(&hugel.as_str()) && answers.contains(&kuste.as_str()));
+            assert_eq!(crate::fmt_result(&crate::score(&hugel, &kuste)), "02001");
+        }
+    }
+}
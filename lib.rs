@@ -1,8 +1,97 @@
-use std::fmt::Debug;
+use rustc_hash::FxHashSet as HashSet;
+use std::convert::TryInto;
+use std::fmt::{self, Debug};
 use std::hash::Hash;
 
+mod adversary;
+mod analysis;
+mod cache;
+mod candidates;
+mod config;
+mod constraints;
+mod daily;
+mod duel;
+mod error;
+#[cfg(feature = "wordle-ffi")]
+mod ffi;
+mod game;
+mod history;
+mod json;
+mod lang;
+mod lookahead;
+mod multiboard;
+#[cfg(feature = "repl")]
+#[cfg(feature = "nyt")]
+mod nyt;
+mod openers;
+mod optimal;
+mod output;
+mod packed;
+mod pairs;
+mod par;
+mod paths;
+mod patterns;
+mod play;
+mod prefs;
+#[cfg(feature = "repl")]
+mod repl;
+mod results;
+mod rng;
+mod session;
+mod share;
+mod sim;
+mod solver;
+mod superpose;
+mod teach;
+mod tree;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod wordfile;
 mod wordlist;
-pub use wordlist::{ANSW_LIST, GUESS_LIST};
+pub use adversary::{absurdle_solve, adversary_result};
+pub use analysis::{Analysis, Round, analyze, analyze_game};
+pub use cache::{Cache, CacheEntry, cached_opener, choose_opener, parse_opening, strategy_opener, wordlist_checksum};
+pub use candidates::{CandidateSet, PruneTable};
+pub use config::Config;
+pub use constraints::{Conflict, Constraints, consistent, find_conflict};
+pub use daily::{Date, daily_answer};
+pub use duel::{Duel, DuelOutcome};
+pub use error::WordleError;
+#[cfg(feature = "wordle-ffi")]
+pub use ffi::c_header;
+pub use game::Game;
+pub use history::{Correction, GameHistory, Observation};
+pub use json::{FromJson, Json, ToJson};
+pub use lang::{Alphabet, LANGUAGES, Language, alphabet, set_alphabet};
+pub use lookahead::best_guess_depth;
+pub use multiboard::MultiBoard;
+#[cfg(feature = "repl")]
+#[cfg(feature = "nyt")]
+pub use nyt::{NytPuzzle, nyt_puzzle};
+pub use openers::{DORDLE_OPENER, WORDLE_OPENER};
+pub use optimal::{MAX_GUESSES, OptimalPlay, OptimalSolver};
+pub use output::{CsvWriter, JsonObject, Verbosity, fmt_count, json_array, json_output, json_string, print_guess, set_json_output, set_verbosity, verbosity};
+pub use packed::{pack_word, unpack_word, word_bytes, word_bytes_n, word_list_bytes, word_str};
+pub use pairs::{OpeningPair, rank_pairs};
+pub use patterns::{Pattern, PatternTable, partition_by_pattern, pattern_counts};
+pub use play::{play_line, play_out};
+pub use prefs::Preferences;
+#[cfg(feature = "repl")]
+pub use repl::{Editor, Input, ReplHelper, editor, raw_argument, read_secret};
+pub use results::{INITIAL_RATING, Rating, ResultsDb, Standing, elo_update, league_points};
+pub use rng::Rng;
+pub use session::{Session, game_json, parse_game_json};
+pub use share::SharedGrid;
+pub use sim::{Checkpoint, Shard, SimReport};
+pub use solver::{BestGuess, Entropy, ExpectedSize, Frequency, Minimax, Objective, STRATEGIES, Score, Strategy, best_guess, best_guesses, evaluate_all,
+                 evaluate_all_progress, rank, rank_guesses, strategy_named};
+pub use superpose::{all_pairs, prune_pairs, superposed_score};
+pub use teach::explain;
+#[cfg(feature = "embedded-tree")]
+pub use tree::embedded_tree;
+pub use tree::DecisionTree;
+pub use wordfile::{WordList, load_lists, load_lists_with};
+pub use wordlist::{ANSW_LIST, ANSW_WORDS, GUESS_LIST, GUESS_WORDS};
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
 #[repr(u8)]
@@ -12,14 +101,112 @@ pub enum Color {
     GREEN,
 }
 
-type Histogram = [i8; 26];
+/// A precomputed best first guess for the whole answer list, and the most candidates it can
+/// leave.  Searching for it takes a long time, so see mkopeners.rs and openers.rs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Opener {
+    pub word: &'static str,
+    pub worst_case: usize,
+}
+
+/// The best first guess on 'boards' boards starting with all of 'answers', and its worst case
+/// total candidates, by the default objective.  Every board starts the same, so its worst case
+/// is just 'boards' times the single board worst case.
+pub fn best_opener<'a>(answers: &[&str], guesses: &[&'a str], boards: usize) -> Option<(&'a str, usize)> {
+    let best = best_guess(answers, guesses, &Preferences::default())?;
+    Some((best.word, boards * best.worst_case))
+}
+
+/// What combining word lists into one guess pool found.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PoolStats {
+    /// Words repeated within the guess list or within the answer list, and dropped.
+    pub duplicates: usize,
+    /// Answers which were also in the guess list.
+    pub overlap: usize,
+}
+
+/// The guess pool: every guess, then every answer not already among them, each once.
+pub fn combine_lists<'a>(guesses: &[&'a str], answers: &[&'a str]) -> (Vec<&'a str>, PoolStats) {
+    let mut stats = PoolStats::default();
+    let mut seen = HashSet::default();
+    let mut pool = Vec::with_capacity(guesses.len() + answers.len());
+    for &g in guesses {
+        if seen.insert(g) {
+            pool.push(g);
+        } else {
+            stats.duplicates += 1;
+        }
+    }
+    let mut seen_answers = HashSet::default();
+    for &a in answers {
+        if !seen_answers.insert(a) {
+            stats.duplicates += 1;
+        } else if seen.insert(a) {
+            pool.push(a);
+        } else {
+            stats.overlap += 1;
+        }
+    }
+    (pool, stats)
+}
+
+/// The builtin guess pool, GUESS_LIST then ANSW_LIST.
+pub fn builtin_guesses() -> Vec<&'static str> {
+    combine_lists(GUESS_LIST, ANSW_LIST).0
+}
 
+/// Whether these are the builtin answers, and the builtin guesses followed by the answers.
+pub fn is_builtin(answers: &[&str], guesses: &[&str]) -> bool {
+    answers == ANSW_LIST && guesses.len() == GUESS_LIST.len() + ANSW_LIST.len()
+        && guesses[..GUESS_LIST.len()] == *GUESS_LIST && guesses[GUESS_LIST.len()..] == *ANSW_LIST
+}
+
+/// The first guess for the given lists: from the precomputed openers if they are the builtin
+/// lists, otherwise searched for.
+pub fn opener_for<'a>(answers: &[&str], guesses: &[&'a str], boards: usize) -> Option<(&'a str, usize)> {
+    let builtin = is_builtin(answers, guesses);
+    let book = match boards {
+        1 => Some(WORDLE_OPENER),
+        2 => Some(DORDLE_OPENER),
+        _ => None,
+    };
+    match book {
+        Some(o) if builtin => guesses.iter().copied().find(|&g| g == o.word).map(|g| (g, o.worst_case)),
+        _ => best_opener(answers, guesses, boards),
+    }
+}
+
+/// Room for every letter a word may have: a to z, then any an Alphabet adds, as the bytes after
+/// 'z'.  Arrays indexed by letter have this many entries, and letter bitmasks fit a u32.
+pub const LETTERS: usize = 32;
+
+// The index every byte which is neither a letter nor a byte an Alphabet may add shares.
+const NO_LETTER: usize = LETTERS - 1;
+const _: () = assert!(26 + Alphabet::MAX_EXTRA <= NO_LETTER, "no index left for bytes which are no letter");
+
+/// Where 'letter' goes in an array indexed by letter.  Bytes which are no letter, such as
+/// uppercase letters or digits, all share one index no letter has, rather than panicking;
+/// scoring tells them apart by comparing the bytes themselves.
+#[inline]
+pub fn letter_index(letter: u8) -> usize {
+    match letter {
+        b'a'..=0x7f => (letter - b'a') as usize,
+        _ => NO_LETTER,
+    }
+}
+
+/// The longest words the solver plays: a result for this many letters still fits a Pattern.
+pub const MAX_LETTERS: usize = 10;
+
+pub type Histogram = [i8; LETTERS];
+
+/// Letter counts of a word of any length, plus one; -1 for a letter the word lacks.
 #[inline]
 pub fn histo(word: &[u8]) -> Histogram {
-    assert!(word.len() == 5);
-    let mut res = [-1; 26];
-    for i in 0..5 {
-        let w: usize = (word[i] - b'a') as usize;
+    let mut res = [-1; LETTERS];
+    for &c in word {
+        let w: usize = letter_index(c);
         if res[w] > 0 {
             res[w] += 1;
         } else {
@@ -30,61 +217,148 @@ pub fn histo(word: &[u8]) -> Histogram {
 }
 
 pub fn score(answ: &str, guess: &str) -> [Color; 5] {
-    let mut res = [Color::GREY; 5];
-    let mut hist = histo(answ.as_bytes());
+    score_n(answ, guess)
+}
+
+/// As score(), for 'N' letter words.
+pub fn score_n<const N: usize>(answ: &str, guess: &str) -> [Color; N] {
+    // Words as typed, rather than stored, are first stored a byte a letter.
+    if !answ.is_ascii() || !guess.is_ascii() {
+        let alphabet = alphabet();
+        return score_n(&alphabet.encode_text(answ), &alphabet.encode_text(guess));
+    }
+    match (answ.as_bytes().try_into(), guess.as_bytes().try_into()) {
+        (Ok(answ), Ok(guess)) => score_bytes(answ, guess),
+        _ => panic!("words to score must have {} letters", N),
+    }
+}
+
+/// As score_n(), for words as stored, a byte a letter.
+pub fn score_bytes<const N: usize>(answ: &[u8; N], guess: &[u8; N]) -> [Color; N] {
+    let mut res = [Color::GREY; N];
+    score_into(answ, guess, &mut res);
+    res
+}
 
-    let answ = answ.as_bytes();
-    let guess = guess.as_bytes();
-    assert!(answ.len() == 5 && guess.len() == 5);
+/// As score_bytes(), for words of a length known only at run time, scored into 'res', which has
+/// a color for each letter.
+#[inline]
+pub fn score_into(answ: &[u8], guess: &[u8], res: &mut [Color]) {
+    let n = res.len();
+    assert!(answ.len() == n && guess.len() == n, "words to score must have {} letters", n);
+    res.fill(Color::GREY);
+    let mut hist = histo(answ);
 
     // Set green squares
-    for i in 0..5 {
+    for i in 0..n {
         let a = answ[i];
         let g = guess[i];
         if a == g {
             res[i] = Color::GREEN;
-            hist[(a - b'a') as usize] -= 1;
+            hist[letter_index(a)] -= 1;
         }
     }
 
     // Set yellow squares
-    for i in 0..5 {
+    for i in 0..n {
         let a = answ[i];
         let g = guess[i];
-        if a != g {
-            if hist[(g - b'a') as usize] > 0 {
+        if a == g {
+            continue;
+        }
+        if letter_index(g) == NO_LETTER {
+            // Bytes which are no letter share an index, so are counted one by one.
+            let unmatched = (0..n).filter(|&j| answ[j] == g && guess[j] != g).count();
+            if (0..i).filter(|&j| guess[j] == g && res[j] == Color::YELLOW).count() < unmatched {
                 res[i] = Color::YELLOW;
-                hist[(g - b'a') as usize] -= 1;
             }
+        } else if hist[letter_index(g)] > 0 {
+            res[i] = Color::YELLOW;
+            hist[letter_index(g)] -= 1;
         }
     }
-
-    res
 }
 
-#[cfg(test)]
-mod test_score {
-    use super::*;
+// Whether 'word', whose histo() is 'hist', is consistent with 'guess' and its 'result'.
+#[inline]
+fn eligible<const N: usize>(word: &[u8; N], mut hist: Histogram, guess: [u8; N], result: [Color; N]) -> bool {
+    // First, filter green squares
+    for i in 0..N {
+        let w = word[i];
+        let g = guess[i];
+        let r = result[i];
+        if r == Color::GREEN {
+            if w != g {
+                return false;
+            }
+            hist[letter_index(g)] -= 1;
+        } else if r == Color::YELLOW {
+            hist[letter_index(g)] -= 1;
+        }
+    }
 
-    #[test]
-    fn test_score() {
-        assert_eq!(score("solar", "taser"),
-                   [Color::GREY, Color::YELLOW, Color::YELLOW, Color::GREY, Color::GREEN]);
-        assert_eq!(score("solar", "cling"),
-                   [Color::GREY, Color::YELLOW, Color::GREY, Color::GREY, Color::GREY]);
+    // Filter yellow and grey squares
+    for i in 0..N {
+        let w = word[i];
+        let g = guess[i];
+        let r = result[i];
+        if r == Color::GREEN {
+            continue;
+        }
+        // Letter 'w' must not be the yellow or gray letter.
+        if w == g {
+            return false;
+        }
+
+        let g_freq = if letter_index(g) == NO_LETTER {
+            // As hist[] would have it, were the byte alone at its index.
+            let count = word.iter().filter(|&&w| w == g).count() as i8;
+            let colored = (0..N).filter(|&j| guess[j] == g && result[j] != Color::GREY).count() as i8;
+            if count == 0 { -1 - colored } else { count - colored }
+        } else {
+            hist[letter_index(g)]
+        };
+
+        // If 'word' does not have letter 'g', or else it has fewer 'g's than implied by the
+        // number of green or yellow square results for that letter in 'guess', this candidate
+        // is invalid.
+        if r == Color::YELLOW && g_freq < 0 {
+            return false;
+        }
+        // If 'word' has more 'g's than implied by the number of green or yellow square results
+        // for that letter in 'guess', this candidate is invalid.
+        if r == Color::GREY && g_freq > 0 {
+            return false;
+        }
     }
+
+    true
+}
+
+/// How many of 'answers' are consistent with 'guess' and its 'result', given each answer's
+/// histo(): AnswerIterator::prune(...).count(), without stepping an iterator.  To count the
+/// answers giving every result at once, see pattern_counts().
+pub fn count_eligible<const N: usize>(answers: &[&str], histos: &[Histogram], guess: [u8; N], result: [Color; N])
+    -> usize {
+    answers.iter().zip(histos).filter(|&(a, &h)| eligible(&word_bytes_n(a), h, guess, result)).count()
+}
+
+/// Whether 'word', as stored, is consistent with 'guess' and its 'result'.
+pub fn is_eligible<const N: usize>(word: &[u8; N], guess: [u8; N], result: [Color; N]) -> bool {
+    eligible(word, histo(word), guess, result)
 }
 
-pub struct AnswerIterator<'str, 'slice> {
+/// The answers consistent with one 'N' letter guess and its result, given each answer's histo().
+pub struct AnswerIterator<'str, 'slice, const N: usize = 5> {
     answers: &'slice[&'str str],
     histos: &'slice[Histogram],
     index: usize,
-    guess: [u8; 5],
-    result: [Color; 5],
+    guess: [u8; N],
+    result: [Color; N],
 }
 
-impl<'str, 'slice> AnswerIterator<'str, 'slice> {
-    pub fn prune(answers: &'slice[&'str str], histos: &'slice[Histogram], guess: [u8; 5], result: [Color; 5]) -> Self {
+impl<'str, 'slice, const N: usize> AnswerIterator<'str, 'slice, N> {
+    pub fn prune(answers: &'slice[&'str str], histos: &'slice[Histogram], guess: [u8; N], result: [Color; N]) -> Self {
         Self {
             answers, histos, index: 0, guess, result,
         }
@@ -92,62 +366,11 @@ impl<'str, 'slice> AnswerIterator<'str, 'slice> {
 
     #[inline]
     fn eligible(&self) -> bool {
-        let word = self.answers[self.index].as_bytes();
-        let guess = self.guess;
-        let result = self.result;
-
-        let mut hist = self.histos[self.index];
-
-        assert!(word.len() == 5 && guess.len() == 5 && result.len() == 5);
-
-        // First, filter green squares
-        for i in 0..5 {
-            let w = word[i];
-            let g = guess[i];
-            let r = result[i];
-            if r == Color::GREEN {
-                if w != g {
-                    return false;
-                }
-                hist[(g - b'a') as usize] -= 1;
-            } else if r == Color::YELLOW {
-                hist[(g - b'a') as usize] -= 1;
-            }
-        }
-
-        // Filter yellow and grey squares
-        for i in 0..5 {
-            let w = word[i];
-            let g = guess[i];
-            let r = result[i];
-            if r == Color::GREEN {
-                continue;
-            }
-            // Letter 'w' must not be the yellow or gray letter.
-            if w == g {
-                return false;
-            }
-
-            let g_freq = hist[(g - b'a') as usize];
-
-            // If 'word' does not have letter 'g', or else it has fewer 'g's than implied by the
-            // number of green or yellow square results for that letter in 'guess', this candidate
-            // is invalid.
-            if r == Color::YELLOW && g_freq < 0 {
-                return false;
-            }
-            // If 'word' has more 'g's than implied by the number of green or yellow square results
-            // for that letter in 'guess', this candidate is invalid.
-            if r == Color::GREY && g_freq > 0 {
-                return false;
-            }
-        }
-
-        true
+        eligible(&word_bytes_n(self.answers[self.index]), self.histos[self.index], self.guess, self.result)
     }
 }
 
-impl<'str, 'slice> Iterator for AnswerIterator<'str, 'slice> {
+impl<'str, 'slice, const N: usize> Iterator for AnswerIterator<'str, 'slice, N> {
     type Item = &'str str;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -164,48 +387,528 @@ impl<'str, 'slice> Iterator for AnswerIterator<'str, 'slice> {
 }
 
 pub fn parse_guess(guess: &str) -> Option<[u8; 5]> {
-    let mut res = [0u8; 5];
-    if guess.len() != 5 {
-        return None;
+    parse_guess_n(guess)
+}
+
+/// Parse a guess of 'N' lowercase letters of the alphabet(), as typed or as stored.
+pub fn parse_guess_n<const N: usize>(guess: &str) -> Option<[u8; N]> {
+    check_guess_n(guess, None).ok()
+}
+
+pub fn check_guess(guess: &str, words: Option<&[&str]>) -> Result<[u8; 5], WordleError> {
+    check_guess_n(guess, words)
+}
+
+/// As parse_guess_n(), saying what's wrong with a guess which isn't one, and if 'words' is given,
+/// also requiring the guess to be one of them.
+pub fn check_guess_n<const N: usize>(guess: &str, words: Option<&[&str]>) -> Result<[u8; N], WordleError> {
+    let alphabet = alphabet();
+    let stored = alphabet.encode_text(guess);
+    if let Some((c, _)) = guess.chars().zip(stored.bytes()).find(|&(_, b)| !alphabet.is_letter(b)) {
+        return Err(WordleError::InvalidCharacter(c));
+    }
+    // Stored, each letter is a byte.
+    if stored.len() != N {
+        return Err(WordleError::InvalidLength { expected: N, found: stored.len() });
     }
-    for (i, b) in guess.as_bytes().iter().enumerate() {
-        res[i] = *b;
+    if words.is_some_and(|words| !words.contains(&stored.as_str())) {
+        return Err(WordleError::NotInWordList);
     }
-    Some(res)
+    let mut res = [0u8; N];
+    res.copy_from_slice(stored.as_bytes());
+    Ok(res)
 }
 
 pub fn parse_result(result: &str) -> Option<[Color; 5]> {
-    let mut res = [Color::GREY; 5];
-    if result.len() != 5 {
-        return None;
-    }
-    for (i, b) in result.as_bytes().iter().enumerate() {
-        res[i] = match b {
-            b'0' => Color::GREY,
-            b'1' => Color::YELLOW,
-            b'2' => Color::GREEN,
-            _ => {
-                return None;
-            }
+    parse_result_n(result)
+}
+
+// A tile's color in any of the notations parse_result_n() takes a character at a time.
+fn tile_color(c: char) -> Option<Color> {
+    match c {
+        '0' | 'b' | 'B' | '-' | '.' | '_' | '\u{2b1b}' | '\u{2b1c}' => Some(Color::GREY),
+        '1' | 'y' | 'Y' | '\u{1f7e8}' | '\u{1f7e6}' => Some(Color::YELLOW),
+        '2' | 'g' | 'G' | '\u{1f7e9}' | '\u{1f7e7}' => Some(Color::GREEN),
+        _ => None,
+    }
+}
+
+/// Parse a result, a character per tile: 0/1/2 for grey, yellow and green; b/y/g or -/y/g in
+/// either case; or the squares the game shares (black or white, yellow, green, or blue and
+/// orange in high contrast).  Failing that, it may be the guess itself with green letters in
+/// uppercase, yellow in lowercase, and greys as '.', '-' or '_'.
+pub fn parse_result_n<const N: usize>(result: &str) -> Option<[Color; N]> {
+    check_result_n(result).ok()
+}
+
+pub fn check_result(result: &str) -> Result<[Color; 5], WordleError> {
+    check_result_n(result)
+}
+
+/// As parse_result_n(), with an error for a result which isn't one.
+pub fn check_result_n<const N: usize>(result: &str) -> Result<[Color; N], WordleError> {
+    // Pasted emoji may carry variation selectors.
+    let chars = result.chars().filter(|&c| c != '\u{fe0f}').collect::<Vec<_>>();
+    if chars.len() != N {
+        return Err(WordleError::InvalidPattern);
+    }
+    let mut res = [Color::GREY; N];
+    if chars.iter().zip(&mut res).all(|(&c, r)| tile_color(c).map(|color| *r = color).is_some()) {
+        return Ok(res);
+    }
+    for (&c, r) in chars.iter().zip(&mut res) {
+        *r = match c {
+            'A'..='Z' => Color::GREEN,
+            'a'..='z' => Color::YELLOW,
+            '.' | '-' | '_' => Color::GREY,
+            _ => return Err(WordleError::InvalidPattern),
         };
     }
-    Some(res)
+    Ok(res)
 }
 
-pub fn maybe_prune<'a>(answers: &[&'a str], opt_guess: Option<&str>, opt_result: Option<&str>) -> Option<Vec<&'a str>> {
-    let guess = opt_guess?;
-    let result = opt_result?;
+/// Format a result in the same 0/1/2 notation accepted by parse_result().
+pub fn fmt_result<const N: usize>(result: &[Color; N]) -> String {
+    result.iter().map(|c| match c {
+        Color::GREY => '0',
+        Color::YELLOW => '1',
+        Color::GREEN => '2',
+    }).collect()
+}
+
+/// Describe a scored guess in words, e.g. "C grey, R yellow, A green, N grey, E grey".
+pub fn describe<const N: usize>(guess: &[u8; N], result: &[Color; N]) -> String {
+    guess.iter().zip(result).map(|(&g, c)| format!("{} {}", alphabet().upper(g), match c {
+        Color::GREY => "grey",
+        Color::YELLOW => "yellow",
+        Color::GREEN => "green",
+    })).collect::<Vec<_>>().join(", ")
+}
+
+/// A scored guess as terminal text, each letter on its color as in the game.
+pub fn fmt_colored<const N: usize>(guess: &[u8; N], result: &[Color; N]) -> String {
+    guess.iter().zip(result).map(|(&g, &c)| fmt_tile(g, Some(c))).collect()
+}
+
+// A letter as a tile of terminal text, on its color, or for None, on the light grey of a key not
+// yet played.
+pub(crate) fn fmt_tile(letter: u8, color: Option<Color>) -> String {
+    // ANSI colors: white on bright black, yellow or green, or black on white.
+    let colors = match color {
+        Some(Color::GREY) => "97;100",
+        Some(Color::YELLOW) => "97;43",
+        Some(Color::GREEN) => "97;42",
+        None => "30;47",
+    };
+    format!("\x1b[1;{}m {} \x1b[0m", colors, alphabet().upper(letter))
+}
+
+/// A result as the squares the game shares.
+pub fn fmt_squares<const N: usize>(result: &[Color; N]) -> String {
+    result.iter().map(|c| match c {
+        Color::GREY => '\u{2b1b}',
+        Color::YELLOW => '\u{1f7e8}',
+        Color::GREEN => '\u{1f7e9}',
+    }).collect()
+}
+
+pub fn parse_observation(opt_guess: Option<&str>, opt_result: Option<&str>) -> Option<Observation> {
+    parse_observation_n(opt_guess, opt_result)
+}
+
+/// As parse_observation(), for 'N' letter words.
+pub fn parse_observation_n<const N: usize>(opt_guess: Option<&str>, opt_result: Option<&str>) -> Option<Observation<N>> {
+    Some((parse_guess_n(opt_guess?)?, parse_result_n(opt_result?)?))
+}
+
+pub fn check_observation(guess: &str, result: &str) -> Result<Observation, WordleError> {
+    check_observation_n(guess, result)
+}
+
+/// A guess of 'N' letters and its result, with an error for whichever isn't one.
+pub fn check_observation_n<const N: usize>(guess: &str, result: &str) -> Result<Observation<N>, WordleError> {
+    Ok((check_guess_n(guess, None)?, check_result_n(result)?))
+}
+
+pub fn prune<'a>(answers: &[&'a str], guess: [u8; 5], result: [Color; 5]) -> Vec<&'a str> {
+    prune_n(answers, guess, result)
+}
+
+/// As prune(), for 'N' letter words.
+pub fn prune_n<'a, const N: usize>(answers: &[&'a str], guess: [u8; N], result: [Color; N]) -> Vec<&'a str> {
     let histos = answers.iter().map(|a| histo(a.as_bytes())).collect::<Vec<_>>();
 
-    Some(AnswerIterator::prune(answers, &histos, parse_guess(guess)?, parse_result(result)?).collect())
+    AnswerIterator::prune(answers, &histos, guess, result).collect()
+}
+
+pub fn maybe_prune<'a>(answers: &[&'a str], opt_guess: Option<&str>, opt_result: Option<&str>) -> Option<Vec<&'a str>> {
+    let (guess, result) = parse_observation(opt_guess, opt_result)?;
+
+    Some(prune(answers, guess, result))
+}
+
+/// Restrictions on which words may be played as guesses.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Mode {
+    Normal,
+    /// Yellows may be ignored positionally, but letters proven absent must not be reused.
+    SemiHard,
+    /// Wordle's hard mode: greens must be kept, and yellow letters reused.
+    Hard,
+}
+
+impl Mode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "normal" => Some(Mode::Normal),
+            "semi" => Some(Mode::SemiHard),
+            "hard" => Some(Mode::Hard),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Mode::Normal => "normal",
+            Mode::SemiHard => "semi",
+            Mode::Hard => "hard",
+        })
+    }
+}
+
+pub fn avoids_letters(word: &str, letters: u32) -> bool {
+    word.bytes().all(|b| letters & (1 << letter_index(b)) == 0)
+}
+
+/// The subset of 'guesses' which may be played in 'mode', given what is 'known' so far.
+pub fn guess_pool<'a, const N: usize>(guesses: &[&'a str], mode: Mode, known: &Constraints<N>) -> Vec<&'a str> {
+    match mode {
+        Mode::Normal => guesses.to_vec(),
+        Mode::SemiHard => {
+            let dead = known.dead_letters();
+            guesses.iter().copied().filter(|g| avoids_letters(g, dead)).collect()
+        }
+        Mode::Hard => guesses.iter().copied().filter(|g| known.allows_hard(g)).collect(),
+    }
+}
+
+/// Which of the guesses the mode allows are searched for the best one.  Narrower pools make late
+/// suggestions quicker, without changing them much.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Pool {
+    All,
+    /// Only the candidates left.
+    Candidates,
+    /// Only guesses which tell some of the candidates apart; the rest leave every one of them.
+    Useful,
+    /// The candidates once two or fewer are left, since guessing one can't do worse then, and
+    /// the useful guesses before.
+    Auto,
+}
+
+impl Pool {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "all" => Some(Pool::All),
+            "candidates" => Some(Pool::Candidates),
+            "useful" => Some(Pool::Useful),
+            "auto" => Some(Pool::Auto),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Pool::All => "all",
+            Pool::Candidates => "candidates",
+            Pool::Useful => "useful",
+            Pool::Auto => "auto",
+        })
+    }
+}
+
+// Whether 'guess' gives some two candidates different results.  A result is fixed by where the
+// guess's letters are green and by how many of each letter are colored, which is the lesser of
+// the guess's and the answer's counts; so it suffices to compare each against the candidates'
+// extremes.
+fn tells_apart(guess: &str, some_at: &[u32], all_at: &[u32], fewest: &[u8; LETTERS], most: &[u8; LETTERS]) -> bool {
+    let mut counts = [0u8; LETTERS];
+    for (i, b) in guess.bytes().enumerate() {
+        let bit = 1 << letter_index(b);
+        if some_at[i] & bit != 0 && all_at[i] & bit == 0 {
+            return true;
+        }
+        counts[letter_index(b)] += 1;
+    }
+    (0..LETTERS).any(|l| counts[l] > fewest[l] && most[l] > fewest[l])
+}
+
+/// The guesses of 'pool' which 'filter' keeps for 'candidates', or every one of them if it would
+/// keep none.
+pub fn narrow_pool<'a>(pool: Vec<&'a str>, candidates: &[&str], filter: Pool) -> Vec<&'a str> {
+    let narrowed = match filter {
+        Pool::All => return pool,
+        Pool::Auto if candidates.len() <= 2 => return narrow_pool(pool, candidates, Pool::Candidates),
+        Pool::Candidates => {
+            let candidates = candidates.iter().copied().collect::<HashSet<_>>();
+            pool.iter().copied().filter(|g| candidates.contains(g)).collect::<Vec<_>>()
+        }
+        Pool::Useful | Pool::Auto => {
+            let some_at = position_letters(candidates);
+            let mut all_at = vec![u32::MAX; some_at.len()];
+            let (mut fewest, mut most) = ([u8::MAX; LETTERS], [0u8; LETTERS]);
+            for word in candidates {
+                let mut counts = [0u8; LETTERS];
+                for (mask, b) in all_at.iter_mut().zip(word.bytes()) {
+                    *mask &= 1 << letter_index(b);
+                    counts[letter_index(b)] += 1;
+                }
+                for l in 0..LETTERS {
+                    fewest[l] = fewest[l].min(counts[l]);
+                    most[l] = most[l].max(counts[l]);
+                }
+            }
+            pool.iter().copied().filter(|g| tells_apart(g, &some_at, &all_at, &fewest, &most)).collect()
+        }
+    };
+    if narrowed.is_empty() { pool } else { narrowed }
+}
+
+/// The most candidates 'guess' can leave, over every possible answer in 'answers'.
+pub fn worst_case(answers: &[&str], guess: &str) -> usize {
+    pattern_counts(answers, guess).iter().copied().max().unwrap_or(0)
+}
+
+/// For each position, the letters some candidate has there, as bitmasks with bit 0 for 'a'.
+/// There are as many positions as the candidates have letters.
+pub fn position_letters(candidates: &[&str]) -> Vec<u32> {
+    let mut res = vec![0; candidates.first().map_or(0, |c| c.len())];
+    for word in candidates {
+        for (mask, b) in res.iter_mut().zip(word.bytes()) {
+            *mask |= 1 << letter_index(b);
+        }
+    }
+    res
+}
+
+/// A guess giving a different result for each of 'candidates', so that the guess after it is
+/// sure to win, if there is one.  Candidates are preferred, since they might win outright.
+pub fn discriminator<'a>(candidates: &[&'a str], guesses: &[&'a str]) -> Option<&'a str> {
+    let separates = |guess: &str| {
+        let results = candidates.iter().map(|c| Pattern::of(c, guess)).collect::<Vec<_>>();
+        results.iter().enumerate().all(|(i, r)| !results[..i].contains(r))
+    };
+    candidates.iter().chain(guesses).copied().find(|g| separates(g))
+}
+
+/// The candidates as a JSON object, for print_rem and its multiboard counterpart.
+pub fn candidates_json(answers: &[&str]) -> JsonObject {
+    let words = answers.iter().map(|a| alphabet().decode(a)).collect::<Vec<_>>();
+    JsonObject::new("candidates").num("count", answers.len()).strs("candidates", &words)
 }
 
 pub fn print_rem(answers: &[&str]) {
+    if json_output() {
+        println!("{}", candidates_json(answers));
+        return;
+    }
     let len = answers.len();
 
     println!("{} candidate answers remain: {}{}",
              len,
-             answers.iter().take(7).copied().collect::<Vec<_>>().join(", "),
+             answers.iter().take(7).map(|a| alphabet().decode(a)).collect::<Vec<_>>().join(", "),
              if len <= 7 { "" } else { ", ..." },
              );
 }
+
+#[cfg(test)]
+mod test_score {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score("solar", "taser"),
+                   [Color::GREY, Color::YELLOW, Color::YELLOW, Color::GREY, Color::GREEN]);
+        assert_eq!(score("solar", "cling"),
+                   [Color::GREY, Color::YELLOW, Color::GREY, Color::GREY, Color::GREY]);
+    }
+
+    #[test]
+    fn test_other_lengths() {
+        let result = score_n::<6>("planet", "please");
+        assert_eq!(fmt_result(&result), "221100");
+        let answers = ["planet", "plants", "planks", "sample"];
+        assert_eq!(prune_n(&answers, parse_guess_n("please").unwrap(), result), ["planet"]);
+        assert_eq!(parse_guess_n::<4>("please"), None);
+
+        let answers = ["plan", "clan", "span", "plot"];
+        assert_eq!(fmt_result(&score_n::<4>("span", "plan")), "1022");
+        assert_eq!(prune_n(&answers, *b"plan", parse_result_n("0222").unwrap()), ["clan"]);
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        // Stored, ñ is '{'; typed in an alphabet without it, it scores as a letter no word has.
+        assert_eq!(fmt_result(&score("ni{os", "nin{s")), "22012");
+        assert_eq!(fmt_result(&score("niños", "ninos")), "22022");
+        assert_eq!(fmt_result(&score("sueño", "ni{os")), "00011");
+        assert_eq!(histo("ni{os".as_bytes())[letter_index(b'{')], 1);
+        // Uppercase letters and digits are no letters the answer has.
+        assert_eq!(fmt_result(&score("cigar", "CIGAR")), "00000");
+        assert_eq!(fmt_result(&score("cigar", "c1g4r")), "20202");
+        // Different bytes which are no letters are still different.
+        assert_eq!(fmt_result(&score("ab1cd", "ab2cd")), "22022");
+        assert_eq!(fmt_result(&score("1b2cd", "21x1d")), "11002");
+        assert_eq!(prune(&["ab1cd", "ab2cd", "abxcd"], *b"ab2cd", score("ab1cd", "ab2cd")), ["ab1cd", "abxcd"]);
+        assert_eq!(prune(&["1b2cd", "2b1cd", "xbxcd"], *b"21x1d", score("1b2cd", "21x1d")), ["1b2cd"]);
+        assert_eq!(letter_index(b'A'), letter_index(b'0'));
+        assert_eq!(parse_guess("ABCDE"), None);
+        assert_eq!(parse_guess("ni~os"), None);
+        assert_eq!(parse_guess("niños"), None);
+    }
+
+    #[test]
+    fn test_check_guess() {
+        let words = ["cigar", "rebut"];
+        assert_eq!(check_guess("cigar", Some(&words)), Ok(*b"cigar"));
+        assert_eq!(check_guess("cigar", None), Ok(*b"cigar"));
+        assert_eq!(check_guess("zzzzz", Some(&words)), Err(WordleError::NotInWordList));
+        assert_eq!(check_guess("Cigar", None), Err(WordleError::InvalidCharacter('C')));
+        assert_eq!(check_guess("ci9ar", None), Err(WordleError::InvalidCharacter('9')));
+        assert_eq!(check_guess("niños", None), Err(WordleError::InvalidCharacter('ñ')));
+        assert_eq!(check_guess("cigars", None), Err(WordleError::InvalidLength { expected: 5, found: 6 }));
+        assert_eq!(check_guess("cigars", None).unwrap_err().to_string(), "has 6 letters, not 5");
+        assert_eq!(check_result("0120x"), Err(WordleError::InvalidPattern));
+        assert_eq!(check_result("012"), Err(WordleError::InvalidPattern));
+        assert_eq!(check_observation("cigar", "byg-g"), Ok((*b"cigar", parse_result("01202").unwrap())));
+        assert_eq!(check_observation("cigar!", "byg-g"), Err(WordleError::InvalidCharacter('!')));
+    }
+
+    #[test]
+    fn test_count_eligible() {
+        let answers = ["hatch", "watch", "latch", "cigar"];
+        let histos = answers.iter().map(|a| histo(a.as_bytes())).collect::<Vec<_>>();
+        let guess = parse_guess("patch").unwrap();
+        for answ in answers {
+            let result = score(answ, "patch");
+            assert_eq!(count_eligible(&answers, &histos, guess, result),
+                       AnswerIterator::prune(&answers, &histos, guess, result).count());
+        }
+        assert_eq!(count_eligible(&answers, &histos, guess, score("hatch", "patch")), 3);
+    }
+
+    #[test]
+    fn test_fmt_result() {
+        let result = score("solar", "taser");
+        assert_eq!(fmt_result(&result), "01102");
+        assert_eq!(parse_result(&fmt_result(&result)), Some(result));
+    }
+
+    #[test]
+    fn test_parse_result() {
+        let result = Some([Color::GREY, Color::YELLOW, Color::GREEN, Color::GREY, Color::GREEN]);
+        for s in ["01202", "byg-g", "BYGBG", "-yg.g", "\u{2b1b}\u{1f7e8}\u{1f7e9}\u{2b1c}\u{1f7e9}",
+                  "\u{2b1b}\u{fe0f}\u{1f7e6}\u{1f7e7}\u{2b1b}\u{fe0f}\u{1f7e7}", ".rA_E"] {
+            assert_eq!(parse_result(s), result, "{}", s);
+        }
+        assert_eq!(parse_result("0120"), None);
+        assert_eq!(parse_result("01203"), None);
+        assert_eq!(parse_result("cr?ne"), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&parse_guess("crane").unwrap(), &score("aside", "crane")),
+                   "C grey, R grey, A yellow, N grey, E green");
+    }
+
+    #[test]
+    fn test_worst_case() {
+        let answers = ["hatch", "watch", "latch", "patch", "batch", "match"];
+        assert_eq!(worst_case(&answers, "match"), 5);
+        assert_eq!(worst_case(&answers, "blimp"), 2);
+        assert_eq!(worst_case(&[], "blimp"), 0);
+    }
+
+    #[test]
+    fn test_discriminator() {
+        let candidates = ["match", "batch", "patch"];
+        assert_eq!(discriminator(&candidates, &["hatch", "chump", "champ"]), Some("chump"));
+        assert_eq!(discriminator(&candidates, &["hatch"]), None);
+        assert_eq!(discriminator(&["cigar", "rebut"], &[]), Some("cigar"));
+    }
+
+    #[test]
+    fn test_position_letters() {
+        let masks = position_letters(&["match", "batch", "patch"]);
+        let bit = |c: u8| 1u32 << letter_index(c);
+        assert_eq!(masks[0], bit(b'm') | bit(b'b') | bit(b'p'));
+        assert_eq!(masks[1..], [bit(b'a'), bit(b't'), bit(b'c'), bit(b'h')]);
+        assert!(position_letters(&[]).is_empty());
+        assert_eq!(position_letters(&["plan", "clan"])[1], bit(b'l'));
+    }
+
+    #[test]
+    fn test_openers() {
+        assert_eq!(worst_case(ANSW_LIST, WORDLE_OPENER.word), WORDLE_OPENER.worst_case);
+        assert_eq!(2 * worst_case(ANSW_LIST, DORDLE_OPENER.word), DORDLE_OPENER.worst_case);
+
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        assert_eq!(best_opener(&answers, &guesses, 1), Some(("whelp", 1)));
+        assert_eq!(opener_for(&answers, &guesses, 2), Some(("whelp", 2)));
+        assert_eq!(best_opener(&answers, &[], 1), None);
+
+        assert_eq!(opener_for(ANSW_LIST, &builtin_guesses(), 1), Some((WORDLE_OPENER.word, WORDLE_OPENER.worst_case)));
+    }
+}
+
+#[cfg(test)]
+mod test_lists {
+    use super::*;
+
+    #[test]
+    fn test_combine_lists() {
+        let (pool, stats) = combine_lists(&["aahed", "cigar", "aahed"], &["cigar", "rebut", "rebut"]);
+        assert_eq!(pool, ["aahed", "cigar", "rebut"]);
+        assert_eq!(stats, PoolStats { duplicates: 2, overlap: 1 });
+
+        let (pool, stats) = combine_lists(GUESS_LIST, ANSW_LIST);
+        assert_eq!(stats, PoolStats::default());
+        assert!(is_builtin(ANSW_LIST, &pool));
+    }
+}
+
+#[cfg(test)]
+mod test_mode {
+    use super::*;
+
+    #[test]
+    fn test_guess_pool() {
+        let guesses = ["cigar", "rebut", "sissy"];
+        let mut known = Constraints::<5>::new();
+        known.add(parse_guess("salty").unwrap(), score("cigar", "salty"));
+        assert_eq!(guess_pool(&guesses, Mode::Normal, &known), guesses);
+        assert_eq!(guess_pool(&guesses, Mode::SemiHard, &known), ["cigar"]);
+        let known = Constraints::from_history(&[(parse_guess("rusty").unwrap(), score("sissy", "rusty"))]);
+        assert_eq!(guess_pool(&guesses, Mode::Hard, &known), ["sissy"]);
+    }
+
+    #[test]
+    fn test_narrow_pool() {
+        let candidates = ["hatch", "watch", "latch"];
+        let pool = vec!["hatch", "cigar", "whelp", "tooth", "batch", "hohos"];
+        // "hohos" colors both of "hatch"'s h's, but only one of "watch"'s.
+        assert_eq!(narrow_pool(pool.clone(), &candidates, Pool::Useful), ["hatch", "whelp", "hohos"]);
+        assert_eq!(narrow_pool(pool.clone(), &candidates, Pool::Auto), ["hatch", "whelp", "hohos"]);
+        assert_eq!(narrow_pool(pool.clone(), &candidates, Pool::Candidates), ["hatch"]);
+        assert_eq!(narrow_pool(pool.clone(), &candidates[..2], Pool::Auto), ["hatch"]);
+        assert_eq!(narrow_pool(pool.clone(), &candidates, Pool::All), pool);
+        assert_eq!(narrow_pool(pool.clone(), &["watch"], Pool::Useful), pool);
+        assert_eq!(Pool::parse("useful").map(|p| p.to_string()).as_deref(), Some("useful"));
+    }
+}
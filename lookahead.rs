@@ -0,0 +1,119 @@
+use crate::{CandidateSet, Pattern, PatternTable, PruneTable, pattern_counts};
+
+/// The guess leaving the fewest candidates in the worst case after 'depth' guesses, each after
+/// the first the best reply to the result before it.  A solved game leaves none, so the count is
+/// 0 when the guesses always find the answer.  Depth 1 is the greedy search without tie-breaks;
+/// each extra level multiplies the work by about the number of guesses, so deeper searches are
+/// for small candidate sets.  Those search the candidates as bitsets of a PruneTable built for
+/// them, pruning with ANDs.  Returns None and usize::MAX if there are no guesses.
+pub fn best_guess_depth<'a>(answers: &[&str], guesses: &[&'a str], depth: usize) -> (Option<&'a str>, usize) {
+    let best = if depth <= 1 {
+        // One level needs no table, which for many candidates would be large.
+        guesses.iter().map(|&g| (g, leaves(answers, g))).min_by_key(|&(_, left)| left)
+    } else {
+        let table = PatternTable::new(answers, guesses);
+        let prune = PruneTable::new(&table);
+        search(&prune, &prune.full(), depth, usize::MAX).map(|(g, left)| (guesses[g], left))
+    };
+    match best {
+        Some((guess, left)) => (Some(guess), left),
+        None => (None, usize::MAX),
+    }
+}
+
+// The most candidates 'guess' can leave, not counting the answer it solves.
+fn leaves(answers: &[&str], guess: &str) -> usize {
+    let mut counts = pattern_counts(answers, guess);
+    counts[Pattern::solved(guess.len()).index()] = 0;
+    counts.iter().copied().max().unwrap_or(0)
+}
+
+// As leaves(), for the candidates of 'set' and a guess by its index in the table.
+fn set_leaves(prune: &PruneTable, set: &CandidateSet, guess: usize) -> usize {
+    let solved = prune.table().solved();
+    prune.counts(set, guess).filter(|&(p, _)| p != solved).map(|(_, n)| n).max().unwrap_or(0)
+}
+
+// The best guess, by its index in the table, leaving fewer than 'bound' of the candidates of
+// 'set' after 'depth' guesses, if there is one.
+fn search(prune: &PruneTable, set: &CandidateSet, depth: usize, bound: usize) -> Option<(usize, usize)> {
+    let table = prune.table();
+    let len = set.len();
+    if len == 1 {
+        let answer = table.answers()[set.iter().next().unwrap()];
+        if let Some(g) = table.guess_index(answer) {
+            return Some((g, 0)).filter(|_| bound > 0);
+        }
+    }
+
+    // With one guess left, two or more candidates can't all be solved.
+    let floor = if depth == 1 && len > 1 { 1 } else { 0 };
+    if bound <= floor {
+        return None;
+    }
+
+    // Search the greedy best first, so good bounds come early.
+    let mut greedy = (0..table.guesses().len()).map(|g| (g, set_leaves(prune, set, g))).collect::<Vec<_>>();
+    greedy.sort_by_key(|&(_, left)| left);
+    let mut best = None;
+    let mut bound = bound;
+    let mut splits = false;
+    for (guess, left) in greedy {
+        if bound == floor {
+            break;
+        }
+        if depth == 1 || left == 0 {
+            splits = true;
+            if left < bound {
+                best = Some((guess, left));
+                bound = left;
+            }
+            continue;
+        }
+        // A guess which tells nothing only wastes a level.
+        if left == len {
+            continue;
+        }
+        splits = true;
+        if let Some(worst) = guess_value(prune, set, guess, depth, bound) {
+            best = Some((guess, worst));
+            bound = worst;
+        }
+    }
+    if !splits && depth > 1 {
+        return search(prune, set, 1, bound);
+    }
+    best
+}
+
+// The most candidates left after 'guess' and 'depth' - 1 best replies, if fewer than 'bound'.
+fn guess_value(prune: &PruneTable, set: &CandidateSet, guess: usize, depth: usize, bound: usize) -> Option<usize> {
+    let solved = prune.table().solved();
+    let mut buckets = prune.partition(set, guess).filter(|&(p, _)| p != solved)
+        .map(|(_, left)| (left.len(), left)).collect::<Vec<_>>();
+    // The largest buckets are likeliest to exceed the bound and cut the search short.
+    buckets.sort_by_key(|&(len, _)| std::cmp::Reverse(len));
+    let mut worst = 0;
+    for (_, bucket) in &buckets {
+        let (_, left) = search(prune, bucket, depth - 1, bound)?;
+        worst = worst.max(left);
+    }
+    Some(worst)
+}
+
+#[cfg(test)]
+mod test_lookahead {
+    use super::*;
+
+    #[test]
+    fn test_best_guess_depth() {
+        let answers = ["hatch", "watch", "latch", "patch", "match"];
+        let guesses = ["hatch", "watch", "latch", "patch", "match", "whelp"];
+        // Any answer leaves the other four, and "whelp" leaves "hatch" or "match".
+        assert_eq!(best_guess_depth(&answers, &guesses, 1), (Some("whelp"), 2));
+        assert_eq!(best_guess_depth(&answers, &guesses, 2), (Some("whelp"), 1));
+        assert_eq!(best_guess_depth(&answers, &guesses, 3), (Some("whelp"), 0));
+        assert_eq!(best_guess_depth(&answers[..1], &guesses, 2), (Some("hatch"), 0));
+        assert_eq!(best_guess_depth(&answers, &[], 2), (None, usize::MAX));
+    }
+}
@@ -0,0 +1,6 @@
+// Regenerate wordle.h from the functions of ffi.rs:
+//   cargo run --features wordle-ffi --bin mkheader > wordle.h
+// Their doc comments, up to the first blank line, are the header's comments.
+fn main() {
+    print!("{}", wordle::c_header(include_str!("ffi.rs")));
+}
@@ -0,0 +1,15 @@
+// Regenerate openers.rs:
+//   cargo run --release --bin mkopeners > openers.rs
+use wordle::{ANSW_LIST, best_opener, builtin_guesses};
+
+fn main() {
+    let guesses = builtin_guesses();
+
+    println!("// Generated by mkopeners.rs; do not edit.");
+    println!("use crate::Opener;");
+    for (name, boards) in [("WORDLE", 1), ("DORDLE", 2)] {
+        let (word, worst_case) = best_opener(ANSW_LIST, &guesses, boards).unwrap();
+        println!();
+        println!("pub const {}_OPENER: Opener = Opener {{ word: {:?}, worst_case: {} }};", name, word, worst_case);
+    }
+}
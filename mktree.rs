@@ -0,0 +1,11 @@
+// Regenerate tree.txt:
+//   cargo run --release --bin mktree > tree.txt
+use wordle::{ANSW_LIST, WORDLE_OPENER, DecisionTree, builtin_guesses};
+
+fn main() {
+    let guesses = builtin_guesses();
+
+    let tree = DecisionTree::<5>::build(ANSW_LIST, &guesses, WORDLE_OPENER.word);
+    eprintln!("{} nodes, worst case {} guesses", tree.len(), tree.depth());
+    print!("{}", tree.to_text());
+}
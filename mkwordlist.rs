@@ -0,0 +1,39 @@
+// Regenerate wordlist.rs from word list files, one word per line:
+//   cargo run --bin mkwordlist answers.txt guesses.txt > wordlist.rs
+// The guess file holds the words which are valid guesses but never answers, of as many letters
+// as the answers.  Each list is written as strs, and again as byte arrays for scoring and pruning.  New lists change
+// the best openers and the decision tree, so regenerate openers.rs and tree.txt after.
+use anyhow::{Result, anyhow, bail};
+use std::path::PathBuf;
+use wordle::{WordList, combine_lists, load_lists};
+
+fn list_source(name: &str, words: &[&str]) -> String {
+    format!("pub static {}: &[&str] = &[{}];", name, words.iter().map(|w| format!("{:?}", w)).collect::<Vec<_>>().join(", "))
+}
+
+fn bytes_source(name: &str, words: &[&str], word_len: usize) -> String {
+    format!("pub static {}: &[[u8; {}]] = &[{}];", name, word_len,
+            words.iter().map(|w| format!("*b{:?}", w)).collect::<Vec<_>>().join(", "))
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).map(PathBuf::from).collect::<Vec<_>>();
+    let (answers, guesses) = match &args[..] {
+        [answers, guesses] => (answers, guesses),
+        _ => bail!("Usage: mkwordlist answers guesses"),
+    };
+    let word_len = WordList::from_file(answers)?.word_len().ok_or_else(|| anyhow!("{}: no words", answers.display()))?;
+    let (answers, guesses) = load_lists(Some(answers), Some(guesses), word_len)?;
+    let (pool, stats) = combine_lists(guesses, answers);
+    eprintln!("{} answers, {} guesses in all", answers.len(), pool.len());
+    if stats.duplicates > 0 || stats.overlap > 0 {
+        eprintln!("Warning: {} repeated words, and {} answers also in the guess file, which the solvers ignore",
+                  stats.duplicates, stats.overlap);
+    }
+
+    println!("{}", list_source("ANSW_LIST", answers));
+    println!("{}", list_source("GUESS_LIST", guesses));
+    println!("{}", bytes_source("ANSW_WORDS", answers, word_len));
+    println!("{}", bytes_source("GUESS_WORDS", guesses, word_len));
+    Ok(())
+}
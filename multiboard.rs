@@ -0,0 +1,306 @@
+use crate::{Color, Game, GameHistory, Pattern, Preferences, pattern_counts, play_out, score_n, word_bytes_n};
+use crate::par::*;
+use rustc_hash::FxHashSet as HashSet;
+
+// What was done to the boards, as their history keeps it for undo and redo.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Move<const K: usize, const N: usize> {
+    Guess([u8; N], [Option<[Color; N]>; K]),
+    Reset(usize),
+}
+
+/// Candidate answers for 'K' simultaneous boards, which all receive the same guesses but have
+/// different answers (Dordle, Quordle, ...), of 'N' letters.
+#[derive(Clone, Debug)]
+pub struct MultiBoard<'a, const K: usize, const N: usize = 5> {
+    // Every answer, which the boards are replayed from on undo.
+    answers: &'a [&'a str],
+    games: [Game<'a, N>; K],
+    // Boards whose answer has been guessed, which need no more guesses.
+    solved: [bool; K],
+    // The guesses recorded since the boards were new.
+    rounds: usize,
+    history: GameHistory<Move<K, N>>,
+}
+
+impl<'a, const K: usize, const N: usize> MultiBoard<'a, K, N> {
+    pub fn new(answers: &'a [&'a str]) -> Self {
+        Self {
+            answers,
+            games: [(); K].map(|_| Game::new(answers)),
+            solved: [false; K],
+            rounds: 0,
+            history: GameHistory::default(),
+        }
+    }
+
+    /// The guesses the game allows: 7 for Dordle, 9 for Quordle, 13 for Octordle.
+    pub const MAX_GUESSES: usize = K + 5;
+
+    /// The guesses left of MAX_GUESSES.  Resetting a board doesn't give back those spent on it.
+    pub fn rounds_left(&self) -> usize {
+        Self::MAX_GUESSES.saturating_sub(self.rounds)
+    }
+
+    /// Whether board 'i' has been solved, by a guess scoring all green on it.
+    pub fn is_solved(&self, i: usize) -> bool {
+        self.solved[i]
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.solved.iter().all(|&s| s)
+    }
+
+    pub fn unsolved_boards(&self) -> usize {
+        self.solved.iter().filter(|&&s| !s).count()
+    }
+
+    /// Whether, with 'rounds_left' guesses, every guess has to be one of the candidates: a guess
+    /// solves at most one board, so none can be spent only narrowing them down.
+    pub fn must_guess_candidate(&self, rounds_left: usize) -> bool {
+        rounds_left <= self.unsolved_boards()
+    }
+
+    /// Whether 'rounds_left' guesses are too few to solve every board, however lucky.
+    pub fn loss_unavoidable(&self, rounds_left: usize) -> bool {
+        rounds_left < self.unsolved_boards()
+    }
+
+    // The candidates of the boards not yet solved.
+    fn unsolved(&self) -> impl Iterator<Item = &[&'a str]> {
+        self.games.iter().zip(self.solved).filter(|&(_, solved)| !solved).map(|(game, _)| game.candidates())
+    }
+
+    /// The candidates left on board 'i'.
+    pub fn board(&self, i: usize) -> &[&'a str] {
+        self.games[i].candidates()
+    }
+
+    pub fn boards(&self) -> impl Iterator<Item = &[&'a str]> {
+        self.games.iter().map(Game::candidates)
+    }
+
+    pub fn games(&self) -> &[Game<'a, N>; K] {
+        &self.games
+    }
+
+    pub fn reset_board(&mut self, i: usize) {
+        self.history.record(Move::Reset(i));
+        self.play_move(Move::Reset(i));
+    }
+
+    /// Apply each board's result for 'guess', marking the boards it solves.  A board already
+    /// solved, or without a result, is left unchanged.
+    pub fn record(&mut self, guess: [u8; N], results: [Option<[Color; N]>; K]) {
+        self.history.record(Move::Guess(guess, results));
+        self.play_move(Move::Guess(guess, results));
+    }
+
+    /// Take back the last guess or board reset, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        if self.history.undo().is_none() {
+            return false;
+        }
+        let history = std::mem::take(&mut self.history);
+        *self = Self::new(self.answers);
+        for &m in history.entries() {
+            self.play_move(m);
+        }
+        self.history = history;
+        true
+    }
+
+    /// Make again the last guess or board reset undone, returning whether there was one and
+    /// none has been made since.
+    pub fn redo(&mut self) -> bool {
+        match self.history.redo() {
+            Some(m) => {
+                self.play_move(m);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Apply 'm' to the boards, without recording it.
+    fn play_move(&mut self, m: Move<K, N>) {
+        match m {
+            Move::Guess(guess, results) => {
+                for ((game, solved), result) in self.games.iter_mut().zip(&mut self.solved).zip(results) {
+                    match result {
+                        Some(result) if !*solved => {
+                            game.apply(guess, result);
+                            *solved = result == [Color::GREEN; N];
+                        }
+                        _ => {}
+                    }
+                }
+                self.rounds += 1;
+            }
+            Move::Reset(i) => {
+                self.games[i].reset();
+                self.solved[i] = false;
+            }
+        }
+    }
+
+    /// The guess minimizing the worst case total candidates across the boards not yet solved,
+    /// scored as in the single board solver: twice the worst case, less one if the guess may be
+    /// an answer.  With 'rounds_left' guesses too few to spare one, only candidates are guessed.
+    pub fn best_guess(&self, guesses: &[&'a str], rounds_left: usize) -> (Option<&'a str>, usize) {
+        self.best_guess_progress(guesses, rounds_left, &|_, _| {})
+    }
+
+    /// As best_guess, calling 'progress' with each guess and its score as it is scored, from
+    /// whichever thread scored it.
+    pub fn best_guess_progress(&self, guesses: &[&'a str], rounds_left: usize,
+                               progress: &(dyn Fn(&str, usize) + Sync)) -> (Option<&'a str>, usize) {
+        let mut answers_total = HashSet::<&str>::default();
+        for b in self.unsolved() {
+            answers_total.extend(b);
+        }
+        let candidates;
+        let guesses = if self.must_guess_candidate(rounds_left) {
+            candidates = guesses.iter().copied().filter(|g| answers_total.contains(g)).collect::<Vec<_>>();
+            &candidates[..]
+        } else {
+            guesses
+        };
+
+        // Find the guess that, for any remaining answer, minimizes the maximum candidates.  An
+        // answer leaves the candidates of each board which give the same result, so counting
+        // every board's candidates by result, once per guess, gives every answer's total.
+        let scored_guesses = guesses.par_iter().map(|guess| {
+            let mut totals = vec![0; Pattern::count(guess.len())];
+            for b in self.unsolved() {
+                for (total, count) in totals.iter_mut().zip(pattern_counts(b, guess).iter()) {
+                    *total += count;
+                }
+            }
+            let sco = totals.iter().copied().max().unwrap_or(0);
+
+            // Prioritize guesses that are possible answers.
+            let sco = if answers_total.contains(guess) { sco * 2 - 1 } else { sco * 2 };
+            progress(guess, sco);
+            (sco, *guess)
+        }).collect::<Vec<_>>();
+
+        match Preferences::default().pick(&scored_guesses) {
+            Some((guess, sco)) => (Some(guess), sco),
+            None => (None, usize::MAX),
+        }
+    }
+
+    /// Let the solver play every board to the end, opening with 'opener', and return its
+    /// guesses, which may be more than MAX_GUESSES.  Each board's answer must be among its
+    /// candidates.
+    pub fn play(mut self, guesses: &[&'a str], opener: &'a str, answers: [&str; K]) -> Vec<&'a str> {
+        // The summed worst case doesn't reward finishing a board, so a board down to its last
+        // candidate is played out first.
+        let next = |boards: &Self| match boards.unsolved().find(|b| b.len() == 1) {
+            Some(b) => Some(b[0]),
+            None => boards.best_guess(guesses, boards.rounds_left()).0,
+        };
+        play_out(&mut self, &[opener], usize::MAX, next, |boards, guess| {
+            boards.record(word_bytes_n(guess), answers.map(|answ| Some(score_n(answ, guess))));
+            boards.all_solved()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_multiboard {
+    use super::*;
+    use crate::{parse_guess, score};
+
+    #[test]
+    fn test_record() {
+        let answers = ["hatch", "watch", "cigar", "rebut"];
+        let mut mb = MultiBoard::<2>::new(&answers);
+        let guess = parse_guess("batch").unwrap();
+        mb.record(guess, [Some(score("hatch", "batch")), None]);
+        assert_eq!(mb.board(0), ["hatch", "watch"]);
+        assert_eq!(mb.board(1), answers);
+        mb.reset_board(0);
+        assert_eq!(mb.board(0), answers);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let answers = ["hatch", "watch", "cigar", "rebut"];
+        let mut mb = MultiBoard::<2>::new(&answers);
+        assert!(!mb.undo() && !mb.redo());
+        mb.record(parse_guess("hatch").unwrap(), [Some([Color::GREEN; 5]), Some(score("cigar", "hatch"))]);
+        mb.reset_board(0);
+        assert!(mb.undo());
+        assert!(mb.is_solved(0));
+        assert!(mb.undo());
+        assert!(!mb.is_solved(0) && mb.board(1) == answers && mb.rounds_left() == 7);
+        assert!(mb.redo());
+        assert_eq!((mb.board(0), mb.board(1), mb.rounds_left()), (&["hatch"][..], &["cigar"][..], 6));
+
+        // A new guess discards the reset undone.
+        mb.record(parse_guess("cigar").unwrap(), [None, Some([Color::GREEN; 5])]);
+        assert!(!mb.redo());
+        assert!(mb.all_solved());
+        assert!(mb.undo() && mb.undo() && !mb.undo());
+        assert!(!mb.all_solved());
+    }
+
+    #[test]
+    fn test_best_guess() {
+        let answers = ["hatch", "watch", "latch"];
+        let mut mb = MultiBoard::<2>::new(&answers);
+        mb.record(parse_guess("cigar").unwrap(), [Some(score("cigar", "cigar")), None]);
+        assert!(mb.board(0).is_empty());
+        let mb = MultiBoard::<2>::new(&answers);
+        // 'whelp' separates all three answers, leaving one candidate on each board.
+        assert_eq!(mb.best_guess(&["hatch", "whelp"], 7), (Some("whelp"), 4));
+        assert_eq!(mb.best_guess(&["hatch"], 7), (Some("hatch"), 7));
+    }
+
+    #[test]
+    fn test_solved() {
+        let answers = ["hatch", "watch", "latch"];
+        let mut mb = MultiBoard::<2>::new(&answers);
+        mb.record(parse_guess("hatch").unwrap(), [Some([Color::GREEN; 5]), Some(score("watch", "hatch"))]);
+        assert!(mb.is_solved(0) && !mb.is_solved(1) && !mb.all_solved());
+        assert_eq!(mb.board(0), ["hatch"]);
+        // Only the unsolved board counts: 'watch' leaves it one candidate.
+        assert_eq!(mb.best_guess(&["watch", "whelp"], 7), (Some("watch"), 1));
+        mb.record(parse_guess("watch").unwrap(), [Some(score("hatch", "watch")), Some([Color::GREEN; 5])]);
+        assert_eq!(mb.board(0), ["hatch"]);
+        assert!(mb.all_solved());
+        mb.reset_board(0);
+        assert!(!mb.is_solved(0) && mb.is_solved(1));
+    }
+
+    #[test]
+    fn test_budget() {
+        let answers = ["hatch", "watch", "latch"];
+        let mut mb = MultiBoard::<2>::new(&answers);
+        assert_eq!(MultiBoard::<8>::MAX_GUESSES, 13);
+        assert!(!mb.must_guess_candidate(3) && mb.must_guess_candidate(2) && !mb.loss_unavoidable(2));
+        // Two rounds for two boards leave none to spend on 'whelp'.
+        assert_eq!(mb.best_guess(&["hatch", "whelp"], 2), (Some("hatch"), 7));
+        assert!(mb.loss_unavoidable(1));
+        for _ in 0..6 {
+            mb.record(parse_guess("cigar").unwrap(), [None, None]);
+        }
+        assert_eq!(mb.rounds_left(), 1);
+        mb.reset_board(0);
+        assert_eq!(mb.rounds_left(), 1);
+    }
+
+    #[test]
+    fn test_play() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let guesses = ["hatch", "watch", "latch", "patch", "whelp"];
+        // 'whelp' tells every board its answer, and then each takes a round.
+        assert_eq!(MultiBoard::<4>::new(&answers).play(&guesses, "whelp", answers).len(), 5);
+        assert_eq!(MultiBoard::<2>::new(&answers).play(&guesses, "latch", ["latch", "hatch"]), ["latch", "whelp", "hatch"]);
+        let answers = ["coat", "boat", "cost"];
+        let line = MultiBoard::<2, 4>::new(&answers).play(&answers, "coat", ["boat", "cost"]);
+        assert!(line.contains(&"boat") && line.contains(&"cost"));
+    }
+}
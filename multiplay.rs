@@ -0,0 +1,382 @@
+// The command line of dordle, quordle and octordle, each of which includes this module, so the
+// library keeps only the game itself, in MultiBoard.
+use wordle::{Checkpoint, Color, CsvWriter, Game, Input, MultiBoard, PoolStats, Rng, Shard, SimReport, Verbosity, candidates_json,
+            choose_opener, combine_lists, JsonObject, json_output, load_lists, parse_guess_n, parse_result_n, print_guess,
+            print_rem, set_json_output, set_verbosity, verbosity, wordlist_checksum};
+use anyhow::{Result, anyhow, bail};
+use indicatif::{ProgressBar, ProgressStyle};
+use rustc_hash::FxHashSet as HashSet;
+use std::convert::{TryFrom, TryInto};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+// Boards are named left and right in Dordle, and numbered from 1 with more of them.
+fn board_names<const K: usize>() -> [String; K] {
+    if K == 2 {
+        vec!["left".to_string(), "right".to_string()].try_into().unwrap()
+    } else {
+        std::array::from_fn(|i| (i + 1).to_string())
+    }
+}
+
+fn print_best_guess<'a, const K: usize, const N: usize>(boards: &MultiBoard<'a, K, N>, guesses: &[&'a str]) {
+    // Hidden automatically when stderr is not a terminal.
+    let pb = ProgressBar::new(guesses.len() as u64);
+    pb.set_style(ProgressStyle::with_template("{bar:40} {pos}/{len} guesses ({per_sec}, {eta} left)").unwrap());
+    let rounds_left = boards.rounds_left();
+    let (bestguess, bestsco) = boards.best_guess_progress(guesses, rounds_left, &|_, _| pb.inc(1));
+    pb.finish_and_clear();
+
+    if boards.must_guess_candidate(rounds_left) && !boards.loss_unavoidable(rounds_left) {
+        println!("With {} guesses left for {} boards, only a candidate can win", rounds_left, boards.unsolved_boards());
+    }
+    print_guess(bestguess.unwrap_or(""), Some(bestsco.div_ceil(2)));
+}
+
+fn print_rems<const K: usize, const N: usize>(boards: &MultiBoard<K, N>, names: &[String; K]) {
+    for (i, (name, answers)) in names.iter().zip(boards.boards()).enumerate() {
+        if json_output() {
+            println!("{}", candidates_json(answers).str("board", name).bool("solved", boards.is_solved(i)));
+            continue;
+        }
+        match answers {
+            [answer] if boards.is_solved(i) => println!("{}: \u{2705} {}", name, answer),
+            _ => {
+                print!("{}: ", name);
+                print_rem(answers);
+            }
+        }
+    }
+}
+
+// A board's result, or None for a skip marker ('-' or 'x') leaving the board unchanged.
+fn parse_board_result<const N: usize>(result: &str) -> Option<Option<[Color; N]>> {
+    match result {
+        "-" | "x" => Some(None),
+        _ => parse_result_n(result).map(Some),
+    }
+}
+
+struct Args {
+    // Opening guess, or "auto" for the best one for the word lists.
+    first: Option<String>,
+    // Read plain commands from stdin, even on a terminal.
+    batch: bool,
+    // How much simulations print, where to save each of their games, and to write them as CSV.
+    verbosity: Verbosity,
+    log: Option<PathBuf>,
+    csv: Option<PathBuf>,
+    // Where simulations record their progress, and whether to resume from it, or to start over.
+    checkpoint: Option<PathBuf>,
+    resume: bool,
+    force: bool,
+    // The slice of the full simulation to run.
+    shard: Shard,
+    // Answer and extra guess lists to use instead of the builtin ones.
+    answers: Option<PathBuf>,
+    guesses: Option<PathBuf>,
+    // Letters per word; other than 5, the answers must be given with -a.
+    length: usize,
+    // Print candidates, suggestions and simulation results as JSON.
+    json: bool,
+    // Shard outputs to merge into one report, instead of running interactively.
+    merge: Option<Vec<String>>,
+    // Seed for sampled simulations, so a sample can be repeated.
+    seed: Option<u64>,
+}
+
+fn parse_args(prog: &str) -> Result<Args> {
+    let mut res = Args { first: None, batch: false, verbosity: Verbosity::Normal, log: None, csv: None,
+                         checkpoint: None, resume: false, force: false, shard: Shard::ALL, answers: None, guesses: None,
+                         length: 5, json: false, merge: None, seed: None };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--first" => res.first = Some(args.next().ok_or_else(|| anyhow!("--first needs a word or 'auto'"))?),
+            "--batch" => res.batch = true,
+            "-q" | "--quiet" => res.verbosity = Verbosity::Quiet,
+            "-v" | "--verbose" => res.verbosity = Verbosity::Verbose,
+            "--log" => res.log = Some(args.next().ok_or_else(|| anyhow!("--log needs a file"))?.into()),
+            "--csv" => res.csv = Some(args.next().ok_or_else(|| anyhow!("--csv needs a file"))?.into()),
+            "--checkpoint" => res.checkpoint = Some(args.next().ok_or_else(|| anyhow!("--checkpoint needs a file"))?.into()),
+            "--resume" => res.resume = true,
+            "--force" => res.force = true,
+            "--shard" => res.shard = args.next().as_deref().and_then(Shard::parse)
+                .ok_or_else(|| anyhow!("--shard needs i/n, with i < n"))?,
+            "-a" => res.answers = Some(args.next().ok_or_else(|| anyhow!("-a needs a word list file"))?.into()),
+            "-g" => res.guesses = Some(args.next().ok_or_else(|| anyhow!("-g needs a word list file"))?.into()),
+            "--length" => res.length = args.next().and_then(|s| s.parse().ok()).filter(|n| (4..=7).contains(n))
+                .ok_or_else(|| anyhow!("--length needs a number from 4 to 7"))?,
+            "--json" => res.json = true,
+            "--seed" => res.seed = Some(args.next().and_then(|s| s.parse().ok()).ok_or_else(|| anyhow!("--seed needs a number"))?),
+            "merge" => res.merge = Some(args.by_ref().collect()),
+            _ => bail!("Usage: {} [--batch] [-q|-v] [--log file] [--csv file] [--checkpoint file [--resume|--force]] [--first word|auto] \
+                        [--shard i/n] [-a answers] [-g guesses] [--length n] [--json] [--seed n] [merge file...]", prog),
+        }
+    }
+    if (res.resume || res.force) && res.checkpoint.is_none() {
+        bail!("--resume and --force need --checkpoint file");
+    }
+    if res.resume && res.force {
+        bail!("--resume and --force can't be given together");
+    }
+    Ok(res)
+}
+
+/// The interactive solver for 'K' simultaneous boards, run as the program 'prog'.
+pub fn multiboard_main<const K: usize>(prog: &str) -> Result<()> {
+    let args = parse_args(prog)?;
+    // The boards are built for their word length, so each length is run as its own.
+    match args.length {
+        4 => run::<K, 4>(prog, args),
+        5 => run::<K, 5>(prog, args),
+        6 => run::<K, 6>(prog, args),
+        7 => run::<K, 7>(prog, args),
+        _ => unreachable!("parse_args() checks the length"),
+    }
+}
+
+fn run<const K: usize, const N: usize>(prog: &str, args: Args) -> Result<()> {
+    set_json_output(args.json);
+    set_verbosity(args.verbosity);
+    if let Some(paths) = &args.merge {
+        SimReport::load_shards(paths)?.print_summary(0, Some(MultiBoard::<K>::MAX_GUESSES));
+        return Ok(());
+    }
+    let names = board_names::<K>();
+    let (answ_list, guess_list) = load_lists(args.answers.as_deref(), args.guesses.as_deref(), N)?;
+    let mut boards = MultiBoard::<K, N>::new(answ_list);
+    let (guesses, stats) = combine_lists(guess_list, answ_list);
+    if stats != PoolStats::default() {
+        println!("Guess pool: {} words, after dropping {} duplicates and {} answers already among the guesses",
+                 guesses.len(), stats.duplicates, stats.overlap);
+    }
+
+    let (opener, opener_worst) = choose_opener(args.first.as_deref(), answ_list, &guesses, K)
+        .map_err(|e| anyhow!("--first: {}", e))?;
+
+    let mut input = Input::repl(args.batch, prog, &["g", "r", "u", "redo", "p", "b", "fs", "x"]);
+    input.set_guesses(&guesses);
+    // Each sample without a seed of its own takes the next from here.
+    let mut rng = Rng::new(args.seed.unwrap_or_else(Rng::random_seed));
+    loop {
+        print_rems(&boards, &names);
+        let mut candidates = boards.boards().flatten().copied().collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates.dedup();
+        input.set_candidates(&candidates);
+
+        let tline = match input.readline("> ") {
+            Some(tline) if tline != "x" => tline,
+            _ => break,
+        };
+
+        let mut words = tline.split(' ');
+        let cmd = words.next().unwrap();
+        match cmd {
+            // guess word, then a result per board
+            "g" => {
+                let guess = words.next().and_then(parse_guess_n);
+                let results = words.by_ref().take(K).map(parse_board_result).collect::<Option<Vec<_>>>();
+                if let (Some(guess), Some(results)) = (guess, results) {
+                    if let Ok(results) = <[_; K]>::try_from(results) {
+                        boards.record(guess, results);
+                        if boards.loss_unavoidable(boards.rounds_left()) {
+                            println!("With {} guesses left for {} boards, the game can't be won",
+                                     boards.rounds_left(), boards.unsolved_boards());
+                        }
+                        continue;
+                    }
+                }
+                println!("Usage: g guess {}", (1..=K).map(|i| format!("result{}", i)).collect::<Vec<_>>().join(" "));
+                println!("       result is 0 for grey, 1 for yellow, 2 for green, or b/y/g, or pasted squares");
+                println!("       or - to leave that board unchanged");
+            }
+            // reset every board, or just the one named
+            "r" => {
+                match words.next() {
+                    None => {
+                        boards = MultiBoard::new(answ_list);
+                    }
+                    Some(name) => match names.iter().position(|n| n == name) {
+                        Some(i) => boards.reset_board(i),
+                        None => println!("Usage: r [{}]", names.join("|")),
+                    },
+                }
+            }
+            // undo the last guess or board reset
+            "u" => {
+                if !boards.undo() {
+                    println!("Nothing to undo");
+                }
+            }
+            // make again the last guess or board reset undone
+            "redo" => {
+                if !boards.redo() {
+                    println!("Nothing to redo");
+                }
+            }
+            // print
+            "p" => {
+                if json_output() {
+                    print_rems(&boards, &names);
+                    continue;
+                }
+                for (name, answers) in names.iter().zip(boards.boards()) {
+                    println!("{}: {}", name, answers.join(", "));
+                }
+            }
+            // best guess
+            "b" => {
+                if boards.all_solved() {
+                    println!("Every board is solved");
+                    continue;
+                }
+                if boards.games().iter().all(Game::is_new) && !boards.must_guess_candidate(boards.rounds_left()) {
+                    print_guess(opener, Some(opener_worst));
+                    continue;
+                }
+
+                print_best_guess(&boards, &guesses);
+            }
+            // run full simulation of all words, or of a random sample of games
+            "fs" => {
+                let sample = match (words.next().map(str::parse), words.next().map(str::parse).transpose()) {
+                    (None, _) => None,
+                    (Some(Ok(n)), Ok(seed)) if n > 0 => Some((n, seed.unwrap_or_else(|| rng.next_u64()))),
+                    _ => {
+                        println!("Usage: fs [games [seed]]");
+                        continue;
+                    }
+                };
+                fullsim::<K, N>(answ_list, &guesses, opener, sample, &args);
+            }
+            _ => {
+                println!("No command '{}'", cmd);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// 'n' distinct games of K different answers, chosen by 'rng', or every game if there are fewer.
+fn sample_games<'a, const K: usize>(answers: &[&'a str], n: usize, rng: &mut Rng) -> Vec<[&'a str; K]> {
+    let total = (0..K).fold(1usize, |acc, i| acc.saturating_mul(answers.len().saturating_sub(i)) / (i + 1));
+    let mut seen = HashSet::default();
+    let mut res = Vec::with_capacity(n.min(total));
+    while res.len() < n.min(total) {
+        let mut game: [usize; K] = std::array::from_fn(|_| rng.below(answers.len()));
+        game.sort_unstable();
+        if game.windows(2).all(|w| w[0] != w[1]) && seen.insert(game) {
+            res.push(game.map(|i| answers[i]));
+        }
+    }
+    res
+}
+
+// The columns of the CSV of simulated games: the rounds the game took, then each board's answer
+// and the guesses up to the one solving it, separated by spaces.
+fn csv_header<const K: usize>() -> Vec<String> {
+    let names = board_names::<K>();
+    std::iter::once("rounds".to_string())
+        .chain(names.iter().flat_map(|name| [format!("answer_{}", name), format!("sequence_{}", name)]))
+        .collect()
+}
+
+fn csv_game<const K: usize>(csv: &mut CsvWriter, game: &[&str; K], played: &[&str]) {
+    let mut row = vec![played.len().to_string()];
+    for answer in game {
+        let solved = played.iter().position(|g| g == answer).map_or(played.len(), |i| i + 1);
+        row.push(answer.to_string());
+        row.push(played[..solved].join(" "));
+    }
+    csv.row(&row);
+}
+
+// Two boards play every pair of answers.  More boards have too many combinations, so game k
+// plays answer k and those every n/K answers after it.  Given a sample size and seed, random
+// games are played instead, and the average over every game is estimated from them.  The
+// arguments give the shard to play, where to save and write each game, and where to checkpoint
+// progress.
+fn fullsim<const K: usize, const N: usize>(answers: &[&str], guesses: &[&str], opener: &str, sample: Option<(usize, u64)>,
+                                           args: &Args) {
+    let shard = args.shard;
+    let name = format!("Checkpoint of {} boards, lists {:016x}, opener '{}', shard {}/{}{}", K,
+                       wordlist_checksum(answers, guesses), opener, shard.index, shard.count,
+                       sample.map(|(n, seed)| format!(", {} games sampled with seed {}", n, seed)).unwrap_or_default());
+    let (mut checkpoint, mut report) = match &args.checkpoint {
+        Some(path) if args.resume => match Checkpoint::resume(path, &name) {
+            Ok((checkpoint, report)) => (Some(checkpoint), report),
+            Err(e) => return println!("Can't resume from {}: {}", path.display(), e),
+        },
+        Some(path) => match Checkpoint::create(path, &name, args.force) {
+            Ok(checkpoint) => (Some(checkpoint), SimReport::new()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists =>
+                return println!("{} has games from an earlier run; --resume it, or --force to start over", path.display()),
+            Err(e) => return println!("Can't write {}: {}", path.display(), e),
+        },
+        None => (None, SimReport::new()),
+    };
+    let mut csv = match &args.csv {
+        Some(path) => match CsvWriter::create(path, &csv_header::<K>().iter().map(String::as_str).collect::<Vec<_>>()) {
+            Ok(csv) => Some(csv),
+            Err(e) => return println!("Can't write {}: {}", path.display(), e),
+        },
+        None => None,
+    };
+    let games: Box<dyn Iterator<Item = [&str; K]>> = if let Some((n, seed)) = sample {
+        if verbosity() > Verbosity::Quiet {
+            println!("Sampling {} games with seed {}", n, seed);
+        }
+        Box::new(sample_games(answers, n, &mut Rng::new(seed)).into_iter())
+    } else if K == 2 {
+        Box::new(answers.iter().enumerate()
+            .flat_map(|(ii, &answ1)| answers[ii+1..].iter().map(move |&answ2| [answ1, answ2][..].try_into().unwrap())))
+    } else {
+        let stride = (answers.len() / K).max(1);
+        Box::new((0..answers.len()).map(move |k| std::array::from_fn(|i| answers[(k + i * stride) % answers.len()])))
+    };
+    let games = games.enumerate().filter(|&(k, _)| shard.contains(k)).map(|(_, game)| game)
+        .filter(|game| !report.contains(&game.join(" x "))).collect::<Vec<_>>();
+    if !report.is_empty() && verbosity() > Verbosity::Quiet {
+        println!("Resuming after {} games, with {} left", report.len(), games.len());
+    }
+    report.play_all_with(&games, |game| game.join(" x "), |&game| {
+        let played = MultiBoard::<K, N>::new(answers).play(guesses, opener, game);
+        (played.len(), played)
+    }, |game, rounds, played| {
+        if let Some(checkpoint) = checkpoint.as_mut() {
+            checkpoint.record(&game.join(" x "), rounds);
+        }
+        if let Some(csv) = csv.as_mut() {
+            csv_game(csv, game, &played);
+        }
+    });
+    if let (Some(path), Some(checkpoint)) = (&args.checkpoint, checkpoint) {
+        if let Err(e) = checkpoint.finish() {
+            println!("Can't write {}: {}", path.display(), e);
+        }
+    }
+    if let (Some(path), Some(csv)) = (&args.csv, csv) {
+        if let Err(e) = csv.finish() {
+            println!("Can't write {}: {}", path.display(), e);
+        }
+    }
+    report.print_summary(0, Some(MultiBoard::<K>::MAX_GUESSES));
+    if sample.is_some() && report.len() > 1 {
+        let margin = 1.96 * report.standard_error();
+        if json_output() {
+            println!("{}", JsonObject::new("estimate").float("average", report.average()).float("low", report.average() - margin)
+                .float("high", report.average() + margin));
+        } else {
+            println!("Estimated average {:.4} rounds, 95% confidence interval {:.4} to {:.4}",
+                     report.average(), report.average() - margin, report.average() + margin);
+        }
+    }
+    if let Some(path) = &args.log {
+        if let Err(e) = report.save(path) {
+            println!("Can't save games to {}: {}", path.display(), e);
+        }
+    }
+}
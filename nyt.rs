@@ -0,0 +1,60 @@
+// A day's puzzle from the New York Times, whose answers no longer follow the original list's
+// order.  Fetched with curl, which must be on the PATH, and cached, since a day's puzzle never
+// changes once published.
+use crate::{Cache, Date, Json};
+use std::convert::TryInto;
+use std::io;
+use std::process::Command;
+
+// Responses depend on no word list, so they are cached under a fixed checksum.
+const CHECKSUM: u64 = 0;
+
+/// The answer of a day's puzzle, and its number.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NytPuzzle {
+    pub solution: String,
+    pub puzzle: u32,
+}
+
+fn parse_response(text: &str) -> Option<NytPuzzle> {
+    let json = Json::parse(text).ok()?;
+    let solution = json.field::<String>("solution").ok()?;
+    if solution.len() != 5 || !solution.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(NytPuzzle { solution: solution.to_lowercase(), puzzle: json.field::<u64>("days_since_launch").ok()?.try_into().ok()? })
+}
+
+/// The puzzle for 'date', from 'cache' if it was fetched before.
+pub fn nyt_puzzle(date: Date, cache: Option<&Cache>) -> io::Result<NytPuzzle> {
+    let name = format!("nyt-{}.json", date);
+    if let Some(puzzle) = cache.and_then(|c| c.load(&name, CHECKSUM)).as_deref().and_then(parse_response) {
+        return Ok(puzzle);
+    }
+    let url = format!("https://www.nytimes.com/svc/wordle/v2/{}.json", date);
+    let output = Command::new("curl").args(["--silent", "--show-error", "--fail", "--max-time", "20", &url]).output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("fetching {}: {}", url, String::from_utf8_lossy(&output.stderr).trim())));
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let puzzle = parse_response(&text).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData,
+        format!("{} has no puzzle", url)))?;
+    if let Some(cache) = cache {
+        cache.store(&name, CHECKSUM, &text)?;
+    }
+    Ok(puzzle)
+}
+
+#[cfg(test)]
+mod test_nyt {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let text = r#"{"id":1536,"solution":"rebus","print_date":"2022-01-01","days_since_launch": 196,"editor":"x"}"#;
+        assert_eq!(parse_response(text), Some(NytPuzzle { solution: "rebus".to_string(), puzzle: 196 }));
+        assert_eq!(parse_response(r#"{"status":"ERROR"}"#), None);
+        assert_eq!(parse_response("<html>"), None);
+        assert_eq!(parse_response(r#"{"solution":"toolong","days_since_launch":1}"#), None);
+    }
+}
@@ -0,0 +1,6 @@
+// Generated by mkopeners.rs; do not edit.
+use crate::Opener;
+
+pub const WORDLE_OPENER: Opener = Opener { word: "raise", worst_case: 167 };
+
+pub const DORDLE_OPENER: Opener = Opener { word: "raise", worst_case: 334 };
@@ -0,0 +1,169 @@
+use crate::PatternTable;
+use rustc_hash::FxHashMap as HashMap;
+
+/// The most guesses a game may take.
+pub const MAX_GUESSES: usize = 6;
+
+/// How play which is optimal over every answer goes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimalPlay<'a> {
+    pub opener: &'a str,
+    /// Guesses taken over every answer, counting the one which solves it.
+    pub total: usize,
+    pub answers: usize,
+    pub worst: usize,
+}
+
+impl OptimalPlay<'_> {
+    pub fn average(&self) -> f64 {
+        self.total as f64 / self.answers as f64
+    }
+}
+
+// What a search of a set of candidates found.
+#[derive(Clone, Copy)]
+enum Known {
+    // The best next guess, by index, and the guesses it takes to solve every candidate.
+    Exact(usize, usize),
+    // No play takes fewer guesses.
+    AtLeast(usize),
+}
+
+/// Exhaustive search for the play taking the fewest guesses in total over every answer, within
+/// MAX_GUESSES each.  Searches of a set of candidates are remembered, and a guess is abandoned as
+/// soon as its results can't beat the best guess found.  The answers and guesses are those of the
+/// table; for the builtin lists a search from a given opener takes about a minute, but one over
+/// every opener takes many hours.
+pub struct OptimalSolver<'t, 'a> {
+    table: &'t PatternTable<'a>,
+    memo: HashMap<(Vec<u16>, usize), Known>,
+}
+
+// Fewest guesses 'n' candidates can take: one solved by the next guess, the rest by the one after.
+fn lower_bound(n: usize) -> usize {
+    (2 * n).saturating_sub(1)
+}
+
+impl<'t, 'a> OptimalSolver<'t, 'a> {
+    pub fn new(table: &'t PatternTable<'a>) -> Self {
+        Self { table, memo: HashMap::default() }
+    }
+
+    /// Optimal play opening with 'opener', or with the best opener if None.  None if the answers
+    /// can't all be solved in time, or the opener isn't among the table's guesses.
+    pub fn solve(&mut self, opener: Option<&str>) -> Option<OptimalPlay<'a>> {
+        let all = (0..self.table.answers().len() as u16).collect::<Vec<_>>();
+        let (guess, total) = match opener {
+            Some(word) => {
+                let guess = self.table.guesses().iter().position(|&g| g == word)?;
+                (guess, self.guess_total(&all, guess, MAX_GUESSES, usize::MAX)?)
+            }
+            None => self.search(&all, MAX_GUESSES, usize::MAX)?,
+        };
+        let worst = self.worst(&all, guess, MAX_GUESSES);
+        Some(OptimalPlay { opener: self.table.guesses()[guess], total, answers: all.len(), worst })
+    }
+
+    // The candidates giving each result of 'guess', but the one it solves.
+    fn buckets(&self, answers: &[u16], guess: usize) -> Vec<Vec<u16>> {
+        let mut buckets = self.table.partition(guess, answers);
+        buckets[self.table.solved().index()].clear();
+        buckets.retain(|b| !b.is_empty());
+        buckets
+    }
+
+    // The best guess for 'answers' with 'depth' guesses left, and the guesses it takes, if fewer
+    // than 'bound'.
+    fn search(&mut self, answers: &[u16], depth: usize, bound: usize) -> Option<(usize, usize)> {
+        let n = answers.len();
+        let floor = lower_bound(n);
+        if depth == 0 || floor >= bound || (depth == 1 && n > 1) {
+            return None;
+        }
+        if n == 1 {
+            let word = self.table.answers()[answers[0] as usize];
+            if let Some(guess) = self.table.guesses().iter().position(|&g| g == word) {
+                return Some((guess, 1));
+            }
+        }
+        let key = (answers.to_vec(), depth);
+        match self.memo.get(&key) {
+            Some(&Known::Exact(guess, total)) => return Some((guess, total)).filter(|_| total < bound),
+            Some(&Known::AtLeast(total)) if total >= bound => return None,
+            _ => {}
+        }
+
+        // Each candidate takes this guess, and then at least lower_bound() more for each result.
+        let mut ordered = (0..self.table.guesses().len()).filter_map(|g| {
+            let buckets = self.buckets(answers, g);
+            if buckets.len() == 1 && buckets[0].len() == n {
+                return None;
+            }
+            Some((n + buckets.iter().map(|b| lower_bound(b.len())).sum::<usize>(), g))
+        }).collect::<Vec<_>>();
+        ordered.sort_unstable();
+
+        let mut best = None;
+        let mut bound = bound;
+        for (least, guess) in ordered {
+            if least >= bound {
+                break;
+            }
+            if let Some(total) = self.guess_total(answers, guess, depth, bound) {
+                best = Some((guess, total));
+                bound = total;
+                if total == floor {
+                    break;
+                }
+            }
+        }
+        let known = match best {
+            Some((guess, total)) => Known::Exact(guess, total),
+            None => Known::AtLeast(bound),
+        };
+        self.memo.insert(key, known);
+        best
+    }
+
+    // The guesses taken by playing 'guess' and then optimally, if fewer than 'bound'.
+    fn guess_total(&mut self, answers: &[u16], guess: usize, depth: usize, bound: usize) -> Option<usize> {
+        let buckets = self.buckets(answers, guess);
+        let mut rest = buckets.iter().map(|b| lower_bound(b.len())).sum::<usize>();
+        let mut total = answers.len();
+        for bucket in &buckets {
+            rest -= lower_bound(bucket.len());
+            let (_, sub) = self.search(bucket, depth - 1, bound.checked_sub(total + rest)?)?;
+            total += sub;
+        }
+        Some(total).filter(|&t| t < bound)
+    }
+
+    // The most guesses taken by optimal play after opening with 'guess'.
+    fn worst(&mut self, answers: &[u16], guess: usize, depth: usize) -> usize {
+        let buckets = self.buckets(answers, guess);
+        1 + buckets.iter().map(|b| {
+            let (next, _) = self.search(b, depth - 1, usize::MAX).unwrap();
+            self.worst(b, next, depth - 1)
+        }).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test_optimal {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let answers = ["hatch", "watch", "latch", "patch", "match"];
+        let guesses = ["hatch", "watch", "latch", "patch", "match", "whelp"];
+        let table = PatternTable::new(&answers, &guesses);
+        let mut solver = OptimalSolver::new(&table);
+        // "whelp" tells every answer apart but "hatch" and "match": 5 + 3 * 1 + (2 + 1).
+        assert_eq!(solver.solve(Some("whelp")),
+                   Some(OptimalPlay { opener: "whelp", total: 11, answers: 5, worst: 3 }));
+        // Opening with an answer solves it, but leaves the other four together: 5 + 4 + 4.
+        assert_eq!(solver.solve(Some("hatch")).unwrap().total, 13);
+        assert_eq!(solver.solve(None).unwrap().opener, "whelp");
+        assert_eq!(solver.solve(Some("zzzzz")), None);
+    }
+}
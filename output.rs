@@ -0,0 +1,223 @@
+use crate::{ToJson, alphabet};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+// Set once from the command line, before any output.
+static JSON: AtomicBool = AtomicBool::new(false);
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// How much simulations print: each game with -v, only the summary by default, and with -q just
+/// its first line, without a progress bar.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+/// Print candidates, suggestions and simulation results as JSON, one object per line, instead
+/// of text.
+pub fn set_json_output(on: bool) {
+    JSON.store(on, Ordering::Relaxed);
+}
+
+pub fn json_output() -> bool {
+    JSON.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        _ => Verbosity::Verbose,
+    }
+}
+
+/// 's' as a quoted JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// A JSON array of already encoded values.
+pub fn json_array<I: IntoIterator<Item = String>>(values: I) -> String {
+    format!("[{}]", values.into_iter().collect::<Vec<_>>().join(","))
+}
+
+/// A JSON object, built a field at a time.  Its "type" field says what it describes.
+#[derive(Clone, Debug)]
+pub struct JsonObject {
+    fields: Vec<(&'static str, String)>,
+}
+
+impl JsonObject {
+    pub fn new(kind: &str) -> Self {
+        Self { fields: Vec::new() }.str("type", kind)
+    }
+
+    pub fn str(self, key: &'static str, value: &str) -> Self {
+        self.raw(key, json_string(value))
+    }
+
+    pub fn num<T: fmt::Display>(self, key: &'static str, value: T) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    /// A float field; JSON has no NaN or infinity, so those are null.
+    pub fn float(self, key: &'static str, value: f64) -> Self {
+        self.raw(key, if value.is_finite() { value.to_string() } else { "null".to_string() })
+    }
+
+    pub fn bool(self, key: &'static str, value: bool) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    pub fn strs<S: AsRef<str>>(self, key: &'static str, values: &[S]) -> Self {
+        self.raw(key, json_array(values.iter().map(|v| json_string(v.as_ref()))))
+    }
+
+    /// A field holding the JSON form of 'value'.
+    pub fn json<T: ToJson + ?Sized>(self, key: &'static str, value: &T) -> Self {
+        self.raw(key, value.to_json().to_string())
+    }
+
+    /// A field whose value is already encoded.
+    pub fn raw(mut self, key: &'static str, value: String) -> Self {
+        self.fields.push((key, value));
+        self
+    }
+}
+
+impl fmt::Display for JsonObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}:{}", json_string(key), value)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// 'n' with commas between thousands, e.g. "12,947".
+pub fn fmt_count(n: usize) -> String {
+    let digits = n.to_string();
+    let mut res = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            res.push(',');
+        }
+        res.push(c);
+    }
+    res
+}
+
+// 's' as a CSV field, quoted if it must be.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Rows of CSV, written to a file as they come, as each game of a simulation finishes.  The first
+/// error writing them is kept for finish() to return.
+pub struct CsvWriter {
+    out: BufWriter<File>,
+    error: Option<io::Error>,
+}
+
+impl CsvWriter {
+    pub fn create(path: &Path, header: &[&str]) -> io::Result<Self> {
+        let mut res = Self { out: BufWriter::new(File::create(path)?), error: None };
+        res.row(header);
+        Ok(res)
+    }
+
+    pub fn row<S: AsRef<str>>(&mut self, fields: &[S]) {
+        if self.error.is_none() {
+            let line = fields.iter().map(|f| csv_field(f.as_ref())).collect::<Vec<_>>().join(",");
+            self.error = writeln!(self.out, "{}", line).err();
+        }
+    }
+
+    /// Flush the rows, returning the first error writing any of them.
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => self.out.flush(),
+        }
+    }
+}
+
+/// Print a suggested guess, with the most candidates it can leave if known.
+pub fn print_guess(guess: &str, worst_case: Option<usize>) {
+    let guess = alphabet().decode(guess);
+    if json_output() {
+        let obj = JsonObject::new("best_guess").str("guess", &guess);
+        println!("{}", match worst_case {
+            Some(worst) => obj.num("worst_case", worst),
+            None => obj,
+        });
+        return;
+    }
+    match worst_case {
+        Some(worst) => println!("Best guess: '{}' with worst case {} candidates", guess, worst),
+        None => println!("Best guess: '{}'", guess),
+    }
+}
+
+#[cfg(test)]
+mod test_output {
+    use super::*;
+    use crate::{Color, Json};
+
+    #[test]
+    fn test_json() {
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+        assert_eq!(fmt_count(1234567), "1,234,567");
+        assert_eq!(fmt_count(999), "999");
+        let obj = JsonObject::new("game").str("answer", "cigar").num("rounds", 3).float("average", f64::NAN)
+            .strs("left", &["rebut", "sissy"]).bool("solved", true);
+        assert_eq!(obj.to_string(),
+                   r#"{"type":"game","answer":"cigar","rounds":3,"average":null,"left":["rebut","sissy"],"solved":true}"#);
+
+        // What JsonObject writes, Json reads back.
+        let json = Json::parse(&obj.str("note", "\"a\"\\\n\u{1}").json("result", &Color::YELLOW).to_string()).unwrap();
+        assert_eq!(json.field::<String>("answer").as_deref(), Ok("cigar"));
+        assert_eq!(json.field::<String>("note").as_deref(), Ok("\"a\"\\\n\u{1}"));
+        assert_eq!(json.field::<Vec<String>>("left"), Ok(vec!["rebut".to_string(), "sissy".to_string()]));
+        assert_eq!((json.field::<usize>("rounds"), json.get("average")), (Ok(3), Some(&Json::Null)));
+        assert_eq!(json.field::<Color>("result"), Ok(Color::YELLOW));
+    }
+
+    #[test]
+    fn test_csv() {
+        let path = std::env::temp_dir().join(format!("wordle-csv-test-{}", std::process::id()));
+        let mut csv = CsvWriter::create(&path, &["answer", "note"]).unwrap();
+        csv.row(&["cigar", "a,\"b\""]);
+        csv.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "answer,note\ncigar,\"a,\"\"b\"\"\"\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+// Words as byte arrays, as scoring and pruning take them, or five letter words packed five bits
+// a letter into a u32, for comparing and hashing words without going through str.  wordlist.rs
+// has the builtin lists both as strs and as byte arrays, as mkwordlist writes them.
+use crate::{ANSW_LIST, ANSW_WORDS, GUESS_LIST, GUESS_WORDS};
+use std::any::Any;
+use std::borrow::Cow;
+use std::convert::TryInto;
+
+pub fn word_bytes(word: &str) -> [u8; 5] {
+    word_bytes_n(word)
+}
+
+/// A word of a list, as stored, as a byte array: unlike parse_guess_n(), without checking it's
+/// letters of the alphabet.  Panics if it hasn't 'N' bytes.
+pub fn word_bytes_n<const N: usize>(word: &str) -> [u8; N] {
+    word.as_bytes().try_into().expect("listed words have as many letters as the game")
+}
+
+/// A word of the byte array lists as a str.
+pub fn word_str<const N: usize>(word: &[u8; N]) -> &str {
+    std::str::from_utf8(word).expect("listed words are a byte a letter")
+}
+
+/// 'words' as byte arrays: for the builtin lists, those of wordlist.rs, borrowed.
+pub fn word_list_bytes<const N: usize>(words: &[&str]) -> Cow<'static, [[u8; N]]> {
+    let builtin = if std::ptr::eq(words, ANSW_LIST) {
+        Some(ANSW_WORDS)
+    } else if std::ptr::eq(words, GUESS_LIST) {
+        Some(GUESS_WORDS)
+    } else {
+        None
+    };
+    // The builtin lists are five letter words, so are only borrowed for N of 5.
+    match builtin.as_ref().and_then(|words| (words as &dyn Any).downcast_ref::<&'static [[u8; N]]>()) {
+        Some(&words) => Cow::Borrowed(words),
+        None => Cow::Owned(words.iter().map(|w| word_bytes_n(w)).collect()),
+    }
+}
+
+/// A word as stored as one number, five bits a letter, first letter highest, so packed words
+/// order as the words do.  The letters an alphabet adds after z pack as well as a to z.
+pub const fn pack_word(word: [u8; 5]) -> u32 {
+    let mut res = 0;
+    let mut i = 0;
+    while i < 5 {
+        res = res << 5 | (word[i] - b'a') as u32;
+        i += 1;
+    }
+    res
+}
+
+/// The word 'packed' was packed from.
+pub const fn unpack_word(packed: u32) -> [u8; 5] {
+    let mut res = [0; 5];
+    let mut i = 0;
+    while i < 5 {
+        res[i] = b'a' + (packed >> (5 * (4 - i)) & 31) as u8;
+        i += 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_packed {
+    use super::*;
+
+    #[test]
+    fn test_packed() {
+        assert_eq!(ANSW_WORDS.len(), ANSW_LIST.len());
+        assert_eq!(GUESS_WORDS.len(), GUESS_LIST.len());
+        assert!(ANSW_WORDS.iter().zip(ANSW_LIST).all(|(w, &a)| word_str(w) == a));
+        assert!(GUESS_WORDS.iter().zip(GUESS_LIST).all(|(w, &g)| word_str(w) == g));
+        assert_eq!(word_bytes("ni{os"), *b"ni{os");
+        assert!(matches!(word_list_bytes::<5>(ANSW_LIST), Cow::Borrowed(words) if words == ANSW_WORDS));
+        assert_eq!(*word_list_bytes(&["cigar", "rebut"]), [*b"cigar", *b"rebut"]);
+        assert_eq!(*word_list_bytes::<4>(&["plan"]), [*b"plan"]);
+
+        assert_eq!(pack_word(*b"aaaaa"), 0);
+        assert_eq!(unpack_word(pack_word(*b"cigar")), *b"cigar");
+        assert_eq!(unpack_word(pack_word(*b"ni{os")), *b"ni{os");
+        assert!(pack_word(*b"cigar") < pack_word(*b"rebut"));
+        assert!(pack_word(*b"zebra") > pack_word(*b"yield"));
+    }
+}
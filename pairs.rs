@@ -0,0 +1,91 @@
+use crate::PatternTable;
+use crate::par::*;
+use std::cmp::Ordering;
+
+/// A fixed two word opening, played whatever the first result, and how it splits the answers:
+/// the candidates left are those giving the same results for both words.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpeningPair<'a> {
+    pub first: &'a str,
+    pub second: &'a str,
+    /// The most candidates it can leave.
+    pub worst_case: usize,
+    /// The candidates it leaves on average, if each answer is equally likely.
+    pub expected_remaining: f64,
+}
+
+impl<'a> OpeningPair<'a> {
+    /// Evaluate the guesses 'first' and 'second', by their indices in 'table', against every
+    /// answer of the table.
+    pub fn evaluate(table: &PatternTable<'a>, first: usize, second: usize) -> Self {
+        Self::evaluate_with(table, first, second, &mut vec![0; table.pattern_count().pow(2)])
+    }
+
+    // As evaluate(), counting the answers giving each pair of results in 'counts', which must be
+    // zeroed and is left zeroed, so one buffer serves many pairs.
+    fn evaluate_with(table: &PatternTable<'a>, first: usize, second: usize, counts: &mut [u32]) -> Self {
+        let n = table.answers().len();
+        let key = |a| table.pattern(first, a).index() * table.pattern_count() + table.pattern(second, a).index();
+        for a in 0..n {
+            counts[key(a)] += 1;
+        }
+        let (mut worst_case, mut sum_squares) = (0, 0);
+        for a in 0..n {
+            // Taken by the first answer of each bucket, so each bucket is counted once.
+            let size = std::mem::take(&mut counts[key(a)]) as usize;
+            worst_case = worst_case.max(size);
+            sum_squares += size * size;
+        }
+        let guesses = table.guesses();
+        Self { first: guesses[first], second: guesses[second], worst_case,
+               expected_remaining: sum_squares as f64 / n.max(1) as f64 }
+    }
+
+    // Fewest candidates in the worst case, then on average.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.worst_case.cmp(&other.worst_case)
+            .then(self.expected_remaining.partial_cmp(&other.expected_remaining).unwrap())
+    }
+}
+
+/// The best 'top' pairs of a guess from 'firsts' with any other guess of the table, by their
+/// indices in it, from best to worst: fewest candidates in the worst case, then on average.
+/// Each pair is evaluated once, and the cost is a pass over the answers per pair, so pairing every
+/// guess with every other is far slower than pairing a few good openers with the rest.
+pub fn rank_pairs<'a>(table: &PatternTable<'a>, firsts: &[usize], top: usize) -> Vec<OpeningPair<'a>> {
+    let mut res = firsts.par_iter().enumerate().flat_map_iter(|(i, &first)| {
+        let mut counts = vec![0; table.pattern_count().pow(2)];
+        // A pair of two firsts is evaluated with the earlier one first.
+        let mut pairs = (0..table.guesses().len())
+            .filter(|&second| second != first && !firsts[..i].contains(&second))
+            .map(|second| OpeningPair::evaluate_with(table, first, second, &mut counts))
+            .collect::<Vec<_>>();
+        pairs.sort_by(OpeningPair::cmp);
+        pairs.truncate(top);
+        pairs
+    }).collect::<Vec<_>>();
+    res.sort_by(OpeningPair::cmp);
+    res.truncate(top);
+    res
+}
+
+#[cfg(test)]
+mod test_pairs {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let answers = ["hatch", "watch", "latch", "patch", "cigar"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        let table = PatternTable::new(&answers, &guesses);
+        // "cigar" only tells itself apart, which "hatch" had already done.
+        let pair = OpeningPair::evaluate(&table, 0, 2);
+        assert_eq!((pair.first, pair.second, pair.worst_case, pair.expected_remaining), ("hatch", "cigar", 3, 2.2));
+
+        let ranked = rank_pairs(&table, &[0, 1, 2], 10);
+        assert_eq!(ranked.iter().map(|p| (p.first, p.second)).collect::<Vec<_>>(),
+                   [("hatch", "whelp"), ("whelp", "cigar"), ("hatch", "cigar")]);
+        assert_eq!(ranked[0].worst_case, 1);
+        assert_eq!(rank_pairs(&table, &[2], 1).iter().map(|p| p.second).collect::<Vec<_>>(), ["whelp"]);
+    }
+}
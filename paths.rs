@@ -0,0 +1,13 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Location of one of our files: '$override_var' if set, else 'name' under the XDG base
+/// directory '$xdg_var', which defaults to '$HOME/home_default'.
+pub fn xdg_path(override_var: &str, xdg_var: &str, home_default: &str, name: &str) -> Option<PathBuf> {
+    if let Some(path) = env::var_os(override_var) {
+        return Some(path.into());
+    }
+    let base = env::var_os(xdg_var).map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(home_default)))?;
+    Some(base.join("wordle").join(name))
+}
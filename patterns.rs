@@ -0,0 +1,288 @@
+use crate::{BestGuess, Color, MAX_LETTERS, Preferences, Strategy, alphabet, rank, score_into};
+use crate::par::*;
+use crate::solver::best_bounded;
+use rustc_hash::FxHashMap as HashMap;
+
+/// A result encoded as a number below Pattern::count() of its length: its colors as base 3
+/// digits, first tile lowest.  Cheap to compare and usable as an array index.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pattern(pub u16);
+
+impl Pattern {
+    /// Number of distinct results for words of 'len' letters.
+    pub const fn count(len: usize) -> usize {
+        3usize.pow(len as u32)
+    }
+
+    /// The result of guessing the answer, for words of 'len' letters.
+    pub const fn solved(len: usize) -> Pattern {
+        Pattern(Self::count(len) as u16 - 1)
+    }
+
+    /// The result of 'guess' for 'answ', as score() gives it, for words of any length up to
+    /// MAX_LETTERS.
+    pub fn of(answ: &str, guess: &str) -> Self {
+        // Words as typed, rather than stored, are first stored a byte a letter.
+        if !answ.is_ascii() || !guess.is_ascii() {
+            let alphabet = alphabet();
+            return Self::of(&alphabet.encode_text(answ), &alphabet.encode_text(guess));
+        }
+        let mut colors = [Color::GREY; MAX_LETTERS];
+        let colors = &mut colors[..guess.len()];
+        score_into(answ.as_bytes(), guess.as_bytes(), colors);
+        Self::from_colors(colors)
+    }
+
+    fn from_colors(colors: &[Color]) -> Self {
+        Pattern(colors.iter().rev().fold(0, |acc, &c| acc * 3 + c as u16))
+    }
+
+    pub fn colors<const N: usize>(self) -> [Color; N] {
+        let mut index = self.0;
+        let mut res = [Color::GREY; N];
+        for c in &mut res {
+            *c = match index % 3 {
+                0 => Color::GREY,
+                1 => Color::YELLOW,
+                _ => Color::GREEN,
+            };
+            index /= 3;
+        }
+        res
+    }
+
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl<const N: usize> From<[Color; N]> for Pattern {
+    fn from(result: [Color; N]) -> Self {
+        Self::from_colors(&result)
+    }
+}
+
+impl<const N: usize> From<Pattern> for [Color; N] {
+    fn from(pattern: Pattern) -> Self {
+        pattern.colors()
+    }
+}
+
+/// How many of 'answers' give each result for 'guess', indexed by Pattern.
+pub fn pattern_counts(answers: &[&str], guess: &str) -> Vec<usize> {
+    let mut res = vec![0; Pattern::count(guess.len())];
+    for answ in answers {
+        res[Pattern::of(answ, guess).index()] += 1;
+    }
+    res
+}
+
+/// 'answers' grouped by the result they give for 'guess', indexed by Pattern, each group in the
+/// order given.
+pub fn partition_by_pattern<'a>(guess: &str, answers: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut res = vec![Vec::new(); Pattern::count(guess.len())];
+    for &answ in answers {
+        res[Pattern::of(answ, guess).index()].push(answ);
+    }
+    res
+}
+
+/// The result of every guess against every answer, as pattern indices, so searches look results
+/// up instead of scoring.  For the builtin lists it takes about 30MB.
+pub struct PatternTable<'a> {
+    answers: Vec<&'a str>,
+    guesses: Vec<&'a str>,
+    answer_index: HashMap<&'a str, usize>,
+    guess_index: HashMap<&'a str, usize>,
+    // Row per guess.
+    patterns: Vec<Pattern>,
+}
+
+impl<'a> PatternTable<'a> {
+    pub fn new(answers: &[&'a str], guesses: &[&'a str]) -> Self {
+        let patterns = guesses.par_iter()
+            .flat_map_iter(|g| answers.iter().map(move |a| Pattern::of(a, g)))
+            .collect();
+        Self {
+            answers: answers.to_vec(),
+            guesses: guesses.to_vec(),
+            answer_index: answers.iter().enumerate().map(|(i, &a)| (a, i)).collect(),
+            guess_index: guesses.iter().enumerate().map(|(i, &g)| (g, i)).collect(),
+            patterns,
+        }
+    }
+
+    pub fn answers(&self) -> &[&'a str] {
+        &self.answers
+    }
+
+    pub fn guesses(&self) -> &[&'a str] {
+        &self.guesses
+    }
+
+    /// The index of 'answer' among the table's answers.
+    pub fn answer_index(&self, answer: &str) -> Option<usize> {
+        self.answer_index.get(answer).copied()
+    }
+
+    /// The index of 'guess' among the table's guesses.
+    pub fn guess_index(&self, guess: &str) -> Option<usize> {
+        self.guess_index.get(guess).copied()
+    }
+
+    /// The result of guess 'guess' against answer 'answer', by their indices in the table.
+    pub fn pattern(&self, guess: usize, answer: usize) -> Pattern {
+        self.patterns[guess * self.answers.len() + answer]
+    }
+
+    /// As score_n(), if both words are in the table.
+    pub fn lookup<const N: usize>(&self, answer: &str, guess: &str) -> Option<[Color; N]> {
+        let (a, g) = (*self.answer_index.get(answer)?, *self.guess_index.get(guess)?);
+        Some(self.pattern(g, a).colors())
+    }
+
+    /// Number of distinct results for the table's words.
+    pub fn pattern_count(&self) -> usize {
+        Pattern::count(self.word_len())
+    }
+
+    /// The result of guessing the answer, for the table's words.
+    pub fn solved(&self) -> Pattern {
+        Pattern::solved(self.word_len())
+    }
+
+    fn word_len(&self) -> usize {
+        self.guesses.first().map_or(0, |g| g.len())
+    }
+
+    /// As partition_by_pattern(), for a guess and answers given by their indices in the table.
+    pub fn partition<A: Copy + Into<usize>>(&self, guess: usize, answers: &[A]) -> Vec<Vec<A>> {
+        let mut res = vec![Vec::new(); self.pattern_count()];
+        for &a in answers {
+            res[self.pattern(guess, a.into()).index()].push(a);
+        }
+        res
+    }
+
+    /// As BestGuess::evaluate(), for answers given by index.
+    pub fn evaluate(&self, answers: &[usize], guess: usize, is_candidate: bool) -> BestGuess<'a> {
+        self.evaluate_bounded(answers, guess, is_candidate, usize::MAX).unwrap()
+    }
+
+    /// As BestGuess::evaluate_bounded(), for answers given by index.
+    pub fn evaluate_bounded(&self, answers: &[usize], guess: usize, is_candidate: bool, bound: usize)
+        -> Option<BestGuess<'a>> {
+        let mut buckets = vec![0; self.pattern_count()];
+        // The results seen, so only those buckets need visiting.
+        let mut seen = Vec::new();
+        for &a in answers {
+            let p = self.pattern(guess, a);
+            if buckets[p.index()] == 0 {
+                seen.push(p);
+            }
+            buckets[p.index()] += 1;
+            if buckets[p.index()] > bound {
+                return None;
+            }
+        }
+        Some(BestGuess::from_buckets(self.guesses[guess], is_candidate, seen.iter().map(|p| buckets[p.index()])))
+    }
+
+    // The indices of 'answers', if all are in the table, and which of the table's answers they are.
+    fn candidate_indices(&self, answers: &[&str]) -> Option<(Vec<usize>, Vec<bool>)> {
+        let indices = answers.iter().map(|a| self.answer_index.get(a).copied()).collect::<Option<Vec<_>>>()?;
+        let mut candidates = vec![false; self.answers.len()];
+        for &i in &indices {
+            candidates[i] = true;
+        }
+        Some((indices, candidates))
+    }
+}
+
+/// The minimax strategy, looking results up in the table; words it lacks are scored as usual.
+impl<'t> Strategy for PatternTable<'t> {
+    fn name(&self) -> &'static str {
+        "minimax"
+    }
+
+    fn order<'a>(&self, evaluated: Vec<BestGuess<'a>>, prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        rank(evaluated, prefs)
+    }
+
+    fn rank_guesses<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        let (indices, candidates) = match self.candidate_indices(answers) {
+            Some(found) => found,
+            None => return self.order(crate::evaluate_all(answers, guesses), prefs),
+        };
+        let is_candidate = |g: &str| self.answer_index.get(g).is_some_and(|&i| candidates[i]);
+        let evaluated = guesses.par_iter().map(|&g| match self.guess_index.get(g) {
+            // Same word, but with the caller's lifetime.
+            Some(&i) => BestGuess { word: g, ..self.evaluate(&indices, i, is_candidate(g)) },
+            None => BestGuess::evaluate(answers, g, is_candidate(g)),
+        }).collect();
+        self.order(evaluated, prefs)
+    }
+
+    /// Stops looking up a guess's results once it can't be the best.
+    fn best_guess<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Option<BestGuess<'a>> {
+        let (indices, candidates) = match self.candidate_indices(answers) {
+            Some(found) => found,
+            None => return crate::Minimax.best_guess(answers, guesses, prefs),
+        };
+        let is_candidate = |g: &str| self.answer_index.get(g).is_some_and(|&i| candidates[i]);
+        best_bounded(guesses, prefs, |g, bound| match self.guess_index.get(g) {
+            Some(&i) => Some(BestGuess { word: g, ..self.evaluate_bounded(&indices, i, is_candidate(g), bound)? }),
+            None => BestGuess::evaluate_bounded(answers, g, is_candidate(g), bound),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_patterns {
+    use super::*;
+    use crate::{Minimax, parse_result, score, score_n};
+
+    #[test]
+    fn test_pattern() {
+        assert_eq!(Pattern::from([Color::GREY; 5]), Pattern(0));
+        assert_eq!(Pattern::from([Color::GREEN; 5]), Pattern::solved(5));
+        for i in 0..Pattern::count(5) as u16 {
+            assert_eq!(Pattern::from(Pattern(i).colors::<5>()), Pattern(i));
+        }
+        assert_eq!(<[Color; 5]>::from(Pattern(1)), parse_result("10000").unwrap());
+        assert_eq!(Pattern::of("cigar", "humph"), Pattern(0));
+        assert_eq!(Pattern::of("planet", "please"), Pattern::from(score_n::<6>("planet", "please")));
+        assert_eq!(Pattern::solved(4), Pattern::of("plan", "plan"));
+
+        let counts = pattern_counts(&["hatch", "watch", "latch", "cigar"], "patch");
+        assert_eq!(counts[Pattern::from(score("hatch", "patch")).index()], 3);
+        assert_eq!(counts.iter().sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_partition() {
+        let answers = ["hatch", "watch", "latch", "cigar"];
+        let buckets = partition_by_pattern("patch", &answers);
+        assert_eq!(buckets[Pattern::from(score("hatch", "patch")).index()], ["hatch", "watch", "latch"]);
+        assert_eq!(buckets[Pattern::from(score("cigar", "patch")).index()], ["cigar"]);
+        assert_eq!(buckets.iter().map(Vec::len).sum::<usize>(), 4);
+
+        let table = PatternTable::new(&answers, &["patch"]);
+        let indices = table.partition(0, &[0u16, 1, 2, 3]);
+        assert_eq!(indices.iter().map(Vec::len).collect::<Vec<_>>(), buckets.iter().map(Vec::len).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_table() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        let table = PatternTable::new(&answers, &guesses);
+        assert_eq!(table.lookup("watch", "whelp"), Some(score("watch", "whelp")));
+        assert_eq!(table.lookup::<5>("cigar", "whelp"), None);
+
+        let prefs = Preferences::default();
+        assert_eq!(table.rank_guesses(&answers, &guesses, &prefs), Minimax.rank_guesses(&answers, &guesses, &prefs));
+        assert_eq!(table.best_guess(&answers[1..], &["hatch", "zzzzz"], &prefs),
+                   Minimax.best_guess(&answers[1..], &["hatch", "zzzzz"], &prefs));
+    }
+}
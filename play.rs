@@ -0,0 +1,64 @@
+// The solver playing a game out, as the simulators, the server and the multi-board games all
+// have it do.
+use crate::{Game, Preferences, Strategy, score_n, word_bytes_n};
+
+/// The guesses played on 'state' until 'play' reports the game over: first the words of
+/// 'opening', then those 'next' chooses, giving up after 'max_rounds' or when 'next' has none.
+pub fn play_out<'a, S>(state: &mut S, opening: &[&'a str], max_rounds: usize, next: impl Fn(&S) -> Option<&'a str>,
+                       mut play: impl FnMut(&mut S, &'a str) -> bool) -> Vec<&'a str> {
+    let mut line = Vec::new();
+    while line.len() < max_rounds {
+        let guess = match opening.get(line.len()) {
+            Some(&word) => word,
+            None => match next(state) {
+                Some(word) => word,
+                None => break,
+            },
+        };
+        line.push(guess);
+        if play(state, guess) {
+            break;
+        }
+    }
+    line
+}
+
+/// The guesses the solver plays from 'game' until it finds 'answer', starting with the words of
+/// 'opening', and giving up after 'max_rounds'.  The line ends short of 'answer' if the solver
+/// gave up, or if 'answer' isn't a candidate.
+pub fn play_line<'a, const N: usize>(game: &Game<N>, guesses: &[&'a str], strategy: &dyn Strategy, prefs: &Preferences,
+                     opening: &[&'a str], answer: &str, max_rounds: usize) -> Vec<&'a str> {
+    let next = |game: &Game<N>| Some(strategy.best_guess(game.candidates(), &game.guess_pool(guesses), prefs)?.word);
+    play_out(&mut game.clone(), opening, max_rounds, next, |game, guess| {
+        if answer == guess || game.candidates().is_empty() {
+            return true;
+        }
+        game.apply(word_bytes_n(guess), score_n(answer, guess));
+        false
+    })
+}
+
+#[cfg(test)]
+mod test_play {
+    use super::*;
+    use crate::Minimax;
+
+    #[test]
+    fn test_play_line() {
+        let answers = ["hatch", "watch", "latch", "cigar"];
+        let game = Game::<5>::new(&answers);
+        let prefs = Preferences::default();
+        assert_eq!(play_line(&game, &answers, &Minimax, &prefs, &["cigar"], "cigar", 10), ["cigar"]);
+        let line = play_line(&game, &answers, &Minimax, &prefs, &["cigar"], "latch", 10);
+        assert_eq!((line[0], line.last()), ("cigar", Some(&"latch")));
+        assert_eq!(play_line(&game, &answers, &Minimax, &prefs, &["cigar", "hatch"], "latch", 1), ["cigar"]);
+        // An answer which isn't a candidate is never found.
+        assert_ne!(play_line(&game, &answers, &Minimax, &prefs, &[], "zebra", 10).last(), Some(&"zebra"));
+
+        let mut count = 0;
+        assert_eq!(play_out(&mut count, &["a"], 10, |&n| if n < 3 { Some("b") } else { None }, |n, _| {
+            *n += 1;
+            false
+        }), ["a", "b", "b"]);
+    }
+}
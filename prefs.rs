@@ -0,0 +1,147 @@
+use crate::{ANSW_LIST, Objective, Rng, letter_index};
+use rustc_hash::FxHashSet as HashSet;
+
+/// Weighted preferences for breaking ties between near-optimal guesses.  A word scores the
+/// weight of each preference it satisfies.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Preferences {
+    /// Prefer common words, i.e. words from the curated answer list.
+    pub common: i32,
+    /// Prefer words without repeated letters.
+    pub unique: i32,
+    /// Avoid words which look like plurals.
+    pub no_plurals: i32,
+    /// How many more worst-case candidates than the best guess a guess may leave and still be
+    /// considered near-optimal.
+    pub slack: usize,
+    /// How guesses compare before the preferences above apply.
+    pub objective: Objective,
+    /// Break exact ties at random, in an order fixed by this seed, instead of by list order.
+    pub seed: Option<u64>,
+}
+
+// A cheap heuristic: "robes" looks plural, "dress", "focus" and "basis" do not.
+fn looks_plural(word: &str) -> bool {
+    match word.as_bytes() {
+        [.., before, b's'] => !matches!(before, b's' | b'u' | b'i'),
+        _ => false,
+    }
+}
+
+fn has_repeats(word: &str) -> bool {
+    let mut seen = 0u32;
+    for b in word.bytes() {
+        let bit = 1 << letter_index(b);
+        if seen & bit != 0 {
+            return true;
+        }
+        seen |= bit;
+    }
+    false
+}
+
+impl Preferences {
+    pub const KEYS: &'static [&'static str] = &["common", "unique", "plurals", "slack", "objective", "seed"];
+
+    pub fn set(&mut self, key: &str, value: &str) -> Option<()> {
+        match key {
+            "common" => self.common = value.parse().ok()?,
+            "unique" => self.unique = value.parse().ok()?,
+            "plurals" => self.no_plurals = value.parse().ok()?,
+            "slack" => self.slack = value.parse().ok()?,
+            "objective" => self.objective = Objective::parse(value)?,
+            "seed" if value == "off" => self.seed = None,
+            "seed" => self.seed = Some(value.parse().ok()?),
+            _ => return None,
+        }
+        Some(())
+    }
+
+    pub fn weight(&self, word: &str, common: &HashSet<&str>) -> i32 {
+        let mut w = 0;
+        if common.contains(word) {
+            w += self.common;
+        }
+        if !has_repeats(word) {
+            w += self.unique;
+        }
+        if !looks_plural(word) {
+            w += self.no_plurals;
+        }
+        w
+    }
+
+    /// Orders guesses which tie on everything else: by their order in the list unless there is a
+    /// seed, and otherwise at random for it.
+    pub fn tie_break(&self, word: &str) -> u64 {
+        self.seed.map_or(0, |seed| Rng::keyed(seed, word))
+    }
+
+    /// Choose from guesses scored by 'best_guess' (twice the worst case, minus one for possible
+    /// answers; lower is better): the most preferred guess within 'slack' of the best score, then
+    /// the lowest score, then by tie_break(), then the earliest.
+    pub fn pick<'a>(&self, scored: &[(usize, &'a str)]) -> Option<(&'a str, usize)> {
+        let best = scored.iter().map(|&(sco, _)| sco).min()?;
+        let limit = best.saturating_add(2 * self.slack);
+        let common = if self.common != 0 {
+            ANSW_LIST.iter().copied().collect()
+        } else {
+            HashSet::default()
+        };
+
+        let mut res: Option<(i32, usize, &'a str)> = None;
+        for &(sco, guess) in scored {
+            if sco > limit {
+                continue;
+            }
+            let w = self.weight(guess, &common);
+            let better = match res {
+                None => true,
+                Some((bw, bsco, bguess)) => w > bw || (w == bw && sco < bsco)
+                    || (w == bw && sco == bsco && self.tie_break(guess) < self.tie_break(bguess)),
+            };
+            if better {
+                res = Some((w, sco, guess));
+            }
+        }
+        res.map(|(_, sco, guess)| (guess, sco))
+    }
+}
+
+#[cfg(test)]
+mod test_prefs {
+    use super::*;
+
+    #[test]
+    fn test_pick() {
+        let scored = [(9, "soare"), (11, "arise"), (13, "robes"), (9, "sissy"), (30, "cigar")];
+        let mut prefs = Preferences::default();
+        assert_eq!(prefs.pick(&scored), Some(("soare", 9)));
+        prefs.common = 1;
+        assert_eq!(prefs.pick(&scored), Some(("sissy", 9)));
+        prefs.slack = 1;
+        assert_eq!(prefs.pick(&scored), Some(("sissy", 9)));
+        prefs.unique = 2;
+        assert_eq!(prefs.pick(&scored), Some(("arise", 11)));
+        assert_eq!(prefs.pick(&[]), None);
+
+        // A seed reorders exact ties, and only those.
+        let tied = [(9, "soare"), (9, "sissy"), (9, "cigar"), (7, "rebut")];
+        prefs = Preferences::default();
+        assert_eq!(prefs.pick(&tied[..3]), Some(("soare", 9)));
+        let picks = (0..20).map(|seed| Preferences { seed: Some(seed), ..Default::default() }.pick(&tied[..3]).unwrap().0)
+            .collect::<HashSet<_>>();
+        assert!(picks.len() > 1);
+        assert_eq!(Preferences { seed: Some(1), ..Default::default() }.pick(&tied), Some(("rebut", 7)));
+    }
+
+    #[test]
+    fn test_looks_plural() {
+        assert!(looks_plural("robes"));
+        assert!(!looks_plural("dress"));
+        assert!(!looks_plural("focus"));
+        assert!(!looks_plural("basis"));
+        assert!(!looks_plural("salet"));
+        assert!(looks_plural("cats") && looks_plural("tables") && !looks_plural("s"));
+    }
+}
@@ -0,0 +1,5 @@
+mod multiplay;
+
+fn main() -> anyhow::Result<()> {
+    multiplay::multiboard_main::<4>("quordle")
+}
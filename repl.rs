@@ -0,0 +1,226 @@
+use crate::alphabet;
+use crate::paths::xdg_path;
+use rustc_hash::FxHashSet as HashSet;
+use rustyline::completion::Completer;
+use rustyline::config::Configurer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{ColorMode, Context, Helper};
+use std::borrow::Cow::{self, Borrowed, Owned};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal};
+use std::path::PathBuf;
+
+pub type Editor = rustyline::Editor<ReplHelper>;
+
+/// Line editor helper shared by the interactive binaries.
+#[derive(Default)]
+pub struct ReplHelper {
+    masking: bool,
+    // The command names completed at the start of a line.
+    commands: &'static [&'static str],
+    // The words completed after "g": candidates first, then the other guesses.
+    candidates: Vec<&'static str>,
+    guesses: Vec<&'static str>,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        let prefix = &line[..pos];
+        if self.masking {
+            return Ok((pos, Vec::new()));
+        }
+        match prefix.split_once(' ') {
+            None => Ok((0, self.commands.iter().filter(|c| c.starts_with(prefix)).map(|c| c.to_string()).collect())),
+            Some(("g", word)) if !word.contains(' ') => {
+                let alphabet = alphabet();
+                let stored = alphabet.encode_text(word);
+                let candidates = self.candidates.iter().copied().filter(|c| c.starts_with(&stored)).collect::<Vec<_>>();
+                let listed = candidates.iter().copied().collect::<HashSet<_>>();
+                let others = self.guesses.iter().copied().filter(|g| g.starts_with(&stored) && !listed.contains(g));
+                Ok((2, candidates.into_iter().chain(others).map(|w| alphabet.decode(w)).collect()))
+            }
+            _ => Ok((pos, Vec::new())),
+        }
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Validator for ReplHelper {}
+
+impl Highlighter for ReplHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        if self.masking {
+            Owned("*".repeat(line.len()))
+        } else {
+            Borrowed(line)
+        }
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize) -> bool {
+        self.masking
+    }
+}
+
+impl Helper for ReplHelper {}
+
+pub fn editor() -> Editor {
+    let mut rl = Editor::new();
+    rl.set_helper(Some(ReplHelper::default()));
+    rl
+}
+
+/// Read a line without echoing it or recording it in the history.
+pub fn read_secret(rl: &mut Editor, prompt: &str) -> rustyline::Result<String> {
+    let color_mode = rl.config_mut().color_mode();
+    // Masking is implemented by the highlighter, so it must run even without color support.
+    rl.set_color_mode(ColorMode::Forced);
+    rl.helper_mut().unwrap().masking = true;
+    let res = rl.readline(prompt);
+    rl.helper_mut().unwrap().masking = false;
+    rl.set_color_mode(color_mode);
+    res
+}
+
+/// The rest of a command line read with readline_raw() after the command, as typed, or None if
+/// there is nothing more.  Unlike the encoded line's words, a file name keeps every character,
+/// spaces included.
+pub fn raw_argument(line: &str) -> Option<&str> {
+    let arg = line.split_once(' ')?.1.trim();
+    if arg.is_empty() { None } else { Some(arg) }
+}
+
+/// Where a binary's line editor history is kept: the file 'prog' in '$WORDLE_HISTORY', else in
+/// wordle/history under '$XDG_STATE_HOME' (default '~/.local/state').
+pub fn history_path(prog: &str) -> Option<PathBuf> {
+    Some(xdg_path("WORDLE_HISTORY", "XDG_STATE_HOME", ".local/state", "history")?.join(prog))
+}
+
+/// Where commands come from: a line editor on a terminal, with the file its history is kept in
+/// if any, or plain stdin lines in batch mode.
+pub enum Input {
+    Interactive(Box<Editor>, Option<PathBuf>),
+    Batch(io::StdinLock<'static>),
+}
+
+impl Input {
+    /// Batch mode is used when asked for, or when stdin is not a terminal.
+    pub fn new(batch: bool) -> Self {
+        if batch || !io::stdin().is_terminal() {
+            Input::Batch(io::stdin().lock())
+        } else {
+            Input::Interactive(Box::new(editor()), None)
+        }
+    }
+
+    /// As new(), for the REPL of 'prog': the line editor completes 'commands', and keeps its
+    /// history in history_path() from one run to the next.
+    pub fn repl(batch: bool, prog: &str, commands: &'static [&'static str]) -> Self {
+        let mut input = Self::new(batch);
+        if let Input::Interactive(rl, history) = &mut input {
+            rl.helper_mut().unwrap().commands = commands;
+            *history = history_path(prog);
+            if let Some(path) = history {
+                // There's none the first time.
+                rl.load_history(path).ok();
+            }
+        }
+        input
+    }
+
+    /// Complete the word after "g" from 'guesses', listing those of 'candidates' first.
+    pub fn set_guesses(&mut self, guesses: &[&'static str]) {
+        if let Input::Interactive(rl, _) = self {
+            rl.helper_mut().unwrap().guesses = guesses.to_vec();
+        }
+    }
+
+    /// The candidates set_guesses() lists first, from now on.
+    pub fn set_candidates(&mut self, candidates: &[&'static str]) {
+        if let Input::Interactive(rl, _) = self {
+            rl.helper_mut().unwrap().candidates = candidates.to_vec();
+        }
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        matches!(self, Input::Interactive(..))
+    }
+
+    /// Read the next command, or None at end of input, with letters past z as stored.  Batch
+    /// mode prints no prompt and skips blank lines.
+    pub fn readline(&mut self, prompt: &str) -> Option<String> {
+        self.readline_raw(prompt).map(|line| alphabet().encode_text(&line))
+    }
+
+    /// As readline(), but the line as typed, for commands taking arguments that aren't words,
+    /// such as file names; see raw_argument().
+    pub fn readline_raw(&mut self, prompt: &str) -> Option<String> {
+        match self {
+            Input::Interactive(rl, history) => {
+                let line = rl.readline(prompt).ok()?;
+                rl.add_history_entry(&line);
+                // Saved a line at a time, since REPLs may exit without returning.
+                if let Some(path) = history {
+                    if let Some(dir) = path.parent() {
+                        fs::create_dir_all(dir).ok();
+                    }
+                    if let Err(e) = rl.save_history(path) {
+                        eprintln!("Warning: can't save history to {}: {}", path.display(), e);
+                        *history = None;
+                    }
+                }
+                Some(line)
+            }
+            Input::Batch(stdin) => loop {
+                let mut line = String::new();
+                if stdin.read_line(&mut line).ok()? == 0 {
+                    return None;
+                }
+                let line = line.trim_end_matches(&['\r', '\n'][..]);
+                if !line.is_empty() {
+                    return Some(line.to_string());
+                }
+            },
+        }
+    }
+
+    /// Read a line that is neither echoed nor recorded in the history, as readline() does.
+    pub fn read_secret(&mut self, prompt: &str) -> Option<String> {
+        match self {
+            Input::Interactive(rl, _) => read_secret(rl, prompt).ok().map(|line| alphabet().encode_text(&line)),
+            Input::Batch(_) => self.readline(prompt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_repl {
+    use super::*;
+    use crate::{ANSW_LIST, Game, game_json, parse_game_json, parse_guess, score};
+
+    #[test]
+    fn test_raw_argument() {
+        let dir = std::env::temp_dir().join(format!("wordle-josé-{}", std::process::id()));
+        let path = dir.join("a~b c.json");
+        let line = format!("save {}", path.display());
+        assert_eq!(raw_argument(&line), path.to_str());
+        // Encoded, both the é and the ~ would be lost.
+        assert_ne!(alphabet().decode(&alphabet().encode_text(&line)), line);
+        assert_eq!(raw_argument("save"), None);
+        assert_eq!(raw_argument("save  "), None);
+
+        let mut game = Game::new(ANSW_LIST);
+        game.apply(parse_guess("crane").unwrap(), score("cigar", "crane"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(raw_argument(&line).unwrap(), game_json(&game)).unwrap();
+        let text = fs::read_to_string(raw_argument(&format!("load {}", path.display())).unwrap()).unwrap();
+        assert_eq!(parse_game_json::<5>(&text, ANSW_LIST).unwrap().candidates(), game.candidates());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+use crate::paths::xdg_path;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+pub const INITIAL_RATING: f64 = 1500.0;
+const ELO_K: f64 = 16.0;
+
+/// League scoring: 6 points for a first-guess solve, down to 1 point for six guesses, 0 for a
+/// loss.
+pub fn league_points(rounds: usize) -> u32 {
+    if rounds <= 6 {
+        7 - rounds as u32
+    } else {
+        0
+    }
+}
+
+/// Elo rating update for a game between 'a' and 'b', where 'score_a' is 1 for a win, 0.5 for a
+/// draw and 0 for a loss.  Returns the new ratings.
+pub fn elo_update(a: f64, b: f64, score_a: f64) -> (f64, f64) {
+    let expected_a = 1.0 / (1.0 + 10f64.powf((b - a) / 400.0));
+    let delta = ELO_K * (score_a - expected_a);
+    (a + delta, b - delta)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rating {
+    pub elo: f64,
+    pub games: u32,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self { elo: INITIAL_RATING, games: 0 }
+    }
+}
+
+/// One player's line on the group leaderboard.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Standing {
+    pub player: String,
+    pub played: u32,
+    pub won: u32,
+    /// Average guesses over the games won.
+    pub average: f64,
+    /// Consecutive days won, up to the latest day anyone submitted.
+    pub streak: u32,
+    pub best_streak: u32,
+}
+
+/// Results which persist across runs, stored as one tab-separated record per line.
+#[derive(Default, Debug)]
+pub struct ResultsDb {
+    ratings: BTreeMap<String, Rating>,
+    /// Guesses each player took for each daily puzzle; more than six is a loss.
+    daily: BTreeMap<(String, u32), usize>,
+}
+
+impl ResultsDb {
+    /// Default location: $WORDLE_RESULTS, else $XDG_DATA_HOME/wordle/results.
+    pub fn default_path() -> Option<PathBuf> {
+        xdg_path("WORDLE_RESULTS", "XDG_DATA_HOME", ".local/share", "results")
+    }
+
+    /// A missing file is an empty database.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut db = Self::default();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(db),
+            Err(e) => return Err(e),
+        };
+        for (lineno, line) in text.lines().enumerate() {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let ok = match fields[..] {
+                ["rating", name, elo, games] => match (elo.parse(), games.parse()) {
+                    (Ok(elo), Ok(games)) => {
+                        db.ratings.insert(name.to_string(), Rating { elo, games });
+                        true
+                    }
+                    _ => false,
+                },
+                ["daily", player, day, rounds] => match (day.parse(), rounds.parse()) {
+                    (Ok(day), Ok(rounds)) => {
+                        db.daily.insert((player.to_string(), day), rounds);
+                        true
+                    }
+                    _ => false,
+                },
+                _ => false,
+            };
+            if !ok {
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                    format!("{}:{}: bad record", path.display(), lineno + 1)));
+            }
+        }
+        Ok(db)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut out = Vec::new();
+        for (name, r) in &self.ratings {
+            writeln!(out, "rating\t{}\t{}\t{}", name, r.elo, r.games)?;
+        }
+        for ((player, day), rounds) in &self.daily {
+            writeln!(out, "daily\t{}\t{}\t{}", player, day, rounds)?;
+        }
+        fs::write(path, out)
+    }
+
+    pub fn rating(&self, name: &str) -> Rating {
+        self.ratings.get(name).copied().unwrap_or_default()
+    }
+
+    /// Record one game between 'a' and 'b'; see elo_update().
+    pub fn record_game(&mut self, a: &str, b: &str, score_a: f64) {
+        let (ra, rb) = (self.rating(a), self.rating(b));
+        let (elo_a, elo_b) = elo_update(ra.elo, rb.elo, score_a);
+        self.ratings.insert(a.to_string(), Rating { elo: elo_a, games: ra.games + 1 });
+        self.ratings.insert(b.to_string(), Rating { elo: elo_b, games: rb.games + 1 });
+    }
+
+    /// Record how many guesses 'player' took for daily puzzle 'day', replacing any earlier
+    /// submission for that day.  Returns the replaced count.
+    pub fn submit_daily(&mut self, player: &str, day: u32, rounds: usize) -> Option<usize> {
+        self.daily.insert((player.to_string(), day), rounds)
+    }
+
+    /// Every player's standing, best first: most wins, then fewest average guesses.
+    pub fn leaderboard(&self) -> Vec<Standing> {
+        let latest = self.daily.keys().map(|&(_, day)| day).max().unwrap_or(0);
+        let mut res: Vec<Standing> = Vec::new();
+        // Keys are sorted by player, then day, so each player's games are contiguous and in order.
+        let mut prev_day = None;
+        let mut total = 0;
+        for ((player, day), &rounds) in &self.daily {
+            if res.last().map(|s| &s.player) != Some(player) {
+                res.push(Standing { player: player.clone(), played: 0, won: 0, average: 0.0, streak: 0, best_streak: 0 });
+                prev_day = None;
+                total = 0;
+            }
+            let s = res.last_mut().unwrap();
+            s.played += 1;
+            if league_points(rounds) == 0 {
+                s.streak = 0;
+            } else {
+                s.won += 1;
+                total += rounds;
+                s.average = total as f64 / s.won as f64;
+                s.streak = if prev_day.map(|d| d + 1) == Some(*day) { s.streak + 1 } else { 1 };
+                s.best_streak = s.best_streak.max(s.streak);
+            }
+            prev_day = Some(*day);
+        }
+        // A streak only stands if it reaches the latest day.
+        for s in &mut res {
+            if !self.daily.contains_key(&(s.player.clone(), latest)) {
+                s.streak = 0;
+            }
+        }
+        res.sort_by(|a, b| b.won.cmp(&a.won).then(a.average.total_cmp(&b.average)).then(a.player.cmp(&b.player)));
+        res
+    }
+}
+
+#[cfg(test)]
+mod test_results {
+    use super::*;
+
+    #[test]
+    fn test_league_points() {
+        assert_eq!(league_points(1), 6);
+        assert_eq!(league_points(6), 1);
+        assert_eq!(league_points(7), 0);
+        assert_eq!(league_points(12), 0);
+    }
+
+    #[test]
+    fn test_elo_update() {
+        let (a, b) = elo_update(1500.0, 1500.0, 1.0);
+        assert_eq!((a, b), (1508.0, 1492.0));
+        let (a, b) = elo_update(1600.0, 1400.0, 0.5);
+        assert!(a < 1600.0 && b > 1400.0 && (a + b - 3000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let path = std::env::temp_dir().join(format!("wordle-results-test-{}", std::process::id()));
+        let mut db = ResultsDb::default();
+        db.record_game("minimax", "semi-hard", 0.0);
+        db.save(&path).unwrap();
+        let loaded = ResultsDb::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.rating("minimax"), db.rating("minimax"));
+        assert_eq!(loaded.rating("semi-hard").games, 1);
+        assert_eq!(loaded.rating("nobody"), Rating::default());
+    }
+
+    #[test]
+    fn test_leaderboard() {
+        let path = std::env::temp_dir().join(format!("wordle-daily-test-{}", std::process::id()));
+        let mut db = ResultsDb::default();
+        for (day, rounds) in [(1, 4), (2, 3), (3, 7), (4, 4), (5, 5)] {
+            db.submit_daily("ann", day, rounds);
+        }
+        for (day, rounds) in [(1, 3), (2, 3), (3, 4)] {
+            db.submit_daily("bob", day, rounds);
+        }
+        assert_eq!(db.submit_daily("bob", 3, 5), Some(4));
+        db.save(&path).unwrap();
+        let board = ResultsDb::load(&path).unwrap().leaderboard();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(board.len(), 2);
+        assert_eq!(board[0], Standing { player: "ann".to_string(), played: 5, won: 4, average: 4.0, streak: 2, best_streak: 2 });
+        // bob's three-day streak ended when he missed day 4.
+        assert_eq!(board[1], Standing { player: "bob".to_string(), played: 3, won: 3, average: 11.0 / 3.0, streak: 0, best_streak: 3 });
+    }
+}
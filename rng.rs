@@ -0,0 +1,78 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::SystemTime;
+
+/// A small pseudo-random generator (SplitMix64).  The same seed gives the same numbers on every
+/// platform, so a run can be repeated from the seed it printed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A seed which differs from run to run, for when none is given.
+    pub fn random_seed() -> u64 {
+        RandomState::new().hash_one(SystemTime::now())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below 'n', which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no numbers below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A random item of 'items', which must not be empty.
+    pub fn choose<'t, T>(&mut self, items: &'t [T]) -> &'t T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A number fixed by 'seed' and 'word', but otherwise unrelated to either, for ordering words
+    /// at random without keeping state.
+    pub fn keyed(seed: u64, word: &str) -> u64 {
+        Self::new(word.bytes().fold(seed, |h, b| h.rotate_left(8) ^ b as u64)).next_u64()
+    }
+}
+
+#[cfg(test)]
+mod test_rng {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let draws = (0..100).map(|_| a.below(10)).collect::<Vec<_>>();
+        assert_eq!(draws, (0..100).map(|_| b.below(10)).collect::<Vec<_>>());
+        assert!(draws.iter().all(|&d| d < 10));
+        assert!((0..10).all(|d| draws.contains(&d)));
+        assert_ne!(Rng::new(8).next_u64(), Rng::new(7).next_u64());
+
+        let mut words = ["cigar", "rebut", "sissy", "humph", "awake"];
+        Rng::new(1).shuffle(&mut words);
+        let mut again = ["cigar", "rebut", "sissy", "humph", "awake"];
+        Rng::new(1).shuffle(&mut again);
+        assert_eq!(words, again);
+        again.sort_unstable();
+        assert_eq!(again, ["awake", "cigar", "humph", "rebut", "sissy"]);
+        assert!(words.contains(Rng::new(3).choose(&words)));
+        assert_eq!(Rng::keyed(5, "cigar"), Rng::keyed(5, "cigar"));
+        assert_ne!(Rng::keyed(5, "cigar"), Rng::keyed(5, "rebut"));
+    }
+}
@@ -0,0 +1,278 @@
+// An HTTP server for the solver, so a web page or a chat bot can use it without driving the
+// REPL:
+//   cargo run --release --features serve --bin serve -- [--addr host:port]
+// Requests and responses are JSON objects, as --json prints them:
+//   POST /prune       {"guess": "raise", "result": "01200", "candidates": [...]}
+//   POST /best-guess  {"candidates": [...], "guesses": [...], "strategy": "entropy"}
+//   POST /simulate    {"answers": [...], "strategy": "minimax", "mode": "hard", "opening": [...]}
+// Candidates and answers are the builtin answer list when not given, and guesses the builtin
+// guesses.  Errors are {"type": "error", "message": ...}.  Each connection is served on a
+// thread of its own, up to MAX_CONNECTIONS at once, and only MAX_BEST_GUESSES best guesses and
+// MAX_SIMULATIONS simulations run at once; more are turned away.
+use anyhow::{Result, anyhow, bail};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use wordle::{ANSW_LIST, Cache, Game, Json, JsonObject, MAX_GUESSES, Minimax, Mode, Preferences, SimReport, Strategy,
+             builtin_guesses, check_guess, check_result, play_line, prune, strategy_named, strategy_opener};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+// Bodies larger than this are turned down; every word list fits.
+const MAX_BODY: usize = 1 << 20;
+// Nor are request and header lines longer than this read, or more header lines than MAX_HEADERS.
+const MAX_LINE: usize = 8 << 10;
+const MAX_HEADERS: usize = 100;
+// As the simulators, a game the solver hasn't won after this many guesses is given up.
+const MAX_ROUNDS: usize = 4 * MAX_GUESSES;
+// A client slower than this to send its request, or to take the response, is hung up on.
+const TIMEOUT: Duration = Duration::from_secs(10);
+// Each connection has a thread, which waits up to TIMEOUT on a slow client.
+const MAX_CONNECTIONS: usize = 64;
+// A best guess among every guess takes every core for a second or so.
+const MAX_BEST_GUESSES: usize = 2;
+// Simulations of a whole answer list take minutes, and each one takes every core.
+const MAX_SIMULATIONS: usize = 1;
+
+static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+static BEST_GUESSES: AtomicUsize = AtomicUsize::new(0);
+static SIMULATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// One of at most 'max' of something running at once, counted by 'running', and given back
+// when dropped.
+struct Running(&'static AtomicUsize);
+
+impl Running {
+    fn start(running: &'static AtomicUsize, max: usize) -> Option<Self> {
+        running.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| Some(n + 1).filter(|&n| n <= max))
+            .ok().map(|_| Running(running))
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+// Read a line of the request's head into 'line', returning its length, 0 at the end of input.
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> Result<usize> {
+    line.clear();
+    let len = reader.by_ref().take(MAX_LINE as u64 + 1).read_line(line)?;
+    if len > MAX_LINE {
+        bail!("line longer than {} bytes", MAX_LINE);
+    }
+    Ok(len)
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    read_line(&mut reader, &mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => bail!("bad request line"),
+    };
+    let mut len = 0;
+    for headers in 0.. {
+        if read_line(&mut reader, &mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if headers == MAX_HEADERS {
+            bail!("more than {} header lines", MAX_HEADERS);
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                len = value.trim().parse().map_err(|_| anyhow!("bad Content-Length"))?;
+            }
+        }
+    }
+    if len > MAX_BODY {
+        bail!("body of {} bytes is too large", len);
+    }
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+    Ok(Request { method, path, body: String::from_utf8(body)? })
+}
+
+fn respond(mut stream: &TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    write!(stream, "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                    Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Headers: Content-Type\r\n\
+                    Connection: close\r\n\r\n{}", status, body.len(), body)
+}
+
+// The string array field 'key' of 'body', checked to be words, or the builtin answers.
+fn words_field(body: &Json, key: &str) -> Result<Vec<String>> {
+    let words = match body.opt_field::<Vec<String>>(key).map_err(|e| anyhow!(e))? {
+        Some(words) => words,
+        None => return Ok(ANSW_LIST.iter().map(|w| w.to_string()).collect()),
+    };
+    for word in &words {
+        check_guess(word, None).map_err(|e| anyhow!("'{}' {}", word, e))?;
+    }
+    Ok(words)
+}
+
+// The string field 'key' of 'body', if it has one.
+fn str_field(body: &Json, key: &str) -> Result<Option<String>> {
+    body.opt_field(key).map_err(|e| anyhow!(e))
+}
+
+fn strategy_field(body: &Json) -> Result<&'static dyn Strategy> {
+    match str_field(body, "strategy")? {
+        Some(name) => strategy_named(&name).ok_or_else(|| anyhow!("no strategy '{}'", name)),
+        None => Ok(&Minimax),
+    }
+}
+
+fn prune_request(body: &Json) -> Result<JsonObject> {
+    let candidates = words_field(body, "candidates")?;
+    let guess = str_field(body, "guess")?.ok_or_else(|| anyhow!("no guess"))?;
+    let bguess = check_guess(&guess, None).map_err(|e| anyhow!("the guess {}", e))?;
+    let result = str_field(body, "result")?.ok_or_else(|| anyhow!("no result"))?;
+    let result = check_result(&result).map_err(|e| anyhow!("the result {}", e))?;
+    let candidates = candidates.iter().map(String::as_str).collect::<Vec<_>>();
+    Ok(JsonObject::new("prune").strs("candidates", &prune(&candidates, bguess, result)))
+}
+
+fn best_guess_request(body: &Json, guesses: &[&'static str]) -> Result<JsonObject> {
+    let candidates = words_field(body, "candidates")?;
+    let candidates = candidates.iter().map(String::as_str).collect::<Vec<_>>();
+    let given = match body.get("guesses") {
+        Some(_) => Some(words_field(body, "guesses")?),
+        None => None,
+    };
+    let given = given.as_ref().map(|g| g.iter().map(String::as_str).collect::<Vec<_>>());
+    let best = strategy_field(body)?.best_guess(&candidates, given.as_deref().unwrap_or(guesses), &Preferences::default())
+        .ok_or_else(|| anyhow!("no candidates"))?;
+    Ok(JsonObject::new("best_guess").str("guess", best.word).num("worst_case", best.worst_case)
+        .float("expected_remaining", best.expected_remaining).float("entropy", best.entropy))
+}
+
+// The rounds the solver takes to find 'answer', or MAX_ROUNDS + 1 if it gives up.
+fn play(guesses: &[&str], strategy: &dyn Strategy, mode: Mode, opening: &[&str], answer: &str) -> usize {
+    let mut game = Game::<5>::new(ANSW_LIST);
+    game.set_mode(mode);
+    let line = play_line(&game, guesses, strategy, &Preferences::default(), opening, answer, MAX_ROUNDS);
+    if line.last() == Some(&answer) { line.len() } else { MAX_ROUNDS + 1 }
+}
+
+fn simulate_request(body: &Json, guesses: &[&'static str]) -> Result<JsonObject> {
+    let answers = words_field(body, "answers")?;
+    if let Some(answer) = answers.iter().find(|a| !ANSW_LIST.contains(&a.as_str())) {
+        bail!("'{}' is not in the answer list", answer);
+    }
+    let strategy = strategy_field(body)?;
+    let mode = match str_field(body, "mode")? {
+        Some(mode) => Mode::parse(&mode).ok_or_else(|| anyhow!("no mode '{}'; try normal, semi or hard", mode))?,
+        None => Mode::Normal,
+    };
+    let opening = match body.opt_field::<Vec<String>>("opening").map_err(|e| anyhow!(e))? {
+        Some(words) => words.iter().map(|w| guesses.iter().copied().find(|g| g == w)
+            .ok_or_else(|| anyhow!("'{}' is not in the word list", w))).collect::<Result<Vec<_>>>()?,
+        None => vec![strategy_opener(Cache::open_default().as_ref(), ANSW_LIST, guesses, strategy)
+            .ok_or_else(|| anyhow!("no guesses to open with"))?.0],
+    };
+    let mut report = SimReport::quiet();
+    report.play_all(&answers, |answ| answ.clone(), |answ| play(guesses, strategy, mode, &opening, answ));
+    Ok(JsonObject::new("simulate").str("strategy", strategy.name()).str("mode", &mode.to_string())
+        .strs("opening", &opening).num("games", report.len()).float("average", report.average())
+        .num("worst", report.worst()).num("failures", report.failures(MAX_GUESSES).len())
+        .json("histogram", &report.histogram(MAX_GUESSES)[..]))
+}
+
+// The status and body turning a request away, as too many of 'what' are running.
+fn busy(what: &str) -> (&'static str, String) {
+    let message = format!("too many {} are running; try again later", what);
+    ("503 Service Unavailable", JsonObject::new("error").str("message", &message).to_string())
+}
+
+// The status and body answering 'req'.
+fn handle(req: &Request, guesses: &[&'static str]) -> (&'static str, String) {
+    let body = || Json::parse(&req.body).map_err(|e| anyhow!("the body is not JSON: {}", e));
+    let res = match (req.method.as_str(), req.path.as_str()) {
+        ("OPTIONS", _) => return ("204 No Content", String::new()),
+        ("POST", "/prune") => body().and_then(|body| prune_request(&body)),
+        ("POST", "/best-guess") => match Running::start(&BEST_GUESSES, MAX_BEST_GUESSES) {
+            Some(_running) => body().and_then(|body| best_guess_request(&body, guesses)),
+            None => return busy("best guesses"),
+        },
+        ("POST", "/simulate") => match Running::start(&SIMULATIONS, MAX_SIMULATIONS) {
+            Some(_running) => body().and_then(|body| simulate_request(&body, guesses)),
+            None => return busy("simulations"),
+        },
+        (_, "/prune") | (_, "/best-guess") | (_, "/simulate") => {
+            return ("405 Method Not Allowed", JsonObject::new("error").str("message", "use POST").to_string());
+        }
+        _ => return ("404 Not Found", JsonObject::new("error").str("message", "no such endpoint").to_string()),
+    };
+    match res {
+        Ok(obj) => ("200 OK", obj.to_string()),
+        Err(e) => ("400 Bad Request", JsonObject::new("error").str("message", &e.to_string()).to_string()),
+    }
+}
+
+// Read the request on 'stream', and answer it.
+fn serve(stream: &TcpStream, guesses: &[&'static str]) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    let (status, body) = match read_request(stream) {
+        Ok(req) => {
+            let (status, body) = handle(&req, guesses);
+            eprintln!("{} {} {}", req.method, req.path, status);
+            (status, body)
+        }
+        Err(e) => ("400 Bad Request", JsonObject::new("error").str("message", &e.to_string()).to_string()),
+    };
+    respond(stream, status, &body)
+}
+
+fn main() -> Result<()> {
+    let mut addr = DEFAULT_ADDR.to_string();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--addr", Some(a)) => addr = a,
+            _ => bail!("Usage: serve [--addr host:port]"),
+        }
+    }
+
+    let guesses = Arc::new(builtin_guesses());
+    let listener = TcpListener::bind(&addr)?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                continue;
+            }
+        };
+        let running = match Running::start(&CONNECTIONS, MAX_CONNECTIONS) {
+            Some(running) => running,
+            None => {
+                let (status, body) = busy("connections");
+                if let Err(e) = stream.set_write_timeout(Some(TIMEOUT)).and_then(|_| respond(&stream, status, &body)) {
+                    eprintln!("Error: {}", e);
+                }
+                continue;
+            }
+        };
+        let guesses = Arc::clone(&guesses);
+        std::thread::spawn(move || {
+            let _running = running;
+            if let Err(e) = serve(&stream, &guesses) {
+                eprintln!("Error: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
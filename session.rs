@@ -0,0 +1,130 @@
+use crate::paths::xdg_path;
+use crate::{FromJson, Game, GameHistory, Json, Mode, Observation, Pool, ToJson, fmt_result, parse_observation_n};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The game in progress, saved after every command so it can be resumed after a crash.  One
+/// "guess<TAB>result" line per observation.
+pub struct Session {
+    path: PathBuf,
+}
+
+impl Session {
+    /// '$WORDLE_SESSION', else 'wordle/session' under '$XDG_STATE_HOME' (default
+    /// '~/.local/state').
+    pub fn default_path() -> Option<PathBuf> {
+        xdg_path("WORDLE_SESSION", "XDG_STATE_HOME", ".local/state", "session")
+    }
+
+    pub fn open(path: &Path) -> Self {
+        Self { path: path.to_path_buf() }
+    }
+
+    pub fn open_default() -> Option<Self> {
+        Self::default_path().map(|path| Self::open(&path))
+    }
+
+    /// The saved game of 'N' letter words, if there is one.
+    pub fn load<const N: usize>(&self) -> io::Result<Option<GameHistory<Observation<N>>>> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let mut history = GameHistory::new();
+        for (lineno, line) in text.lines().enumerate() {
+            let mut fields = line.split('\t');
+            let (guess, result) = parse_observation_n(fields.next(), fields.next())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData,
+                    format!("{}:{}: bad observation", self.path.display(), lineno + 1)))?;
+            history.push(guess, result);
+        }
+        Ok(Some(history))
+    }
+
+    /// Save the observations in effect; an empty game clears the session.
+    pub fn save<const N: usize>(&self, history: &GameHistory<Observation<N>>) -> io::Result<()> {
+        if history.is_empty() {
+            return self.clear();
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut out = String::new();
+        for (guess, result) in history.entries() {
+            out.push_str(&format!("{}\t{}\n", String::from_utf8_lossy(guess), fmt_result(result)));
+        }
+        fs::write(&self.path, out)
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A game as the REPL's save command writes it: its JSON form, on one line.
+pub fn game_json<const N: usize>(game: &Game<N>) -> String {
+    game.to_json().to_string()
+}
+
+/// The game game_json() wrote as 'text', played from 'answers'.  Its candidates are found again
+/// from its observations, in case the word list has changed since.
+pub fn parse_game_json<'a, const N: usize>(text: &str, answers: &'a [&'a str]) -> Result<Game<'a, N>, String> {
+    let json = Json::parse(text).map_err(|e| format!("the saved game isn't JSON: {}", e))?;
+    let mut game = Game::resume(answers, GameHistory::from_json(&json)?);
+    if let Some(mode) = json.opt_field::<String>("mode")? {
+        game.set_mode(Mode::parse(&mode).ok_or_else(|| format!("unknown mode '{}'", mode))?);
+    }
+    if let Some(pool) = json.opt_field::<String>("pool")? {
+        game.set_pool(Pool::parse(&pool).ok_or_else(|| format!("unknown pool '{}'", pool))?);
+    }
+    Ok(game)
+}
+
+#[cfg(test)]
+mod test_session {
+    use super::*;
+    use crate::{parse_guess, score};
+
+    #[test]
+    fn test_save_load() {
+        let path = std::env::temp_dir().join(format!("wordle-session-test-{}", std::process::id()));
+        let session = Session::open(&path);
+        assert_eq!(session.load::<5>().unwrap(), None);
+
+        let mut history = GameHistory::new();
+        for guess in ["arise", "bludy"] {
+            history.push(parse_guess(guess).unwrap(), score("humph", guess));
+        }
+        session.save(&history).unwrap();
+        assert_eq!(session.load::<5>().unwrap(), Some(history));
+
+        session.save(&GameHistory::<Observation>::new()).unwrap();
+        assert_eq!(session.load::<5>().unwrap(), None);
+        session.clear().unwrap();
+    }
+
+    #[test]
+    fn test_game_json() {
+        let answers = ["hatch", "watch", "latch", "cigar"];
+        let mut game = Game::new(&answers);
+        game.apply(parse_guess("batch").unwrap(), score("watch", "batch"));
+        game.set_mode(Mode::Hard);
+        game.set_pool(Pool::Candidates);
+        let text = game_json(&game);
+        assert_eq!(text, r#"{"type":"game","mode":"hard","pool":"candidates","guesses":["batch"],"results":["02222"],"candidates":["hatch","watch","latch"]}"#);
+        let loaded = parse_game_json::<5>(&text, &answers).unwrap();
+        assert_eq!(loaded.history(), game.history());
+        assert_eq!(loaded.candidates(), game.candidates());
+        assert_eq!((loaded.mode(), loaded.pool()), (Mode::Hard, Pool::Candidates));
+
+        assert!(parse_game_json::<5>(r#"{"guesses":["batch"],"results":[]}"#, &answers).is_err());
+        let short = r#"{"guesses":["bat"],"results":["022"]}"#;
+        assert_eq!(parse_game_json::<5>(short, &answers).err().unwrap(), "'bat' has 3 letters, not 5");
+        assert_eq!(parse_game_json::<3>(short, &["cat", "bat", "cot"]).unwrap().candidates(), ["cat"]);
+    }
+}
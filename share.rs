@@ -0,0 +1,157 @@
+// The grids players share in chat, read back: what they reveal of the game, and remarks on it
+// for a chat bot to post.
+use crate::{Color, MAX_GUESSES, check_result, fmt_count, prune, score, word_bytes};
+use rustc_hash::FxHashSet;
+
+/// A game as the emoji grid Wordle shares, as GameHistory::share_grid() writes them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharedGrid {
+    /// The day's puzzle number, if the header gives one.
+    pub puzzle: Option<u32>,
+    pub solved: bool,
+    pub hard: bool,
+    /// The result of each guess, in order.
+    pub rows: Vec<[Color; 5]>,
+}
+
+// "1st", "2nd" and so on.
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+impl SharedGrid {
+    /// Find a grid in a chat message: a "Wordle 1,234 3/6*" header, then a row of squares per
+    /// guess.  Text around the grid is ignored.  None if there is no header, or its score
+    /// doesn't match the rows.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut lines = text.lines().map(str::trim).skip_while(|l| !l.starts_with("Wordle "));
+        let mut words = lines.next()?.split_whitespace().skip(1).collect::<Vec<_>>();
+        let (score, hard) = match words.pop()?.split_once('/')? {
+            (score, total) if total == MAX_GUESSES.to_string() => (score, false),
+            (score, total) if total == format!("{}*", MAX_GUESSES) => (score, true),
+            _ => return None,
+        };
+        let puzzle = match words[..] {
+            [] => None,
+            [day] => Some(day.replace(',', "").parse().ok()?),
+            _ => return None,
+        };
+        let rows = lines.skip_while(|l| l.is_empty()).map_while(|l| check_result(l).ok()).collect::<Vec<_>>();
+        let solved = score != "X";
+        match score.parse::<usize>() {
+            Ok(n) if n == rows.len() && rows.last() == Some(&[Color::GREEN; 5]) => {}
+            Err(_) if !solved && !rows.is_empty() => {}
+            _ => return None,
+        }
+        Some(Self { puzzle, solved, hard, rows })
+    }
+
+    /// The words of 'guesses' each row's guess could have been, if the answer was 'answer'.
+    pub fn possible_guesses<'a>(&self, answer: &str, guesses: &[&'a str]) -> Vec<Vec<&'a str>> {
+        self.rows.iter().map(|row| guesses.iter().copied().filter(|g| score(answer, g) == *row).collect()).collect()
+    }
+
+    /// Those of 'answers' for which each row is the result of a word of 'guesses'.
+    pub fn possible_answers<'a>(&self, answers: &[&'a str], guesses: &[&str]) -> Vec<&'a str> {
+        answers.iter().copied().filter(|answ| {
+            let results = guesses.iter().map(|g| score(answ, g)).collect::<FxHashSet<_>>();
+            self.rows.iter().all(|row| results.contains(row))
+        }).collect()
+    }
+
+    /// Remarks on the game, a line each, as "Your 3rd guess, 'cling', left 14 candidates".  The
+    /// guesses are worked out from the rows where there is only one they could have been, given
+    /// 'answer'; without it, the grid only narrows down the answer.
+    pub fn commentary(&self, answer: Option<&str>, answers: &[&str], guesses: &[&str]) -> Vec<String> {
+        let mut res = Vec::new();
+        if let Some(answer) = answer {
+            // The candidates left, while every guess so far is known.
+            let mut candidates = Some(answers.to_vec());
+            for (i, (row, possible)) in self.rows.iter().zip(self.possible_guesses(answer, guesses)).enumerate() {
+                let nth = ordinal(i + 1);
+                match possible[..] {
+                    [] => {
+                        res.push(format!("No guess gives your {} row when the answer is '{}'", nth, answer));
+                        return res;
+                    }
+                    _ if *row == [Color::GREEN; 5] => {
+                        res.push(format!("Your {} guess found '{}'", nth, answer));
+                        break;
+                    }
+                    [guess] => match candidates.as_mut() {
+                        Some(left) => {
+                            *left = prune(left, word_bytes(guess), *row);
+                            let plural = if left.len() == 1 { "" } else { "s" };
+                            res.push(format!("Your {} guess, '{}', left {} candidate{}", nth, guess, fmt_count(left.len()),
+                                             plural));
+                        }
+                        None => res.push(format!("Your {} guess must have been '{}'", nth, guess)),
+                    },
+                    _ => {
+                        candidates = None;
+                        res.push(format!("Your {} guess was one of {} words, such as '{}'", nth,
+                                         fmt_count(possible.len()), possible[0]));
+                    }
+                }
+            }
+            if !self.solved {
+                res.push(format!("The answer was '{}'", answer));
+            }
+        }
+        let fitting = self.possible_answers(answers, guesses);
+        match fitting[..] {
+            [] => res.push("No answer fits the grid".to_string()),
+            [only] => res.push(format!("From the grid alone, the answer must be '{}'", only)),
+            _ => res.push(format!("From the grid alone, the answer could be any of {} words", fmt_count(fitting.len()))),
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test_share {
+    use super::*;
+    use crate::{GameHistory, parse_guess};
+
+    #[test]
+    fn test_parse() {
+        let mut h = GameHistory::new();
+        for guess in ["raise", "cling", "cigar"] {
+            h.push(parse_guess(guess).unwrap(), score("cigar", guess));
+        }
+        let text = format!("look at this!\n{}\nnot bad", h.share_grid(Some(1234), true));
+        let grid = SharedGrid::parse(&text).unwrap();
+        assert_eq!(grid, SharedGrid { puzzle: Some(1234), solved: true, hard: true,
+                                      rows: vec![score("cigar", "raise"), score("cigar", "cling"), [Color::GREEN; 5]] });
+
+        let grid = SharedGrid::parse("Wordle X/6\n\u{2b1b}\u{2b1b}\u{1f7e8}\u{2b1b}\u{1f7e9}\n").unwrap();
+        assert_eq!((grid.puzzle, grid.solved, grid.hard, grid.rows.len()), (None, false, false, 1));
+        assert_eq!(SharedGrid::parse("Wordle 2/6\n\n\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}"), None);
+        assert_eq!(SharedGrid::parse("no grid here"), None);
+        assert_eq!(ordinal(1) + &ordinal(2) + &ordinal(3) + &ordinal(11) + &ordinal(22), "1st2nd3rd11th22nd");
+    }
+
+    #[test]
+    fn test_commentary() {
+        let answers = ["cigar", "vicar", "rebut"];
+        let guesses = ["raise", "cling", "cigar", "vicar", "rebut"];
+        let rows = vec![score("cigar", "raise"), score("cigar", "cling"), [Color::GREEN; 5]];
+        let grid = SharedGrid { puzzle: None, solved: true, hard: false, rows };
+        assert_eq!(grid.possible_guesses("cigar", &guesses)[1], ["cling"]);
+        assert_eq!(grid.commentary(Some("cigar"), &answers, &guesses), [
+            "Your 1st guess, 'raise', left 2 candidates",
+            "Your 2nd guess, 'cling', left 1 candidate",
+            "Your 3rd guess found 'cigar'",
+            "From the grid alone, the answer must be 'cigar'",
+        ]);
+        assert_eq!(grid.commentary(Some("rebut"), &answers, &guesses)[0],
+                   "No guess gives your 1st row when the answer is 'rebut'");
+    }
+}
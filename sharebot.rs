@@ -0,0 +1,35 @@
+// A chat bot's side of remarking on shared grids: a bridge to Discord, Slack or the like runs it
+// on each message, and posts what it prints.
+//   echo "$message" | sharebot [--answer word]
+// The answer is the one given, else the builtin list's answer to the grid's puzzle.  Exits with
+// status 1, printing nothing, for a message with no grid.
+use anyhow::{Result, anyhow, bail};
+use std::io::Read;
+use wordle::{ANSW_LIST, SharedGrid, builtin_guesses, check_guess, daily_answer};
+
+fn main() -> Result<()> {
+    let mut answer = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answer" => {
+                let word = args.next().ok_or_else(|| anyhow!("--answer needs a word"))?;
+                check_guess(&word, Some(ANSW_LIST)).map_err(|e| anyhow!("--answer '{}' {}", word, e))?;
+                answer = Some(word);
+            }
+            _ => bail!("Usage: sharebot [--answer word]"),
+        }
+    }
+
+    let mut message = String::new();
+    std::io::stdin().read_to_string(&mut message)?;
+    let grid = match SharedGrid::parse(&message) {
+        Some(grid) => grid,
+        None => std::process::exit(1),
+    };
+    let answer = answer.as_deref().or_else(|| grid.puzzle.map(daily_answer));
+    for line in grid.commentary(answer, ANSW_LIST, &builtin_guesses()) {
+        println!("{}", line);
+    }
+    Ok(())
+}
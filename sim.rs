@@ -0,0 +1,401 @@
+use crate::par::*;
+use crate::{FromJson, Json, JsonObject, ToJson, Verbosity, json_array, json_output, verbosity};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// One of 'count' deterministic slices of a simulation's games, numbered from 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Shard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl Shard {
+    /// The whole simulation.
+    pub const ALL: Shard = Shard { index: 0, count: 1 };
+
+    /// Parse "i/n".
+    pub fn parse(s: &str) -> Option<Self> {
+        let (index, count) = s.split_once('/')?;
+        let (index, count) = (index.parse().ok()?, count.parse().ok()?);
+        if index >= count {
+            return None;
+        }
+        Some(Self { index, count })
+    }
+
+    /// Whether the k'th game of the simulation belongs to this shard.
+    pub fn contains(&self, k: usize) -> bool {
+        k % self.count == self.index
+    }
+}
+
+/// Rounds taken by each simulated game, keyed by its answers.  As text, the simulators print
+/// one "label: rounds" line per game, with -v, or save them to a file.  As JSON, each game is
+/// {"type": "game", "label": ..., "rounds": ...}, and a report's "games" an array of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimReport {
+    games: BTreeMap<String, usize>,
+    // Record games without printing them.
+    quiet: bool,
+}
+
+impl SimReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A report which prints nothing as games are recorded.
+    pub fn quiet() -> Self {
+        Self { quiet: true, ..Self::default() }
+    }
+
+    /// Record a game, printing its line at -v unless the report is quiet.
+    pub fn record(&mut self, label: String, rounds: usize) {
+        if self.quiet || verbosity() < Verbosity::Verbose {
+            self.games.insert(label, rounds);
+            return;
+        }
+        if json_output() {
+            println!("{}", game_json(&label, rounds));
+        } else {
+            println!("{}: {}", label, rounds);
+        }
+        self.games.insert(label, rounds);
+    }
+
+    /// Play 'games' in parallel, recording them in order as each batch finishes.  Progress is shown
+    /// on stderr when it is a terminal, except with -q.
+    pub fn play_all<G, L, P>(&mut self, games: &[G], label: L, play: P)
+        where G: Sync, L: Fn(&G) -> String, P: Fn(&G) -> usize + Sync {
+        self.play_all_with(games, label, |game| (play(game), ()), |_, _, ()| {});
+    }
+
+    /// As play_all(), with 'play' giving 'detail' of each game besides its rounds, such as its
+    /// guesses, for 'each' to write out as the game is recorded.
+    pub fn play_all_with<G, T, L, P, E>(&mut self, games: &[G], label: L, play: P, mut each: E)
+        where G: Sync, T: Send, L: Fn(&G) -> String, P: Fn(&G) -> (usize, T) + Sync, E: FnMut(&G, usize, T) {
+        // Large enough to keep every core busy, small enough that lines appear steadily.
+        const BATCH: usize = 256;
+        let pb = if verbosity() == Verbosity::Quiet { ProgressBar::hidden() } else { ProgressBar::new(games.len() as u64) };
+        pb.set_style(ProgressStyle::with_template("{bar:40} {pos}/{len} games ({per_sec}, {eta} left)").unwrap());
+        for batch in games.chunks(BATCH) {
+            let played = batch.par_iter().map(&play).collect::<Vec<_>>();
+            pb.suspend(|| {
+                for (game, (rounds, detail)) in batch.iter().zip(played) {
+                    self.record(label(game), rounds);
+                    each(game, rounds, detail);
+                }
+            });
+            pb.inc(batch.len() as u64);
+        }
+        pb.finish_and_clear();
+    }
+
+    /// Parse the game lines of a simulator's output, as text or JSON, ignoring everything else it
+    /// printed.  A saved report in JSON is read whole.
+    pub fn parse(text: &str) -> Self {
+        let mut res = Self::new();
+        for line in text.lines() {
+            if line.starts_with(char::is_whitespace) {
+                continue;
+            }
+            if let Some(json) = line.starts_with('{').then(|| Json::parse(line).ok()).flatten() {
+                let games = json.get("games").and_then(Json::as_array).unwrap_or(std::slice::from_ref(&json));
+                res.games.extend(games.iter().filter_map(|game| read_game(game).ok()));
+            } else if let Some((label, rounds)) = line.rsplit_once(": ") {
+                if let Ok(rounds) = rounds.parse() {
+                    res.games.insert(label.to_string(), rounds);
+                }
+            }
+        }
+        res
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Write the game lines, for load() to read back, or to a .json file, the report as JSON.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if path.extension().is_some_and(|ext| ext == "json") {
+            return fs::write(path, self.to_json().to_string() + "\n");
+        }
+        fs::write(path, self.games().map(|(label, rounds)| format!("{}: {}\n", label, rounds)).collect::<String>())
+    }
+
+    /// Load and merge the reports of several shards.
+    pub fn load_shards<P: AsRef<Path>>(paths: &[P]) -> io::Result<Self> {
+        let mut res = Self::new();
+        for path in paths {
+            let path = path.as_ref();
+            res.merge(Self::load(path)?).map_err(|game| io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: game '{}' appears in more than one shard", path.display(), game)))?;
+        }
+        Ok(res)
+    }
+
+    /// Add another shard's games.  Returns a game both reports contain, if any.
+    pub fn merge(&mut self, other: SimReport) -> Result<(), String> {
+        for (label, rounds) in other.games {
+            if self.games.insert(label.clone(), rounds).is_some() {
+                return Err(label);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    pub fn contains(&self, label: &str) -> bool {
+        self.games.contains_key(label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// The games and the rounds each took, by label.
+    pub fn games(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.games.iter().map(|(label, &rounds)| (label.as_str(), rounds))
+    }
+
+    pub fn average(&self) -> f64 {
+        self.games.values().sum::<usize>() as f64 / self.games.len() as f64
+    }
+
+    /// The standard error of the average, taking the games as a random sample of a larger
+    /// simulation: the average of the whole is within about twice this of the sample's, 95% of
+    /// the time.
+    pub fn standard_error(&self) -> f64 {
+        let n = self.games.len() as f64;
+        let average = self.average();
+        let variance = self.games.values().map(|&r| (r as f64 - average).powi(2)).sum::<f64>() / (n - 1.0);
+        (variance / n).sqrt()
+    }
+
+    pub fn worst(&self) -> usize {
+        self.games.values().copied().max().unwrap_or(0)
+    }
+
+    /// The labels of games taking more than 'limit' rounds, which count as losses.
+    pub fn failures(&self, limit: usize) -> Vec<&str> {
+        self.games().filter(|&(_, r)| r > limit).map(|(label, _)| label).collect()
+    }
+
+    /// How many games took each number of rounds from 1, over at least 'min_rows' rows.
+    pub fn histogram(&self, min_rows: usize) -> Vec<usize> {
+        (1..=self.worst().max(min_rows)).map(|i| self.games.values().filter(|&&r| r == i).count()).collect()
+    }
+
+    /// Print the average and worst rounds, and a histogram of at least 'min_rows' rows.  Given a
+    /// limit, games taking more rounds are listed as losses.
+    pub fn print_summary(&self, min_rows: usize, limit: Option<usize>) {
+        // With no games there is no average, as for an empty shard or a run already finished.
+        if self.is_empty() {
+            if json_output() {
+                println!("{}", JsonObject::new("summary").num("games", 0));
+            } else {
+                println!("No games played");
+            }
+            return;
+        }
+        let (worst, average) = (self.worst(), self.average());
+        let histogram = self.histogram(min_rows).into_iter();
+        let losses = limit.map(|limit| self.failures(limit)).unwrap_or_default();
+        if json_output() {
+            let mut summary = JsonObject::new("summary").num("games", self.games.len()).float("average", average)
+                .num("worst", worst).raw("histogram", json_array(histogram.map(|n| n.to_string())));
+            if limit.is_some() {
+                summary = summary.strs("losses", &losses);
+            }
+            println!("{}", summary);
+            return;
+        }
+        println!("Average {} rounds, worst {} rounds", average, worst);
+        if verbosity() == Verbosity::Quiet {
+            return;
+        }
+        for (i, n) in histogram.enumerate() {
+            println!("  {} rounds: {}", i + 1, n);
+        }
+        if let (Some(limit), false) = (limit, losses.is_empty()) {
+            println!("Lost {} games, taking over {} rounds: {}", losses.len(), limit, losses.join(", "));
+        }
+    }
+}
+
+/// Where a long simulation writes each game as it finishes, so that an interrupted run can resume
+/// without replaying them.  Its first line names the simulation, so that a checkpoint of one isn't
+/// resumed by another.  Games are flushed every FLUSH_GAMES games or FLUSH_INTERVAL, whichever
+/// comes first, so a killed run loses only those since.  The first error writing it is kept for
+/// finish() to return.
+pub struct Checkpoint {
+    out: BufWriter<File>,
+    // Games recorded since the last flush, and when that was.
+    unflushed: usize,
+    flushed: Instant,
+    error: Option<io::Error>,
+}
+
+impl Checkpoint {
+    pub const FLUSH_GAMES: usize = 100;
+    pub const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Start the checkpoint of simulation 'name' at 'path'.  A file there with games in it is
+    /// only replaced if 'replace' is given, since it may hold days of progress.
+    pub fn create(path: &Path, name: &str, replace: bool) -> io::Result<Self> {
+        if !replace && fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists,
+                                      format!("{} already has games", path.display())));
+        }
+        let mut out = BufWriter::new(File::create(path)?);
+        writeln!(out, "{}", name)?;
+        out.flush()?;
+        Ok(Self::new(out))
+    }
+
+    fn new(out: BufWriter<File>) -> Self {
+        Self { out, unflushed: 0, flushed: Instant::now(), error: None }
+    }
+
+    /// Continue the checkpoint of simulation 'name' at 'path', with the games it has so far.
+    pub fn resume(path: &Path, name: &str) -> io::Result<(Self, SimReport)> {
+        let text = fs::read_to_string(path)?;
+        if text.lines().next() != Some(name) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      format!("{} is the checkpoint of another simulation", path.display())));
+        }
+        let mut out = BufWriter::new(OpenOptions::new().append(true).open(path)?);
+        // A run killed while writing may have left half a line.
+        if !text.ends_with('\n') {
+            writeln!(out)?;
+        }
+        Ok((Self::new(out), SimReport::parse(&text)))
+    }
+
+    pub fn record(&mut self, label: &str, rounds: usize) {
+        if self.error.is_some() {
+            return;
+        }
+        self.error = writeln!(self.out, "{}: {}", label, rounds).err();
+        self.unflushed += 1;
+        if self.error.is_none() && (self.unflushed >= Self::FLUSH_GAMES || self.flushed.elapsed() >= Self::FLUSH_INTERVAL) {
+            self.error = self.out.flush().err();
+            self.unflushed = 0;
+            self.flushed = Instant::now();
+        }
+    }
+
+    /// Flush the games, returning the first error writing any of them.
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => self.out.flush(),
+        }
+    }
+}
+
+// A game as --json -v prints it.
+fn game_json(label: &str, rounds: usize) -> Json {
+    Json::object("game", vec![("label", label.to_json()), ("rounds", rounds.to_json())])
+}
+
+// The label and rounds of a game game_json() wrote.
+fn read_game(json: &Json) -> Result<(String, usize), String> {
+    if json.get("type").and_then(Json::as_str) != Some("game") {
+        return Err("not a game".to_string());
+    }
+    Ok((json.field("label")?, json.field("rounds")?))
+}
+
+impl ToJson for SimReport {
+    fn to_json(&self) -> Json {
+        Json::object("report", vec![("games", Json::Array(self.games().map(|(label, rounds)| game_json(label, rounds)).collect()))])
+    }
+}
+
+impl FromJson for SimReport {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        let games = json.field::<Vec<Json>>("games")?.iter().map(read_game).collect::<Result<_, _>>()?;
+        Ok(Self { games, quiet: false })
+    }
+}
+
+#[cfg(test)]
+mod test_sim {
+    use super::*;
+
+    #[test]
+    fn test_shard() {
+        assert_eq!(Shard::parse("1/4"), Some(Shard { index: 1, count: 4 }));
+        assert_eq!(Shard::parse("4/4"), None);
+        assert_eq!(Shard::parse("1"), None);
+        let shards = [Shard::parse("0/3").unwrap(), Shard::parse("1/3").unwrap(), Shard::parse("2/3").unwrap()];
+        for k in 0..10 {
+            assert_eq!(shards.iter().filter(|s| s.contains(k)).count(), 1);
+        }
+        assert!(Shard::ALL.contains(7));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = SimReport::parse("Best guess: 'arise'\ncigar: 3\nrebut: 4\nAverage 3.5 rounds, worst 4 rounds\n  1 rounds: 0\n");
+        let b = SimReport::parse("left: 2309 candidate answers remain: cigar, ...\ncigar x sissy: 5\n");
+        assert_eq!(a.len(), 2);
+        assert_eq!(b.len(), 1);
+        a.merge(b).unwrap();
+        assert_eq!(a.len(), 3);
+        assert_eq!((a.average(), a.worst(), a.failures(4)), (4.0, 5, vec!["cigar x sissy"]));
+        assert!((a.standard_error() - 1.0 / 3f64.sqrt()).abs() < 1e-9);
+        assert_eq!(a.histogram(2), [0, 0, 1, 1, 1]);
+        assert_eq!(a.merge(SimReport::parse("rebut: 4")), Err("rebut".to_string()));
+
+        let path = std::env::temp_dir().join(format!("wordle-sim-test-{}", std::process::id()));
+        a.save(&path).unwrap();
+        assert_eq!(SimReport::load(&path).unwrap().games, a.games);
+        fs::remove_file(&path).unwrap();
+        let json_path = path.with_extension("json");
+        a.save(&json_path).unwrap();
+        assert_eq!(SimReport::load(&json_path).unwrap().games, a.games);
+        fs::remove_file(&json_path).unwrap();
+
+        // The game lines of --json -v count as those of text do.
+        let c = SimReport::parse("{\"type\":\"game\",\"label\":\"humph\",\"rounds\":4}\n{\"type\":\"summary\",\"games\":1}\n");
+        assert_eq!(c.games().collect::<Vec<_>>(), [("humph", 4)]);
+        assert_eq!(SimReport::from_json(&c.to_json()), Ok(c));
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let path = std::env::temp_dir().join(format!("wordle-checkpoint-test-{}", std::process::id()));
+        let mut checkpoint = Checkpoint::create(&path, "fs of 3 games", false).unwrap();
+        checkpoint.record("cigar x rebut", 4);
+        checkpoint.finish().unwrap();
+        // Games in a checkpoint are kept, unless replacing it is forced.
+        assert_eq!(Checkpoint::create(&path, "fs of 3 games", false).err().map(|e| e.kind()), Some(io::ErrorKind::AlreadyExists));
+        assert_eq!(Checkpoint::resume(&path, "fs of 3 games").unwrap().1.len(), 1);
+        assert!(Checkpoint::resume(&path, "fs of 4 games").is_err());
+        let (mut checkpoint, report) = Checkpoint::resume(&path, "fs of 3 games").unwrap();
+        assert!(report.contains("cigar x rebut") && report.len() == 1);
+        checkpoint.record("cigar x sissy", 5);
+        checkpoint.finish().unwrap();
+        assert_eq!(Checkpoint::resume(&path, "fs of 3 games").unwrap().1.len(), 2);
+
+        // Games are on disk well before finish(), for a run which is killed.
+        let mut checkpoint = Checkpoint::create(&path, "fs of 3 games", true).unwrap();
+        assert_eq!(Checkpoint::resume(&path, "fs of 3 games").unwrap().1.len(), 0);
+        for i in 0..Checkpoint::FLUSH_GAMES {
+            checkpoint.record(&format!("game {}", i), 3);
+        }
+        assert_eq!(Checkpoint::resume(&path, "fs of 3 games").unwrap().1.len(), Checkpoint::FLUSH_GAMES);
+        drop(checkpoint);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,445 @@
+use crate::{ANSW_LIST, LETTERS, Pattern, Preferences, letter_index, pattern_counts};
+use crate::par::*;
+use rustc_hash::FxHashSet as HashSet;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::atomic::{self, AtomicUsize};
+
+/// How the solver compares two guesses, before tie-break preferences apply.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Objective {
+    /// Fewest worst case candidates, then fewest expected candidates, then possible answers.
+    #[default]
+    Lexicographic,
+    /// Fewest worst case candidates, then possible answers: the original solver.
+    WorstCase,
+}
+
+impl Objective {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "lex" => Some(Objective::Lexicographic),
+            "worst" => Some(Objective::WorstCase),
+            _ => None,
+        }
+    }
+
+    /// Less is better.
+    pub fn cmp(&self, a: &BestGuess, b: &BestGuess) -> Ordering {
+        let by_worst = a.worst_case.cmp(&b.worst_case);
+        let by_expected = match self {
+            Objective::Lexicographic => a.expected_remaining.total_cmp(&b.expected_remaining),
+            Objective::WorstCase => Ordering::Equal,
+        };
+        by_worst.then(by_expected).then(b.is_candidate.cmp(&a.is_candidate))
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Objective::Lexicographic => "lex",
+            Objective::WorstCase => "worst",
+        })
+    }
+}
+
+/// A guess's merit as one number, lower is better; see BestGuess::score().
+pub type Score = usize;
+
+/// A guess, and what playing it would tell us about the remaining answers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BestGuess<'a> {
+    pub word: &'a str,
+    /// The most candidates it can leave.
+    pub worst_case: usize,
+    /// The candidates it leaves on average, if each remaining answer is equally likely.
+    pub expected_remaining: f64,
+    /// Bits of information the result is expected to give.
+    pub entropy: f64,
+    /// Whether it is itself one of the remaining answers.
+    pub is_candidate: bool,
+}
+
+impl<'a> BestGuess<'a> {
+    pub fn evaluate(answers: &[&str], word: &'a str, is_candidate: bool) -> Self {
+        Self::from_buckets(word, is_candidate, pattern_counts(answers, word).iter().copied())
+    }
+
+    /// As evaluate(), but None as soon as more than 'bound' answers are found to give the same
+    /// result, so a search can stop scoring a guess which can't beat its best.
+    pub fn evaluate_bounded(answers: &[&str], word: &'a str, is_candidate: bool, bound: usize) -> Option<Self> {
+        let mut buckets = vec![0; Pattern::count(word.len())];
+        for answ in answers {
+            let bucket = &mut buckets[Pattern::of(answ, word).index()];
+            *bucket += 1;
+            if *bucket > bound {
+                return None;
+            }
+        }
+        Some(Self::from_buckets(word, is_candidate, buckets.iter().copied()))
+    }
+
+    /// Evaluate a guess from how many answers give each of its results.
+    pub fn from_buckets(word: &'a str, is_candidate: bool, buckets: impl Iterator<Item = usize> + Clone) -> Self {
+        let n = buckets.clone().sum::<usize>() as f64;
+        let mut res = Self { word, worst_case: 0, expected_remaining: 0.0, entropy: 0.0, is_candidate };
+        // Summed exactly, so the expectation doesn't depend on the order of the buckets.
+        let mut sum_squares = 0;
+        for b in buckets.filter(|&b| b > 0) {
+            let p = b as f64 / n;
+            res.worst_case = res.worst_case.max(b);
+            sum_squares += b * b;
+            res.entropy -= p * p.log2();
+        }
+        res.expected_remaining = sum_squares as f64 / n;
+        res
+    }
+
+    /// The worst case objective as one number: twice the worst case, less one for a possible
+    /// answer, so that possible answers win ties.  Lower is better.
+    pub fn score(&self) -> Score {
+        if self.is_candidate {
+            (self.worst_case * 2).saturating_sub(1)
+        } else {
+            self.worst_case * 2
+        }
+    }
+}
+
+/// Order evaluated guesses from best to worst: those within 'prefs.slack' of the best worst case
+/// by preference weight and then the objective, then the rest by the objective.  Ties keep
+/// their order, unless 'prefs' has a seed to shuffle them by.
+pub fn rank<'a>(mut evaluated: Vec<BestGuess<'a>>, prefs: &Preferences) -> Vec<BestGuess<'a>> {
+    let best = evaluated.iter().map(|g| g.worst_case).min().unwrap_or(0);
+    let limit = best.saturating_add(prefs.slack);
+    let common = if prefs.common != 0 {
+        ANSW_LIST.iter().copied().collect()
+    } else {
+        HashSet::default()
+    };
+    let weight = |g: &BestGuess| if g.worst_case <= limit { Some(prefs.weight(g.word, &common)) } else { None };
+
+    // Better first: near-optimal, then heavier, then by the objective.
+    evaluated.sort_by(|a, b| weight(b).cmp(&weight(a)).then_with(|| prefs.objective.cmp(a, b))
+        .then_with(|| prefs.tie_break(a.word).cmp(&prefs.tie_break(b.word))));
+    evaluated
+}
+
+/// Every guess, evaluated against 'answers', in the order given.
+pub fn evaluate_all<'a>(answers: &[&str], guesses: &[&'a str]) -> Vec<BestGuess<'a>> {
+    evaluate_all_progress(answers, guesses, &|_| {})
+}
+
+/// As evaluate_all, calling 'progress' with each guess as it is evaluated, from whichever thread
+/// evaluated it.
+pub fn evaluate_all_progress<'a>(answers: &[&str], guesses: &[&'a str], progress: &(dyn Fn(&BestGuess) + Sync))
+    -> Vec<BestGuess<'a>> {
+    let answers_hash = answers.iter().copied().collect::<HashSet<_>>();
+    guesses.par_iter()
+        .map(|&g| {
+            let res = BestGuess::evaluate(answers, g, answers_hash.contains(g));
+            progress(&res);
+            res
+        })
+        .collect()
+}
+
+/// Every guess, evaluated against 'answers', from best to worst.
+pub fn rank_guesses<'a>(answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Vec<BestGuess<'a>> {
+    rank(evaluate_all(answers, guesses), prefs)
+}
+
+/// The guess rank() would put first, evaluating each of 'guesses' with 'evaluate' and a bound:
+/// the most candidates it may leave in the worst case and still be near-optimal, going by the
+/// best guess evaluated so far on any thread.  A guess over the bound can't come first, so
+/// 'evaluate' may give up on it with None.
+pub fn best_bounded<'a>(guesses: &[&'a str], prefs: &Preferences,
+                        evaluate: impl Fn(&'a str, usize) -> Option<BestGuess<'a>> + Sync) -> Option<BestGuess<'a>> {
+    let best = AtomicUsize::new(usize::MAX);
+    let evaluated = guesses.par_iter().filter_map(|&g| {
+        let res = evaluate(g, best.load(atomic::Ordering::Relaxed).saturating_add(prefs.slack))?;
+        best.fetch_min(res.worst_case, atomic::Ordering::Relaxed);
+        Some(res)
+    }).collect();
+    rank(evaluated, prefs).into_iter().next()
+}
+
+/// The minimax best guess by 'prefs.objective', breaking near-ties by the other preferences.
+pub fn best_guess<'a>(answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Option<BestGuess<'a>> {
+    Minimax.best_guess(answers, guesses, prefs)
+}
+
+/// The 'n' best minimax guesses with default preferences, best first.
+pub fn best_guesses<'a>(answers: &[&str], guesses: &[&'a str], n: usize) -> Vec<BestGuess<'a>> {
+    Minimax.best_guesses(answers, guesses, &Preferences::default(), n)
+}
+
+/// A way of choosing the next guess from evaluated guesses.  Implementing order() is enough for a
+/// custom heuristic; the binaries search through the other methods.
+pub trait Strategy: Sync {
+    fn name(&self) -> &'static str;
+
+    /// Order evaluated guesses from best to worst.
+    fn order<'a>(&self, evaluated: Vec<BestGuess<'a>>, prefs: &Preferences) -> Vec<BestGuess<'a>>;
+
+    fn rank_guesses<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        self.order(evaluate_all(answers, guesses), prefs)
+    }
+
+    fn best_guess<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Option<BestGuess<'a>> {
+        self.rank_guesses(answers, guesses, prefs).into_iter().next()
+    }
+
+    /// As best_guess, calling 'progress' with each guess as it is evaluated.
+    fn best_guess_progress<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences,
+                               progress: &(dyn Fn(&BestGuess) + Sync)) -> Option<BestGuess<'a>> {
+        self.order(evaluate_all_progress(answers, guesses, progress), prefs).into_iter().next()
+    }
+
+    fn best_guesses<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences, n: usize)
+        -> Vec<BestGuess<'a>> {
+        let mut ranked = self.rank_guesses(answers, guesses, prefs);
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// The best guess with default preferences and its score, or None and Score::MAX if there
+    /// are no guesses.
+    fn choose<'a>(&self, answers: &[&str], guesses: &[&'a str]) -> (Option<&'a str>, Score) {
+        match self.best_guess(answers, guesses, &Preferences::default()) {
+            Some(best) => (Some(best.word), best.score()),
+            None => (None, Score::MAX),
+        }
+    }
+}
+
+/// Minimize the candidates left in the worst case, as rank() orders guesses.
+pub struct Minimax;
+
+impl Strategy for Minimax {
+    fn name(&self) -> &'static str {
+        "minimax"
+    }
+
+    fn order<'a>(&self, evaluated: Vec<BestGuess<'a>>, prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        rank(evaluated, prefs)
+    }
+
+    /// Stops scoring a guess against the answers once it can't be the best.
+    fn best_guess<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences) -> Option<BestGuess<'a>> {
+        let answers_hash = answers.iter().copied().collect::<HashSet<_>>();
+        best_bounded(guesses, prefs, |g, bound| BestGuess::evaluate_bounded(answers, g, answers_hash.contains(g), bound))
+    }
+}
+
+/// Maximize the expected information gained, then prefer possible answers.  The tie-break
+/// preferences are for the minimax search, and are ignored.
+pub struct Entropy;
+
+impl Strategy for Entropy {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn order<'a>(&self, mut evaluated: Vec<BestGuess<'a>>, _prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        evaluated.sort_by(|a, b| b.entropy.total_cmp(&a.entropy).then(b.is_candidate.cmp(&a.is_candidate)));
+        evaluated
+    }
+}
+
+/// Minimize the candidates left on average, then prefer possible answers.  The tie-break
+/// preferences are for the minimax search, and are ignored.
+pub struct ExpectedSize;
+
+impl Strategy for ExpectedSize {
+    fn name(&self) -> &'static str {
+        "expected"
+    }
+
+    fn order<'a>(&self, mut evaluated: Vec<BestGuess<'a>>, _prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        evaluated.sort_by(|a, b| a.expected_remaining.total_cmp(&b.expected_remaining)
+            .then(b.is_candidate.cmp(&a.is_candidate)));
+        evaluated
+    }
+}
+
+/// Prefer words with the letters most candidates share, in the positions they have them, found
+/// without scoring any guess against the candidates.  Far cheaper than the other strategies, for
+/// huge candidate sets and as a baseline to compare them with, though it plays worse.  The
+/// tie-break preferences are ignored.
+pub struct Frequency;
+
+impl Frequency {
+    /// Every guess with its letter score, best first: for each position, the candidates with its
+    /// letter there, plus for each of its distinct letters, the candidates containing it.  Down
+    /// to two candidates, only candidates are considered, since another guess can't win sooner.
+    pub fn scores<'a>(answers: &[&str], guesses: &[&'a str]) -> Vec<(usize, &'a str)> {
+        let mut positional = vec![[0; LETTERS]; answers.first().map_or(0, |a| a.len())];
+        let mut containing = [0; LETTERS];
+        for answ in answers {
+            let mut seen = 0u32;
+            for (i, b) in answ.bytes().enumerate() {
+                let c = letter_index(b);
+                positional[i][c] += 1;
+                if seen & (1 << c) == 0 {
+                    containing[c] += 1;
+                    seen |= 1 << c;
+                }
+            }
+        }
+        let answers_hash = answers.iter().copied().collect::<HashSet<_>>();
+        let mut res = guesses.iter().filter(|g| answers.len() > 2 || answers_hash.contains(*g)).map(|&g| {
+            let mut seen = 0u32;
+            let mut score = 0;
+            for (i, b) in g.bytes().enumerate() {
+                let c = letter_index(b);
+                score += positional[i][c];
+                if seen & (1 << c) == 0 {
+                    score += containing[c];
+                    seen |= 1 << c;
+                }
+            }
+            (score, g)
+        }).collect::<Vec<_>>();
+        // Candidates first among equals, then in list order.
+        res.sort_by_key(|&(score, g)| (std::cmp::Reverse(score), !answers_hash.contains(g)));
+        res
+    }
+}
+
+impl Strategy for Frequency {
+    fn name(&self) -> &'static str {
+        "frequency"
+    }
+
+    /// Evaluated guesses don't carry the candidates' letters, so are ordered as Entropy orders
+    /// them.
+    fn order<'a>(&self, evaluated: Vec<BestGuess<'a>>, prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        Entropy.order(evaluated, prefs)
+    }
+
+    /// Every guess considered, by letter score, each evaluated against 'answers'.  Only
+    /// best_guess() is cheap.
+    fn rank_guesses<'a>(&self, answers: &[&str], guesses: &[&'a str], _prefs: &Preferences) -> Vec<BestGuess<'a>> {
+        let answers_hash = answers.iter().copied().collect::<HashSet<_>>();
+        Self::scores(answers, guesses).par_iter()
+            .map(|&(_, g)| BestGuess::evaluate(answers, g, answers_hash.contains(g)))
+            .collect()
+    }
+
+    fn best_guess<'a>(&self, answers: &[&str], guesses: &[&'a str], _prefs: &Preferences) -> Option<BestGuess<'a>> {
+        let &(_, word) = Self::scores(answers, guesses).first()?;
+        Some(BestGuess::evaluate(answers, word, answers.contains(&word)))
+    }
+
+    /// As best_guess(), which is quick enough to need no progress.
+    fn best_guess_progress<'a>(&self, answers: &[&str], guesses: &[&'a str], prefs: &Preferences,
+                               _progress: &(dyn Fn(&BestGuess) + Sync)) -> Option<BestGuess<'a>> {
+        self.best_guess(answers, guesses, prefs)
+    }
+}
+
+pub const STRATEGIES: &[&dyn Strategy] = &[&Minimax, &Entropy, &ExpectedSize, &Frequency];
+
+pub fn strategy_named(name: &str) -> Option<&'static dyn Strategy> {
+    STRATEGIES.iter().copied().find(|s| s.name() == name)
+}
+
+#[cfg(test)]
+mod test_solver {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let g = BestGuess::evaluate(&answers, "whelp", false);
+        assert_eq!(g.worst_case, 1);
+        assert_eq!(g.expected_remaining, 1.0);
+        assert_eq!(g.entropy, 2.0);
+        assert_eq!(g.score(), 2);
+
+        let g = BestGuess::evaluate(&answers, "hatch", true);
+        assert_eq!(g.worst_case, 3);
+        assert_eq!(g.expected_remaining, 2.5);
+        assert_eq!(g.score(), 5);
+    }
+
+    #[test]
+    fn test_best_guess() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let guesses = ["hatch", "whelp", "cigar"];
+        let ranked = rank_guesses(&answers, &guesses, &Preferences::default());
+        assert_eq!(ranked.iter().map(|g| g.word).collect::<Vec<_>>(), ["whelp", "hatch", "cigar"]);
+        assert_eq!(best_guess(&answers, &guesses, &Preferences::default()).unwrap().word, "whelp");
+        assert_eq!(best_guess(&answers, &[], &Preferences::default()), None);
+        let seen = std::sync::atomic::AtomicUsize::new(0);
+        let best = Minimax.best_guess_progress(&answers, &guesses, &Preferences::default(), &|_| {
+            seen.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        });
+        assert_eq!(best.unwrap().word, "whelp");
+        assert_eq!(seen.into_inner(), guesses.len());
+        assert_eq!(best_guesses(&answers, &guesses, 2), ranked[..2]);
+        assert_eq!(best_guesses(&answers, &guesses, 5).len(), 3);
+    }
+
+    #[test]
+    fn test_best_bounded() {
+        let answers = ["hatch", "watch", "latch", "patch"];
+        assert_eq!(BestGuess::evaluate_bounded(&answers, "hatch", true, 2), None);
+        assert_eq!(BestGuess::evaluate_bounded(&answers, "hatch", true, 3), Some(BestGuess::evaluate(&answers, "hatch", true)));
+
+        // Giving up on guesses early mustn't change which comes first.
+        let answers = &ANSW_LIST[..300];
+        let guesses = &crate::GUESS_LIST[..1000];
+        let table = crate::PatternTable::new(answers, guesses);
+        for prefs in [Preferences::default(), Preferences { slack: 3, unique: 1, ..Default::default() }] {
+            let best = rank_guesses(answers, guesses, &prefs)[0].word;
+            assert_eq!(Minimax.best_guess(answers, guesses, &prefs).map(|g| g.word), Some(best));
+            assert_eq!(table.best_guess(answers, guesses, &prefs).map(|g| g.word), Some(best));
+        }
+    }
+
+    #[test]
+    fn test_objective() {
+        // Both leave at most two, but "abase" usually leaves one.
+        let answers = ["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve"];
+        let guesses = ["zupan", "abase"];
+        let mut prefs = Preferences::default();
+        let ranked = rank_guesses(&answers, &guesses, &prefs);
+        assert_eq!(ranked.iter().map(|g| (g.word, g.worst_case)).collect::<Vec<_>>(), [("abase", 2), ("zupan", 2)]);
+        assert!(ranked[0].expected_remaining < ranked[1].expected_remaining);
+        prefs.objective = Objective::WorstCase;
+        assert_eq!(best_guess(&answers, &guesses, &prefs).unwrap().word, "zupan");
+
+        prefs.unique = 1;
+        prefs.slack = 10;
+        assert_eq!(best_guess(&answers, &["abase", "zupan", "cigar"], &prefs).unwrap().word, "cigar");
+    }
+
+    #[test]
+    fn test_strategy() {
+        // "cigar" leaves at most two, but "naval" tells us more on average.
+        let answers = ["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve"];
+        let guesses = ["cigar", "naval"];
+        let prefs = Preferences::default();
+        assert_eq!(Minimax.best_guess(&answers, &guesses, &prefs).unwrap().word, "cigar");
+        assert_eq!(Entropy.best_guess(&answers, &guesses, &prefs).unwrap().word, "naval");
+        // Both leave at most two, as in test_objective, but "abase" usually leaves one.
+        assert_eq!(ExpectedSize.best_guess(&answers, &["zupan", "abase"], &prefs).unwrap().word, "abase");
+        assert_eq!(Minimax.choose(&answers, &guesses), (Some("cigar"), 3));
+        assert_eq!(Entropy.choose(&answers, &[]), (None, Score::MAX));
+        assert_eq!(strategy_named("entropy").unwrap().name(), "entropy");
+        assert!(strategy_named("random").is_none());
+
+        // "hatch" has the "-atch" every candidate shares; "whelp" and "cigar" less of it.
+        let answers = ["hatch", "watch", "latch", "patch"];
+        let scores = Frequency::scores(&answers, &["whelp", "hatch", "cigar"]);
+        assert_eq!(scores.iter().map(|&(_, g)| g).collect::<Vec<_>>(), ["hatch", "whelp", "cigar"]);
+        assert_eq!(scores[0].0, 1 + 4 * 4 + 4 * 4);
+        assert_eq!(Frequency.best_guess(&answers, &["whelp", "hatch"], &prefs).map(|g| (g.word, g.worst_case)),
+                   Some(("hatch", 3)));
+        assert_eq!(Frequency.best_guess(&answers[..2], &["whelp", "watch"], &prefs).unwrap().word, "watch");
+        assert_eq!(Frequency.rank_guesses(&answers, &["cigar", "hatch"], &prefs)[0].word, "hatch");
+    }
+}
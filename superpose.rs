@@ -0,0 +1,56 @@
+use crate::{Color, score_n};
+
+/// The feedback for 'guess' against several hidden answers at once: each tile shows the best
+/// color it earns against any of them, as in Xordle.
+pub fn superposed_score<const N: usize>(answers: &[&str], guess: &str) -> [Color; N] {
+    let mut res = [Color::GREY; N];
+    for answ in answers {
+        for (r, c) in res.iter_mut().zip(score_n::<N>(answ, guess)) {
+            if c as u8 > *r as u8 {
+                *r = c;
+            }
+        }
+    }
+    res
+}
+
+/// Every unordered pair of distinct answers.
+pub fn all_pairs<'a>(answers: &[&'a str]) -> Vec<(&'a str, &'a str)> {
+    let mut res = Vec::with_capacity(answers.len() * answers.len().saturating_sub(1) / 2);
+    for (i, &a) in answers.iter().enumerate() {
+        for &b in &answers[i + 1..] {
+            res.push((a, b));
+        }
+    }
+    res
+}
+
+/// The pairs which would give 'guess' the superposed 'result'.
+pub fn prune_pairs<'a, const N: usize>(pairs: &[(&'a str, &'a str)], guess: &str, result: [Color; N]) -> Vec<(&'a str, &'a str)> {
+    pairs.iter().copied().filter(|&(a, b)| superposed_score(&[a, b], guess) == result).collect()
+}
+
+#[cfg(test)]
+mod test_superpose {
+    use super::*;
+    use crate::{parse_result, score};
+
+    #[test]
+    fn test_superposed_score() {
+        assert_eq!(superposed_score(&["cigar"], "crane"), score("cigar", "crane"));
+        // cigar: c green, r and a yellow; squad only adds another yellow a.
+        assert_eq!(superposed_score(&["cigar", "squad"], "crane"), parse_result("21100").unwrap());
+        assert_eq!(superposed_score(&[], "crane"), [Color::GREY; 5]);
+    }
+
+    #[test]
+    fn test_prune_pairs() {
+        let pairs = all_pairs(&["cigar", "squad", "humph", "rebut"]);
+        assert_eq!(pairs.len(), 6);
+        let result = superposed_score::<5>(&["cigar", "squad"], "crane");
+        let left = prune_pairs(&pairs, "crane", result);
+        assert!(left.contains(&("cigar", "squad")));
+        assert!(left.iter().all(|&(a, b)| superposed_score(&[a, b], "crane") == result));
+        assert!(!left.contains(&("humph", "rebut")));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Color, alphabet, prune_n};
+
+fn count(word: &[u8], letter: u8) -> usize {
+    word.iter().filter(|&&b| b == letter).count()
+}
+
+/// Plain-language account of what a scored guess taught us, given the candidate 'answers' which
+/// remained before it was played.
+pub fn explain<const N: usize>(answers: &[&str], guess: [u8; N], result: [Color; N]) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // Number of tiles showing each letter as present (green or yellow) in the answer.
+    let present = |letter| (0..N).filter(|&j| guess[j] == letter && result[j] != Color::GREY).count();
+
+    for i in 0..N {
+        let g = guess[i];
+        let upper = alphabet().upper(g);
+        let pos = i + 1;
+        let k = present(g);
+        let (color, eliminated, why) = match result[i] {
+            Color::GREEN => ("green",
+                answers.iter().filter(|a| a.as_bytes()[i] != g).count(),
+                format!("the answer has {} in position {}", upper, pos)),
+            Color::YELLOW => ("yellow",
+                answers.iter().filter(|a| a.as_bytes()[i] == g || count(a.as_bytes(), g) < k).count(),
+                format!("the answer has {} {}, but not in position {}", at_least(k), plural(upper, k), pos)),
+            Color::GREY => ("grey",
+                answers.iter().filter(|a| a.as_bytes()[i] == g || count(a.as_bytes(), g) > k).count(),
+                if k == 0 {
+                    format!("there is no {} in the answer", upper)
+                } else {
+                    format!("the answer has exactly {} {}, and not in position {}", k, plural(upper, k), pos)
+                }),
+        };
+        lines.push(format!("The {} {} eliminates {}: {}.", color, upper, nwords(eliminated), why));
+    }
+
+    // Explain which letter counts remain open.
+    let mut seen = Vec::new();
+    for &g in &guess {
+        let k = present(g);
+        if k == 0 || seen.contains(&g) {
+            continue;
+        }
+        seen.push(g);
+        let capped = (0..N).any(|j| guess[j] == g && result[j] == Color::GREY);
+        if !capped {
+            let upper = alphabet().upper(g);
+            lines.push(format!("No {} was grey, so {} {} is still possible.", upper, nth(k + 1), upper));
+        }
+    }
+
+    let remaining = prune_n(answers, guess, result).len();
+    lines.push(format!("Together these leave {} of {} candidates.", remaining, answers.len()));
+    lines
+}
+
+fn nwords(n: usize) -> String {
+    if n == 1 {
+        "1 word".to_string()
+    } else {
+        format!("{} words", n)
+    }
+}
+
+fn at_least(k: usize) -> String {
+    if k == 1 {
+        "an".to_string()
+    } else {
+        format!("at least {}", k)
+    }
+}
+
+fn plural(letter: char, k: usize) -> String {
+    if k == 1 {
+        letter.to_string()
+    } else {
+        format!("{}s", letter)
+    }
+}
+
+fn nth(k: usize) -> &'static str {
+    match k {
+        2 => "a second",
+        3 => "a third",
+        4 => "a fourth",
+        _ => "another",
+    }
+}
+
+#[cfg(test)]
+mod test_teach {
+    use super::*;
+    use crate::{parse_guess, score};
+
+    #[test]
+    fn test_explain() {
+        let answers = ["eerie", "crepe", "there", "cigar"];
+        let guess = parse_guess("reset").unwrap();
+        let lines = explain(&answers, guess, score("crepe", "reset"));
+        assert_eq!(lines[0], "The yellow R eliminates 0 words: the answer has an R, but not in position 1.");
+        assert_eq!(lines[1], "The yellow E eliminates 2 words: the answer has at least 2 Es, but not in position 2.");
+        assert_eq!(lines[2], "The grey S eliminates 0 words: there is no S in the answer.");
+        assert_eq!(lines[3], "The yellow E eliminates 1 word: the answer has at least 2 Es, but not in position 4.");
+        assert_eq!(lines[4], "The grey T eliminates 1 word: there is no T in the answer.");
+        assert_eq!(lines[5], "No R was grey, so a second R is still possible.");
+        assert_eq!(lines[6], "No E was grey, so a third E is still possible.");
+        assert_eq!(lines.last().unwrap(), "Together these leave 1 of 4 candidates.");
+    }
+}
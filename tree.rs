@@ -0,0 +1,267 @@
+use crate::{Color, FromJson, Json, Observation, Pattern, ToJson, best_opener, fmt_result, parse_guess_n, parse_result_n, partition_by_pattern};
+use rustc_hash::FxHashMap as HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The guess to play after each sequence of results, starting from the opener, for words of 'N'
+/// letters.  As text, one
+/// node per line: the space separated results so far, a tab, and the guess.  As JSON, an array
+/// of "nodes" with the "path" of results and the "guess"; load() reads either, and save() writes
+/// JSON to a .json file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecisionTree<const N: usize = 5> {
+    nodes: HashMap<Vec<[Color; N]>, String>,
+}
+
+impl<const N: usize> DecisionTree<N> {
+    /// The minimax solver's tree for 'answers', opening with 'opener'.
+    pub fn build(answers: &[&str], guesses: &[&str], opener: &str) -> Self {
+        let mut res = Self::default();
+        res.build_node(answers, guesses, &mut Vec::new(), opener);
+        res
+    }
+
+    fn build_node(&mut self, answers: &[&str], guesses: &[&str], path: &mut Vec<[Color; N]>, guess: &str) {
+        self.nodes.insert(path.clone(), guess.to_string());
+        for (p, next) in partition_by_pattern(guess, answers).iter().enumerate() {
+            if next.is_empty() || p == Pattern::solved(guess.len()).index() {
+                continue;
+            }
+            let result = Pattern(p as u16).colors();
+            let next_guess = if next.len() == 1 {
+                next[0]
+            } else {
+                best_opener(next, guesses, 1).unwrap().0
+            };
+            path.push(result);
+            self.build_node(next, guesses, path, next_guess);
+            path.pop();
+        }
+    }
+
+    pub fn parse(text: &str) -> io::Result<Self> {
+        let invalid = |n: usize| io::Error::new(io::ErrorKind::InvalidData, format!("bad tree node on line {}", n + 1));
+        let mut res = Self::default();
+        for (n, line) in text.lines().enumerate() {
+            let (path, guess) = line.split_once('\t').ok_or_else(|| invalid(n))?;
+            let path = path.split_whitespace().map(parse_result_n).collect::<Option<Vec<_>>>().ok_or_else(|| invalid(n))?;
+            if parse_guess_n::<N>(guess).is_none() {
+                return Err(invalid(n));
+            }
+            res.nodes.insert(path, guess.to_string());
+        }
+        Ok(res)
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let res = if text.trim_start().starts_with('{') {
+            Json::parse(&text).and_then(|json| Self::from_json(&json)).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        } else {
+            Self::parse(&text)
+        };
+        res.map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if path.extension().is_some_and(|ext| ext == "json") {
+            fs::write(path, self.to_json().to_string() + "\n")
+        } else {
+            fs::write(path, self.to_text())
+        }
+    }
+
+    // The paths of the nodes, sorted so parents come before their children.
+    fn sorted_paths(&self) -> Vec<&Vec<[Color; N]>> {
+        let mut paths = self.nodes.keys().collect::<Vec<_>>();
+        paths.sort_by_key(|p| (p.len(), p.iter().map(|r| r.map(|c| c as u8)).collect::<Vec<_>>()));
+        paths
+    }
+
+    /// The tree as text, sorted so parents come before their children.
+    pub fn to_text(&self) -> String {
+        let mut res = String::new();
+        for path in self.sorted_paths() {
+            let path_text = path.iter().map(fmt_result).collect::<Vec<_>>().join(" ");
+            writeln!(res, "{}\t{}", path_text, self.nodes[path]).unwrap();
+        }
+        res
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The guess after 'history', if every guess so far was the tree's.
+    pub fn next(&self, history: &[Observation<N>]) -> Option<&str> {
+        for i in 0..history.len() {
+            let results = history[..i].iter().map(|&(_, r)| r).collect::<Vec<_>>();
+            if self.nodes.get(&results)?.as_bytes() != &history[i].0[..] {
+                return None;
+            }
+        }
+        let results = history.iter().map(|&(_, r)| r).collect::<Vec<_>>();
+        self.nodes.get(&results).map(String::as_str)
+    }
+
+    /// The guess after the results 'path', from the opener on.
+    pub fn guess_at(&self, path: &[[Color; N]]) -> Option<&str> {
+        self.nodes.get(path).map(String::as_str)
+    }
+
+    /// The results the guess after 'path' may give, other than solving it, each with the guess
+    /// to play after it; in result order.
+    pub fn children(&self, path: &[[Color; N]]) -> Vec<([Color; N], &str)> {
+        let mut res = self.nodes.iter().filter(|(p, _)| p.len() == path.len() + 1 && p.starts_with(path))
+            .map(|(p, guess)| (p[path.len()], guess.as_str())).collect::<Vec<_>>();
+        res.sort_by_key(|&(r, _)| Pattern::from(r).index());
+        res
+    }
+
+    /// The tree as an outline to read: the opener, then after each result, indented below the
+    /// guess it is for, "result guess".
+    pub fn to_outline(&self) -> String {
+        let mut res = String::new();
+        if let Some(opener) = self.guess_at(&[]) {
+            writeln!(res, "{}", opener).unwrap();
+            self.outline_node(&mut Vec::new(), &mut res);
+        }
+        res
+    }
+
+    fn outline_node(&self, path: &mut Vec<[Color; N]>, out: &mut String) {
+        for (result, guess) in self.children(path) {
+            writeln!(out, "{}{} {}", "  ".repeat(path.len() + 1), fmt_result(&result), guess).unwrap();
+            path.push(result);
+            self.outline_node(path, out);
+            path.pop();
+        }
+    }
+
+    /// The tree in Graphviz's DOT language, a node per guess and an edge per result.
+    pub fn to_dot(&self) -> String {
+        let mut res = String::from("digraph tree {\n");
+        if let Some(opener) = self.guess_at(&[]) {
+            writeln!(res, "  n0 [label=\"{}\"];", opener).unwrap();
+            self.dot_node(&mut Vec::new(), 0, &mut 0, &mut res);
+        }
+        res.push_str("}\n");
+        res
+    }
+
+    // Write the edges from node 'id', at 'path', and the nodes below; 'last' is the highest id
+    // yet.
+    fn dot_node(&self, path: &mut Vec<[Color; N]>, id: usize, last: &mut usize, out: &mut String) {
+        for (result, guess) in self.children(path) {
+            *last += 1;
+            let child = *last;
+            writeln!(out, "  n{} [label=\"{}\"];", child, guess).unwrap();
+            writeln!(out, "  n{} -> n{} [label=\"{}\"];", id, child, fmt_result(&result)).unwrap();
+            path.push(result);
+            self.dot_node(path, child, last, out);
+            path.pop();
+        }
+    }
+
+    /// The most guesses the tree takes to find any answer.
+    pub fn depth(&self) -> usize {
+        self.nodes.keys().map(|p| p.len() + 1).max().unwrap_or(0)
+    }
+}
+
+impl<const N: usize> ToJson for DecisionTree<N> {
+    fn to_json(&self) -> Json {
+        let nodes = self.sorted_paths().into_iter().map(|path| {
+            Json::object("node", vec![("path", path.to_json()), ("guess", self.nodes[path].to_json())])
+        }).collect();
+        Json::object("tree", vec![("nodes", Json::Array(nodes))])
+    }
+}
+
+impl<const N: usize> FromJson for DecisionTree<N> {
+    fn from_json(json: &Json) -> Result<Self, String> {
+        let mut res = Self::default();
+        for (n, node) in json.field::<Vec<Json>>("nodes")?.iter().enumerate() {
+            let invalid = |e| format!("bad tree node {}: {}", n + 1, e);
+            let guess = node.field::<String>("guess").map_err(invalid)?;
+            crate::check_guess_n::<N>(&guess, None).map_err(|e| invalid(format!("'{}' {}", guess, e)))?;
+            res.nodes.insert(node.field("path").map_err(invalid)?, guess);
+        }
+        Ok(res)
+    }
+}
+
+/// The decision tree for the builtin lists, generated by mktree.rs.
+#[cfg(feature = "embedded-tree")]
+pub fn embedded_tree() -> &'static DecisionTree {
+    static TREE: std::sync::OnceLock<DecisionTree> = std::sync::OnceLock::new();
+    TREE.get_or_init(|| DecisionTree::parse(include_str!("tree.txt")).unwrap())
+}
+
+#[cfg(test)]
+mod test_tree {
+    use super::*;
+    use crate::{parse_guess, score};
+
+    #[test]
+    fn test_build() {
+        let answers = ["cigar", "rebut", "sissy", "humph"];
+        let tree = DecisionTree::build(&answers, &answers, "cigar");
+        assert_eq!(DecisionTree::parse(&tree.to_text()).unwrap(), tree);
+
+        for answer in answers {
+            let mut history = Vec::new();
+            loop {
+                let guess = tree.next(&history).unwrap();
+                let result = score(answer, guess);
+                history.push((parse_guess(guess).unwrap(), result));
+                if guess == answer {
+                    break;
+                }
+            }
+            assert!(history.len() <= tree.depth());
+        }
+
+        let outline = tree.to_outline();
+        assert!(outline.starts_with("cigar\n"));
+        assert_eq!(outline.lines().count(), tree.len());
+        let dot = tree.to_dot();
+        assert!(dot.starts_with("digraph tree {\n  n0 [label=\"cigar\"];\n"));
+        assert_eq!(dot.matches(" -> ").count(), tree.len() - 1);
+        let children = tree.children(&[]);
+        assert_eq!(children.len(), 3);
+        assert!(children.iter().all(|&(r, g)| tree.guess_at(&[r]) == Some(g)));
+
+        let off_book = [(parse_guess("rebut").unwrap(), score("sissy", "rebut"))];
+        assert_eq!(tree.next(&off_book), None);
+
+        let path = std::env::temp_dir().join(format!("wordle-tree-test-{}", std::process::id()));
+        tree.save(&path).unwrap();
+        assert_eq!(DecisionTree::load(&path).unwrap(), tree);
+        let json_path = path.with_extension("json");
+        tree.save(&json_path).unwrap();
+        assert!(fs::read_to_string(&json_path).unwrap().starts_with(r#"{"type":"tree","nodes":[{"type":"node","path":[],"guess":"cigar"},"#));
+        assert_eq!(DecisionTree::load(&json_path).unwrap(), tree);
+        fs::write(&json_path, r#"{"type":"tree","nodes":[{"path":["222"],"guess":"cigar"}]}"#).unwrap();
+        assert_eq!(DecisionTree::<5>::load(&json_path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&json_path).unwrap();
+        fs::write(&path, "22222\n").unwrap();
+        assert_eq!(DecisionTree::<5>::load(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_word_length() {
+        let answers = ["coat", "boat", "moat", "cost"];
+        let tree = DecisionTree::<4>::build(&answers, &answers, "coat");
+        assert_eq!(DecisionTree::parse(&tree.to_text()).unwrap(), tree);
+        assert_eq!(DecisionTree::from_json(&tree.to_json()), Ok(tree.clone()));
+        assert!(DecisionTree::<5>::parse(&tree.to_text()).is_err());
+    }
+}
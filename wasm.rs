@@ -0,0 +1,50 @@
+// The solver for a web page, through wasm-bindgen.  Build without the default features, which
+// need threads and a terminal:
+//   cargo rustc --lib --release --target wasm32-unknown-unknown --no-default-features --features wasm \
+//       --crate-type cdylib
+// Results are returned as strings of digits, as everywhere else: 0 for grey, 1 for yellow, 2
+// for green.  Any notation parse_result() takes is accepted.
+use crate::{ANSW_LIST, builtin_guesses, check_guess, check_result, fmt_result};
+use wasm_bindgen::prelude::*;
+
+/// The builtin answer list.
+#[wasm_bindgen]
+pub fn answers() -> Vec<String> {
+    ANSW_LIST.iter().map(|w| w.to_string()).collect()
+}
+
+/// The result of 'guess' when the answer is 'answer'.
+#[wasm_bindgen]
+pub fn score(answer: &str, guess: &str) -> Result<String, JsError> {
+    check_guess(guess, None).map_err(|e| JsError::new(&format!("the guess {}", e)))?;
+    check_guess(answer, None).map_err(|e| JsError::new(&format!("the answer {}", e)))?;
+    Ok(fmt_result(&crate::score(answer, guess)))
+}
+
+// 'words' as strs, if they are all 5 letter words.
+fn check_words(words: &[String]) -> Result<Vec<&str>, JsError> {
+    words.iter().map(|w| check_guess(w, None).map(|_| w.as_str()).map_err(|e| JsError::new(&format!("'{}' {}", w, e))))
+        .collect()
+}
+
+/// The 'candidates' still possible after 'guess' gave 'result'.
+#[wasm_bindgen]
+pub fn prune(candidates: Vec<String>, guess: &str, result: &str) -> Result<Vec<String>, JsError> {
+    let bguess = check_guess(guess, None).map_err(|e| JsError::new(&format!("the guess {}", e)))?;
+    let result = check_result(result).map_err(|e| JsError::new(&format!("the result {}", e)))?;
+    let candidates = check_words(&candidates)?;
+    Ok(crate::prune(&candidates, bguess, result).into_iter().map(str::to_string).collect())
+}
+
+/// The minimax best guess for 'candidates' from 'guesses', or from the builtin lists if
+/// 'guesses' is empty.
+#[wasm_bindgen]
+pub fn best_guess(candidates: Vec<String>, guesses: Vec<String>) -> Result<Option<String>, JsError> {
+    let candidates = check_words(&candidates)?;
+    let guesses = if guesses.is_empty() {
+        builtin_guesses()
+    } else {
+        check_words(&guesses)?
+    };
+    Ok(crate::best_guess(&candidates, &guesses, &Default::default()).map(|best| best.word.to_string()))
+}
@@ -0,0 +1,150 @@
+use crate::{ANSW_LIST, Alphabet, GUESS_LIST};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// A word list read from text, one word per line.  Blank lines are skipped; every other line
+/// must be a lowercase word, all of the same length, of a to z or another Alphabet, whose
+/// words are stored as Alphabet::encode() gives them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WordList {
+    words: Vec<String>,
+}
+
+impl WordList {
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        Self::from_reader_in(reader, Alphabet::LATIN)
+    }
+
+    /// As from_reader(), for words of 'alphabet'.
+    pub fn from_reader_in<R: BufRead>(reader: R, alphabet: Alphabet) -> io::Result<Self> {
+        let mut res = Self::default();
+        for (lineno, line) in reader.lines().enumerate() {
+            let line = line?;
+            let word = line.trim();
+            if word.is_empty() {
+                continue;
+            }
+            let invalid = |why: &str| io::Error::new(io::ErrorKind::InvalidData,
+                format!("line {}: '{}' {}", lineno + 1, word, why));
+            let word = match alphabet.encode(word) {
+                Some(word) => word,
+                None if alphabet == Alphabet::LATIN => return Err(invalid("is not a lowercase ASCII word")),
+                None => return Err(invalid("is not a lowercase word of the alphabet")),
+            };
+            if let Some(len) = res.word_len() {
+                if word.len() != len {
+                    return Err(invalid(&format!("does not have {} letters like the words before it", len)));
+                }
+            }
+            res.words.push(word);
+        }
+        Ok(res)
+    }
+
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        Self::from_file_in(path, Alphabet::LATIN)
+    }
+
+    /// As from_file(), for words of 'alphabet'.
+    pub fn from_file_in(path: &Path, alphabet: Alphabet) -> io::Result<Self> {
+        let file = File::open(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        Self::from_reader_in(BufReader::new(file), alphabet).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+
+    /// The length of every word, or None for an empty list.
+    pub fn word_len(&self) -> Option<usize> {
+        self.words.first().map(String::len)
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.words.iter().map(String::as_str).collect()
+    }
+
+    /// The words, for a list which lives as long as the program, like the builtin lists.
+    pub fn leak(self) -> &'static [&'static str] {
+        let list: &'static WordList = Box::leak(Box::new(self));
+        list.words().leak()
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// The answer and guess lists for a run: the builtin lists, or the files given, whose words must
+/// have 'word_len' letters.  An answer file must not be empty.  The builtin lists are of one
+/// length, so other lengths need an answer file, and without a guess file guess only answers.
+pub fn load_lists(answers: Option<&Path>, guesses: Option<&Path>, word_len: usize)
+    -> io::Result<(&'static [&'static str], &'static [&'static str])> {
+    let builtin_len = ANSW_LIST[0].len();
+    let default = match answers {
+        _ if word_len == builtin_len => (ANSW_LIST, GUESS_LIST),
+        Some(_) => (&[][..], &[][..]),
+        None => return Err(io::Error::new(io::ErrorKind::NotFound, format!(
+            "the builtin answers are {} letter words; give a list of {} letter words with -a", builtin_len, word_len))),
+    };
+    load_lists_with(answers, guesses, word_len, Alphabet::LATIN, default)
+}
+
+/// As load_lists(), for words of 'alphabet', with the answer and guess lists 'default' instead
+/// of the builtin ones for files not given.
+pub fn load_lists_with(answers: Option<&Path>, guesses: Option<&Path>, word_len: usize, alphabet: Alphabet,
+                       default: (&'static [&'static str], &'static [&'static str]))
+    -> io::Result<(&'static [&'static str], &'static [&'static str])> {
+    let load = |path: Option<&Path>, default, allow_empty: bool| -> io::Result<&'static [&'static str]> {
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(default),
+        };
+        let list = WordList::from_file_in(path, alphabet)?;
+        if list.word_len().is_some_and(|len| len != word_len) || (list.is_empty() && !allow_empty) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: expected {} letter words", path.display(), word_len)));
+        }
+        Ok(list.leak())
+    };
+    Ok((load(answers, default.0, false)?, load(guesses, default.1, true)?))
+}
+
+#[cfg(test)]
+mod test_wordfile {
+    use super::*;
+
+    #[test]
+    fn test_from_reader() {
+        let list = WordList::from_reader("cigar\n\n  rebut\nsissy\n".as_bytes()).unwrap();
+        assert_eq!(list.words(), ["cigar", "rebut", "sissy"]);
+        assert_eq!(list.word_len(), Some(5));
+        assert!(WordList::from_reader("".as_bytes()).unwrap().is_empty());
+
+        let err = WordList::from_reader("cigar\nCigar\n".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2:"));
+        assert!(WordList::from_reader("cigar\nplanet\n".as_bytes()).is_err());
+        assert!(WordList::from_reader("naïve\n".as_bytes()).is_err());
+        let es = crate::Language::named("es").unwrap().alphabet;
+        assert_eq!(WordList::from_reader_in("niños\nperro\n".as_bytes(), es).unwrap().words(), ["ni{os", "perro"]);
+    }
+
+    #[test]
+    fn test_load_lists() {
+        let path = std::env::temp_dir().join(format!("wordle-wordfile-test-{}", std::process::id()));
+        std::fs::write(&path, "cigar\nrebut\n").unwrap();
+        let (answers, guesses) = load_lists(Some(&path), None, 5).unwrap();
+        assert_eq!(answers, ["cigar", "rebut"]);
+        assert_eq!(guesses, GUESS_LIST);
+        assert!(load_lists(None, Some(&path), 6).is_err());
+        std::fs::write(&path, "coat
+boat
+").unwrap();
+        assert_eq!(load_lists(Some(&path), None, 4).unwrap(), (&["coat", "boat"][..], &[][..]));
+        assert_eq!(load_lists(None, None, 4).unwrap_err().kind(), io::ErrorKind::NotFound);
+        std::fs::remove_file(&path).unwrap();
+        assert!(load_lists(Some(&path), None, 5).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+/* The solver's C interface.  Generated from ffi.rs, which says how to build the library, by
+ *   cargo run --features wordle-ffi --bin mkheader > wordle.h
+ * ffi.rs tests that it is up to date. */
+#ifndef WORDLE_H
+#define WORDLE_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Write the result of 'guess' when the answer is 'answer' to 'result', which has room for 6
+ * bytes.  Returns 0, or -1 if either isn't a 5 letter word. */
+int wordle_score(const char *answer, const char *guess, char *result);
+
+/* Write those of the 'ncandidates' strings at 'candidates' still possible after 'guess' gave
+ * 'result' to 'out', which has room for 'ncandidates' pointers.  'result' may be in any
+ * notation parse_result() takes.  Returns how many were written, or -1 if any candidate isn't
+ * a 5 letter word. */
+int wordle_prune(const char *const *candidates, size_t ncandidates, const char *guess,
+                 const char *result, const char **out);
+
+/* Write the minimax best guess for the 'ncandidates' strings at 'candidates', from the
+ * 'nguesses' at 'guesses' or from the builtin lists if there are none, to the 'len' bytes at
+ * 'out'.  Returns its length, 0 if there are no candidates, or -1 if any word isn't a 5 letter
+ * word. */
+int wordle_best_guess(const char *const *candidates, size_t ncandidates, const char *const *guesses,
+                      size_t nguesses, char *out, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -1,179 +1,1748 @@
-use anyhow::Result;
-use rayon::prelude::*;
-use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-use std::cmp::max;
+use anyhow::{Result, anyhow, bail};
+use indicatif::{ProgressBar, ProgressStyle};
+use itertools::Itertools;
+use std::cmp::{Ordering, max};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
 
-use wordle::{ANSW_LIST, GUESS_LIST, AnswerIterator, histo, maybe_prune, parse_guess, print_rem, score};
+use wordle::{ANSW_LIST, BestGuess, Cache, Color, Config, Constraints, CsvWriter, Date, DecisionTree, Duel, DuelOutcome, Game, Input, JsonObject, LANGUAGES, Language, MAX_GUESSES, Minimax, Mode, Observation, OptimalSolver, PatternTable, Pool, PoolStats, Preferences, ResultsDb, Rng, Session, Shard, SimReport, STRATEGIES, Strategy, ToJson, Verbosity, WordleError, alphabet, analyze, analyze_game, best_guess, best_guess_depth, cached_opener, check_guess_n, check_result_n, choose_opener, combine_lists, daily_answer, describe, discriminator, explain, find_conflict, fmt_colored, fmt_count, fmt_squares, fmt_result, game_json, guess_pool, is_builtin, json_array, json_output, league_points, letter_index, parse_game_json, worst_case, parse_observation_n, parse_opening, parse_result_n, play_line, position_letters, print_guess, print_rem, prune_n, rank_pairs, raw_argument, score_n, set_alphabet, set_json_output, set_verbosity, strategy_named, strategy_opener, word_bytes_n, wordlist_checksum};
 
-fn best_guess<'a>(answers: &[&'a str], guesses: &[&'a str]) -> (Option<&'a str>, usize) {
-    let mut bestguess: Option<&'a str> = None;
-    let mut bestsco = usize::MAX;
+// The answers in play: the builtin list, or the one given with -a.
+static ANSWERS: OnceLock<&'static [&'static str]> = OnceLock::new();
 
-    let histos = answers.iter().map(|a| histo(a.as_bytes())).collect::<Vec<_>>();
+fn answer_list() -> &'static [&'static str] {
+    ANSWERS.get().copied().unwrap_or(ANSW_LIST)
+}
 
-    // Find the guess that, for any remaining answer, minimizes the maximum candidates
-    let scored_guesses = guesses.par_iter().map(|guess| {
-        let guessa = guess.as_bytes();
-        let bguess = [guessa[0], guessa[1], guessa[2], guessa[3], guessa[4]];
-        //println!("eval: {}", guess);
+// Estimate how long a best guess search will take, from timing a sample of the guesses.
+fn estimate_seconds(answers: &[&str], guesses: &[&str]) -> f64 {
+    const SAMPLE: usize = 16;
+    let step = max(guesses.len() / SAMPLE, 1);
+    let start = Instant::now();
+    let sampled = guesses.iter().step_by(step).map(|g| BestGuess::evaluate(answers, g, false)).collect::<Vec<_>>();
+    let per_guess = start.elapsed().as_secs_f64() / max(std::hint::black_box(sampled).len(), 1) as f64;
+    per_guess * guesses.len() as f64 / rayon::current_num_threads() as f64
+}
 
-        let mut sco = 0;
+// As strategy.best_guess, ticking 'pb' per guess with the best worst case seen so far.
+fn best_guess_progress<'a>(answers: &[&str], guesses: &[&'a str], strategy: &dyn Strategy, prefs: &Preferences,
+                           pb: &ProgressBar) -> Option<BestGuess<'a>> {
+    let best_so_far = Mutex::new(usize::MAX);
+    strategy.best_guess_progress(answers, guesses, prefs, &|res| {
+        let mut best = best_so_far.lock().unwrap();
+        if res.worst_case < *best {
+            *best = res.worst_case;
+            pb.set_message(format!("'{}' ({})", res.word, res.worst_case));
+        }
+        pb.inc(1);
+    })
+}
 
-        for answ in answers {
-            let result = score(answ, guess);
-            let numrem = AnswerIterator::prune(answers, &histos, bguess, result).count();
+fn print_best_guess<'a>(answers: &[&str], guesses: &[&'a str], strategy: &dyn Strategy, prefs: &Preferences,
+                        progress: bool) -> Option<&'a str> {
+    // Hidden automatically when stderr is not a terminal.
+    let pb = if progress { ProgressBar::new(guesses.len() as u64) } else { ProgressBar::hidden() };
+    pb.set_style(ProgressStyle::with_template("{bar:40} {pos}/{len} guesses ({per_sec}) best so far: {msg}")
+        .unwrap());
+    let best = best_guess_progress(answers, guesses, strategy, prefs, &pb);
+    pb.finish_and_clear();
 
-            sco = max(sco, numrem);
-        }
+    match best {
+        Some(best) => print_guess(best.word, Some(best.worst_case)),
+        None => println!("No guesses to choose from"),
+    }
+    best.map(|b| b.word)
+}
 
-        (sco, guess)
-    }).collect::<Vec<_>>();
+// A guess from the decision tree.
+fn print_book_guess(guess: &str) {
+    if json_output() {
+        println!("{}", JsonObject::new("best_guess").str("guess", guess).str("source", "tree"));
+    } else {
+        println!("Best guess: '{}' from the decision tree", guess);
+    }
+}
 
-    let mut answers_hash = HashSet::<&str>::default();
-    answers_hash.extend(answers);
+// A table of guesses in rank order, marking those which may be the answer.
+fn print_ranked(ranked: &[BestGuess]) {
+    if json_output() {
+        let rows = ranked.iter().map(|g| JsonObject::new("guess").str("guess", g.word).num("worst_case", g.worst_case)
+            .float("expected", g.expected_remaining).float("entropy", g.entropy).bool("candidate", g.is_candidate)
+            .to_string());
+        println!("{}", JsonObject::new("ranked").raw("guesses", json_array(rows)));
+        return;
+    }
+    println!("  #  guess  worst  expected  entropy");
+    for (i, g) in ranked.iter().enumerate() {
+        println!("{:>3}  {}  {:>5}  {:>8.2}  {:>7.2}{}", i + 1, alphabet().decode(g.word), g.worst_case, g.expected_remaining, g.entropy,
+                 if g.is_candidate { "  candidate" } else { "" });
+    }
+}
 
-    for (sco, guess) in scored_guesses {
-        // Prioritize guesses that are possible answers.
-        let mut sco = sco * 2;
-        if answers_hash.contains(guess) {
-            sco -= 1;
-        }
+// A row per position, showing the letters still possible there; a determined position shows
+// its letter in capitals.
+fn print_matrix(answers: &[&str]) {
+    let alphabet = alphabet();
+    let letters = (b'a'..=b'z').chain(alphabet.extra_letters()).collect::<Vec<_>>();
+    println!("   {}", letters.iter().map(|&c| alphabet.letter(c)).collect::<String>());
+    for (i, mask) in position_letters(answers).iter().enumerate() {
+        let row = letters.iter().map(|&c| {
+            if mask & (1 << letter_index(c)) == 0 {
+                '.'
+            } else if mask.count_ones() == 1 {
+                alphabet.upper(c)
+            } else {
+                alphabet.letter(c)
+            }
+        }).collect::<String>();
+        println!("{}  {}", i + 1, row);
+    }
+}
 
-        if sco < bestsco {
-            bestsco = sco;
-            bestguess = Some(guess);
-        }
+// The tree to play from without a --tree file: the embedded one, which is for the builtin lists.
+#[cfg(feature = "embedded-tree")]
+fn default_tree<const N: usize>(builtin: bool) -> Option<&'static DecisionTree<N>> {
+    let tree: &'static dyn std::any::Any = wordle::embedded_tree();
+    if builtin { tree.downcast_ref() } else { None }
+}
+
+#[cfg(not(feature = "embedded-tree"))]
+fn default_tree<const N: usize>(_builtin: bool) -> Option<&'static DecisionTree<N>> {
+    None
+}
+
+// The decision tree's next guess, if the game has followed it so far.  Trees are built by the
+// default solver, so they only apply to minimax in normal mode with default preferences.
+fn book_guess<'t, const N: usize>(tree: Option<&'t DecisionTree<N>>, game: &Game<N>, strategy: &dyn Strategy, prefs: &Preferences)
+    -> Option<&'t str> {
+    if game.mode() != Mode::Normal || strategy.name() != Minimax.name() || *prefs != Preferences::default() {
+        return None;
     }
+    tree?.next(game.history().entries())
+}
+
+// Lines the solver plays are cut off after this many guesses, in case a strategy never finds
+// the answer.
+const MAX_ROUNDS: usize = 4 * MAX_GUESSES;
 
-    (bestguess, bestsco)
+// The rounds the solver takes to find 'answer', or max_rounds + 1 if it gives up, and its guesses.
+fn sim_one<'a, const N: usize>(guesses: &[&'a str], mode: Mode, strategy: &dyn Strategy, opening: &[&'a str], answer: &'a str,
+               max_rounds: usize) -> (usize, Vec<&'a str>) {
+    let mut game = Game::<N>::new(answer_list());
+    game.set_mode(mode);
+    let line = play_line(&game, guesses, strategy, &Preferences::default(), opening, answer, max_rounds);
+    (if line.last() == Some(&answer) { line.len() } else { max_rounds + 1 }, line)
 }
 
-fn print_best_guess<'a>(answers: &[&'a str], guesses: &[&'a str]) -> Option<&'a str> {
-    let (bestguess, bestsco) = best_guess(answers, guesses);
+// The columns of the CSV of simulated games: 'guesses' is how many the game took, and 'sequence'
+// what they were.
+const CSV_HEADER: &[&str] = &["strategy", "mode", "answer", "guesses", "opening", "sequence"];
 
-    println!("Best guess: '{}' with worst case {} candidates", bestguess.unwrap_or(""), (bestsco + 1) / 2);
-    bestguess
+fn csv_game(csv: &mut CsvWriter, strategy: &dyn Strategy, mode: Mode, answer: &str, rounds: usize, opening: &[&str],
+            line: &[&str]) {
+    csv.row(&[strategy.name(), &mode.to_string(), answer, &rounds.to_string(), &opening.join(" "), &line.join(" ")]);
 }
 
-fn sim_one<'a>(guesses: &[&'a str], answer: &'a str) -> usize {
-    let mut answers = ANSW_LIST.to_vec();
+// Let the bot play out a secret answer round by round, explaining its choices.
+fn challenge<const N: usize>(input: &mut Input, guesses: &[&str], opener: &str, mode: Mode, prefs: &Preferences, config: &Config) {
+    let answer = match input.read_secret("Secret answer: ") {
+        Some(line) => line.trim().to_string(),
+        None => return,
+    };
+    if !answer_list().contains(&answer.as_str()) {
+        println!("'{}' is not in the answer list; the bot would never find it.", answer);
+        return;
+    }
+
+    let mut game = Game::<N>::new(answer_list());
+    game.set_mode(mode);
     let mut nrounds = 0;
     loop {
+        let answers = game.candidates();
         let guess = if nrounds == 0 {
-            "arise"
+            println!("Opening with '{}': worst case {} candidates remain.", opener, worst_case(answers, opener));
+            opener
         } else {
-            let (guess, _) = best_guess(&answers, guesses);
-            guess.unwrap()
+            let best = best_guess(answers, &game.guess_pool(guesses), prefs).unwrap();
+            if answers.len() == 1 {
+                println!("Only '{}' remains.", best.word);
+            } else if best.is_candidate {
+                println!("Guessing candidate '{}': worst case {} candidates remain.", best.word, best.worst_case);
+            } else {
+                println!("Probing with non-candidate '{}' to split {} candidates: worst case {} remain.",
+                         best.word, answers.len(), best.worst_case);
+            }
+            best.word
         };
 
         nrounds += 1;
+        let result = score_n(&answer, guess);
+        let bguess = word_bytes_n(guess);
+        if config.accessible {
+            println!("Round {}: {}", nrounds, describe(&bguess, &result));
+        } else {
+            println!("Round {}: {} -> {}", nrounds, guess, fmt_result(&result));
+        }
         if answer == guess {
             break;
         }
-        let result = score(answer, guess);
 
-        let histos = answers.iter().map(|a| histo(a.as_bytes())).collect::<Vec<_>>();
-        answers = AnswerIterator::prune(&answers, &histos, parse_guess(guess).unwrap(), result).collect();
+        game.apply(bguess, result);
+        print_rem(game.candidates());
+
+        // Scripts get the whole game without pausing.
+        if input.is_interactive() {
+            match input.readline("[enter to continue, x to stop] ") {
+                Some(line) if line != "x" => {}
+                _ => return,
+            }
+        }
+    }
+
+    println!("Solved '{}' in {} rounds.", answer, nrounds);
+}
+
+// Grade each guess of a finished game by the worst case it risked, against the best possible.
+fn grade<const N: usize>(history: &[Observation<N>], guesses: &[&str]) {
+    let mut answers = answer_list().to_vec();
+    for (i, &(guess, result)) in history.iter().enumerate() {
+        let word = std::str::from_utf8(&guess).unwrap();
+        let best = match i {
+            0 => cached_opener(Cache::open_default().as_ref(), &answers, guesses, 1).unwrap().1,
+            _ => best_guess(&answers, guesses, &Preferences::default()).unwrap().worst_case,
+        };
+        let next = prune_n(&answers, guess, result);
+        println!("  {}. {}: {} -> {} candidates, worst case {} (best possible {})",
+                 i + 1, word, answers.len(), next.len(), worst_case(&answers, word), best);
+        answers = next;
+    }
+}
+
+// Play every answer of the shard, printing the summary, saving each game to 'log' if given, and
+// writing it to 'csv' as it finishes.
+fn fullsim<const N: usize>(guesses: &[&str], opening: &[&str], mode: Mode, shard: Shard, log: Option<&Path>, csv: Option<&Path>) {
+    let mut csv = match csv.map(|path| (path, CsvWriter::create(path, CSV_HEADER))) {
+        Some((path, Err(e))) => return println!("Can't write {}: {}", path.display(), e),
+        Some((path, Ok(csv))) => Some((path, csv)),
+        None => None,
+    };
+    // Every game scores the same pairs of words, so score them once.
+    let table = PatternTable::new(answer_list(), guesses);
+    let games = answer_list().iter().enumerate().filter(|&(k, _)| shard.contains(k)).map(|(_, &answ)| answ)
+        .collect::<Vec<_>>();
+    let mut report = SimReport::new();
+    report.play_all_with(&games, |answ| answ.to_string(), |answ| sim_one::<N>(guesses, mode, &table, opening, answ, MAX_ROUNDS),
+                         |answ, rounds, line| if let Some((_, csv)) = csv.as_mut() {
+                             csv_game(csv, &Minimax, mode, answ, rounds, opening, &line);
+                         });
+    report.print_summary(6, Some(MAX_GUESSES));
+    if let Some(path) = log {
+        if let Err(e) = report.save(path) {
+            println!("Can't save games to {}: {}", path.display(), e);
+        }
+    }
+    if let Some((path, csv)) = csv {
+        if let Err(e) = csv.finish() {
+            println!("Can't write {}: {}", path.display(), e);
+        }
+    }
+}
+
+// One strategy's games over every answer, and the seconds they took, writing each to 'csv' as it
+// finishes.  'table' plays minimax.
+fn bench_one<'a, const N: usize>(guesses: &[&'a str], table: &PatternTable, strategy: &dyn Strategy, mode: Mode,
+                 opening: &[&'a str], mut csv: Option<&mut CsvWriter>) -> (SimReport, f64) {
+    let player: &dyn Strategy = if strategy.name() == table.name() { table } else { strategy };
+    let start = Instant::now();
+    let mut report = SimReport::quiet();
+    report.play_all_with(answer_list(), |answ| answ.to_string(),
+                         |answ| sim_one::<N>(guesses, mode, player, opening, answ, MAX_ROUNDS),
+                         |answ, rounds, line| if let Some(csv) = csv.as_mut() {
+                             csv_game(csv, strategy, mode, answ, rounds, opening, &line);
+                         });
+    (report, start.elapsed().as_secs_f64())
+}
+
+// Play strategies over every answer, reporting their average guesses, distribution, failures
+// and time taken, and optionally each game as CSV.
+fn bench_cmd<'a, const N: usize>(args: &[String], guesses: &[&'a str], opener: &'a str, named_opener: bool) -> Result<()> {
+    const BENCH_USAGE: &str = "Usage: wordle bench [--strategy name|all] [--mode normal|semi|hard] \
+                               [--opening word[,word...]] [--csv file]";
+    let mut strategies = vec![&Minimax as &dyn Strategy];
+    let mut mode = Mode::Normal;
+    let mut opening = None;
+    let mut csv = None;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match (arg, args.next()) {
+            ("--strategy", Some("all")) => strategies = STRATEGIES.to_vec(),
+            ("--strategy", Some(name)) => strategies = vec![named_strategy(name)?],
+            ("--mode", Some(name)) => mode = Mode::parse(name).ok_or_else(|| anyhow!(BENCH_USAGE))?,
+            ("--opening", Some(words)) => opening = Some(parse_opening(words, guesses).map_err(|e| anyhow!("--opening: {}", e))?),
+            ("--csv", Some(path)) => csv = Some(PathBuf::from(path)),
+            _ => bail!(BENCH_USAGE),
+        }
     }
 
-    nrounds
+    // Every game scores the same pairs of words, so score them once for minimax.
+    let table = PatternTable::new(answer_list(), guesses);
+    let mut rows = match &csv {
+        Some(path) => Some(CsvWriter::create(path, CSV_HEADER).map_err(|e| anyhow!("{}: {}", path.display(), e))?),
+        None => None,
+    };
+    for strategy in strategies {
+        let opening = match &opening {
+            Some(words) => words.clone(),
+            None => vec![opener_for_strategy(guesses, opener, named_opener, strategy)?],
+        };
+        let (report, seconds) = bench_one::<N>(guesses, &table, strategy, mode, &opening, rows.as_mut());
+        let failures = report.failures(MAX_GUESSES).len();
+        if json_output() {
+            println!("{}", JsonObject::new("bench").str("strategy", strategy.name()).str("mode", &mode.to_string())
+                .strs("opening", &opening).num("games", report.len()).float("average", report.average())
+                .num("worst", report.worst()).num("failures", failures).float("seconds", seconds)
+                .raw("histogram", json_array(report.histogram(MAX_GUESSES).iter().map(|n| n.to_string()))));
+        } else {
+            println!("{} in {} mode, opening '{}': average {:.4} guesses, worst {}, {} failures in {} games, {:.1}s",
+                     strategy.name(), mode, opening.join(" "), report.average(), report.worst(), failures, report.len(),
+                     seconds);
+            for (i, n) in report.histogram(MAX_GUESSES).iter().enumerate() {
+                println!("  {} guesses: {}", i + 1, n);
+            }
+        }
+    }
+    if let (Some(path), Some(rows)) = (csv, rows) {
+        rows.finish().map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
 }
 
-fn fullsim<'a>(guesses: &[&'a str]) {
-    let mut worst = 0;
-    let mut total = 0;
-    let mut hist = HashMap::<_, usize>::default();
+// Play several strategies, by default all of them, over every answer, and tabulate them side by
+// side.
+fn compare_cmd<'a, const N: usize>(args: &[String], guesses: &[&'a str], opener: &'a str, named_opener: bool) -> Result<()> {
+    const COMPARE_USAGE: &str = "Usage: wordle compare [--strategies name,name...] [--mode normal|semi|hard]";
+    let mut strategies = STRATEGIES.to_vec();
+    let mut mode = Mode::Normal;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match (arg, args.next()) {
+            ("--strategies", Some(names)) => strategies = names.split(',').map(named_strategy).collect::<Result<_>>()?,
+            ("--mode", Some(name)) => mode = Mode::parse(name).ok_or_else(|| anyhow!(COMPARE_USAGE))?,
+            _ => bail!(COMPARE_USAGE),
+        }
+    }
 
-    for answ in ANSW_LIST {
-        let rounds = sim_one(guesses, answ);
-        println!("{}: {}", answ, rounds);
-        if rounds > worst {
-            worst = rounds;
+    let table = PatternTable::new(answer_list(), guesses);
+    if !json_output() {
+        println!("{:<10} {:<7} {:>8} {:>6} {:>7} {:>9}", "strategy", "opener", "average", "worst", "losses", "time");
+    }
+    for strategy in strategies {
+        let opener = opener_for_strategy(guesses, opener, named_opener, strategy)?;
+        let (report, seconds) = bench_one::<N>(guesses, &table, strategy, mode, &[opener], None);
+        let losses = report.failures(MAX_GUESSES).len();
+        if json_output() {
+            println!("{}", JsonObject::new("compare").str("strategy", strategy.name()).str("mode", &mode.to_string())
+                .str("opener", opener).float("average", report.average()).num("worst", report.worst())
+                .num("losses", losses).float("seconds", seconds));
+        } else {
+            println!("{:<10} {:<7} {:>8.4} {:>6} {:>7} {:>8.1}s", strategy.name(), opener, report.average(),
+                     report.worst(), losses, seconds);
         }
-        *hist.entry(rounds).or_default() += 1;
-        total += rounds;
     }
+    Ok(())
+}
 
-    println!("Average {} rounds, worst {} rounds", (total as f64) / (ANSW_LIST.len() as f64), worst);
-    for i in 1..=6 {
-        println!("  {} rounds: {}", i, hist.get(&i).unwrap_or(&0));
+// Replay a transcript of "guess result" lines, saying after each how the guess compares with
+// the strategy's choice: the candidates it left, and where the strategy ranks it.
+fn replay_cmd<const N: usize>(args: &[String], guesses: &[&str]) -> Result<()> {
+    const REPLAY_USAGE: &str = "Usage: wordle replay file [--strategy name] [--mode normal|semi|hard]";
+    let (mut path, mut strategy, mut mode) = (None, &Minimax as &dyn Strategy, Mode::Normal);
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--strategy" => strategy = named_strategy(args.next().ok_or_else(|| anyhow!(REPLAY_USAGE))?)?,
+            "--mode" => mode = args.next().and_then(Mode::parse).ok_or_else(|| anyhow!(REPLAY_USAGE))?,
+            _ if path.is_none() && !arg.starts_with('-') => path = Some(arg),
+            _ => bail!(REPLAY_USAGE),
+        }
     }
+    let path = path.ok_or_else(|| anyhow!(REPLAY_USAGE))?;
+    let text = fs::read_to_string(path).map_err(|e| anyhow!("{}: {}", path, e))?;
+
+    let alphabet = alphabet();
+    let prefs = Preferences::default();
+    let mut game = Game::<N>::new(answer_list());
+    game.set_mode(mode);
+    for (lineno, line) in text.lines().enumerate() {
+        let mut fields = line.split_whitespace();
+        let (word, result) = match (fields.next(), fields.next()) {
+            (None, _) => continue,
+            (Some(word), _) if word.starts_with('#') => continue,
+            (Some(word), Some(result)) => (alphabet.encode_text(word), result),
+            (Some(_), None) => bail!("{}:{}: no result", path, lineno + 1),
+        };
+        let guess = check_guess_n(&word, None).map_err(|e| anyhow!("{}:{}: '{}' {}", path, lineno + 1, alphabet.decode(&word), e))?;
+        let result = check_result_n(result).map_err(|e| anyhow!("{}:{}: '{}' {}", path, lineno + 1, result, e))?;
+        if let Some(conflict) = find_conflict(game.history().entries(), (guess, result)) {
+            bail!("{}:{}: that result {}, as {}", path, lineno + 1, WordleError::ContradictoryFeedback, conflict.reason);
+        }
+
+        let ranked = strategy.rank_guesses(game.candidates(), &game.guess_pool(guesses), &prefs);
+        let rank = ranked.iter().position(|g| g.word == word);
+        let worst = worst_case(game.candidates(), &word);
+        let before = game.candidates().len();
+        game.try_apply(guess, result).map_err(|e| anyhow!("{}:{}: that result {}", path, lineno + 1, e))?;
+        let turn = game.history().len();
+        let best = &ranked[0];
+        if json_output() {
+            println!("{}", JsonObject::new("replay").num("turn", turn).str("guess", &alphabet.decode(&word))
+                .str("result", &fmt_result(&result)).num("before", before).num("after", game.candidates().len())
+                .num("rank", rank.map_or(0, |r| r + 1)).num("of", ranked.len()).num("worst_case", worst)
+                .str("best", &alphabet.decode(best.word)).num("best_worst_case", best.worst_case));
+            continue;
+        }
+        let verdict = match rank {
+            Some(0) => format!("{}'s choice too", strategy.name()),
+            Some(r) => format!("{} ranks it {} of {}; '{}' has worst case {}", strategy.name(), fmt_count(r + 1),
+                               fmt_count(ranked.len()), alphabet.decode(best.word), best.worst_case),
+            None => format!("not a guess {} mode allows", mode),
+        };
+        println!("{}. {} {}: {} -> {} candidates, worst case {}; {}", turn, alphabet.decode(&word), fmt_result(&result),
+                 before, game.candidates().len(), worst, verdict);
+    }
+    if game.is_solved() && !json_output() {
+        println!("Solved in {} guesses", game.history().len());
+    }
+    Ok(())
 }
 
-fn main() -> Result<()> {
-    let mut answers = ANSW_LIST.to_vec();
-    let mut guesses = GUESS_LIST.to_vec();
-    guesses.reserve(ANSW_LIST.len());
-    guesses.extend_from_slice(ANSW_LIST);
+// Print the decision tree as an outline, as JSON with --json, or for Graphviz.
+fn tree_cmd<const N: usize>(args: &[String], tree: Option<&DecisionTree<N>>) -> Result<()> {
+    let tree = tree.ok_or_else(|| anyhow!("no decision tree; give one with --tree file, or build one with --build-tree file"))?;
+    match args {
+        [] if json_output() => println!("{}", tree.to_json()),
+        [] => print!("{}", tree.to_outline()),
+        [dot] if dot == "--dot" => print!("{}", tree.to_dot()),
+        _ => bail!("Usage: wordle tree [--dot]"),
+    }
+    Ok(())
+}
+
+// Rank fixed two word openings, played whatever the first result, by how they split the answers.
+fn pairs_cmd(args: &[String], guesses: &[&str]) -> Result<()> {
+    const PAIRS_USAGE: &str = "Usage: wordle pairs [--with word]... [--firsts n] [--top n]";
+    let table = PatternTable::new(answer_list(), guesses);
+    let (mut with, mut nfirsts, mut top) = (Vec::new(), 20, 10);
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match (arg, args.next()) {
+            ("--with", Some(word)) => with.push(table.guess_index(word).ok_or_else(|| anyhow!("'{}' is not in the word list", word))?),
+            ("--firsts", Some(n)) => nfirsts = n.parse().map_err(|_| anyhow!(PAIRS_USAGE))?,
+            ("--top", Some(n)) => top = n.parse().map_err(|_| anyhow!(PAIRS_USAGE))?,
+            _ => bail!(PAIRS_USAGE),
+        }
+    }
+
+    // Pairing every guess with every other takes hours, and good pairs mostly start with a good
+    // opener, so unless words were given, the best few openers are paired with every guess.
+    let firsts = if with.is_empty() {
+        table.rank_guesses(answer_list(), guesses, &Preferences::default()).iter().take(nfirsts)
+            .map(|g| table.guess_index(g.word).unwrap()).collect()
+    } else {
+        with
+    };
+    for (i, pair) in rank_pairs(&table, &firsts, top).iter().enumerate() {
+        if json_output() {
+            println!("{}", JsonObject::new("pair").str("first", pair.first).str("second", pair.second)
+                .num("worst_case", pair.worst_case).float("expected_remaining", pair.expected_remaining));
+        } else {
+            println!("{}. {} {}: worst case {} candidates, {:.2} on average", i + 1, pair.first, pair.second,
+                     pair.worst_case, pair.expected_remaining);
+        }
+    }
+    Ok(())
+}
+
+// Tournament entrants: a strategy, played from its opener in a mode.
+struct Player<'a> {
+    name: String,
+    opener: &'a str,
+    mode: Mode,
+    strategy: &'static dyn Strategy,
+}
+
+// Every strategy as an entrant playing in 'mode', rated by its name, and outside normal mode by
+// its name and the mode.
+fn players<'a>(guesses: &[&'a str], mode: Mode) -> Result<Vec<Player<'a>>> {
+    let cache = Cache::open_default();
+    STRATEGIES.iter().map(|&strategy| {
+        let opener = strategy_opener(cache.as_ref(), answer_list(), guesses, strategy)
+            .ok_or_else(|| anyhow!("no guesses to open with"))?.0;
+        let name = match mode {
+            Mode::Normal => strategy.name().to_string(),
+            _ => format!("{}-{}", strategy.name(), mode),
+        };
+        Ok(Player { name, opener, mode, strategy })
+    }).collect()
+}
+
+// Play every strategy in 'mode' against the first 'nanswers' answers and update their ratings,
+// scoring each answer as a game between every pair of entrants.
+fn tournament<const N: usize>(guesses: &[&str], nanswers: usize, mode: Mode) -> Result<()> {
+    let path = ResultsDb::default_path().ok_or_else(|| anyhow!("no results database; set WORDLE_RESULTS"))?;
+    let mut db = ResultsDb::load(&path)?;
+    let players = players(guesses, mode)?;
+    let before = players.iter().map(|p| db.rating(&p.name).elo).collect::<Vec<_>>();
+    let mut points = vec![0; players.len()];
+    let mut rounds = vec![0; players.len()];
+
+    // Every entrant sees the same answers, in the official order.
+    let schedule = &answer_list()[..nanswers.min(answer_list().len())];
+    for answ in schedule {
+        let game = players.iter().map(|p| sim_one::<N>(guesses, p.mode, p.strategy, &[p.opener], answ, MAX_ROUNDS).0)
+            .collect::<Vec<_>>();
+        println!("{}: {}", answ, game.iter().join(" "));
+
+        for (i, &r) in game.iter().enumerate() {
+            points[i] += league_points(r);
+            rounds[i] += r;
+        }
+        for (i, j) in (0..players.len()).tuple_combinations() {
+            let score_i = match league_points(game[i]).cmp(&league_points(game[j])) {
+                Ordering::Greater => 1.0,
+                Ordering::Equal => 0.5,
+                Ordering::Less => 0.0,
+            };
+            db.record_game(&players[i].name, &players[j].name, score_i);
+        }
+    }
+    db.save(&path)?;
+
+    println!("{:<16} {:>6} {:>7} {:>8}", "player", "points", "avg", "rating");
+    for i in (0..players.len()).sorted_by(|&a, &b| db.rating(&players[b].name).elo.total_cmp(&db.rating(&players[a].name).elo)) {
+        let elo = db.rating(&players[i].name).elo;
+        println!("{:<16} {:>6} {:>7.3} {:>8.1} ({:+.1})", players[i].name, points[i],
+                 rounds[i] as f64 / schedule.len() as f64, elo, elo - before[i]);
+    }
+    Ok(())
+}
+
+struct Args {
+    // Opening guess, or "auto" for the best one for the word lists.
+    first: Option<String>,
+    // Read plain commands from stdin, even on a terminal.
+    batch: bool,
+    // Print only the suggested guess for one-shot subcommands, and only the average of
+    // simulations; or print each game of them.
+    quiet: bool,
+    verbose: bool,
+    // Where to save each game of a simulation, and to write them as CSV.
+    log: Option<PathBuf>,
+    csv: Option<PathBuf>,
+    // The slice of the full simulation to run.
+    shard: Shard,
+    // Answer and extra guess lists to use instead of the builtin ones.
+    answers: Option<PathBuf>,
+    guesses: Option<PathBuf>,
+    // Language whose lists and alphabet to play with.
+    lang: &'static Language,
+    // Letters per word; other than 5, the answers must be given with -a.
+    length: usize,
+    // Decision tree to play from, and where to write one instead of running interactively.
+    tree: Option<PathBuf>,
+    build_tree: Option<PathBuf>,
+    // Print candidates, suggestions and simulation results as JSON.
+    json: bool,
+    // Search for the optimal play instead of running interactively.
+    optimal: bool,
+    // Seed for random answers, so a game can be replayed.
+    seed: Option<u64>,
+    // One-shot subcommand and its arguments, if any.
+    command: Vec<String>,
+}
+
+const USAGE: &str = "Usage: wordle [--batch] [-q|-v] [--log file] [--csv file] [--first word|auto] [--shard i/n] [-a answers] [-g guesses]
+              [--lang code] [--length n] [--tree file] [--build-tree file] [--optimal] [--json] [--seed n]
+              [solve [[--guess] guess [--result] result]...|
+               play [--answer word [--strategy name]] [--mode normal|semi|hard] [--share]|
+               duel [--strategy name] [--mode normal|semi|hard]|
+               daily [--date YYYY-MM-DD] [--nyt] [--play|--sim [--strategy name]] [--mode normal|semi|hard] [--share]|
+               bench [--strategy name|all] [--mode normal|semi|hard] [--opening word[,word...]] [--csv file]|
+               compare [--strategies name,name...] [--mode normal|semi|hard]|
+               pairs [--with word]... [--firsts n] [--top n]|
+               replay file [--strategy name] [--mode normal|semi|hard]|
+               tree [--dot]|
+               cache info|clear|rebuild|
+               merge file...|
+               league [submit player day guesses|X]]";
+
+fn parse_args() -> Result<Args> {
+    let mut res = Args { first: None, batch: false, quiet: false, verbose: false, log: None, csv: None, shard: Shard::ALL, answers: None, guesses: None,
+                         lang: &LANGUAGES[0], length: 5, tree: None, build_tree: None, json: false, optimal: false, seed: None, command: Vec::new() };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--first" => res.first = Some(args.next().ok_or_else(|| anyhow!("--first needs a word or 'auto'"))?),
+            "--batch" => res.batch = true,
+            "-q" | "--quiet" => res.quiet = true,
+            "-v" | "--verbose" => res.verbose = true,
+            "--log" => res.log = Some(args.next().ok_or_else(|| anyhow!("--log needs a file"))?.into()),
+            "--csv" => res.csv = Some(args.next().ok_or_else(|| anyhow!("--csv needs a file"))?.into()),
+            "--shard" => res.shard = args.next().as_deref().and_then(Shard::parse)
+                .ok_or_else(|| anyhow!("--shard needs i/n, with i < n"))?,
+            "-a" => res.answers = Some(args.next().ok_or_else(|| anyhow!("-a needs a word list file"))?.into()),
+            "-g" => res.guesses = Some(args.next().ok_or_else(|| anyhow!("-g needs a word list file"))?.into()),
+            "--lang" => res.lang = args.next().as_deref().and_then(Language::named).ok_or_else(|| anyhow!("--lang needs one of {}",
+                LANGUAGES.iter().map(|l| l.code).collect::<Vec<_>>().join(", ")))?,
+            "--length" => res.length = args.next().and_then(|s| s.parse().ok()).filter(|n| (4..=7).contains(n))
+                .ok_or_else(|| anyhow!("--length needs a number from 4 to 7"))?,
+            "--tree" => res.tree = Some(args.next().ok_or_else(|| anyhow!("--tree needs a file"))?.into()),
+            "--build-tree" => res.build_tree = Some(args.next().ok_or_else(|| anyhow!("--build-tree needs a file"))?.into()),
+            "--json" => res.json = true,
+            "--optimal" => res.optimal = true,
+            "--seed" => res.seed = Some(args.next().and_then(|s| s.parse().ok()).ok_or_else(|| anyhow!("--seed needs a number"))?),
+            "solve" | "play" | "duel" | "daily" | "bench" | "compare" | "pairs" | "replay" | "tree" | "cache" | "merge" | "league" => {
+                res.command.push(arg);
+                res.command.extend(args.by_ref());
+            }
+            _ => bail!(USAGE),
+        }
+    }
+    Ok(res)
+}
+
+fn fmt_age(age: std::time::Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=119 => format!("{} seconds", secs),
+        120..=7199 => format!("{} minutes", secs / 60),
+        7200..=172799 => format!("{} hours", secs / 3600),
+        _ => format!("{} days", secs / 86400),
+    }
+}
+
+// Report on, wipe, or regenerate the on-disk cache of computed artifacts.
+fn cache_cmd(args: &[String], guesses: &[&str]) -> Result<()> {
+    let cache = Cache::open_default().ok_or_else(|| anyhow!("no cache directory; set WORDLE_CACHE or HOME"))?;
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["info"] => {
+            let current = wordlist_checksum(answer_list(), guesses);
+            let entries = cache.entries()?;
+            println!("{}: {} artifacts, {} bytes", cache.dir().display(), entries.len(),
+                     entries.iter().map(|e| e.size).sum::<u64>());
+            for ent in entries {
+                let sum = match ent.checksum {
+                    Some(sum) if sum == current => format!("wordlist {:016x} (current)", sum),
+                    Some(sum) => format!("wordlist {:016x} (stale)", sum),
+                    None => "no wordlist checksum".to_string(),
+                };
+                let age = ent.age.map(fmt_age).unwrap_or_else(|| "unknown".to_string());
+                println!("  {}: {} bytes, {}, age {}", ent.name, ent.size, sum, age);
+            }
+        }
+        ["clear"] => println!("Removed {} artifacts", cache.clear()?),
+        ["rebuild"] => {
+            cache.clear()?;
+            cache.rebuild_openers(answer_list(), guesses)?;
+            println!("Rebuilt {} artifacts", cache.entries()?.len());
+        }
+        _ => bail!("Usage: wordle cache info|clear|rebuild"),
+    }
+    Ok(())
+}
+
+// Record a player's daily result, or print the group leaderboard.
+fn league_cmd(args: &[String]) -> Result<()> {
+    let path = ResultsDb::default_path().ok_or_else(|| anyhow!("no results database; set WORDLE_RESULTS"))?;
+    let mut db = ResultsDb::load(&path)?;
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["submit", player, day, rounds] => {
+            let day = day.parse().map_err(|_| anyhow!("bad day number '{}'", day))?;
+            let rounds = match rounds {
+                "X" | "x" => 7,
+                _ => rounds.parse().ok().filter(|r| (1..=6).contains(r))
+                    .ok_or_else(|| anyhow!("guesses must be 1-6, or X for a loss"))?,
+            };
+            if let Some(old) = db.submit_daily(player, day, rounds) {
+                println!("Replaced {}'s earlier result for day {} ({})", player, day, old);
+            }
+            db.save(&path)?;
+        }
+        [] => {
+            println!("{:<12} {:>6} {:>4} {:>7} {:>6} {:>4}", "player", "played", "won", "average", "streak", "best");
+            for s in db.leaderboard() {
+                println!("{:<12} {:>6} {:>4} {:>7.3} {:>6} {:>4}", s.player, s.played, s.won, s.average, s.streak, s.best_streak);
+            }
+        }
+        _ => bail!("Usage: wordle league [submit player day guesses|X]"),
+    }
+    Ok(())
+}
 
-    let mut prev_best_guess = Some("salet");
-    println!("Best guess: 'salet'");
+// The most candidates the 'bd' command searches ahead from; more take too long.
+const LOOKAHEAD_CANDIDATES: usize = 100;
 
-    let mut rl = rustyline::Editor::<()>::new();
-    // rl.load_history("path.txt").ok();
-    // rl.save_history("path.txt").ok();
+// Exit codes for one-shot subcommands.
+const EXIT_INVALID: i32 = 2;
+const EXIT_CONTRADICTORY: i32 = 3;
+const EXIT_SOLVED: i32 = 4;
 
+// The (guess, result) pairs of a solve command: alternating words, each optionally after a
+// --guess or --result flag naming it.
+fn solve_pairs(args: &[String]) -> Option<Vec<[&str; 2]>> {
+    let mut args = args.iter().map(String::as_str);
+    let mut res = Vec::new();
+    while let Some(arg) = args.next() {
+        let guess = if arg == "--guess" { args.next()? } else { arg };
+        let result = match args.next()? {
+            "--result" => args.next()?,
+            result => result,
+        };
+        if guess.starts_with("--") || result.starts_with("--") {
+            return None;
+        }
+        res.push([guess, result]);
+    }
+    Some(res)
+}
+
+fn named_strategy(name: &str) -> Result<&'static dyn Strategy> {
+    strategy_named(name)
+        .ok_or_else(|| anyhow!("no strategy '{}'; try {}", name, STRATEGIES.iter().map(|s| s.name()).join(", ")))
+}
+
+// Each strategy opens with its own best guess, unless one was named.
+fn opener_for_strategy<'a>(guesses: &[&'a str], opener: &'a str, named_opener: bool, strategy: &dyn Strategy)
+    -> Result<&'a str> {
+    if named_opener {
+        return Ok(opener);
+    }
+    Ok(strategy_opener(Cache::open_default().as_ref(), answer_list(), guesses, strategy)
+        .ok_or_else(|| anyhow!("no guesses to open with"))?.0)
+}
+
+// Read the user's next guess in 'game', checking it against the word list and the game's mode.
+// None if they give up.
+fn read_guess<const N: usize>(input: &mut Input, game: &Game<N>, guesses: &[&str]) -> Option<String> {
     loop {
+        let line = match input.readline(&format!("{}> ", game.history().len() + 1)) {
+            Some(line) if line.trim() != "x" => line.trim().to_lowercase(),
+            _ => return None,
+        };
+        if let Err(e) = check_guess_n::<N>(&line, Some(guesses)) {
+            println!("'{}' {}", alphabet().decode(&line), e);
+        } else if guess_pool(&[line.as_str()], game.mode(), &game.constraints()).is_empty() {
+            println!("'{}' ignores earlier feedback, which {} mode doesn't allow", alphabet().decode(&line), game.mode());
+        } else {
+            return Some(line);
+        }
+    }
+}
+
+// How 'guess' compares with the others 'game' allows, by the information each was expected to give.
+fn print_skill<const N: usize>(game: &Game<N>, guess: &str, guesses: &[&str]) {
+    let pool = guess_pool(guesses, game.mode(), &game.constraints());
+    if let Some(a) = analyze(guess, game.candidates(), &pool) {
+        println!("Skill {:.0}: {:.2} bits expected, where '{}' gives {:.2}; ranks {} of {}, above {:.0}% of guesses",
+                 a.skill(), a.guess.entropy, alphabet().decode(a.best.word), a.best.entropy, fmt_count(a.rank),
+                 fmt_count(a.of), a.percentile.floor());
+    }
+}
+
+// A finished game's skill and luck, a line per guess, and on average.
+fn print_luck_skill<const N: usize>(game: &Game<N>, guesses: &[&str]) {
+    let words = game.history().entries().iter().map(|(g, _)| String::from_utf8_lossy(g).into_owned()).collect::<Vec<_>>();
+    let rounds = words.iter().zip(game.history().entries()).map(|(w, &(_, r))| (w.as_str(), r)).collect::<Vec<_>>();
+    let graded = analyze_game(answer_list(), &rounds, guesses);
+    if graded.is_empty() {
+        return;
+    }
+    for (i, round) in graded.iter().enumerate() {
+        println!("  {}. {}: skill {:.0}, luck {:.0} ({} -> {} candidates, {:.1} expected)", i + 1,
+                 alphabet().decode(round.analysis.guess.word), round.analysis.skill(), round.luck, round.before,
+                 round.after, round.expected());
+    }
+    let n = graded.len() as f64;
+    println!("Skill {:.0}, luck {:.0} on average", graded.iter().map(|r| r.analysis.skill()).sum::<f64>() / n,
+             graded.iter().map(|r| r.luck).sum::<f64>() / n);
+}
+
+// Warn that 'guess' and 'result' contradict the earlier observations of 'game', saying how.
+fn warn_conflict<const N: usize>(game: &Game<N>, guess: [u8; N], result: [Color; N]) {
+    if let Some(conflict) = find_conflict(game.history().entries(), (guess, result)) {
+        println!("Warning: that result {}, as {}; u takes it back", WordleError::ContradictoryFeedback, conflict.reason);
+    }
+}
+
+// Once no candidate is left, guess which results were mistyped: the fewest whose change would
+// leave some answer.
+fn suggest_corrections<const N: usize>(game: &Game<N>) {
+    if !game.candidates().is_empty() {
+        return;
+    }
+    let alphabet = alphabet();
+    for correction in game.history().corrections(answer_list()).iter().take(3) {
+        let entries = correction.entries.iter().map(|&i| {
+            format!("#{} ({})", i + 1, alphabet.decode(std::str::from_utf8(&game.history().entries()[i].0).unwrap()))
+        }).collect::<Vec<_>>();
+        let answers = &correction.answers;
+        println!("Did you mistype the result for guess {}?  {}{} would fit",
+                 entries.join(" and "),
+                 answers.iter().take(5).map(|a| alphabet.decode(a)).collect::<Vec<_>>().join(", "),
+                 if answers.len() <= 5 { "" } else { ", ..." });
+    }
+}
+
+fn print_scored<const N: usize>(word: &str, result: &[Color; N], color: bool) {
+    if color {
+        println!("{}", fmt_colored(&word_bytes_n(word), result));
+    } else {
+        println!("{} {}", word, fmt_result(result));
+    }
+}
+
+fn print_rules(mode: Mode) {
+    println!("Guess the word in {} tries{}; x gives up.", MAX_GUESSES, if mode == Mode::Normal {
+        String::new()
+    } else {
+        format!(", in {} mode", mode)
+    });
+}
+
+// The user plays a game against a secret answer, within MAX_GUESSES guesses.  A result to share
+// is headed with the puzzle number, if it is a daily one.
+fn human_game<const N: usize>(guesses: &[&str], answer: &str, mode: Mode, share: bool, batch: bool, day: Option<u32>) -> Result<()> {
+    let color = Config::load_default()?.use_color();
+    let mut game = Game::<N>::new(answer_list());
+    game.set_mode(mode);
+    let mut input = Input::new(batch);
+    print_rules(mode);
+    while game.history().len() < MAX_GUESSES && !game.is_solved() {
+        let word = match read_guess(&mut input, &game, guesses) {
+            Some(word) => word,
+            None => break,
+        };
+        let result = score_n(answer, &word);
+        game.apply(word_bytes_n(&word), result);
+        print_scored(&word, &result, color);
+    }
+
+    if game.is_solved() {
+        println!("Solved in {}/{}", game.history().len(), MAX_GUESSES);
+    } else {
+        println!("The answer was '{}'", answer);
+    }
+    if share && !game.is_new() {
+        println!();
+        print!("{}", game.history().share_grid(day, mode == Mode::Hard));
+    }
+    Ok(())
+}
+
+// The user races the solver to the same secret answer, a guess each per round.  The solver's
+// guesses are shown only by their colors until the end.
+fn duel_cmd<'a, const N: usize>(args: &[String], guesses: &[&'a str], opener: &'a str, named_opener: bool, batch: bool,
+                rng: &mut Rng) -> Result<()> {
+    const DUEL_USAGE: &str = "Usage: wordle duel [--strategy name] [--mode normal|semi|hard]";
+    let mut strategy: &dyn Strategy = &Minimax;
+    let mut mode = Mode::Normal;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match (arg, args.next()) {
+            ("--strategy", Some(name)) => strategy = named_strategy(name)?,
+            ("--mode", Some(name)) => mode = Mode::parse(name).ok_or_else(|| anyhow!(DUEL_USAGE))?,
+            _ => bail!(DUEL_USAGE),
+        }
+    }
+    let opener = opener_for_strategy(guesses, opener, named_opener, strategy)?;
+    let color = Config::load_default()?.use_color();
+    let answer = *rng.choose(answer_list());
+    let mut duel = Duel::<N>::new(answer_list(), answer, mode);
+    let mut input = Input::new(batch);
+    print_rules(mode);
+    println!("The solver ({}) guesses after you each round.", strategy.name());
+    while !duel.is_over() {
+        if duel.player_to_move() {
+            let word = match read_guess(&mut input, duel.player(), guesses) {
+                Some(word) => word,
+                None => break,
+            };
+            let result = duel.play(&word);
+            print_scored(&word, &result, color);
+        } else if let Some((_, result)) = duel.solver_turn(strategy, guesses, opener) {
+            println!("Solver: {}{}", fmt_squares(&result), if duel.solver().is_solved() {
+                String::new()
+            } else {
+                format!("  {} candidates left", duel.solver().candidates().len())
+            });
+        }
+    }
+
+    match duel.outcome() {
+        Some(DuelOutcome::Player) => println!("You win, {} guesses to the solver's {}", duel.player().history().len(),
+                                              duel.solver().history().len()),
+        Some(DuelOutcome::Solver) => println!("The solver wins"),
+        Some(DuelOutcome::Tie) => println!("A tie, at {} guesses each", duel.player().history().len()),
+        None => {}
+    }
+    println!("The answer was '{}'; the solver played {}", duel.answer(),
+             duel.solver().history().entries().iter().map(|(g, _)| String::from_utf8_lossy(g)).join(", "));
+    Ok(())
+}
+
+// Let the solver play out a known answer, printing each guess and its feedback.
+fn play_cmd<'a, const N: usize>(args: &[String], guesses: &[&'a str], opener: &'a str, named_opener: bool, batch: bool,
+                rng: &mut Rng) -> Result<()> {
+    const PLAY_USAGE: &str = "Usage: wordle play [--answer word [--strategy name]] [--mode normal|semi|hard] [--share]";
+    let mut answer = None;
+    let mut strategy: &dyn Strategy = &Minimax;
+    let mut mode = Mode::Normal;
+    let mut share = false;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        if arg == "--share" {
+            share = true;
+            continue;
+        }
+        match (arg, args.next()) {
+            ("--answer", Some(word)) => answer = Some(word),
+            ("--strategy", Some(name)) => strategy = named_strategy(name)?,
+            ("--mode", Some(name)) => mode = Mode::parse(name).ok_or_else(|| anyhow!(PLAY_USAGE))?,
+            _ => bail!(PLAY_USAGE),
+        }
+    }
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let answer = *rng.choose(answer_list());
+            return human_game::<N>(guesses, answer, mode, share, batch, None);
+        }
+    };
+    if !answer_list().contains(&answer) {
+        bail!("'{}' is not in the answer list", answer);
+    }
+    let opener = opener_for_strategy(guesses, opener, named_opener, strategy)?;
+    solver_game::<N>(guesses, strategy, opener, mode, answer, share, None)
+}
+
+// The solver plays out 'answer', printing each guess and its feedback.
+fn solver_game<const N: usize>(guesses: &[&str], strategy: &dyn Strategy, opener: &str, mode: Mode, answer: &str, share: bool,
+               day: Option<u32>) -> Result<()> {
+    let mut game = Game::<N>::new(answer_list());
+    game.set_mode(mode);
+    let line = play_line(&game, guesses, strategy, &Preferences::default(), &[opener], answer, MAX_ROUNDS);
+    if json_output() {
+        println!("{}", JsonObject::new("play").str("answer", answer).str("strategy", strategy.name())
+            .str("mode", &mode.to_string()).strs("guesses", &line).num("rounds", line.len()));
+        return Ok(());
+    }
+    let color = Config::load_default()?.use_color();
+    for (i, word) in line.iter().enumerate() {
+        let (guess, result) = (word_bytes_n(word), score_n(answer, word));
+        game.apply(guess, result);
+        let shown = if color { fmt_colored(&guess, &result) } else { format!("{} {}", word, fmt_result(&result)) };
+        if game.is_solved() {
+            println!("{}. {}", i + 1, shown);
+        } else {
+            println!("{}. {}  {} candidates left", i + 1, shown, game.candidates().len());
+        }
+    }
+    println!("Solved '{}' in {} rounds with {} in {} mode", answer, line.len(), strategy.name(), mode);
+    if share {
+        println!();
+        print!("{}", game.history().share_grid(day, mode == Mode::Hard));
+    }
+    Ok(())
+}
+
+// The number and answer of the New York Times' puzzle for 'date'.
+#[cfg(feature = "nyt")]
+fn nyt_daily(date: Date) -> Result<(u32, String)> {
+    let puzzle = wordle::nyt_puzzle(date, Cache::open_default().as_ref())?;
+    Ok((puzzle.puzzle, puzzle.solution))
+}
+
+#[cfg(not(feature = "nyt"))]
+fn nyt_daily(_date: Date) -> Result<(u32, String)> {
+    bail!("--nyt needs wordle built with the nyt feature")
+}
+
+// A day's puzzle, by default today's: its number, or with --play the user plays it, or with
+// --sim the solver does.  With --nyt, the puzzle is the New York Times', rather than the
+// original list's.
+fn daily_cmd<'a, const N: usize>(args: &[String], guesses: &[&'a str], opener: &'a str, named_opener: bool, batch: bool)
+    -> Result<()> {
+    const DAILY_USAGE: &str = "Usage: wordle daily [--date YYYY-MM-DD] [--nyt] [--play|--sim [--strategy name]] \
+                               [--mode normal|semi|hard] [--share]";
+    let mut date = Date::today();
+    let (mut play, mut sim, mut share, mut nyt) = (false, false, false, false);
+    let mut strategy: &dyn Strategy = &Minimax;
+    let mut mode = Mode::Normal;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--play" => play = true,
+            "--sim" => sim = true,
+            "--share" => share = true,
+            "--nyt" => nyt = true,
+            "--date" => date = args.next().and_then(Date::parse).ok_or_else(|| anyhow!("--date needs YYYY-MM-DD"))?,
+            "--strategy" => {
+                let name = args.next().ok_or_else(|| anyhow!(DAILY_USAGE))?;
+                strategy = named_strategy(name)?;
+            }
+            "--mode" => mode = args.next().and_then(Mode::parse).ok_or_else(|| anyhow!(DAILY_USAGE))?,
+            _ => bail!(DAILY_USAGE),
+        }
+    }
+    if play && sim {
+        bail!(DAILY_USAGE);
+    }
+    if N != 5 {
+        bail!("daily puzzles are 5 letter words");
+    }
+    let (puzzle, answer) = if nyt {
+        nyt_daily(date)?
+    } else {
+        if answer_list() != ANSW_LIST {
+            bail!("daily puzzles need the builtin answer list, which is in their order");
+        }
+        let puzzle = date.puzzle().ok_or_else(|| anyhow!("{} is before the first Wordle, on {}", date, Date::FIRST_PUZZLE))?;
+        (puzzle, daily_answer(puzzle).to_string())
+    };
+    let answer = answer.as_str();
+
+    if play {
+        return human_game::<N>(guesses, answer, mode, share, batch, Some(puzzle));
+    }
+    if sim {
+        if !answer_list().contains(&answer) {
+            bail!("the answer to Wordle {} is not in the answer list", fmt_count(puzzle as usize));
+        }
+        println!("Wordle {}, {}", fmt_count(puzzle as usize), date);
+        let opener = opener_for_strategy(guesses, opener, named_opener, strategy)?;
+        return solver_game::<N>(guesses, strategy, opener, mode, answer, share, Some(puzzle));
+    }
+    if json_output() {
+        println!("{}", JsonObject::new("daily").num("puzzle", puzzle as usize).str("date", &date.to_string()));
+    } else {
+        println!("Wordle {} is the puzzle for {}; daily --play to play it, or --sim to watch the solver",
+                 fmt_count(puzzle as usize), date);
+    }
+    Ok(())
+}
+
+// Suggest the next guess after the given (guess, result) pairs, returning the exit code.
+fn solve<const N: usize>(args: &[String], guesses: &[&str], opener: &str, prefs: &Preferences, quiet: bool) -> i32 {
+    let pairs = match solve_pairs(args) {
+        Some(pairs) => pairs,
+        None => {
+            eprintln!("Usage: wordle solve [[--guess] guess [--result] result]...");
+            return EXIT_INVALID;
+        }
+    };
+
+    let mut answers = answer_list().to_vec();
+    let mut known = Constraints::<N>::new();
+    for pair in pairs {
+        let guess = match check_guess_n(pair[0], None) {
+            Ok(guess) => guess,
+            Err(e) => {
+                eprintln!("'{}' {}", pair[0], e);
+                return EXIT_INVALID;
+            }
+        };
+        let result = match check_result_n(pair[1]) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("'{}' {}", pair[1], e);
+                return EXIT_INVALID;
+            }
+        };
+        if result == [Color::GREEN; N] {
+            println!("{}", pair[0]);
+            return EXIT_SOLVED;
+        }
+        answers = prune_n(&answers, guess, result);
+        known.add(guess, result);
+        if answers.is_empty() {
+            eprintln!("'{} {}' {}", pair[0], pair[1], WordleError::ContradictoryFeedback);
+            return EXIT_CONTRADICTORY;
+        }
+    }
+
+    let guess = if answers.len() == answer_list().len() {
+        opener
+    } else {
+        best_guess(&answers, &guess_pool(guesses, Mode::Normal, &known), prefs).unwrap().word
+    };
+    if quiet {
+        println!("{}", guess);
+    } else {
         print_rem(&answers);
+        print_guess(guess, None);
+    }
+    0
+}
+
+// The REPL's commands, for the line editor to complete.
+const REPL_COMMANDS: &[&str] = &["g", "gb", "r", "u", "redo", "share", "show", "k", "color", "bf", "m", "pool", "hm", "c",
+                                 "p", "lines", "matrix", "save", "load", "b", "bn", "bd", "fs", "pref", "strategy", "t", "tournament",
+                                 "cb", "skill", "tree", "x"];
 
-        let line = rl.readline("> ");
-        let tline = if let Ok(tline) = line {
-            if tline == "x" {
-                break;
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    // A game is built for its word length, so each length is run as its own.
+    match args.length {
+        4 => run::<4>(args),
+        5 => run::<5>(args),
+        6 => run::<6>(args),
+        7 => run::<7>(args),
+        _ => unreachable!("parse_args() checks the length"),
+    }
+}
+
+fn run<const N: usize>(args: Args) -> Result<()> {
+    set_json_output(args.json);
+    set_verbosity(match (args.quiet, args.verbose) {
+        (true, _) => Verbosity::Quiet,
+        (false, true) => Verbosity::Verbose,
+        (false, false) => Verbosity::Normal,
+    });
+    let mut rng = Rng::new(args.seed.unwrap_or_else(Rng::random_seed));
+    let config = Config::load_default()?;
+    set_alphabet(args.lang.alphabet);
+    let (answ_list, guess_list) = args.lang.lists(args.answers.as_deref(), args.guesses.as_deref(), N)?;
+    ANSWERS.set(answ_list).unwrap();
+    let (guesses, stats) = combine_lists(guess_list, answ_list);
+    let builtin = is_builtin(answ_list, &guesses);
+    if stats != PoolStats::default() {
+        println!("Guess pool: {} words, after dropping {} duplicates and {} answers already among the guesses",
+                 guesses.len(), stats.duplicates, stats.overlap);
+    }
+
+    let (mut opener, mut opener_worst) = choose_opener(args.first.as_deref(), answer_list(), &guesses, 1)
+        .map_err(|e| anyhow!("--first: {}", e))?;
+
+    if let Some(path) = &args.build_tree {
+        let tree = DecisionTree::<N>::build(answ_list, &guesses, opener);
+        tree.save(path)?;
+        println!("Wrote {} nodes, worst case {} guesses, to {}", tree.len(), tree.depth(), path.display());
+        return Ok(());
+    }
+    if args.optimal {
+        // Only an opener the user named is kept; otherwise every opener is searched.
+        let first = args.first.as_deref().filter(|&f| f != "auto").map(|_| opener);
+        let table = PatternTable::new(answ_list, &guesses);
+        match OptimalSolver::new(&table).solve(first) {
+            Some(play) => println!("Optimal play opens with '{}': average {:.4} guesses ({} over {} answers), worst {}",
+                                   play.opener, play.average(), play.total, play.answers, play.worst),
+            None => println!("No play solves every answer within {} guesses", MAX_GUESSES),
+        }
+        return Ok(());
+    }
+    let loaded = args.tree.as_deref().map(DecisionTree::<N>::load).transpose()?;
+    let tree = loaded.as_ref().or_else(|| default_tree(builtin));
+
+    match args.command.first().map(String::as_str) {
+        Some("cache") => return cache_cmd(&args.command[1..], &guesses),
+        Some("league") => return league_cmd(&args.command[1..]),
+        Some("play") => {
+            let named = args.first.as_deref().is_some_and(|f| f != "auto");
+            return play_cmd::<N>(&args.command[1..], &guesses, opener, named, args.batch, &mut rng);
+        }
+        Some("bench") => {
+            let named = args.first.as_deref().is_some_and(|f| f != "auto");
+            return bench_cmd::<N>(&args.command[1..], &guesses, opener, named);
+        }
+        Some("compare") => {
+            let named = args.first.as_deref().is_some_and(|f| f != "auto");
+            return compare_cmd::<N>(&args.command[1..], &guesses, opener, named);
+        }
+        Some("pairs") => return pairs_cmd(&args.command[1..], &guesses),
+        Some("replay") => return replay_cmd::<N>(&args.command[1..], &guesses),
+        Some("tree") => return tree_cmd(&args.command[1..], tree),
+        Some("daily") => {
+            let named = args.first.as_deref().is_some_and(|f| f != "auto");
+            return daily_cmd::<N>(&args.command[1..], &guesses, opener, named, args.batch);
+        }
+        Some("duel") => {
+            let named = args.first.as_deref().is_some_and(|f| f != "auto");
+            return duel_cmd::<N>(&args.command[1..], &guesses, opener, named, args.batch, &mut rng);
+        }
+        Some("merge") => {
+            SimReport::load_shards(&args.command[1..])?.print_summary(6, Some(MAX_GUESSES));
+            return Ok(());
+        }
+        _ => {}
+    }
+    if !args.command.is_empty() {
+        std::process::exit(solve::<N>(&args.command[1..], &guesses, opener, &Preferences::default(), args.quiet));
+    }
+
+    let mut prev_best_guess = Some(opener);
+    let mut teaching = false;
+    let mut prefs = Preferences::default();
+    let mut strategy: &dyn Strategy = &Minimax;
+    // Blindfold mode offers no solving help: only bookkeeping and grading.
+    let mut blindfold = false;
+    let mut color = config.use_color();
+    let mut game = Game::<N>::new(answer_list());
+    // Late suggestions needn't search guesses which can't tell the candidates apart.
+    game.set_pool(Pool::Auto);
+    // The best guess before each observation made, for undo and redo to restore.
+    let mut best_before = Vec::new();
+    print_guess(opener, None);
+
+    let mut input = Input::repl(args.batch, "wordle", REPL_COMMANDS);
+    input.set_guesses(&guesses);
+    // Grading each guess searches every guess again, so scripts go without unless they ask.
+    let mut skill = input.is_interactive();
+
+    // Only interactive games are saved; scripts can simply be rerun.
+    let session = if input.is_interactive() { Session::open_default() } else { None };
+    if let Some(saved) = session.as_ref().and_then(|s| s.load::<N>().ok().flatten()) {
+        let moves = saved.entries().iter().map(|(g, r)| format!("{} {}", String::from_utf8_lossy(g), fmt_result(r)))
+            .collect::<Vec<_>>();
+        println!("A previous game was interrupted after: {}", moves.join(", "));
+        if input.readline("Resume it? [y/n] ").as_deref() == Some("y") {
+            game = Game::resume(answer_list(), saved);
+            game.set_pool(Pool::Auto);
+            prev_best_guess = print_best_guess(game.candidates(), &game.guess_pool(&guesses), strategy, &prefs, true);
+        }
+    }
+
+    loop {
+        if let Some(session) = &session {
+            session.save(game.history()).ok();
+        }
+        // Blindfold, completion mustn't give the candidates away.
+        input.set_candidates(if blindfold { &[] } else { game.candidates() });
+        if !blindfold {
+            let answers = game.candidates();
+            print_rem(answers);
+            if (2..=4).contains(&answers.len()) {
+                if let Some(guess) = discriminator(answers, &game.guess_pool(&guesses)) {
+                    println!("Play '{}' to separate {}", guess, answers.join("/"));
+                }
             }
-            rl.add_history_entry(&tline);
-            tline
-        } else {
-            break;
+        }
+
+        // File names are taken from the line as typed; the rest is encoded.
+        let raw = match input.readline_raw("> ") {
+            Some(raw) if raw != "x" => raw,
+            _ => break,
         };
+        let tline = alphabet().encode_text(&raw);
 
         let mut words = tline.split(' ');
         let cmd = words.next().unwrap();
         match cmd {
             // guess prev best word result
             "gb" => {
+                if blindfold {
+                    println!("No best guesses in blindfold mode");
+                    continue;
+                }
                 let result = words.next();
-                if let Some(res) = maybe_prune(&answers, prev_best_guess, result) {
-                    answers = res;
-                    prev_best_guess = print_best_guess(&answers, &guesses);
+                if let Some((guess, result)) = parse_observation_n(prev_best_guess, result) {
+                    if config.accessible {
+                        println!("Recorded: {}", describe(&guess, &result));
+                    }
+                    if teaching {
+                        explain(game.candidates(), guess, result).iter().for_each(|l| println!("{}", l));
+                    }
+                    if color {
+                        println!("{}", fmt_colored(&guess, &result));
+                    }
+                    warn_conflict(&game, guess, result);
+                    best_before.truncate(game.history().len());
+                    best_before.push(prev_best_guess);
+                    game.apply(guess, result);
+                    suggest_corrections(&game);
+                    if game.is_solved() {
+                        println!("Solved in {} guesses", game.history().len());
+                        if skill {
+                            print_luck_skill(&game, &guesses);
+                        }
+                    }
+                    prev_best_guess = match book_guess(tree, &game, strategy, &prefs) {
+                        Some(guess) => {
+                            print_book_guess(guess);
+                            Some(guess)
+                        }
+                        None => print_best_guess(game.candidates(), &game.guess_pool(&guesses), strategy, &prefs,
+                                                 input.is_interactive()),
+                    };
                     continue;
                 }
                 println!("Usage: gb result");
-                println!("       result is 0 for grey, 1 for yellow, 2 for green");
+                println!("       result is 0 for grey, 1 for yellow, 2 for green, or b/y/g, or pasted squares");
             }
             // guess word result
             "g" => {
                 let guess = words.next();
                 let result = words.next();
-                if let Some(res) = maybe_prune(&answers, guess, result) {
-                    answers = res;
+                if let Some(Err(e)) = guess.map(|g| check_guess_n::<N>(g, if blindfold { Some(&guesses) } else { None })) {
+                    println!("'{}' {}", alphabet().decode(guess.unwrap()), e);
+                    continue;
+                }
+                if let Some((bguess, result)) = parse_observation_n(guess, result) {
+                    if blindfold {
+                        if let Some(conflict) = find_conflict(game.history().entries(), (bguess, result)) {
+                            println!("That result {}, as {}; not recorded", WordleError::ContradictoryFeedback,
+                                     conflict.reason);
+                            continue;
+                        }
+                        if prune_n(game.candidates(), bguess, result).is_empty() {
+                            println!("That result {}; not recorded", WordleError::ContradictoryFeedback);
+                            continue;
+                        }
+                    } else {
+                        if teaching {
+                            explain(game.candidates(), bguess, result).iter().for_each(|l| println!("{}", l));
+                        }
+                        if skill {
+                            print_skill(&game, guess.unwrap(), &guesses);
+                        }
+                        warn_conflict(&game, bguess, result);
+                    }
+                    if config.accessible {
+                        println!("Recorded: {}", describe(&bguess, &result));
+                    }
+                    if color {
+                        println!("{}", fmt_colored(&bguess, &result));
+                    }
+                    best_before.truncate(game.history().len());
+                    best_before.push(prev_best_guess);
+                    game.apply(bguess, result);
+                    suggest_corrections(&game);
+                    if game.is_solved() {
+                        println!("Solved in {} guesses", game.history().len());
+                        if blindfold {
+                            grade(game.history().entries(), &guesses);
+                        } else if skill {
+                            print_luck_skill(&game, &guesses);
+                        }
+                    }
                     continue;
                 }
                 println!("Usage: g guess result");
-                println!("       result is 0 for grey, 1 for yellow, 2 for green");
+                println!("       result is 0 for grey, 1 for yellow, 2 for green, or b/y/g, or pasted squares");
             }
             // reset
             "r" => {
-                answers = ANSW_LIST.to_vec();
-                prev_best_guess = Some("salet");
+                game.reset();
+                prev_best_guess = Some(opener);
+                best_before.clear();
+            }
+            // undo the last guess
+            "u" => {
+                match game.undo() {
+                    Some((guess, result)) => {
+                        // A resumed game doesn't know its earlier best guesses.
+                        prev_best_guess = if game.is_new() {
+                            Some(opener)
+                        } else {
+                            best_before.get(game.history().len()).copied().flatten()
+                        };
+                        println!("Undid {} {}", String::from_utf8_lossy(&guess), fmt_result(&result));
+                    }
+                    None => println!("Nothing to undo"),
+                }
+            }
+            // make again the last guess undone
+            "redo" => {
+                match game.redo() {
+                    Some((guess, result)) => {
+                        prev_best_guess = best_before.get(game.history().len()).copied().flatten();
+                        println!("Redid {} {}", String::from_utf8_lossy(&guess), fmt_result(&result));
+                    }
+                    None => println!("Nothing to redo"),
+                }
+            }
+            // the game as the emoji grid Wordle shares, optionally numbered
+            "share" => {
+                let day = match words.next().map(str::parse) {
+                    None => None,
+                    Some(Ok(day)) => Some(day),
+                    Some(Err(_)) => {
+                        println!("Usage: share [day]");
+                        continue;
+                    }
+                };
+                print!("{}", game.history().share_grid(day, game.mode() == Mode::Hard));
+            }
+            // the guesses so far, as the game's board
+            "show" => {
+                game.history().board(color).iter().for_each(|row| println!("{}", row));
+            }
+            // save the game to a file, as JSON
+            "save" => {
+                let path = match raw_argument(&raw) {
+                    Some(path) => path,
+                    None => {
+                        println!("Usage: save file");
+                        continue;
+                    }
+                };
+                match fs::write(path, game_json(&game) + "\n") {
+                    Ok(()) => println!("Saved to {}", path),
+                    Err(e) => println!("Can't save to {}: {}", path, e),
+                }
+            }
+            // load a game saved with save, in place of this one
+            "load" => {
+                let path = match raw_argument(&raw) {
+                    Some(path) => path,
+                    None => {
+                        println!("Usage: load file");
+                        continue;
+                    }
+                };
+                let loaded = fs::read_to_string(path).map_err(|e| e.to_string())
+                    .and_then(|text| parse_game_json(&text, answer_list()));
+                match loaded {
+                    Ok(loaded) => {
+                        game = loaded;
+                        best_before.clear();
+                        println!("Loaded {} guesses from {}", game.history().len(), path);
+                        prev_best_guess = if game.is_new() {
+                            Some(opener)
+                        } else {
+                            print_best_guess(game.candidates(), &game.guess_pool(&guesses), strategy, &prefs, true)
+                        };
+                    }
+                    Err(e) => println!("Can't load {}: {}", path, e),
+                }
+            }
+            // every letter, colored by the best it has shown, as on the game's keyboard
+            "k" => {
+                game.history().keyboard_rows(color).iter().for_each(|row| println!("{}", row));
+            }
+            // colored tiles for recorded guesses
+            "color" => {
+                match words.next() {
+                    Some("on") => color = true,
+                    Some("off") => color = false,
+                    None => {}
+                    Some(_) => {
+                        println!("Usage: color [on|off]");
+                        continue;
+                    }
+                }
+                println!("Color: {}", if color { "on" } else { "off" });
+            }
+            // blindfold mode: track the game, but offer no solving help
+            "bf" => {
+                match words.next() {
+                    Some("on") => blindfold = true,
+                    Some("off") => blindfold = false,
+                    None => {}
+                    Some(_) => {
+                        println!("Usage: bf [on|off]");
+                        continue;
+                    }
+                }
+                println!("Blindfold: {}", if blindfold { "on" } else { "off" });
+            }
+            // mode: normal, semi (never reuse letters proven absent), or hard (keep greens and
+            // reuse yellows)
+            "m" => {
+                if let Some(arg) = words.next() {
+                    if let Some(m) = Mode::parse(arg) {
+                        game.set_mode(m);
+                    } else {
+                        println!("Usage: m [normal|semi|hard]");
+                        continue;
+                    }
+                }
+                println!("Mode: {}", game.mode());
+            }
+            // pool: which guesses suggestions are searched from, of those the mode allows: all,
+            // candidates, useful (those telling some candidates apart), or auto (useful, then
+            // candidates once two or fewer are left)
+            "pool" => {
+                if let Some(arg) = words.next() {
+                    if let Some(pool) = Pool::parse(arg) {
+                        game.set_pool(pool);
+                    } else {
+                        println!("Usage: pool [all|candidates|useful|auto]");
+                        continue;
+                    }
+                }
+                println!("Pool: {}", game.pool());
+            }
+            // hard mode on or off, as 'm hard' and 'm normal'
+            "hm" => {
+                match words.next() {
+                    Some("on") => game.set_mode(Mode::Hard),
+                    Some("off") => game.set_mode(Mode::Normal),
+                    None => {}
+                    Some(_) => {
+                        println!("Usage: hm [on|off]");
+                        continue;
+                    }
+                }
+                println!("Hard mode: {}", if game.mode() == Mode::Hard { "on" } else { "off" });
+            }
+            // what the guesses so far have shown, by position and letter
+            "c" => {
+                let summary = game.constraints().summary();
+                if json_output() {
+                    println!("{}", JsonObject::new("constraints").strs("summary", &summary));
+                    continue;
+                }
+                summary.iter().for_each(|line| println!("{}", line));
             }
             // print
             "p" => {
-                println!("{}", answers.join(", "));
+                if blindfold {
+                    println!("No candidate list in blindfold mode");
+                    continue;
+                }
+                if json_output() {
+                    // The JSON candidates are never abbreviated.
+                    print_rem(game.candidates());
+                } else {
+                    println!("{}", game.candidates().iter().map(|c| alphabet().decode(c)).collect::<Vec<_>>().join(", "));
+                }
+            }
+            // how the solver would play out each remaining candidate
+            "lines" => {
+                const MAX_LINES: usize = 100;
+                if blindfold {
+                    println!("No solving help in blindfold mode");
+                    continue;
+                }
+                if game.candidates().len() > MAX_LINES {
+                    println!("{} candidates remain; lines shows at most {}", game.candidates().len(), MAX_LINES);
+                    continue;
+                }
+                let first = if game.is_new() { Some(opener) } else { None };
+                for answ in game.candidates() {
+                    let line = play_line(&game, &guesses, strategy, &prefs, first.as_slice(), answ, MAX_ROUNDS);
+                    println!("{}: {} ({} rounds)", answ, line.join(" "), game.history().len() + line.len());
+                }
+            }
+            // which letters the candidates allow in each position
+            "matrix" => {
+                if blindfold {
+                    println!("No candidate list in blindfold mode");
+                    continue;
+                }
+                print_matrix(game.candidates());
             }
             // best guess
             "b" => {
-                if answers.len() == ANSW_LIST.len() {
-                    // Precomputed, takes a long time.
-                    println!("Best guess: 'arise' with worst case 168 candidates");
+                if blindfold {
+                    println!("No best guesses in blindfold mode");
+                    continue;
+                }
+                let answers = game.candidates();
+                if answers.len() == answer_list().len() {
+                    print_guess(opener, Some(opener_worst));
                     continue;
                 }
 
-                print_best_guess(&answers, &guesses);
+                if let Some(guess) = book_guess(tree, &game, strategy, &prefs) {
+                    print_book_guess(guess);
+                    continue;
+                }
+                let pool = game.guess_pool(&guesses);
+                // Quick searches aren't worth a remark.
+                let estimate = estimate_seconds(answers, &pool);
+                if estimate >= 1.0 {
+                    let threads = rayon::current_num_threads();
+                    println!("Evaluating {} guesses x {} candidates, about {:.0}s on {} thread{}",
+                             fmt_count(pool.len()), fmt_count(answers.len()), estimate, threads,
+                             if threads == 1 { "" } else { "s" });
+                    let over = config.confirm_seconds.is_some_and(|limit| estimate > limit as f64);
+                    if over && input.is_interactive() && input.readline("Continue? [y/n] ").as_deref() != Some("y") {
+                        continue;
+                    }
+                }
+                print_best_guess(answers, &pool, strategy, &prefs, input.is_interactive());
+            }
+            // the n best guesses, to choose among near-equal ones
+            "bn" => {
+                if blindfold {
+                    println!("No best guesses in blindfold mode");
+                    continue;
+                }
+                let n = match words.next().map(str::parse) {
+                    None => 10,
+                    Some(Ok(n)) if n >= 1 => n,
+                    _ => {
+                        println!("Usage: bn [count]");
+                        continue;
+                    }
+                };
+                let ranked = strategy.best_guesses(game.candidates(), &game.guess_pool(&guesses), &prefs, n);
+                print_ranked(&ranked);
+            }
+            // best guess looking n guesses ahead, for few candidates
+            "bd" => {
+                if blindfold {
+                    println!("No best guesses in blindfold mode");
+                    continue;
+                }
+                let depth = match words.next().map(str::parse) {
+                    None => 2,
+                    Some(Ok(depth)) if depth >= 1 => depth,
+                    _ => {
+                        println!("Usage: bd [depth]");
+                        continue;
+                    }
+                };
+                if game.candidates().len() > LOOKAHEAD_CANDIDATES {
+                    println!("Too many candidates to look ahead; at most {} can be searched", LOOKAHEAD_CANDIDATES);
+                    continue;
+                }
+                match best_guess_depth(game.candidates(), &game.guess_pool(&guesses), depth) {
+                    (Some(guess), left) if json_output() => {
+                        println!("{}", JsonObject::new("best_guess").str("guess", guess).num("depth", depth)
+                            .num("worst_case", left));
+                    }
+                    (Some(guess), left) => println!("Best guess: '{}' leaving at most {} candidates after {} guesses",
+                                                    guess, left, depth),
+                    (None, _) => println!("No guesses to choose from"),
+                }
             }
-            // run full simulation of all words
+            // run full simulation of all words, from the opener or the given opening words
             "fs" => {
-                fullsim(&guesses);
+                let rest = words.collect::<Vec<_>>().join(" ");
+                let opening = if rest.is_empty() { Ok(vec![opener]) } else { parse_opening(&rest, &guesses) };
+                match opening {
+                    Ok(opening) => fullsim::<N>(&guesses, &opening, game.mode(), args.shard, args.log.as_deref(), args.csv.as_deref()),
+                    Err(e) => println!("Usage: fs [word...]: {}", e),
+                }
+            }
+            // tie-break preferences among near-optimal suggestions
+            "pref" => {
+                if let Some(key) = words.next() {
+                    if words.next().and_then(|v| prefs.set(key, v)).is_none() {
+                        println!("Usage: pref [{} n]", Preferences::KEYS.join("|"));
+                        println!("       objective is lex (worst case, then expected) or worst (worst case only)");
+                        println!("       seed breaks exact ties at random for that seed, or off for list order");
+                        continue;
+                    }
+                }
+                println!("Preferences: common {}, unique {}, plurals {}, slack {}, objective {}, seed {}",
+                         prefs.common, prefs.unique, prefs.no_plurals, prefs.slack, prefs.objective,
+                         prefs.seed.map_or("off".to_string(), |s| s.to_string()));
+            }
+            // how the solver chooses guesses: one of STRATEGIES
+            "strategy" => {
+                if let Some(arg) = words.next() {
+                    match strategy_named(arg) {
+                        Some(s) => {
+                            strategy = s;
+                            // A named opener is kept; otherwise open with the strategy's own.
+                            if args.first.as_deref().unwrap_or("auto") == "auto" {
+                                let cache = Cache::open_default();
+                                (opener, opener_worst) = strategy_opener(cache.as_ref(), answer_list(), &guesses,
+                                                                         strategy).unwrap();
+                                if game.is_new() {
+                                    prev_best_guess = Some(opener);
+                                }
+                            }
+                        }
+                        None => {
+                            println!("Usage: strategy [{}]", STRATEGIES.iter().map(|s| s.name()).join("|"));
+                            continue;
+                        }
+                    }
+                }
+                println!("Strategy: {}", strategy.name());
+            }
+            // teaching mode: explain what each guess revealed
+            "t" => {
+                match words.next() {
+                    Some("on") => teaching = true,
+                    Some("off") => teaching = false,
+                    None => {}
+                    Some(_) => {
+                        println!("Usage: t [on|off]");
+                        continue;
+                    }
+                }
+                println!("Teaching: {}", if teaching { "on" } else { "off" });
+            }
+            // skill: grade each guess against the others the mode allowed
+            "skill" => {
+                match words.next() {
+                    Some("on") => skill = true,
+                    Some("off") => skill = false,
+                    None => {}
+                    Some(_) => {
+                        println!("Usage: skill [on|off]");
+                        continue;
+                    }
+                }
+                println!("Skill: {}", if skill { "on" } else { "off" });
+            }
+            // tournament between the strategies, over the first n answers, in a mode
+            "tournament" => {
+                let n = match words.next().map(str::parse) {
+                    Some(Ok(n)) => n,
+                    None => answer_list().len(),
+                    Some(Err(_)) => {
+                        println!("Usage: tournament [answers [normal|semi|hard]]");
+                        continue;
+                    }
+                };
+                let mode = match words.next().map(Mode::parse) {
+                    Some(Some(mode)) => mode,
+                    None => Mode::Normal,
+                    Some(None) => {
+                        println!("Usage: tournament [answers [normal|semi|hard]]");
+                        continue;
+                    }
+                };
+                if let Err(e) = tournament::<N>(&guesses, n, mode) {
+                    println!("tournament: {}", e);
+                }
+            }
+            // the decision tree's guess now, or after the results given, and the guess it plays
+            // after each result of that
+            "tree" => {
+                let tree = match tree {
+                    Some(tree) => tree,
+                    None => {
+                        println!("No decision tree; give one with --tree file");
+                        continue;
+                    }
+                };
+                if blindfold {
+                    println!("No decision tree in blindfold mode");
+                    continue;
+                }
+                let mut path = game.history().entries().iter().map(|&(_, r)| r).collect::<Vec<_>>();
+                match words.map(parse_result_n).collect::<Option<Vec<_>>>() {
+                    Some(results) => path.extend(results),
+                    None => {
+                        println!("Usage: tree [result...]");
+                        continue;
+                    }
+                }
+                let guess = match tree.next(game.history().entries()).and_then(|_| tree.guess_at(&path)) {
+                    Some(guess) => guess,
+                    None => {
+                        println!("The game has left the tree");
+                        continue;
+                    }
+                };
+                println!("The tree plays '{}'", alphabet().decode(guess));
+                for (result, next) in tree.children(&path) {
+                    println!("  if {} then '{}'", fmt_result(&result), alphabet().decode(next));
+                }
+            }
+            // challenge the bot to find a secret answer
+            "cb" => {
+                challenge::<N>(&mut input, &guesses, opener, game.mode(), &prefs, &config);
             }
             _ => {
                 println!("No command '{}'", cmd);
@@ -181,5 +1750,8 @@ fn main() -> Result<()> {
         }
     }
 
+    if let Some(session) = &session {
+        session.clear()?;
+    }
     Ok(())
 }
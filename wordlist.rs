@@ -1,2 +1,4 @@
-pub const ANSW_LIST: &[&str] = &["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve", "heath", "dwarf", "model", "karma", "stink", "grade", "quiet", "bench", "abate", "feign", "major", "death", "fresh", "crust", "stool", "colon", "abase", "marry", "react", "batty", "pride", "floss", "helix", "croak", "staff", "paper", "unfed", "whelp", "trawl", "outdo", "adobe", "crazy", "sower", "repay", "digit", "crate", "cluck", "spike", "mimic", "pound", "maxim", "linen", "unmet", "flesh", "booby", "forth", "first", "stand", "belly", "ivory", "seedy", "print", "yearn", "drain", "bribe", "stout", "panel", "crass", "flume", "offal", "agree", "error", "swirl", "argue", "bleed", "delta", "flick", "totem", "wooer", "front", "shrub", "parry", "biome", "lapel", "start", "greet", "goner", "golem", "lusty", "loopy", "round", "audit", "lying", "gamma", "labor", "islet", "civic", "forge", "corny", "moult", "basic", "salad", "agate", "spicy", "spray", "essay", "fjord", "spend", "kebab", "guild", "aback", "motor", "alone", "hatch", "hyper", "thumb", "dowry", "ought", "belch", "dutch", "pilot", "tweed", "comet", "jaunt", "enema", "steed", "abyss", "growl", "fling", "dozen", "boozy", "erode", "world", "gouge", "click", "briar", "great", "altar", "pulpy", "blurt", "coast", "duchy", "groin", "fixer", "group", "rogue", "badly", "smart", "pithy", "gaudy", "chill", "heron", "vodka", "finer", "surer", "radio", "rouge", "perch", "retch", "wrote", "clock", "tilde", "store", "prove", "bring", "solve", "cheat", "grime", "exult", "usher", "epoch", "triad", "break", "rhino", "viral", "conic", "masse", "sonic", "vital", "trace", "using", "peach", "champ", "baton", "brake", "pluck", "craze", "gripe", "weary", "picky", "acute", "ferry", "aside", "tapir", "troll", "unify", "rebus", "boost", "truss", "siege", "tiger", "banal", "slump", "crank", "gorge", "query", "drink", "favor", "abbey", "tangy", "panic", "solar", "shire", "proxy", "point", "robot", "prick", "wince", "crimp", "knoll", "sugar", "whack", "mount", "perky", "could", "wrung", "light", "those", "moist", "shard", "pleat", "aloft", "skill", "elder", "frame", "humor", "pause", "ulcer", "ultra", "robin", "cynic", "aroma", "caulk", "shake", "dodge", "swill", "tacit", "other", "thorn", "trove", "bloke", "vivid", "spill", "chant", "choke", "rupee", "nasty", "mourn", "ahead", "brine", "cloth", "hoard", "sweet", "month", "lapse", "watch", "today", "focus", "smelt", "tease", "cater", "movie", "saute", "allow", "renew", "their", "slosh", "purge", "chest", "depot", "epoxy", "nymph", "found", "shall", "harry", "stove", "lowly", "snout", "trope", "fewer", "shawl", "natal", "comma", "foray", "scare", "stair", "black", "squad", "royal", "chunk", "mince", "shame", "cheek", "ample", "flair", "foyer", "cargo", "oxide", "plant", "olive", "inert", "askew", "heist", "shown", "zesty", "hasty", "trash", "fella", "larva", "forgo", "story", "hairy", "train", "homer", "badge", "midst", "canny", "fetus", "butch", "farce", "slung", "tipsy", "metal", "yield", "delve", "being", "scour", "glass", "gamer", "scrap", "money", "hinge", "album", "vouch", "asset", "tiara", "crept", "bayou", "atoll", "manor", "creak", "showy", "phase", "froth", "depth", "gloom", "flood", "trait", "girth", "piety", "payer", "goose", "float", "donor", "atone", "primo", "apron", "blown", "cacao", "loser", "input", "gloat", "awful", "brink", "smite", "beady", "rusty", "retro", "droll", "gawky", "hutch", "pinto", "gaily", "egret", "lilac", "sever", "field", "fluff", "hydro", "flack", "agape", "voice", "stead", "stalk", "berth", "madam", "night", "bland", "liver", "wedge", "augur", "roomy", "wacky", "flock", "angry", "bobby", "trite", "aphid", "tryst", "midge", "power", "elope", "cinch", "motto", "stomp", "upset", "bluff", "cramp", "quart", "coyly", "youth", "rhyme", "buggy", "alien", "smear", "unfit", "patty", "cling", "glean", "label", "hunky", "khaki", "poker", "gruel", "twice", "twang", "shrug", "treat", "unlit", "waste", "merit", "woven", "octal", "needy", "clown", "widow", "irony", "ruder", "gauze", "chief", "onset", "prize", "fungi", "charm", "gully", "inter", "whoop", "taunt", "leery", "class", "theme", "lofty", "tibia", "booze", "alpha", "thyme", "eclat", "doubt", "parer", "chute", "stick", "trice", "alike", "sooth", "recap", "saint", "liege", "glory", "grate", "admit", "brisk", "soggy", "usurp", "scald", "scorn", "leave", "twine", "sting", "bough", "marsh", "sloth", "dandy", "vigor", "howdy", "enjoy", "valid", "ionic", "equal", "unset", "floor", "catch", "spade", "stein", "exist", "quirk", "denim", "grove", "spiel", "mummy", "fault", "foggy", "flout", "carry", "sneak", "libel", "waltz", "aptly", "piney", "inept", "aloud", "photo", "dream", "stale", "vomit", "ombre", "fanny", "unite", "snarl", "baker", "there", "glyph", "pooch", "hippy", "spell", "folly", "louse", "gulch", "vault", "godly", "threw", "fleet", "grave", "inane", "shock", "crave", "spite", "valve", "skimp", "claim", "rainy", "musty", "pique", "daddy", "quasi", "arise", "aging", "valet", "opium", "avert", "stuck", "recut", "mulch", "genre", "plume", "rifle", "count", "incur", "total", "wrest", "mocha", "deter", "study", "lover", "safer", "rivet", "funny", "smoke", "mound", "undue", "sedan", "pagan", "swine", "guile", "gusty", "equip", "tough", "canoe", "chaos", "covet", "human", "udder", "lunch", "blast", "stray", "manga", "melee", "lefty", "quick", "paste", "given", "octet", "risen", "groan", "leaky", "grind", "carve", "loose", "sadly", "spilt", "apple", "slack", "honey", "final", "sheen", "eerie", "minty", "slick", "derby", "wharf", "spelt", "coach", "erupt", "singe", "price", "spawn", "fairy", "jiffy", "filmy", "stack", "chose", "sleep", "ardor", "nanny", "niece", "woozy", "handy", "grace", "ditto", "stank", "cream", "usual", "diode", "valor", "angle", "ninja", "muddy", "chase", "reply", "prone", "spoil", "heart", "shade", "diner", "arson", "onion", "sleet", "dowel", "couch", "palsy", "bowel", "smile", "evoke", "creek", "lance", "eagle", "idiot", "siren", "built", "embed", "award", "dross", "annul", "goody", "frown", "patio", "laden", "humid", "elite", "lymph", "edify", "might", "reset", "visit", "gusto", "purse", "vapor", "crock", "write", "sunny", "loath", "chaff", "slide", "queer", "venom", "stamp", "sorry", "still", "acorn", "aping", "pushy", "tamer", "hater", "mania", "awoke", "brawn", "swift", "exile", "birch", "lucky", "freer", "risky", "ghost", "plier", "lunar", "winch", "snare", "nurse", "house", "borax", "nicer", "lurch", "exalt", "about", "savvy", "toxin", "tunic", "pried", "inlay", "chump", "lanky", "cress", "eater", "elude", "cycle", "kitty", "boule", "moron", "tenet", "place", "lobby", "plush", "vigil", "index", "blink", "clung", "qualm", "croup", "clink", "juicy", "stage", "decay", "nerve", "flier", "shaft", "crook", "clean", "china", "ridge", "vowel", "gnome", "snuck", "icing", "spiny", "rigor", "snail", "flown", "rabid", "prose", "thank", "poppy", "budge", "fiber", "moldy", "dowdy", "kneel", "track", "caddy", "quell", "dumpy", "paler", "swore", "rebar", "scuba", "splat", "flyer", "horny", "mason", "doing", "ozone", "amply", "molar", "ovary", "beset", "queue", "cliff", "magic", "truce", "sport", "fritz", "edict", "twirl", "verse", "llama", "eaten", "range", "whisk", "hovel", "rehab", "macaw", "sigma", "spout", "verve", "sushi", "dying", "fetid", "brain", "buddy", "thump", "scion", "candy", "chord", "basin", "march", "crowd", "arbor", "gayly", "musky", "stain", "dally", "bless", "bravo", "stung", "title", "ruler", "kiosk", "blond", "ennui", "layer", "fluid", "tatty", "score", "cutie", "zebra", "barge", "matey", "bluer", "aider", "shook", "river", "privy", "betel", "frisk", "bongo", "begun", "azure", "weave", "genie", "sound", "glove", "braid", "scope", "wryly", "rover", "assay", "ocean", "bloom", "irate", "later", "woken", "silky", "wreck", "dwelt", "slate", "smack", "solid", "amaze", "hazel", "wrist", "jolly", "globe", "flint", "rouse", "civil", "vista", "relax", "cover", "alive", "beech", "jetty", "bliss", "vocal", "often", "dolly", "eight", "joker", "since", "event", "ensue", "shunt", "diver", "poser", "worst", "sweep", "alley", "creed", "anime", "leafy", "bosom", "dunce", "stare", "pudgy", "waive", "choir", "stood", "spoke", "outgo", "delay", "bilge", "ideal", "clasp", "seize", "hotly", "laugh", "sieve", "block", "meant", "grape", "noose", "hardy", "shied", "drawl", "daisy", "putty", "strut", "burnt", "tulip", "crick", "idyll", "vixen", "furor", "geeky", "cough", "naive", "shoal", "stork", "bathe", "aunty", "check", "prime", "brass", "outer", "furry", "razor", "elect", "evict", "imply", "demur", "quota", "haven", "cavil", "swear", "crump", "dough", "gavel", "wagon", "salon", "nudge", "harem", "pitch", "sworn", "pupil", "excel", "stony", "cabin", "unzip", "queen", "trout", "polyp", "earth", "storm", "until", "taper", "enter", "child", "adopt", "minor", "fatty", "husky", "brave", "filet", "slime", "glint", "tread", "steal", "regal", "guest", "every", "murky", "share", "spore", "hoist", "buxom", "inner", "otter", "dimly", "level", "sumac", "donut", "stilt", "arena", "sheet", "scrub", "fancy", "slimy", "pearl", "silly", "porch", "dingo", "sepia", "amble", "shady", "bread", "friar", "reign", "dairy", "quill", "cross", "brood", "tuber", "shear", "posit", "blank", "villa", "shank", "piggy", "freak", "which", "among", "fecal", "shell", "would", "algae", "large", "rabbi", "agony", "amuse", "bushy", "copse", "swoon", "knife", "pouch", "ascot", "plane", "crown", "urban", "snide", "relay", "abide", "viola", "rajah", "straw", "dilly", "crash", "amass", "third", "trick", "tutor", "woody", "blurb", "grief", "disco", "where", "sassy", "beach", "sauna", "comic", "clued", "creep", "caste", "graze", "snuff", "frock", "gonad", "drunk", "prong", "lurid", "steel", "halve", "buyer", "vinyl", "utile", "smell", "adage", "worry", "tasty", "local", "trade", "finch", "ashen", "modal", "gaunt", "clove", "enact", "adorn", "roast", "speck", "sheik", "missy", "grunt", "snoop", "party", "touch", "mafia", "emcee", "array", "south", "vapid", "jelly", "skulk", "angst", "tubal", "lower", "crest", "sweat", "cyber", "adore", "tardy", "swami", "notch", "groom", "roach", "hitch", "young", "align", "ready", "frond", "strap", "puree", "realm", "venue", "swarm", "offer", "seven", "dryer", "diary", "dryly", "drank", "acrid", "heady", "theta", "junto", "pixie", "quoth", "bonus", "shalt", "penne", "amend", "datum", "build", "piano", "shelf", "lodge", "suing", "rearm", "coral", "ramen", "worth", "psalm", "infer", "overt", "mayor", "ovoid", "glide", "usage", "poise", "randy", "chuck", "prank", "fishy", "tooth", "ether", "drove", "idler", "swath", "stint", "while", "begat", "apply", "slang", "tarot", "radar", "credo", "aware", "canon", "shift", "timer", "bylaw", "serum", "three", "steak", "iliac", "shirk", "blunt", "puppy", "penal", "joist", "bunny", "shape", "beget", "wheel", "adept", "stunt", "stole", "topaz", "chore", "fluke", "afoot", "bloat", "bully", "dense", "caper", "sneer", "boxer", "jumbo", "lunge", "space", "avail", "short", "slurp", "loyal", "flirt", "pizza", "conch", "tempo", "droop", "plate", "bible", "plunk", "afoul", "savoy", "steep", "agile", "stake", "dwell", "knave", "beard", "arose", "motif", "smash", "broil", "glare", "shove", "baggy", "mammy", "swamp", "along", "rugby", "wager", "quack", "squat", "snaky", "debit", "mange", "skate", "ninth", "joust", "tramp", "spurn", "medal", "micro", "rebel", "flank", "learn", "nadir", "maple", "comfy", "remit", "gruff", "ester", "least", "mogul", "fetch", "cause", "oaken", "aglow", "meaty", "gaffe", "shyly", "racer", "prowl", "thief", "stern", "poesy", "rocky", "tweet", "waist", "spire", "grope", "havoc", "patsy", "truly", "forty", "deity", "uncle", "swish", "giver", "preen", "bevel", "lemur", "draft", "slope", "annoy", "lingo", "bleak", "ditty", "curly", "cedar", "dirge", "grown", "horde", "drool", "shuck", "crypt", "cumin", "stock", "gravy", "locus", "wider", "breed", "quite", "chafe", "cache", "blimp", "deign", "fiend", "logic", "cheap", "elide", "rigid", "false", "renal", "pence", "rowdy", "shoot", "blaze", "envoy", "posse", "brief", "never", "abort", "mouse", "mucky", "sulky", "fiery", "media", "trunk", "yeast", "clear", "skunk", "scalp", "bitty", "cider", "koala", "duvet", "segue", "creme", "super", "grill", "after", "owner", "ember", "reach", "nobly", "empty", "speed", "gipsy", "recur", "smock", "dread", "merge", "burst", "kappa", "amity", "shaky", "hover", "carol", "snort", "synod", "faint", "haunt", "flour", "chair", "detox", "shrew", "tense", "plied", "quark", "burly", "novel", "waxen", "stoic", "jerky", "blitz", "beefy", "lyric", "hussy", "towel", "quilt", "below", "bingo", "wispy", "brash", "scone", "toast", "easel", "saucy", "value", "spice", "honor", "route", "sharp", "bawdy", "radii", "skull", "phony", "issue", "lager", "swell", "urine", "gassy", "trial", "flora", "upper", "latch", "wight", "brick", "retry", "holly", "decal", "grass", "shack", "dogma", "mover", "defer", "sober", "optic", "crier", "vying", "nomad", "flute", "hippo", "shark", "drier", "obese", "bugle", "tawny", "chalk", "feast", "ruddy", "pedal", "scarf", "cruel", "bleat", "tidal", "slush", "semen", "windy", "dusty", "sally", "igloo", "nerdy", "jewel", "shone", "whale", "hymen", "abuse", "fugue", "elbow", "crumb", "pansy", "welsh", "syrup", "terse", "suave", "gamut", "swung", "drake", "freed", "afire", "shirt", "grout", "oddly", "tithe", "plaid", "dummy", "broom", "blind", "torch", "enemy", "again", "tying", "pesky", "alter", "gazer", "noble", "ethos", "bride", "extol", "decor", "hobby", "beast", "idiom", "utter", "these", "sixth", "alarm", "erase", "elegy", "spunk", "piper", "scaly", "scold", "hefty", "chick", "sooty", "canal", "whiny", "slash", "quake", "joint", "swept", "prude", "heavy", "wield", "femme", "lasso", "maize", "shale", "screw", "spree", "smoky", "whiff", "scent", "glade", "spent", "prism", "stoke", "riper", "orbit", "cocoa", "guilt", "humus", "shush", "table", "smirk", "wrong", "noisy", "alert", "shiny", "elate", "resin", "whole", "hunch", "pixel", "polar", "hotel", "sword", "cleat", "mango", "rumba", "puffy", "filly", "billy", "leash", "clout", "dance", "ovate", "facet", "chili", "paint", "liner", "curio", "salty", "audio", "snake", "fable", "cloak", "navel", "spurt", "pesto", "balmy", "flash", "unwed", "early", "churn", "weedy", "stump", "lease", "witty", "wimpy", "spoof", "saner", "blend", "salsa", "thick", "warty", "manic", "blare", "squib", "spoon", "probe", "crepe", "knack", "force", "debut", "order", "haste", "teeth", "agent", "widen", "icily", "slice", "ingot", "clash", "juror", "blood", "abode", "throw", "unity", "pivot", "slept", "troop", "spare", "sewer", "parse", "morph", "cacti", "tacky", "spool", "demon", "moody", "annex", "begin", "fuzzy", "patch", "water", "lumpy", "admin", "omega", "limit", "tabby", "macho", "aisle", "skiff", "basis", "plank", "verge", "botch", "crawl", "lousy", "slain", "cubic", "raise", "wrack", "guide", "foist", "cameo", "under", "actor", "revue", "fraud", "harpy", "scoop", "climb", "refer", "olden", "clerk", "debar", "tally", "ethic", "cairn", "tulle", "ghoul", "hilly", "crude", "apart", "scale", "older", "plain", "sperm", "briny", "abbot", "rerun", "quest", "crisp", "bound", "befit", "drawn", "suite", "itchy", "cheer", "bagel", "guess", "broad", "axiom", "chard", "caput", "leant", "harsh", "curse", "proud", "swing", "opine", "taste", "lupus", "gumbo", "miner", "green", "chasm", "lipid", "topic", "armor", "brush", "crane", "mural", "abled", "habit", "bossy", "maker", "dusky", "dizzy", "lithe", "brook", "jazzy", "fifty", "sense", "giant", "surly", "legal", "fatal", "flunk", "began", "prune", "small", "slant", "scoff", "torus", "ninny", "covey", "viper", "taken", "moral", "vogue", "owing", "token", "entry", "booth", "voter", "chide", "elfin", "ebony", "neigh", "minim", "melon", "kneed", "decoy", "voila", "ankle", "arrow", "mushy", "tribe", "cease", "eager", "birth", "graph", "odder", "terra", "weird", "tried", "clack", "color", "rough", "weigh", "uncut", "ladle", "strip", "craft", "minus", "dicey", "titan", "lucid", "vicar", "dress", "ditch", "gypsy", "pasta", "taffy", "flame", "swoop", "aloof", "sight", "broke", "teary", "chart", "sixty", "wordy", "sheer", "leper", "nosey", "bulge", "savor", "clamp", "funky", "foamy", "toxic", "brand", "plumb", "dingy", "butte", "drill", "tripe", "bicep", "tenor", "krill", "worse", "drama", "hyena", "think", "ratio", "cobra", "basil", "scrum", "bused", "phone", "court", "camel", "proof", "heard", "angel", "petal", "pouty", "throb", "maybe", "fetal", "sprig", "spine", "shout", "cadet", "macro", "dodgy", "satyr", "rarer", "binge", "trend", "nutty", "leapt", "amiss", "split", "myrrh", "width", "sonar", "tower", "baron", "fever", "waver", "spark", "belie", "sloop", "expel", "smote", "baler", "above", "north", "wafer", "scant", "frill", "awash", "snack", "scowl", "frail", "drift", "limbo", "fence", "motel", "ounce", "wreak", "revel", "talon", "prior", "knelt", "cello", "flake", "debug", "anode", "crime", "salve", "scout", "imbue", "pinky", "stave", "vague", "chock", "fight", "video", "stone", "teach", "cleft", "frost", "prawn", "booty", "twist", "apnea", "stiff", "plaza", "ledge", "tweak", "board", "grant", "medic", "bacon", "cable", "brawl", "slunk", "raspy", "forum", "drone", "women", "mucus", "boast", "toddy", "coven", "tumor", "truer", "wrath", "stall", "steam", "axial", "purer", "daily", "trail", "niche", "mealy", "juice", "nylon", "plump", "merry", "flail", "papal", "wheat", "berry", "cower", "erect", "brute", "leggy", "snipe", "sinew", "skier", "penny", "jumpy", "rally", "umbra", "scary", "modem", "gross", "avian", "greed", "satin", "tonic", "parka", "sniff", "livid", "stark", "trump", "giddy", "reuse", "taboo", "avoid", "quote", "devil", "liken", "gloss", "gayer", "beret", "noise", "gland", "dealt", "sling", "rumor", "opera", "thigh", "tonga", "flare", "wound", "white", "bulky", "etude", "horse", "circa", "paddy", "inbox", "fizzy", "grain", "exert", "surge", "gleam", "belle", "salvo", "crush", "fruit", "sappy", "taker", "tract", "ovine", "spiky", "frank", "reedy", "filth", "spasm", "heave", "mambo", "right", "clank", "trust", "lumen", "borne", "spook", "sauce", "amber", "lathe", "carat", "corer", "dirty", "slyly", "affix", "alloy", "taint", "sheep", "kinky", "wooly", "mauve", "flung", "yacht", "fried", "quail", "brunt", "grimy", "curvy", "cagey", "rinse", "deuce", "state", "grasp", "milky", "bison", "graft", "sandy", "baste", "flask", "hedge", "girly", "swash", "boney", "coupe", "endow", "abhor", "welch", "blade", "tight", "geese", "miser", "mirth", "cloud", "cabal", "leech", "close", "tenth", "pecan", "droit", "grail", "clone", "guise", "ralph", "tango", "biddy", "smith", "mower", "payee", "serif", "drape", "fifth", "spank", "glaze", "allot", "truck", "kayak", "virus", "testy", "tepee", "fully", "zonal", "metro", "curry", "grand", "banjo", "axion", "bezel", "occur", "chain", "nasal", "gooey", "filer", "brace", "allay", "pubic", "raven", "plead", "gnash", "flaky", "munch", "dully", "eking", "thing", "slink", "hurry", "theft", "shorn", "pygmy", "ranch", "wring", "lemon", "shore", "mamma", "froze", "newer", "style", "moose", "antic", "drown", "vegan", "chess", "guppy", "union", "lever", "lorry", "image", "cabby", "druid", "exact", "truth", "dopey", "spear", "cried", "chime", "crony", "stunk", "timid", "batch", "gauge", "rotor", "crack", "curve", "latte", "witch", "bunch", "repel", "anvil", "soapy", "meter", "broth", "madly", "dried", "scene", "known", "magma", "roost", "woman", "thong", "punch", "pasty", "downy", "knead", "whirl", "rapid", "clang", "anger", "drive", "goofy", "email", "music", "stuff", "bleep", "rider", "mecca", "folio", "setup", "verso", "quash", "fauna", "gummy", "happy", "newly", "fussy", "relic", "guava", "ratty", "fudge", "femur", "chirp", "forte", "alibi", "whine", "petty", "golly", "plait", "fleck", "felon", "gourd", "brown", "thrum", "ficus", "stash", "decry", "wiser", "junta", "visor", "daunt", "scree", "impel", "await", "press", "whose", "turbo", "stoop", "speak", "mangy", "eying", "inlet", "crone", "pulse", "mossy", "staid", "hence", "pinch", "teddy", "sully", "snore", "ripen", "snowy", "attic", "going", "leach", "mouth", "hound", "clump", "tonal", "bigot", "peril", "piece", "blame", "haute", "spied", "undid", "intro", "basal", "shine", "gecko", "rodeo", "guard", "steer", "loamy", "scamp", "scram", "manly", "hello", "vaunt", "organ", "feral", "knock", "extra", "condo", "adapt", "willy", "polka", "rayon", "skirt", "faith", "torso", "match", "mercy", "tepid", "sleek", "riser", "twixt", "peace", "flush", "catty", "login", "eject", "roger", "rival", "untie", "refit", "aorta", "adult", "judge", "rower", "artsy", "rural", "shave"];
-pub const GUESS_LIST: &[&str] = &["aahed","aalii","aargh","aarti","abaca","abaci","abacs","abaft","abaka","abamp","aband","abash","abask","abaya","abbas","abbed","abbes","abcee","abeam","abear","abele","abers","abets","abies","abler","ables","ablet","ablow","abmho","abohm","aboil","aboma","aboon","abord","abore","abram","abray","abrim","abrin","abris","absey","absit","abuna","abune","abuts","abuzz","abyes","abysm","acais","acari","accas","accoy","acerb","acers","aceta","achar","ached","aches","achoo","acids","acidy","acing","acini","ackee","acker","acmes","acmic","acned","acnes","acock","acold","acred","acres","acros","acted","actin","acton","acyls","adaws","adays","adbot","addax","added","adder","addio","addle","adeem","adhan","adieu","adios","adits","adman","admen","admix","adobo","adown","adoze","adrad","adred","adsum","aduki","adunc","adust","advew","adyta","adzed","adzes","aecia","aedes","aegis","aeons","aerie","aeros","aesir","afald","afara","afars","afear","aflaj","afore","afrit","afros","agama","agami","agars","agast","agave","agaze","agene","agers","agger","aggie","aggri","aggro","aggry","aghas","agila","agios","agism","agist","agita","aglee","aglet","agley","agloo","aglus","agmas","agoge","agone","agons","agood","agria","agrin","agros","agued","agues","aguna","aguti","aheap","ahent","ahigh","ahind","ahing","ahint","ahold","ahull","ahuru","aidas","aided","aides","aidoi","aidos","aiery","aigas","aight","ailed","aimed","aimer","ainee","ainga","aioli","aired","airer","airns","airth","airts","aitch","aitus","aiver","aiyee","aizle","ajies","ajiva","ajuga","ajwan","akees","akela","akene","aking","akita","akkas","alaap","alack","alamo","aland","alane","alang","alans","alant","alapa","alaps","alary","alate","alays","albas","albee","alcid","alcos","aldea","alder","aldol","aleck","alecs","alefs","aleft","aleph","alews","aleye","alfas","algal","algas","algid","algin","algor","algum","alias","alifs","aline","alist","aliya","alkie","alkos","alkyd","alkyl","allee","allel","allis","allod","allyl","almah","almas","almeh","almes","almud","almug","alods","aloed","aloes","aloha","aloin","aloos","alowe","altho","altos","alula","alums","alure","alvar","alway","amahs","amain","amate","amaut","amban","ambit","ambos","ambry","ameba","ameer","amene","amens","ament","amias","amice","amici","amide","amido","amids","amies","amiga","amigo","amine","amino","amins","amirs","amlas","amman","ammon","ammos","amnia","amnic","amnio","amoks","amole","amort","amour","amove","amowt","amped","ampul","amrit","amuck","amyls","anana","anata","ancho","ancle","ancon","andro","anear","anele","anent","angas","anglo","anigh","anile","anils","anima","animi","anion","anise","anker","ankhs","ankus","anlas","annal","annas","annat","anoas","anole","anomy","ansae","antae","antar","antas","anted","antes","antis","antra","antre","antsy","anura","anyon","apace","apage","apaid","apayd","apays","apeak","apeek","apers","apert","apery","apgar","aphis","apian","apiol","apish","apism","apode","apods","apoop","aport","appal","appay","appel","appro","appui","appuy","apres","apses","apsis","apsos","apted","apter","aquae","aquas","araba","araks","arame","arars","arbas","arced","archi","arcos","arcus","ardeb","ardri","aread","areae","areal","arear","areas","areca","aredd","arede","arefy","areic","arene","arepa","arere","arete","arets","arett","argal","argan","argil","argle","argol","argon","argot","argus","arhat","arias","ariel","ariki","arils","ariot","arish","arked","arled","arles","armed","armer","armet","armil","arnas","arnut","aroba","aroha","aroid","arpas","arpen","arrah","arras","arret","arris","arroz","arsed","arses","arsey","arsis","artal","artel","artic","artis","aruhe","arums","arval","arvee","arvos","aryls","asana","ascon","ascus","asdic","ashed","ashes","ashet","asked","asker","askoi","askos","aspen","asper","aspic","aspie","aspis","aspro","assai","assam","asses","assez","assot","aster","astir","astun","asura","asway","aswim","asyla","ataps","ataxy","atigi","atilt","atimy","atlas","atman","atmas","atmos","atocs","atoke","atoks","atoms","atomy","atony","atopy","atria","atrip","attap","attar","atuas","audad","auger","aught","aulas","aulic","auloi","aulos","aumil","aunes","aunts","aurae","aural","aurar","auras","aurei","aures","auric","auris","aurum","autos","auxin","avale","avant","avast","avels","avens","avers","avgas","avine","avion","avise","aviso","avize","avows","avyze","awarn","awato","awave","aways","awdls","aweel","aweto","awing","awmry","awned","awner","awols","awork","axels","axile","axils","axing","axite","axled","axles","axman","axmen","axoid","axone","axons","ayahs","ayaya","ayelp","aygre","ayins","ayont","ayres","ayrie","azans","azide","azido","azine","azlon","azoic","azole","azons","azote","azoth","azuki","azurn","azury","azygy","azyme","azyms","baaed","baals","babas","babel","babes","babka","baboo","babul","babus","bacca","bacco","baccy","bacha","bachs","backs","baddy","baels","baffs","baffy","bafts","baghs","bagie","bahts","bahus","bahut","bails","bairn","baisa","baith","baits","baiza","baize","bajan","bajra","bajri","bajus","baked","baken","bakes","bakra","balas","balds","baldy","baled","bales","balks","balky","balls","bally","balms","baloo","balsa","balti","balun","balus","bambi","banak","banco","bancs","banda","bandh","bands","bandy","baned","banes","bangs","bania","banks","banns","bants","bantu","banty","banya","bapus","barbe","barbs","barby","barca","barde","bardo","bards","bardy","bared","barer","bares","barfi","barfs","baric","barks","barky","barms","barmy","barns","barny","barps","barra","barre","barro","barry","barye","basan","based","basen","baser","bases","basho","basij","basks","bason","basse","bassi","basso","bassy","basta","basti","basto","basts","bated","bates","baths","batik","batta","batts","battu","bauds","bauks","baulk","baurs","bavin","bawds","bawks","bawls","bawns","bawrs","bawty","bayed","bayer","bayes","bayle","bayts","bazar","bazoo","beads","beaks","beaky","beals","beams","beamy","beano","beans","beany","beare","bears","beath","beats","beaty","beaus","beaut","beaux","bebop","becap","becke","becks","bedad","bedel","bedes","bedew","bedim","bedye","beedi","beefs","beeps","beers","beery","beets","befog","begad","begar","begem","begot","begum","beige","beigy","beins","bekah","belah","belar","belay","belee","belga","bells","belon","belts","bemad","bemas","bemix","bemud","bends","bendy","benes","benet","benga","benis","benne","benni","benny","bento","bents","benty","bepat","beray","beres","bergs","berko","berks","berme","berms","berob","beryl","besat","besaw","besee","beses","besit","besom","besot","besti","bests","betas","beted","betes","beths","betid","beton","betta","betty","bever","bevor","bevue","bevvy","bewet","bewig","bezes","bezil","bezzy","bhais","bhaji","bhang","bhats","bhels","bhoot","bhuna","bhuts","biach","biali","bialy","bibbs","bibes","biccy","bices","bided","bider","bides","bidet","bidis","bidon","bield","biers","biffo","biffs","biffy","bifid","bigae","biggs","biggy","bigha","bight","bigly","bigos","bijou","biked","biker","bikes","bikie","bilbo","bilby","biled","biles","bilgy","bilks","bills","bimah","bimas","bimbo","binal","bindi","binds","biner","bines","bings","bingy","binit","binks","bints","biogs","biont","biota","biped","bipod","birds","birks","birle","birls","biros","birrs","birse","birsy","bises","bisks","bisom","biter","bites","bitos","bitou","bitsy","bitte","bitts","bivia","bivvy","bizes","bizzo","bizzy","blabs","blads","blady","blaer","blaes","blaff","blags","blahs","blain","blams","blart","blase","blash","blate","blats","blatt","blaud","blawn","blaws","blays","blear","blebs","blech","blees","blent","blert","blest","blets","bleys","blimy","bling","blini","blins","bliny","blips","blist","blite","blits","blive","blobs","blocs","blogs","blook","bloop","blore","blots","blows","blowy","blubs","blude","bluds","bludy","blued","blues","bluet","bluey","bluid","blume","blunk","blurs","blype","boabs","boaks","boars","boart","boats","bobac","bobak","bobas","bobol","bobos","bocca","bocce","bocci","boche","bocks","boded","bodes","bodge","bodhi","bodle","boeps","boets","boeuf","boffo","boffs","bogan","bogey","boggy","bogie","bogle","bogue","bogus","bohea","bohos","boils","boing","boink","boite","boked","bokeh","bokes","bokos","bolar","bolas","bolds","boles","bolix","bolls","bolos","bolts","bolus","bomas","bombe","bombo","bombs","bonce","bonds","boned","boner","bones","bongs","bonie","bonks","bonne","bonny","bonza","bonze","booai","booay","boobs","boody","booed","boofy","boogy","boohs","books","booky","bools","booms","boomy","boong","boons","boord","boors","boose","boots","boppy","borak","boral","boras","borde","bords","bored","boree","borel","borer","bores","borgo","boric","borks","borms","borna","boron","borts","borty","bortz","bosie","bosks","bosky","boson","bosun","botas","botel","botes","bothy","botte","botts","botty","bouge","bouks","boult","bouns","bourd","bourg","bourn","bouse","bousy","bouts","bovid","bowat","bowed","bower","bowes","bowet","bowie","bowls","bowne","bowrs","bowse","boxed","boxen","boxes","boxla","boxty","boyar","boyau","boyed","boyfs","boygs","boyla","boyos","boysy","bozos","braai","brach","brack","bract","brads","braes","brags","brail","braks","braky","brame","brane","brank","brans","brant","brast","brats","brava","bravi","braws","braxy","brays","braza","braze","bream","brede","breds","breem","breer","brees","breid","breis","breme","brens","brent","brere","brers","breve","brews","breys","brier","bries","brigs","briki","briks","brill","brims","brins","brios","brise","briss","brith","brits","britt","brize","broch","brock","brods","brogh","brogs","brome","bromo","bronc","brond","brool","broos","brose","brosy","brows","brugh","bruin","bruit","brule","brume","brung","brusk","brust","bruts","buats","buaze","bubal","bubas","bubba","bubbe","bubby","bubus","buchu","bucko","bucks","bucku","budas","budis","budos","buffa","buffe","buffi","buffo","buffs","buffy","bufos","bufty","buhls","buhrs","buiks","buist","bukes","bulbs","bulgy","bulks","bulla","bulls","bulse","bumbo","bumfs","bumph","bumps","bumpy","bunas","bunce","bunco","bunde","bundh","bunds","bundt","bundu","bundy","bungs","bungy","bunia","bunje","bunjy","bunko","bunks","bunns","bunts","bunty","bunya","buoys","buppy","buran","buras","burbs","burds","buret","burfi","burgh","burgs","burin","burka","burke","burks","burls","burns","buroo","burps","burqa","burro","burrs","burry","bursa","burse","busby","buses","busks","busky","bussu","busti","busts","busty","buteo","butes","butle","butoh","butts","butty","butut","butyl","buzzy","bwana","bwazi","byded","bydes","byked","bykes","byres","byrls","byssi","bytes","byway","caaed","cabas","caber","cabob","caboc","cabre","cacas","cacks","cacky","cadee","cades","cadge","cadgy","cadie","cadis","cadre","caeca","caese","cafes","caffs","caged","cager","cages","cagot","cahow","caids","cains","caird","cajon","cajun","caked","cakes","cakey","calfs","calid","calif","calix","calks","calla","calls","calms","calmy","calos","calpa","calps","calve","calyx","caman","camas","cames","camis","camos","campi","campo","camps","campy","camus","caned","caneh","caner","canes","cangs","canid","canna","canns","canso","canst","canto","cants","canty","capas","caped","capes","capex","caphs","capiz","caple","capon","capos","capot","capri","capul","carap","carbo","carbs","carby","cardi","cards","cardy","cared","carer","cares","caret","carex","carks","carle","carls","carns","carny","carob","carom","caron","carpi","carps","carrs","carse","carta","carte","carts","carvy","casas","casco","cased","cases","casks","casky","casts","casus","cates","cauda","cauks","cauld","cauls","caums","caups","cauri","causa","cavas","caved","cavel","caver","caves","cavie","cawed","cawks","caxon","ceaze","cebid","cecal","cecum","ceded","ceder","cedes","cedis","ceiba","ceili","ceils","celeb","cella","celli","cells","celom","celts","cense","cento","cents","centu","ceorl","cepes","cerci","cered","ceres","cerge","ceria","ceric","cerne","ceroc","ceros","certs","certy","cesse","cesta","cesti","cetes","cetyl","cezve","chace","chack","chaco","chado","chads","chaft","chais","chals","chams","chana","chang","chank","chape","chaps","chapt","chara","chare","chark","charr","chars","chary","chats","chave","chavs","chawk","chaws","chaya","chays","cheep","chefs","cheka","chela","chelp","chemo","chems","chere","chert","cheth","chevy","chews","chewy","chiao","chias","chibs","chica","chich","chico","chics","chiel","chiks","chile","chimb","chimo","chimp","chine","ching","chino","chins","chips","chirk","chirl","chirm","chiro","chirr","chirt","chiru","chits","chive","chivs","chivy","chizz","choco","chocs","chode","chogs","choil","choko","choky","chola","choli","cholo","chomp","chons","choof","chook","choom","choon","chops","chota","chott","chout","choux","chowk","chows","chubs","chufa","chuff","chugs","chums","churl","churr","chuse","chuts","chyle","chyme","chynd","cibol","cided","cides","ciels","ciggy","cilia","cills","cimar","cimex","cinct","cines","cinqs","cions","cippi","circs","cires","cirls","cirri","cisco","cissy","cists","cital","cited","citer","cites","cives","civet","civie","civvy","clach","clade","clads","claes","clags","clame","clams","clans","claps","clapt","claro","clart","clary","clast","clats","claut","clave","clavi","claws","clays","cleck","cleek","cleep","clefs","clegs","cleik","clems","clepe","clept","cleve","clews","clied","clies","clift","clime","cline","clint","clipe","clips","clipt","clits","cloam","clods","cloff","clogs","cloke","clomb","clomp","clonk","clons","cloop","cloot","clops","clote","clots","clour","clous","clows","cloye","cloys","cloze","clubs","clues","cluey","clunk","clype","cnida","coact","coady","coala","coals","coaly","coapt","coarb","coate","coati","coats","cobbs","cobby","cobia","coble","cobza","cocas","cocci","cocco","cocks","cocky","cocos","codas","codec","coded","coden","coder","codes","codex","codon","coeds","coffs","cogie","cogon","cogue","cohab","cohen","cohoe","cohog","cohos","coifs","coign","coils","coins","coirs","coits","coked","cokes","colas","colby","colds","coled","coles","coley","colic","colin","colls","colly","colog","colts","colza","comae","comal","comas","combe","combi","combo","combs","comby","comer","comes","comix","commo","comms","commy","compo","comps","compt","comte","comus","coned","cones","coney","confs","conga","conge","congo","conia","conin","conks","conky","conne","conns","conte","conto","conus","convo","cooch","cooed","cooee","cooer","cooey","coofs","cooks","cooky","cools","cooly","coomb","cooms","coomy","coops","coopt","coost","coots","cooze","copal","copay","coped","copen","coper","copes","coppy","copra","copsy","coqui","coram","corbe","corby","cords","cored","cores","corey","corgi","coria","corks","corky","corms","corni","corno","corns","cornu","corps","corse","corso","cosec","cosed","coses","coset","cosey","cosie","costa","coste","costs","cotan","coted","cotes","coths","cotta","cotts","coude","coups","courb","courd","coure","cours","couta","couth","coved","coves","covin","cowal","cowan","cowed","cowks","cowls","cowps","cowry","coxae","coxal","coxed","coxes","coxib","coyau","coyed","coyer","coypu","cozed","cozen","cozes","cozey","cozie","craal","crabs","crags","craic","craig","crake","crame","crams","crans","crape","craps","crapy","crare","craws","crays","creds","creel","crees","crems","crena","creps","crepy","crewe","crews","crias","cribs","cries","crims","crine","crios","cripe","crips","crise","crith","crits","croci","crocs","croft","crogs","cromb","crome","cronk","crons","crool","croon","crops","crore","crost","crout","crows","croze","cruck","crudo","cruds","crudy","crues","cruet","cruft","crunk","cruor","crura","cruse","crusy","cruve","crwth","cryer","ctene","cubby","cubeb","cubed","cuber","cubes","cubit","cuddy","cuffo","cuffs","cuifs","cuing","cuish","cuits","cukes","culch","culet","culex","culls","cully","culms","culpa","culti","cults","culty","cumec","cundy","cunei","cunit","cunts","cupel","cupid","cuppa","cuppy","curat","curbs","curch","curds","curdy","cured","curer","cures","curet","curfs","curia","curie","curli","curls","curns","curny","currs","cursi","curst","cusec","cushy","cusks","cusps","cuspy","cusso","cusum","cutch","cuter","cutes","cutey","cutin","cutis","cutto","cutty","cutup","cuvee","cuzes","cwtch","cyano","cyans","cycad","cycas","cyclo","cyder","cylix","cymae","cymar","cymas","cymes","cymol","cysts","cytes","cyton","czars","daals","dabba","daces","dacha","dacks","dadah","dadas","dados","daffs","daffy","dagga","daggy","dagos","dahls","daiko","daine","daint","daker","daled","dales","dalis","dalle","dalts","daman","damar","dames","damme","damns","damps","dampy","dancy","dangs","danio","danks","danny","dants","daraf","darbs","darcy","dared","darer","dares","darga","dargs","daric","daris","darks","darns","darre","darts","darzi","dashi","dashy","datal","dated","dater","dates","datos","datto","daube","daubs","dauby","dauds","dault","daurs","dauts","daven","davit","dawah","dawds","dawed","dawen","dawks","dawns","dawts","dayan","daych","daynt","dazed","dazer","dazes","deads","deair","deals","deans","deare","dearn","dears","deary","deash","deave","deaws","deawy","debag","debby","debel","debes","debts","debud","debur","debus","debye","decad","decaf","decan","decko","decks","decos","dedal","deeds","deedy","deely","deems","deens","deeps","deere","deers","deets","deeve","deevs","defat","deffo","defis","defog","degas","degum","degus","deice","deids","deify","deils","deism","deist","deked","dekes","dekko","deled","deles","delfs","delft","delis","dells","delly","delos","delph","delts","deman","demes","demic","demit","demob","demoi","demos","dempt","denar","denay","dench","denes","denet","denis","dents","deoxy","derat","deray","dered","deres","derig","derma","derms","derns","derny","deros","derro","derry","derth","dervs","desex","deshi","desis","desks","desse","devas","devel","devis","devon","devos","devot","dewan","dewar","dewax","dewed","dexes","dexie","dhaba","dhaks","dhals","dhikr","dhobi","dhole","dholl","dhols","dhoti","dhows","dhuti","diact","dials","diane","diazo","dibbs","diced","dicer","dices","dicht","dicks","dicky","dicot","dicta","dicts","dicty","diddy","didie","didos","didst","diebs","diels","diene","diets","diffs","dight","dikas","diked","diker","dikes","dikey","dildo","dilli","dills","dimbo","dimer","dimes","dimps","dinar","dined","dines","dinge","dings","dinic","dinks","dinky","dinna","dinos","dints","diols","diota","dippy","dipso","diram","direr","dirke","dirks","dirls","dirts","disas","disci","discs","dishy","disks","disme","dital","ditas","dited","dites","ditsy","ditts","ditzy","divan","divas","dived","dives","divis","divna","divos","divot","divvy","diwan","dixie","dixit","diyas","dizen","djinn","djins","doabs","doats","dobby","dobes","dobie","dobla","dobra","dobro","docht","docks","docos","docus","doddy","dodos","doeks","doers","doest","doeth","doffs","dogan","doges","dogey","doggo","doggy","dogie","dohyo","doilt","doily","doits","dojos","dolce","dolci","doled","doles","dolia","dolls","dolma","dolor","dolos","dolts","domal","domed","domes","domic","donah","donas","donee","doner","donga","dongs","donko","donna","donne","donny","donsy","doobs","dooce","doody","dooks","doole","dools","dooly","dooms","doomy","doona","doorn","doors","doozy","dopas","doped","doper","dopes","dorad","dorba","dorbs","doree","dores","doric","doris","dorks","dorky","dorms","dormy","dorps","dorrs","dorsa","dorse","dorts","dorty","dosai","dosas","dosed","doseh","doser","doses","dosha","dotal","doted","doter","dotes","dotty","douar","douce","doucs","douks","doula","douma","doums","doups","doura","douse","douts","doved","doven","dover","doves","dovie","dowar","dowds","dowed","dower","dowie","dowle","dowls","dowly","downa","downs","dowps","dowse","dowts","doxed","doxes","doxie","doyen","doyly","dozed","dozer","dozes","drabs","drack","draco","draff","drags","drail","drams","drant","draps","drats","drave","draws","drays","drear","dreck","dreed","dreer","drees","dregs","dreks","drent","drere","drest","dreys","dribs","drice","dries","drily","drips","dript","droid","droil","droke","drole","drome","drony","droob","droog","drook","drops","dropt","drouk","drows","drubs","drugs","drums","drupe","druse","drusy","druxy","dryad","dryas","dsobo","dsomo","duads","duals","duans","duars","dubbo","ducal","ducat","duces","ducks","ducky","ducts","duddy","duded","dudes","duels","duets","duett","duffs","dufus","duing","duits","dukas","duked","dukes","dukka","dulce","dules","dulia","dulls","dulse","dumas","dumbo","dumbs","dumka","dumky","dumps","dunam","dunch","dunes","dungs","dungy","dunks","dunno","dunny","dunsh","dunts","duomi","duomo","duped","duper","dupes","duple","duply","duppy","dural","duras","dured","dures","durgy","durns","duroc","duros","duroy","durra","durrs","durry","durst","durum","durzi","dusks","dusts","duxes","dwaal","dwale","dwalm","dwams","dwang","dwaum","dweeb","dwile","dwine","dyads","dyers","dykon","dynel","dynes","dzhos","eagre","ealed","eales","eaned","eards","eared","earls","earns","earnt","earst","eased","easer","eases","easle","easts","eathe","eaved","eaves","ebbed","ebbet","ebons","ebook","ecads","eched","eches","echos","ecrus","edema","edged","edger","edges","edile","edits","educe","educt","eejit","eensy","eeven","eevns","effed","egads","egers","egest","eggar","egged","egger","egmas","ehing","eider","eidos","eigne","eiked","eikon","eilds","eisel","ejido","ekkas","elain","eland","elans","elchi","eldin","elemi","elfed","eliad","elint","elmen","eloge","elogy","eloin","elops","elpee","elsin","elute","elvan","elven","elver","elves","emacs","embar","embay","embog","embow","embox","embus","emeer","emend","emerg","emery","emeus","emics","emirs","emits","emmas","emmer","emmet","emmew","emmys","emoji","emong","emote","emove","empts","emule","emure","emyde","emyds","enarm","enate","ended","ender","endew","endue","enews","enfix","eniac","enlit","enmew","ennog","enoki","enols","enorm","enows","enrol","ensew","ensky","entia","enure","enurn","envoi","enzym","eorls","eosin","epact","epees","ephah","ephas","ephod","ephor","epics","epode","epopt","epris","eques","equid","erbia","erevs","ergon","ergos","ergot","erhus","erica","erick","erics","ering","erned","ernes","erose","erred","erses","eruct","erugo","eruvs","erven","ervil","escar","escot","esile","eskar","esker","esnes","esses","estoc","estop","estro","etage","etape","etats","etens","ethal","ethne","ethyl","etics","etnas","ettin","ettle","etuis","etwee","etyma","eughs","euked","eupad","euros","eusol","evens","evert","evets","evhoe","evils","evite","evohe","ewers","ewest","ewhow","ewked","exams","exeat","execs","exeem","exeme","exfil","exies","exine","exing","exits","exode","exome","exons","expat","expos","exude","exuls","exurb","eyass","eyers","eyots","eyras","eyres","eyrie","eyrir","ezine","fabby","faced","facer","faces","facia","facta","facts","faddy","faded","fader","fades","fadge","fados","faena","faery","faffs","faffy","fagin","faiks","fails","faine","fains","fairs","faked","faker","fakes","fakey","fakie","fakir","falaj","falls","famed","fames","fanal","fands","fanes","fanga","fango","fangs","fanks","fanon","fanos","fanum","faqir","farad","farci","farcy","fards","fared","farer","fares","farle","farls","farms","faros","farro","farse","farts","fasci","fasti","fasts","fated","fates","fatly","fatso","fatwa","faugh","fauld","fauns","faurd","fauts","fauve","favas","favel","faver","faves","favus","fawns","fawny","faxed","faxes","fayed","fayer","fayne","fayre","fazed","fazes","feals","feare","fears","feart","fease","feats","feaze","feces","fecht","fecit","fecks","fedex","feebs","feeds","feels","feens","feers","feese","feeze","fehme","feint","feist","felch","felid","fells","felly","felts","felty","femal","femes","femmy","fends","fendy","fenis","fenks","fenny","fents","feods","feoff","ferer","feres","feria","ferly","fermi","ferms","ferns","ferny","fesse","festa","fests","festy","fetas","feted","fetes","fetor","fetta","fetts","fetwa","feuar","feuds","feued","feyed","feyer","feyly","fezes","fezzy","fiars","fiats","fibro","fices","fiche","fichu","ficin","ficos","fides","fidge","fidos","fiefs","fient","fiere","fiers","fiest","fifed","fifer","fifes","fifis","figgy","figos","fiked","fikes","filar","filch","filed","files","filii","filks","fille","fillo","fills","filmi","films","filos","filum","finca","finds","fined","fines","finis","finks","finny","finos","fiord","fiqhs","fique","fired","firer","fires","firie","firks","firms","firns","firry","firth","fiscs","fisks","fists","fisty","fitch","fitly","fitna","fitte","fitts","fiver","fives","fixed","fixes","fixit","fjeld","flabs","flaff","flags","flaks","flamm","flams","flamy","flane","flans","flaps","flary","flats","flava","flawn","flaws","flawy","flaxy","flays","fleam","fleas","fleek","fleer","flees","flegs","fleme","fleur","flews","flexi","flexo","fleys","flics","flied","flies","flimp","flims","flips","flirs","flisk","flite","flits","flitt","flobs","flocs","floes","flogs","flong","flops","flors","flory","flosh","flota","flote","flows","flubs","flued","flues","fluey","fluky","flump","fluor","flurr","fluty","fluyt","flyby","flype","flyte","foals","foams","foehn","fogey","fogie","fogle","fogou","fohns","foids","foils","foins","folds","foley","folia","folic","folie","folks","folky","fomes","fonda","fonds","fondu","fones","fonly","fonts","foods","foody","fools","foots","footy","foram","forbs","forby","fordo","fords","forel","fores","forex","forks","forky","forme","forms","forts","forza","forze","fossa","fosse","fouat","fouds","fouer","fouet","foule","fouls","fount","fours","fouth","fovea","fowls","fowth","foxed","foxes","foxie","foyle","foyne","frabs","frack","fract","frags","fraim","franc","frape","fraps","frass","frate","frati","frats","fraus","frays","frees","freet","freit","fremd","frena","freon","frere","frets","fribs","frier","fries","frigs","frise","frist","frith","frits","fritt","frize","frizz","froes","frogs","frons","frore","frorn","frory","frosh","frows","frowy","frugs","frump","frush","frust","fryer","fubar","fubby","fubsy","fucks","fucus","fuddy","fudgy","fuels","fuero","fuffs","fuffy","fugal","fuggy","fugie","fugio","fugle","fugly","fugus","fujis","fulls","fumed","fumer","fumes","fumet","fundi","funds","fundy","fungo","fungs","funks","fural","furan","furca","furls","furol","furrs","furth","furze","furzy","fused","fusee","fusel","fuses","fusil","fusks","fusts","fusty","futon","fuzed","fuzee","fuzes","fuzil","fyces","fyked","fykes","fyles","fyrds","fytte","gabba","gabby","gable","gaddi","gades","gadge","gadid","gadis","gadje","gadjo","gadso","gaffs","gaged","gager","gages","gaids","gains","gairs","gaita","gaits","gaitt","gajos","galah","galas","galax","galea","galed","gales","galls","gally","galop","galut","galvo","gamas","gamay","gamba","gambe","gambo","gambs","gamed","games","gamey","gamic","gamin","gamme","gammy","gamps","ganch","gandy","ganef","ganev","gangs","ganja","ganof","gants","gaols","gaped","gaper","gapes","gapos","gappy","garbe","garbo","garbs","garda","gares","garis","garms","garni","garre","garth","garum","gases","gasps","gaspy","gasts","gatch","gated","gater","gates","gaths","gator","gauch","gaucy","gauds","gauje","gault","gaums","gaumy","gaups","gaurs","gauss","gauzy","gavot","gawcy","gawds","gawks","gawps","gawsy","gayal","gazal","gazar","gazed","gazes","gazon","gazoo","geals","geans","geare","gears","geats","gebur","gecks","geeks","geeps","geest","geist","geits","gelds","gelee","gelid","gelly","gelts","gemel","gemma","gemmy","gemot","genal","genas","genes","genet","genic","genii","genip","genny","genoa","genom","genro","gents","genty","genua","genus","geode","geoid","gerah","gerbe","geres","gerle","germs","germy","gerne","gesse","gesso","geste","gests","getas","getup","geums","geyan","geyer","ghast","ghats","ghaut","ghazi","ghees","ghest","ghyll","gibed","gibel","giber","gibes","gibli","gibus","gifts","gigas","gighe","gigot","gigue","gilas","gilds","gilet","gills","gilly","gilpy","gilts","gimel","gimme","gimps","gimpy","ginch","ginge","gings","ginks","ginny","ginzo","gipon","gippo","gippy","girds","girls","girns","giron","giros","girrs","girsh","girts","gismo","gisms","gists","gitch","gites","giust","gived","gives","gizmo","glace","glads","glady","glaik","glair","glams","glans","glary","glaum","glaur","glazy","gleba","glebe","gleby","glede","gleds","gleed","gleek","glees","gleet","gleis","glens","glent","gleys","glial","glias","glibs","gliff","glift","glike","glime","glims","glisk","glits","glitz","gloam","globi","globs","globy","glode","glogg","gloms","gloop","glops","glost","glout","glows","gloze","glued","gluer","glues","gluey","glugs","glume","glums","gluon","glute","gluts","gnarl","gnarr","gnars","gnats","gnawn","gnaws","gnows","goads","goafs","goals","goary","goats","goaty","goban","gobar","gobbi","gobbo","gobby","gobis","gobos","godet","godso","goels","goers","goest","goeth","goety","gofer","goffs","gogga","gogos","goier","gojis","golds","goldy","goles","golfs","golpe","golps","gombo","gomer","gompa","gonch","gonef","gongs","gonia","gonif","gonks","gonna","gonof","gonys","gonzo","gooby","goods","goofs","googs","gooky","goold","gools","gooly","goons","goony","goops","goopy","goors","goory","goosy","gopak","gopik","goral","goras","gored","gores","goris","gorms","gormy","gorps","gorse","gorsy","gosht","gosse","gotch","goths","gothy","gotta","gouch","gouks","goura","gouts","gouty","gowan","gowds","gowfs","gowks","gowls","gowns","goxes","goyim","goyle","graal","grabs","grads","graff","graip","grama","grame","gramp","grams","grana","grans","grapy","gravs","grays","grebe","grebo","grece","greek","grees","grege","grego","grein","grens","grese","greve","grews","greys","grice","gride","grids","griff","grift","grigs","grike","grins","griot","grips","gript","gripy","grise","grist","grisy","grith","grits","grize","groat","grody","grogs","groks","groma","grone","groof","grosz","grots","grouf","grovy","grows","grrls","grrrl","grubs","grued","grues","grufe","grume","grump","grund","gryce","gryde","gryke","grype","grypt","guaco","guana","guano","guans","guars","gucks","gucky","gudes","guffs","gugas","guids","guimp","guiro","gulag","gular","gulas","gules","gulet","gulfs","gulfy","gulls","gulph","gulps","gulpy","gumma","gummi","gumps","gundy","gunge","gungy","gunks","gunky","gunny","guqin","gurdy","gurge","gurls","gurly","gurns","gurry","gursh","gurus","gushy","gusla","gusle","gusli","gussy","gusts","gutsy","gutta","gutty","guyed","guyle","guyot","guyse","gwine","gyals","gyans","gybed","gybes","gyeld","gymps","gynae","gynie","gynny","gynos","gyoza","gypos","gyppo","gyppy","gyral","gyred","gyres","gyron","gyros","gyrus","gytes","gyved","gyves","haafs","haars","hable","habus","hacek","hacks","hadal","haded","hades","hadji","hadst","haems","haets","haffs","hafiz","hafts","haggs","hahas","haick","haika","haiks","haiku","hails","haily","hains","haint","hairs","haith","hajes","hajis","hajji","hakam","hakas","hakea","hakes","hakim","hakus","halal","haled","haler","hales","halfa","halfs","halid","hallo","halls","halma","halms","halon","halos","halse","halts","halva","halwa","hamal","hamba","hamed","hames","hammy","hamza","hanap","hance","hanch","hands","hangi","hangs","hanks","hanky","hansa","hanse","hants","haole","haoma","hapax","haply","happi","hapus","haram","hards","hared","hares","harim","harks","harls","harms","harns","haros","harps","harts","hashy","hasks","hasps","hasta","hated","hates","hatha","hauds","haufs","haugh","hauld","haulm","hauls","hault","hauns","hause","haver","haves","hawed","hawks","hawms","hawse","hayed","hayer","hayey","hayle","hazan","hazed","hazer","hazes","heads","heald","heals","heame","heaps","heapy","heare","hears","heast","heats","heben","hebes","hecht","hecks","heder","hedgy","heeds","heedy","heels","heeze","hefte","hefts","heids","heigh","heils","heirs","hejab","hejra","heled","heles","helio","hells","helms","helos","helot","helps","helve","hemal","hemes","hemic","hemin","hemps","hempy","hench","hends","henge","henna","henny","henry","hents","hepar","herbs","herby","herds","heres","herls","herma","herms","herns","heros","herry","herse","hertz","herye","hesps","hests","hetes","heths","heuch","heugh","hevea","hewed","hewer","hewgh","hexad","hexed","hexer","hexes","hexyl","heyed","hiant","hicks","hided","hider","hides","hiems","highs","hight","hijab","hijra","hiked","hiker","hikes","hikoi","hilar","hilch","hillo","hills","hilts","hilum","hilus","himbo","hinau","hinds","hings","hinky","hinny","hints","hiois","hiply","hired","hiree","hirer","hires","hissy","hists","hithe","hived","hiver","hives","hizen","hoaed","hoagy","hoars","hoary","hoast","hobos","hocks","hocus","hodad","hodja","hoers","hogan","hogen","hoggs","hoghs","hohed","hoick","hoied","hoiks","hoing","hoise","hokas","hoked","hokes","hokey","hokis","hokku","hokum","holds","holed","holes","holey","holks","holla","hollo","holme","holms","holon","holos","holts","homas","homed","homes","homey","homie","homme","honan","honda","honds","honed","honer","hones","hongi","hongs","honks","honky","hooch","hoods","hoody","hooey","hoofs","hooka","hooks","hooky","hooly","hoons","hoops","hoord","hoors","hoosh","hoots","hooty","hoove","hopak","hoped","hoper","hopes","hoppy","horah","horal","horas","horis","horks","horme","horns","horst","horsy","hosed","hosel","hosen","hoser","hoses","hosey","hosta","hosts","hotch","hoten","hotty","houff","houfs","hough","houri","hours","houts","hovea","hoved","hoven","hoves","howbe","howes","howff","howfs","howks","howls","howre","howso","hoxed","hoxes","hoyas","hoyed","hoyle","hubby","hucks","hudna","hudud","huers","huffs","huffy","huger","huggy","huhus","huias","hulas","hules","hulks","hulky","hullo","hulls","hully","humas","humfs","humic","humps","humpy","hunks","hunts","hurds","hurls","hurly","hurra","hurst","hurts","hushy","husks","husos","hutia","huzza","huzzy","hwyls","hydra","hyens","hygge","hying","hykes","hylas","hyleg","hyles","hylic","hymns","hynde","hyoid","hyped","hypes","hypha","hyphy","hypos","hyrax","hyson","hythe","iambi","iambs","ibrik","icers","iched","iches","ichor","icier","icker","ickle","icons","ictal","ictic","ictus","idant","ideas","idees","ident","idled","idles","idola","idols","idyls","iftar","igapo","igged","iglus","ihram","ikans","ikats","ikons","ileac","ileal","ileum","ileus","iliad","ilial","ilium","iller","illth","imago","imams","imari","imaum","imbar","imbed","imide","imido","imids","imine","imino","immew","immit","immix","imped","impis","impot","impro","imshi","imshy","inapt","inarm","inbye","incel","incle","incog","incus","incut","indew","india","indie","indol","indow","indri","indue","inerm","infix","infos","infra","ingan","ingle","inion","inked","inker","inkle","inned","innit","inorb","inrun","inset","inspo","intel","intil","intis","intra","inula","inure","inurn","inust","invar","inwit","iodic","iodid","iodin","iotas","ippon","irade","irids","iring","irked","iroko","irone","irons","isbas","ishes","isled","isles","isnae","issei","istle","items","ither","ivied","ivies","ixias","ixnay","ixora","ixtle","izard","izars","izzat","jaaps","jabot","jacal","jacks","jacky","jaded","jades","jafas","jaffa","jagas","jager","jaggs","jaggy","jagir","jagra","jails","jaker","jakes","jakey","jalap","jalop","jambe","jambo","jambs","jambu","james","jammy","jamon","janes","janns","janny","janty","japan","japed","japer","japes","jarks","jarls","jarps","jarta","jarul","jasey","jaspe","jasps","jatos","jauks","jaups","javas","javel","jawan","jawed","jaxie","jeans","jeats","jebel","jedis","jeels","jeely","jeeps","jeers","jeeze","jefes","jeffs","jehad","jehus","jelab","jello","jells","jembe","jemmy","jenny","jeons","jerid","jerks","jerry","jesse","jests","jesus","jetes","jeton","jeune","jewed","jewie","jhala","jiaos","jibba","jibbs","jibed","jiber","jibes","jiffs","jiggy","jigot","jihad","jills","jilts","jimmy","jimpy","jingo","jinks","jinne","jinni","jinns","jirds","jirga","jirre","jisms","jived","jiver","jives","jivey","jnana","jobed","jobes","jocko","jocks","jocky","jocos","jodel","joeys","johns","joins","joked","jokes","jokey","jokol","joled","joles","jolls","jolts","jolty","jomon","jomos","jones","jongs","jonty","jooks","joram","jorum","jotas","jotty","jotun","joual","jougs","jouks","joule","jours","jowar","jowed","jowls","jowly","joyed","jubas","jubes","jucos","judas","judgy","judos","jugal","jugum","jujus","juked","jukes","jukus","julep","jumar","jumby","jumps","junco","junks","junky","jupes","jupon","jural","jurat","jurel","jures","justs","jutes","jutty","juves","juvie","kaama","kabab","kabar","kabob","kacha","kacks","kadai","kades","kadis","kafir","kagos","kagus","kahal","kaiak","kaids","kaies","kaifs","kaika","kaiks","kails","kaims","kaing","kains","kakas","kakis","kalam","kales","kalif","kalis","kalpa","kamas","kames","kamik","kamis","kamme","kanae","kanas","kandy","kaneh","kanes","kanga","kangs","kanji","kants","kanzu","kaons","kapas","kaphs","kapok","kapow","kapus","kaput","karas","karat","karks","karns","karoo","karos","karri","karst","karsy","karts","karzy","kasha","kasme","katal","katas","katis","katti","kaugh","kauri","kauru","kaury","kaval","kavas","kawas","kawau","kawed","kayle","kayos","kazis","kazoo","kbars","kebar","kebob","kecks","kedge","kedgy","keech","keefs","keeks","keels","keema","keeno","keens","keeps","keets","keeve","kefir","kehua","keirs","kelep","kelim","kells","kelly","kelps","kelpy","kelts","kelty","kembo","kembs","kemps","kempt","kempy","kenaf","kench","kendo","kenos","kente","kents","kepis","kerbs","kerel","kerfs","kerky","kerma","kerne","kerns","keros","kerry","kerve","kesar","kests","ketas","ketch","ketes","ketol","kevel","kevil","kexes","keyed","keyer","khadi","khafs","khans","khaph","khats","khaya","khazi","kheda","kheth","khets","khoja","khors","khoum","khuds","kiaat","kiack","kiang","kibbe","kibbi","kibei","kibes","kibla","kicks","kicky","kiddo","kiddy","kidel","kidge","kiefs","kiers","kieve","kievs","kight","kikoi","kiley","kilim","kills","kilns","kilos","kilps","kilts","kilty","kimbo","kinas","kinda","kinds","kindy","kines","kings","kinin","kinks","kinos","kiore","kipes","kippa","kipps","kirby","kirks","kirns","kirri","kisan","kissy","kists","kited","kiter","kites","kithe","kiths","kitul","kivas","kiwis","klang","klaps","klett","klick","klieg","kliks","klong","kloof","kluge","klutz","knags","knaps","knarl","knars","knaur","knawe","knees","knell","knish","knits","knive","knobs","knops","knosp","knots","knout","knowe","knows","knubs","knurl","knurr","knurs","knuts","koans","koaps","koban","kobos","koels","koffs","kofta","kogal","kohas","kohen","kohls","koine","kojis","kokam","kokas","koker","kokra","kokum","kolas","kolos","kombu","konbu","kondo","konks","kooks","kooky","koori","kopek","kophs","kopje","koppa","korai","koras","korat","kores","korma","koros","korun","korus","koses","kotch","kotos","kotow","koura","kraal","krabs","kraft","krais","krait","krang","krans","kranz","kraut","krays","kreep","kreng","krewe","krona","krone","kroon","krubi","krunk","ksars","kubie","kudos","kudus","kudzu","kufis","kugel","kuias","kukri","kukus","kulak","kulan","kulas","kulfi","kumis","kumys","kuris","kurre","kurta","kurus","kusso","kutas","kutch","kutis","kutus","kuzus","kvass","kvell","kwela","kyack","kyaks","kyang","kyars","kyats","kybos","kydst","kyles","kylie","kylin","kylix","kyloe","kynde","kynds","kypes","kyrie","kytes","kythe","laari","labda","labia","labis","labra","laced","lacer","laces","lacet","lacey","lacks","laddy","laded","lader","lades","laers","laevo","lagan","lahal","lahar","laich","laics","laids","laigh","laika","laiks","laird","lairs","lairy","laith","laity","laked","laker","lakes","lakhs","lakin","laksa","laldy","lalls","lamas","lambs","lamby","lamed","lamer","lames","lamia","lammy","lamps","lanai","lanas","lanch","lande","lands","lanes","lanks","lants","lapin","lapis","lapje","larch","lards","lardy","laree","lares","largo","laris","larks","larky","larns","larnt","larum","lased","laser","lases","lassi","lassu","lassy","lasts","latah","lated","laten","latex","lathi","laths","lathy","latke","latus","lauan","lauch","lauds","laufs","laund","laura","laval","lavas","laved","laver","laves","lavra","lavvy","lawed","lawer","lawin","lawks","lawns","lawny","laxed","laxer","laxes","laxly","layed","layin","layup","lazar","lazed","lazes","lazos","lazzi","lazzo","leads","leady","leafs","leaks","leams","leans","leany","leaps","leare","lears","leary","leats","leavy","leaze","leben","leccy","ledes","ledgy","ledum","leear","leeks","leeps","leers","leese","leets","leeze","lefte","lefts","leger","leges","legge","leggo","legit","lehrs","lehua","leirs","leish","leman","lemed","lemel","lemes","lemma","lemme","lends","lenes","lengs","lenis","lenos","lense","lenti","lento","leone","lepid","lepra","lepta","lered","leres","lerps","leses","lests","letch","lethe","letup","leuch","leuco","leuds","leugh","levas","levee","leves","levin","levis","lewis","lexes","lexis","lezes","lezza","lezzy","liana","liane","liang","liard","liars","liart","liber","libra","libri","lichi","licht","licit","licks","lidar","lidos","liefs","liens","liers","lieus","lieve","lifer","lifes","lifts","ligan","liger","ligge","ligne","liked","liker","likes","likin","lills","lilos","lilts","liman","limas","limax","limba","limbi","limbs","limby","limed","limen","limes","limey","limma","limns","limos","limpa","limps","linac","linch","linds","lindy","lined","lines","liney","linga","lings","lingy","linin","links","linky","linns","linny","linos","lints","linty","linum","linux","lions","lipas","lipes","lipin","lipos","lippy","liras","lirks","lirot","lisks","lisle","lisps","lists","litai","litas","lited","liter","lites","litho","liths","litre","lived","liven","lives","livor","livre","llano","loach","loads","loafs","loams","loans","loast","loave","lobar","lobed","lobes","lobos","lobus","loche","lochs","locie","locis","locks","locos","locum","loden","lodes","loess","lofts","logan","loges","loggy","logia","logie","logoi","logon","logos","lohan","loids","loins","loipe","loirs","lokes","lolls","lolly","lolog","lomas","lomed","lomes","loner","longa","longe","longs","looby","looed","looey","loofa","loofs","looie","looks","looky","looms","loons","loony","loops","loord","loots","loped","loper","lopes","loppy","loral","loran","lords","lordy","lorel","lores","loric","loris","losed","losel","losen","loses","lossy","lotah","lotas","lotes","lotic","lotos","lotsa","lotta","lotte","lotto","lotus","loued","lough","louie","louis","louma","lound","louns","loupe","loups","loure","lours","loury","louts","lovat","loved","loves","lovey","lovie","lowan","lowed","lowes","lownd","lowne","lowns","lowps","lowry","lowse","lowts","loxed","loxes","lozen","luach","luaus","lubed","lubes","lubra","luces","lucks","lucre","ludes","ludic","ludos","luffa","luffs","luged","luger","luges","lulls","lulus","lumas","lumbi","lumme","lummy","lumps","lunas","lunes","lunet","lungi","lungs","lunks","lunts","lupin","lured","lurer","lures","lurex","lurgi","lurgy","lurks","lurry","lurve","luser","lushy","lusks","lusts","lusus","lutea","luted","luter","lutes","luvvy","luxed","luxer","luxes","lweis","lyams","lyard","lyart","lyase","lycea","lycee","lycra","lymes","lynes","lyres","lysed","lyses","lysin","lysis","lysol","lyssa","lyted","lytes","lythe","lytic","lytta","maaed","maare","maars","mabes","macas","maced","macer","maces","mache","machi","machs","macks","macle","macon","madge","madid","madre","maerl","mafic","mages","maggs","magot","magus","mahoe","mahua","mahwa","maids","maiko","maiks","maile","maill","mails","maims","mains","maire","mairs","maise","maist","makar","makes","makis","makos","malam","malar","malas","malax","males","malic","malik","malis","malls","malms","malmy","malts","malty","malus","malva","malwa","mamas","mamba","mamee","mamey","mamie","manas","manat","mandi","maneb","maned","maneh","manes","manet","mangs","manis","manky","manna","manos","manse","manta","manto","manty","manul","manus","mapau","maqui","marae","marah","maras","marcs","mardy","mares","marge","margs","maria","marid","marka","marks","marle","marls","marly","marms","maron","maror","marra","marri","marse","marts","marvy","masas","mased","maser","mases","mashy","masks","massa","massy","masts","masty","masus","matai","mated","mater","mates","maths","matin","matlo","matte","matts","matza","matzo","mauby","mauds","mauls","maund","mauri","mausy","mauts","mauzy","maven","mavie","mavin","mavis","mawed","mawks","mawky","mawns","mawrs","maxed","maxes","maxis","mayan","mayas","mayed","mayos","mayst","mazed","mazer","mazes","mazey","mazut","mbira","meads","meals","meane","means","meany","meare","mease","meath","meats","mebos","mechs","mecks","medii","medle","meeds","meers","meets","meffs","meins","meint","meiny","meith","mekka","melas","melba","melds","melic","melik","mells","melts","melty","memes","memos","menad","mends","mened","menes","menge","mengs","mensa","mense","mensh","menta","mento","menus","meous","meows","merch","mercs","merde","mered","merel","merer","meres","meril","meris","merks","merle","merls","merse","mesal","mesas","mesel","meses","meshy","mesic","mesne","meson","messy","mesto","meted","metes","metho","meths","metic","metif","metis","metol","metre","meuse","meved","meves","mewed","mewls","meynt","mezes","mezze","mezzo","mhorr","miaou","miaow","miasm","miaul","micas","miche","micht","micks","micky","micos","micra","middy","midgy","midis","miens","mieve","miffs","miffy","mifty","miggs","mihas","mihis","miked","mikes","mikra","mikva","milch","milds","miler","miles","milfs","milia","milko","milks","mille","mills","milor","milos","milpa","milts","milty","miltz","mimed","mimeo","mimer","mimes","mimsy","minae","minar","minas","mincy","minds","mined","mines","minge","mings","mingy","minis","minke","minks","minny","minos","mints","mired","mires","mirex","mirid","mirin","mirks","mirky","mirly","miros","mirvs","mirza","misch","misdo","mises","misgo","misos","missa","mists","misty","mitch","miter","mites","mitis","mitre","mitts","mixed","mixen","mixer","mixes","mixte","mixup","mizen","mizzy","mneme","moans","moats","mobby","mobes","mobey","mobie","moble","mochi","mochs","mochy","mocks","moder","modes","modge","modii","modus","moers","mofos","moggy","mohel","mohos","mohrs","mohua","mohur","moile","moils","moira","moire","moits","mojos","mokes","mokis","mokos","molal","molas","molds","moled","moles","molla","molls","molly","molto","molts","molys","momes","momma","mommy","momus","monad","monal","monas","monde","mondo","moner","mongo","mongs","monic","monie","monks","monos","monte","monty","moobs","mooch","moods","mooed","mooks","moola","mooli","mools","mooly","moong","moons","moony","moops","moors","moory","moots","moove","moped","moper","mopes","mopey","moppy","mopsy","mopus","morae","moras","morat","moray","morel","mores","moria","morne","morns","morra","morro","morse","morts","mosed","moses","mosey","mosks","mosso","moste","mosts","moted","moten","motes","motet","motey","moths","mothy","motis","motte","motts","motty","motus","motza","mouch","moues","mould","mouls","moups","moust","mousy","moved","moves","mowas","mowed","mowra","moxas","moxie","moyas","moyle","moyls","mozed","mozes","mozos","mpret","mucho","mucic","mucid","mucin","mucks","mucor","mucro","mudge","mudir","mudra","muffs","mufti","mugga","muggs","muggy","muhly","muids","muils","muirs","muist","mujik","mulct","muled","mules","muley","mulga","mulie","mulla","mulls","mulse","mulsh","mumms","mumps","mumsy","mumus","munga","munge","mungo","mungs","munis","munts","muntu","muons","muras","mured","mures","murex","murid","murks","murls","murly","murra","murre","murri","murrs","murry","murti","murva","musar","musca","mused","muser","muses","muset","musha","musit","musks","musos","musse","mussy","musth","musts","mutch","muted","muter","mutes","mutha","mutis","muton","mutts","muxed","muxes","muzak","muzzy","mvule","myall","mylar","mynah","mynas","myoid","myoma","myope","myops","myopy","mysid","mythi","myths","mythy","myxos","mzees","naams","naans","nabes","nabis","nabks","nabla","nabob","nache","nacho","nacre","nadas","naeve","naevi","naffs","nagas","naggy","nagor","nahal","naiad","naifs","naiks","nails","naira","nairu","naked","naker","nakfa","nalas","naled","nalla","named","namer","names","namma","namus","nanas","nance","nancy","nandu","nanna","nanos","nanua","napas","naped","napes","napoo","nappa","nappe","nappy","naras","narco","narcs","nards","nares","naric","naris","narks","narky","narre","nashi","natch","nates","natis","natty","nauch","naunt","navar","naves","navew","navvy","nawab","nazes","nazir","nazis","nduja","neafe","neals","neaps","nears","neath","neats","nebek","nebel","necks","neddy","needs","neeld","neele","neemb","neems","neeps","neese","neeze","negro","negus","neifs","neist","neive","nelis","nelly","nemas","nemns","nempt","nenes","neons","neper","nepit","neral","nerds","nerka","nerks","nerol","nerts","nertz","nervy","nests","netes","netop","netts","netty","neuks","neume","neums","nevel","neves","nevus","newbs","newed","newel","newie","newsy","newts","nexts","nexus","ngaio","ngana","ngati","ngoma","ngwee","nicad","nicht","nicks","nicol","nidal","nided","nides","nidor","nidus","niefs","nieve","nifes","niffs","niffy","nifty","niger","nighs","nihil","nikab","nikah","nikau","nills","nimbi","nimbs","nimps","niner","nines","ninon","nipas","nippy","niqab","nirls","nirly","nisei","nisse","nisus","niter","nites","nitid","niton","nitre","nitro","nitry","nitty","nival","nixed","nixer","nixes","nixie","nizam","nkosi","noahs","nobby","nocks","nodal","noddy","nodes","nodus","noels","noggs","nohow","noils","noily","noint","noirs","noles","nolls","nolos","nomas","nomen","nomes","nomic","nomoi","nomos","nonas","nonce","nones","nonet","nongs","nonis","nonny","nonyl","noobs","nooit","nooks","nooky","noons","noops","nopal","noria","noris","norks","norma","norms","nosed","noser","noses","notal","noted","noter","notes","notum","nould","noule","nouls","nouns","nouny","noups","novae","novas","novum","noway","nowed","nowls","nowts","nowty","noxal","noxes","noyau","noyed","noyes","nubby","nubia","nucha","nuddy","nuder","nudes","nudie","nudzh","nuffs","nugae","nuked","nukes","nulla","nulls","numbs","numen","nummy","nunny","nurds","nurdy","nurls","nurrs","nutso","nutsy","nyaff","nyala","nying","nyssa","oaked","oaker","oakum","oared","oases","oasis","oasts","oaten","oater","oaths","oaves","obang","obeah","obeli","obeys","obias","obied","obiit","obits","objet","oboes","obole","oboli","obols","occam","ocher","oches","ochre","ochry","ocker","ocrea","octad","octan","octas","octyl","oculi","odahs","odals","odeon","odeum","odism","odist","odium","odors","odour","odyle","odyls","ofays","offed","offie","oflag","ofter","ogams","ogeed","ogees","oggin","ogham","ogive","ogled","ogler","ogles","ogmic","ogres","ohias","ohing","ohmic","ohone","oidia","oiled","oiler","oinks","oints","ojime","okapi","okays","okehs","okras","oktas","oldie","oleic","olein","olent","oleos","oleum","olios","ollas","ollav","oller","ollie","ology","olpae","olpes","omasa","omber","ombus","omens","omers","omits","omlah","omovs","omrah","oncer","onces","oncet","oncus","onely","oners","onery","onium","onkus","onlay","onned","ontic","oobit","oohed","oomph","oonts","ooped","oorie","ooses","ootid","oozed","oozes","opahs","opals","opens","opepe","oping","oppos","opsin","opted","opter","orach","oracy","orals","orang","orant","orate","orbed","orcas","orcin","ordos","oread","orfes","orgia","orgic","orgue","oribi","oriel","orixa","orles","orlon","orlop","ormer","ornis","orpin","orris","ortho","orval","orzos","oscar","oshac","osier","osmic","osmol","ossia","ostia","otaku","otary","ottar","ottos","oubit","oucht","ouens","ouija","oulks","oumas","oundy","oupas","ouped","ouphe","ouphs","ourie","ousel","ousts","outby","outed","outre","outro","outta","ouzel","ouzos","ovals","ovels","ovens","overs","ovist","ovoli","ovolo","ovule","owche","owies","owled","owler","owlet","owned","owres","owrie","owsen","oxbow","oxers","oxeye","oxids","oxies","oxime","oxims","oxlip","oxter","oyers","ozeki","ozzie","paals","paans","pacas","paced","pacer","paces","pacey","pacha","packs","pacos","pacta","pacts","padis","padle","padma","padre","padri","paean","paedo","paeon","paged","pager","pages","pagle","pagod","pagri","paiks","pails","pains","paire","pairs","paisa","paise","pakka","palas","palay","palea","paled","pales","palet","palis","palki","palla","palls","pally","palms","palmy","palpi","palps","palsa","pampa","panax","pance","panda","pands","pandy","paned","panes","panga","pangs","panim","panko","panne","panni","panto","pants","panty","paoli","paolo","papas","papaw","papes","pappi","pappy","parae","paras","parch","pardi","pards","pardy","pared","paren","pareo","pares","pareu","parev","parge","pargo","paris","parki","parks","parky","parle","parly","parma","parol","parps","parra","parrs","parti","parts","parve","parvo","paseo","pases","pasha","pashm","paska","paspy","passe","pasts","pated","paten","pater","pates","paths","patin","patka","patly","patte","patus","pauas","pauls","pavan","paved","paven","paver","paves","pavid","pavin","pavis","pawas","pawaw","pawed","pawer","pawks","pawky","pawls","pawns","paxes","payed","payor","paysd","peage","peags","peaks","peaky","peals","peans","peare","pears","peart","pease","peats","peaty","peavy","peaze","pebas","pechs","pecke","pecks","pecky","pedes","pedis","pedro","peece","peeks","peels","peens","peeoy","peepe","peeps","peers","peery","peeve","peggy","peghs","peins","peise","peize","pekan","pekes","pekin","pekoe","pelas","pelau","peles","pelfs","pells","pelma","pelon","pelta","pelts","pends","pendu","pened","penes","pengo","penie","penis","penks","penna","penni","pents","peons","peony","pepla","pepos","peppy","pepsi","perai","perce","percs","perdu","perdy","perea","peres","peris","perks","perms","perns","perog","perps","perry","perse","perst","perts","perve","pervo","pervs","pervy","pesos","pests","pesty","petar","peter","petit","petre","petri","petti","petto","pewee","pewit","peyse","phage","phang","phare","pharm","pheer","phene","pheon","phese","phial","phish","phizz","phlox","phoca","phono","phons","phots","phpht","phuts","phyla","phyle","piani","pians","pibal","pical","picas","piccy","picks","picot","picra","picul","piend","piers","piert","pieta","piets","piezo","pight","pigmy","piing","pikas","pikau","piked","piker","pikes","pikey","pikis","pikul","pilae","pilaf","pilao","pilar","pilau","pilaw","pilch","pilea","piled","pilei","piler","piles","pilis","pills","pilow","pilum","pilus","pimas","pimps","pinas","pined","pines","pingo","pings","pinko","pinks","pinna","pinny","pinon","pinot","pinta","pints","pinup","pions","piony","pious","pioye","pioys","pipal","pipas","piped","pipes","pipet","pipis","pipit","pippy","pipul","pirai","pirls","pirns","pirog","pisco","pises","pisky","pisos","pissy","piste","pitas","piths","piton","pitot","pitta","piums","pixes","pized","pizes","plaas","plack","plage","plans","plaps","plash","plasm","plast","plats","platt","platy","playa","plays","pleas","plebe","plebs","plena","pleon","plesh","plews","plica","plies","plims","pling","plink","ploat","plods","plong","plonk","plook","plops","plots","plotz","plouk","plows","ploye","ploys","plues","pluff","plugs","plums","plumy","pluot","pluto","plyer","poach","poaka","poake","poboy","pocks","pocky","podal","poddy","podex","podge","podgy","podia","poems","poeps","poets","pogey","pogge","pogos","pohed","poilu","poind","pokal","poked","pokes","pokey","pokie","poled","poler","poles","poley","polio","polis","polje","polks","polls","polly","polos","polts","polys","pombe","pomes","pommy","pomos","pomps","ponce","poncy","ponds","pones","poney","ponga","pongo","pongs","pongy","ponks","ponts","ponty","ponzu","poods","pooed","poofs","poofy","poohs","pooja","pooka","pooks","pools","poons","poops","poopy","poori","poort","poots","poove","poovy","popes","poppa","popsy","porae","poral","pored","porer","pores","porge","porgy","porin","porks","porky","porno","porns","porny","porta","ports","porty","posed","poses","posey","posho","posts","potae","potch","poted","potes","potin","potoo","potsy","potto","potts","potty","pouff","poufs","pouke","pouks","poule","poulp","poult","poupe","poupt","pours","pouts","powan","powin","pownd","powns","powny","powre","poxed","poxes","poynt","poyou","poyse","pozzy","praam","prads","prahu","prams","prana","prang","praos","prase","prate","prats","pratt","praty","praus","prays","predy","preed","prees","preif","prems","premy","prent","preon","preop","preps","presa","prese","prest","preve","prexy","preys","prial","pricy","prief","prier","pries","prigs","prill","prima","primi","primp","prims","primy","prink","prion","prise","priss","proas","probs","prods","proem","profs","progs","proin","proke","prole","proll","promo","proms","pronk","props","prore","proso","pross","prost","prosy","proto","proul","prows","proyn","prunt","pruta","pryer","pryse","pseud","pshaw","psion","psoae","psoai","psoas","psora","psych","psyop","pubco","pubes","pubis","pucan","pucer","puces","pucka","pucks","puddy","pudge","pudic","pudor","pudsy","pudus","puers","puffa","puffs","puggy","pugil","puhas","pujah","pujas","pukas","puked","puker","pukes","pukey","pukka","pukus","pulao","pulas","puled","puler","pules","pulik","pulis","pulka","pulks","pulli","pulls","pully","pulmo","pulps","pulus","pumas","pumie","pumps","punas","punce","punga","pungs","punji","punka","punks","punky","punny","punto","punts","punty","pupae","pupas","pupus","purda","pured","pures","purin","puris","purls","purpy","purrs","pursy","purty","puses","pusle","putid","puton","putti","putto","putts","puzel","pwned","pyats","pyets","pygal","pyins","pylon","pyned","pynes","pyoid","pyots","pyral","pyran","pyres","pyrex","pyric","pyros","pyxed","pyxes","pyxie","pyxis","pzazz","qadis","qaids","qajaq","qanat","qapik","qibla","qophs","qorma","quads","quaff","quags","quair","quais","quaky","quale","quant","quare","quass","quate","quats","quayd","quays","qubit","quean","queme","quena","quern","queyn","queys","quich","quids","quiff","quims","quina","quine","quino","quins","quint","quipo","quips","quipu","quire","quirt","quist","quits","quoad","quods","quoif","quoin","quoit","quoll","quonk","quops","qursh","quyte","rabat","rabic","rabis","raced","races","rache","racks","racon","radge","radix","radon","raffs","rafts","ragas","ragde","raged","ragee","rager","rages","ragga","raggs","raggy","ragis","ragus","rahed","rahui","raias","raids","raiks","raile","rails","raine","rains","raird","raita","raits","rajas","rajes","raked","rakee","raker","rakes","rakia","rakis","rakus","rales","ramal","ramee","ramet","ramie","ramin","ramis","rammy","ramps","ramus","ranas","rance","rands","ranee","ranga","rangi","rangs","rangy","ranid","ranis","ranke","ranks","rants","raped","raper","rapes","raphe","rappe","rared","raree","rares","rarks","rased","raser","rases","rasps","rasse","rasta","ratal","ratan","ratas","ratch","rated","ratel","rater","rates","ratha","rathe","raths","ratoo","ratos","ratus","rauns","raupo","raved","ravel","raver","raves","ravey","ravin","rawer","rawin","rawly","rawns","raxed","raxes","rayah","rayas","rayed","rayle","rayne","razed","razee","razer","razes","razoo","readd","reads","reais","reaks","realo","reals","reame","reams","reamy","reans","reaps","rears","reast","reata","reate","reave","rebbe","rebec","rebid","rebit","rebop","rebuy","recal","recce","recco","reccy","recit","recks","recon","recta","recti","recto","redan","redds","reddy","reded","redes","redia","redid","redip","redly","redon","redos","redox","redry","redub","redux","redye","reech","reede","reeds","reefs","reefy","reeks","reeky","reels","reens","reest","reeve","refed","refel","reffo","refis","refix","refly","refry","regar","reges","reggo","regie","regma","regna","regos","regur","rehem","reifs","reify","reiki","reiks","reink","reins","reird","reist","reive","rejig","rejon","reked","rekes","rekey","relet","relie","relit","rello","reman","remap","remen","remet","remex","remix","renay","rends","reney","renga","renig","renin","renne","renos","rente","rents","reoil","reorg","repeg","repin","repla","repos","repot","repps","repro","reran","rerig","resat","resaw","resay","resee","reses","resew","resid","resit","resod","resow","resto","rests","resty","resus","retag","retax","retem","retia","retie","retox","revet","revie","rewan","rewax","rewed","rewet","rewin","rewon","rewth","rexes","rezes","rheas","rheme","rheum","rhies","rhime","rhine","rhody","rhomb","rhone","rhumb","rhyne","rhyta","riads","rials","riant","riata","ribas","ribby","ribes","riced","ricer","rices","ricey","richt","ricin","ricks","rides","ridgy","ridic","riels","riems","rieve","rifer","riffs","rifte","rifts","rifty","riggs","rigol","riled","riles","riley","rille","rills","rimae","rimed","rimer","rimes","rimus","rinds","rindy","rines","rings","rinks","rioja","riots","riped","ripes","ripps","rises","rishi","risks","risps","risus","rites","ritts","ritzy","rivas","rived","rivel","riven","rives","riyal","rizas","roads","roams","roans","roars","roary","roate","robed","robes","roble","rocks","roded","rodes","roguy","rohes","roids","roils","roily","roins","roist","rojak","rojis","roked","roker","rokes","rolag","roles","rolfs","rolls","romal","roman","romeo","romps","ronde","rondo","roneo","rones","ronin","ronne","ronte","ronts","roods","roofs","roofy","rooks","rooky","rooms","roons","roops","roopy","roosa","roose","roots","rooty","roped","roper","ropes","ropey","roque","roral","rores","roric","rorid","rorie","rorts","rorty","rosed","roses","roset","roshi","rosin","rosit","rosti","rosts","rotal","rotan","rotas","rotch","roted","rotes","rotis","rotls","roton","rotos","rotte","rouen","roues","roule","rouls","roums","roups","roupy","roust","routh","routs","roved","roven","roves","rowan","rowed","rowel","rowen","rowie","rowme","rownd","rowth","rowts","royne","royst","rozet","rozit","ruana","rubai","rubby","rubel","rubes","rubin","ruble","rubli","rubus","ruche","rucks","rudas","rudds","rudes","rudie","rudis","rueda","ruers","ruffe","ruffs","rugae","rugal","ruggy","ruing","ruins","rukhs","ruled","rules","rumal","rumbo","rumen","rumes","rumly","rummy","rumpo","rumps","rumpy","runch","runds","runed","runes","rungs","runic","runny","runts","runty","rupia","rurps","rurus","rusas","ruses","rushy","rusks","rusma","russe","rusts","ruths","rutin","rutty","ryals","rybat","ryked","rykes","rymme","rynds","ryots","ryper","saags","sabal","sabed","saber","sabes","sabha","sabin","sabir","sable","sabot","sabra","sabre","sacks","sacra","saddo","sades","sadhe","sadhu","sadis","sados","sadza","safed","safes","sagas","sager","sages","saggy","sagos","sagum","saheb","sahib","saice","saick","saics","saids","saiga","sails","saims","saine","sains","sairs","saist","saith","sajou","sakai","saker","sakes","sakia","sakis","sakti","salal","salat","salep","sales","salet","salic","salix","salle","salmi","salol","salop","salpa","salps","salse","salto","salts","salue","salut","saman","samas","samba","sambo","samek","samel","samen","sames","samey","samfu","sammy","sampi","samps","sands","saned","sanes","sanga","sangh","sango","sangs","sanko","sansa","santo","sants","saola","sapan","sapid","sapor","saran","sards","sared","saree","sarge","sargo","sarin","saris","sarks","sarky","sarod","saros","sarus","saser","sasin","sasse","satai","satay","sated","satem","sates","satis","sauba","sauch","saugh","sauls","sault","saunt","saury","sauts","saved","saver","saves","savey","savin","sawah","sawed","sawer","saxes","sayed","sayer","sayid","sayne","sayon","sayst","sazes","scabs","scads","scaff","scags","scail","scala","scall","scams","scand","scans","scapa","scape","scapi","scarp","scars","scart","scath","scats","scatt","scaud","scaup","scaur","scaws","sceat","scena","scend","schav","schmo","schul","schwa","sclim","scody","scogs","scoog","scoot","scopa","scops","scots","scoug","scoup","scowp","scows","scrab","scrae","scrag","scran","scrat","scraw","scray","scrim","scrip","scrob","scrod","scrog","scrow","scudi","scudo","scuds","scuff","scuft","scugs","sculk","scull","sculp","sculs","scums","scups","scurf","scurs","scuse","scuta","scute","scuts","scuzz","scyes","sdayn","sdein","seals","seame","seams","seamy","seans","seare","sears","sease","seats","seaze","sebum","secco","sechs","sects","seder","sedes","sedge","sedgy","sedum","seeds","seeks","seeld","seels","seely","seems","seeps","seepy","seers","sefer","segar","segni","segno","segol","segos","sehri","seifs","seils","seine","seirs","seise","seism","seity","seiza","sekos","sekts","selah","seles","selfs","sella","selle","sells","selva","semee","semes","semie","semis","senas","sends","senes","sengi","senna","senor","sensa","sensi","sente","senti","sents","senvy","senza","sepad","sepal","sepic","sepoy","septa","septs","serac","serai","seral","sered","serer","seres","serfs","serge","seric","serin","serks","seron","serow","serra","serre","serrs","serry","servo","sesey","sessa","setae","setal","seton","setts","sewan","sewar","sewed","sewel","sewen","sewin","sexed","sexer","sexes","sexto","sexts","seyen","shads","shags","shahs","shako","shakt","shalm","shaly","shama","shams","shand","shans","shaps","sharn","shash","shaul","shawm","shawn","shaws","shaya","shays","shchi","sheaf","sheal","sheas","sheds","sheel","shend","shent","sheol","sherd","shere","shero","shets","sheva","shewn","shews","shiai","shiel","shier","shies","shill","shily","shims","shins","ships","shirr","shirs","shish","shiso","shist","shite","shits","shiur","shiva","shive","shivs","shlep","shlub","shmek","shmoe","shoat","shoed","shoer","shoes","shogi","shogs","shoji","shojo","shola","shool","shoon","shoos","shope","shops","shorl","shote","shots","shott","showd","shows","shoyu","shred","shris","shrow","shtik","shtum","shtup","shule","shuln","shuls","shuns","shura","shute","shuts","shwas","shyer","sials","sibbs","sibyl","sices","sicht","sicko","sicks","sicky","sidas","sided","sider","sides","sidha","sidhe","sidle","sield","siens","sient","sieth","sieur","sifts","sighs","sigil","sigla","signa","signs","sijos","sikas","siker","sikes","silds","siled","silen","siler","siles","silex","silks","sills","silos","silts","silty","silva","simar","simas","simba","simis","simps","simul","sinds","sined","sines","sings","sinhs","sinks","sinky","sinus","siped","sipes","sippy","sired","siree","sires","sirih","siris","siroc","sirra","sirup","sisal","sises","sista","sists","sitar","sited","sites","sithe","sitka","situp","situs","siver","sixer","sixes","sixmo","sixte","sizar","sized","sizel","sizer","sizes","skags","skail","skald","skank","skart","skats","skatt","skaws","skean","skear","skeds","skeed","skeef","skeen","skeer","skees","skeet","skegg","skegs","skein","skelf","skell","skelm","skelp","skene","skens","skeos","skeps","skers","skets","skews","skids","skied","skies","skiey","skimo","skims","skink","skins","skint","skios","skips","skirl","skirr","skite","skits","skive","skivy","sklim","skoal","skody","skoff","skogs","skols","skool","skort","skosh","skran","skrik","skuas","skugs","skyed","skyer","skyey","skyfs","skyre","skyrs","skyte","slabs","slade","slaes","slags","slaid","slake","slams","slane","slank","slaps","slart","slats","slaty","slaws","slays","slebs","sleds","sleer","slews","sleys","slier","slily","slims","slipe","slips","slipt","slish","slits","slive","sloan","slobs","sloes","slogs","sloid","slojd","slomo","sloom","sloot","slops","slopy","slorm","slots","slove","slows","sloyd","slubb","slubs","slued","slues","sluff","slugs","sluit","slums","slurb","slurs","sluse","slyer","slype","smaak","smaik","smalm","smalt","smarm","smaze","smeek","smees","smeik","smeke","smerk","smews","smirr","smirs","smits","smogs","smoko","smolt","smoor","smoot","smore","smorg","smout","smowt","smugs","smurs","smush","smuts","snabs","snafu","snags","snaps","snarf","snark","snars","snary","snash","snath","snaws","snead","sneap","snebs","sneck","sneds","sneed","snees","snell","snibs","snick","snies","snift","snigs","snips","snipy","snirt","snits","snobs","snods","snoek","snoep","snogs","snoke","snood","snook","snool","snoot","snots","snowk","snows","snubs","snugs","snush","snyes","soaks","soaps","soare","soars","soave","sobas","socas","soces","socko","socks","socle","sodas","soddy","sodic","sodom","sofar","sofas","softa","softs","softy","soger","sohur","soils","soily","sojas","sojus","sokah","soken","sokes","sokol","solah","solan","solas","solde","soldi","soldo","solds","soled","solei","soler","soles","solon","solos","solum","solus","soman","somas","sonce","sonde","sones","songs","sonly","sonne","sonny","sonse","sonsy","sooey","sooks","sooky","soole","sools","sooms","soops","soote","soots","sophs","sophy","sopor","soppy","sopra","soral","soras","sorbo","sorbs","sorda","sordo","sords","sored","soree","sorel","sorer","sores","sorex","sorgo","sorns","sorra","sorta","sorts","sorus","soths","sotol","souce","souct","sough","souks","souls","soums","soups","soupy","sours","souse","souts","sowar","sowce","sowed","sowff","sowfs","sowle","sowls","sowms","sownd","sowne","sowps","sowse","sowth","soyas","soyle","soyuz","sozin","spacy","spado","spaed","spaer","spaes","spags","spahi","spail","spain","spait","spake","spald","spale","spall","spalt","spams","spane","spang","spans","spard","spars","spart","spate","spats","spaul","spawl","spaws","spayd","spays","spaza","spazz","speal","spean","speat","specs","spect","speel","speer","speil","speir","speks","speld","spelk","speos","spets","speug","spews","spewy","spial","spica","spide","spier","spies","spiff","spifs","spile","spims","spina","spink","spins","spirt","spiry","spits","spitz","spivs","splay","splog","spode","spods","spoom","spoor","spoot","spork","sposh","spots","sprad","sprag","sprat","spred","sprew","sprit","sprod","sprog","sprue","sprug","spuds","spued","spuer","spues","spugs","spule","spume","spumy","spurs","sputa","spyal","spyre","squab","squaw","squeg","squid","squit","squiz","stabs","stade","stags","stagy","staig","stane","stang","staph","staps","starn","starr","stars","stats","staun","staws","stays","stean","stear","stedd","stede","steds","steek","steem","steen","steil","stela","stele","stell","steme","stems","stend","steno","stens","stent","steps","stept","stere","stets","stews","stewy","steys","stich","stied","sties","stilb","stile","stime","stims","stimy","stipa","stipe","stire","stirk","stirp","stirs","stive","stivy","stoae","stoai","stoas","stoat","stobs","stoep","stogy","stoit","stoln","stoma","stond","stong","stonk","stonn","stook","stoor","stope","stops","stopt","stoss","stots","stott","stoun","stoup","stour","stown","stowp","stows","strad","strae","strag","strak","strep","strew","stria","strig","strim","strop","strow","stroy","strum","stubs","stude","studs","stull","stulm","stumm","stums","stuns","stupa","stupe","sture","sturt","styed","styes","styli","stylo","styme","stymy","styre","styte","subah","subas","subby","suber","subha","succi","sucks","sucky","sucre","sudds","sudor","sudsy","suede","suent","suers","suete","suets","suety","sugan","sughs","sugos","suhur","suids","suint","suits","sujee","sukhs","sukuk","sulci","sulfa","sulfo","sulks","sulph","sulus","sumis","summa","sumos","sumph","sumps","sunis","sunks","sunna","sunns","sunup","supes","supra","surah","sural","suras","surat","surds","sured","sures","surfs","surfy","surgy","surra","sused","suses","susus","sutor","sutra","sutta","swabs","swack","swads","swage","swags","swail","swain","swale","swaly","swamy","swang","swank","swans","swaps","swapt","sward","sware","swarf","swart","swats","swayl","sways","sweal","swede","sweed","sweel","sweer","swees","sweir","swelt","swerf","sweys","swies","swigs","swile","swims","swink","swipe","swire","swiss","swith","swits","swive","swizz","swobs","swole","swoln","swops","swopt","swots","swoun","sybbe","sybil","syboe","sybow","sycee","syces","sycon","syens","syker","sykes","sylis","sylph","sylva","symar","synch","syncs","synds","syned","synes","synth","syped","sypes","syphs","syrah","syren","sysop","sythe","syver","taals","taata","taber","tabes","tabid","tabis","tabla","tabor","tabun","tabus","tacan","taces","tacet","tache","tacho","tachs","tacks","tacos","tacts","taels","tafia","taggy","tagma","tahas","tahrs","taiga","taigs","taiko","tails","tains","taira","taish","taits","tajes","takas","takes","takhi","takin","takis","takky","talak","talaq","talar","talas","talcs","talcy","talea","taler","tales","talks","talky","talls","talma","talpa","taluk","talus","tamal","tamed","tames","tamin","tamis","tammy","tamps","tanas","tanga","tangi","tangs","tanhs","tanka","tanks","tanky","tanna","tansy","tanti","tanto","tanty","tapas","taped","tapen","tapes","tapet","tapis","tappa","tapus","taras","tardo","tared","tares","targa","targe","tarns","taroc","tarok","taros","tarps","tarre","tarry","tarsi","tarts","tarty","tasar","tased","taser","tases","tasks","tassa","tasse","tasso","tatar","tater","tates","taths","tatie","tatou","tatts","tatus","taube","tauld","tauon","taupe","tauts","tavah","tavas","taver","tawai","tawas","tawed","tawer","tawie","tawse","tawts","taxed","taxer","taxes","taxis","taxol","taxon","taxor","taxus","tayra","tazza","tazze","teade","teads","teaed","teaks","teals","teams","tears","teats","teaze","techs","techy","tecta","teels","teems","teend","teene","teens","teeny","teers","teffs","teggs","tegua","tegus","tehrs","teiid","teils","teind","teins","telae","telco","teles","telex","telia","telic","tells","telly","teloi","telos","temed","temes","tempi","temps","tempt","temse","tench","tends","tendu","tenes","tenge","tenia","tenne","tenno","tenny","tenon","tents","tenty","tenue","tepal","tepas","tepoy","terai","teras","terce","terek","teres","terfe","terfs","terga","terms","terne","terns","terry","terts","tesla","testa","teste","tests","tetes","teths","tetra","tetri","teuch","teugh","tewed","tewel","tewit","texas","texes","texts","thack","thagi","thaim","thale","thali","thana","thane","thang","thans","thanx","tharm","thars","thaws","thawy","thebe","theca","theed","theek","thees","thegn","theic","thein","thelf","thema","thens","theow","therm","thesp","thete","thews","thewy","thigs","thilk","thill","thine","thins","thiol","thirl","thoft","thole","tholi","thoro","thorp","thous","thowl","thrae","thraw","thrid","thrip","throe","thuds","thugs","thuja","thunk","thurl","thuya","thymi","thymy","tians","tiars","tical","ticca","ticed","tices","tichy","ticks","ticky","tiddy","tided","tides","tiers","tiffs","tifos","tifts","tiges","tigon","tikas","tikes","tikis","tikka","tilak","tiled","tiler","tiles","tills","tilly","tilth","tilts","timbo","timed","times","timon","timps","tinas","tinct","tinds","tinea","tined","tines","tinge","tings","tinks","tinny","tints","tinty","tipis","tippy","tired","tires","tirls","tiros","tirrs","titch","titer","titis","titre","titty","titup","tiyin","tiyns","tizes","tizzy","toads","toady","toaze","tocks","tocky","tocos","todde","toeas","toffs","toffy","tofts","tofus","togae","togas","toged","toges","togue","tohos","toile","toils","toing","toise","toits","tokay","toked","toker","tokes","tokos","tolan","tolar","tolas","toled","toles","tolls","tolly","tolts","tolus","tolyl","toman","tombs","tomes","tomia","tommy","tomos","tondi","tondo","toned","toner","tones","toney","tongs","tonka","tonks","tonne","tonus","tools","tooms","toons","toots","toped","topee","topek","toper","topes","tophe","tophi","tophs","topis","topoi","topos","toppy","toque","torah","toran","toras","torcs","tores","toric","torii","toros","torot","torrs","torse","torsi","torsk","torta","torte","torts","tosas","tosed","toses","toshy","tossy","toted","toter","totes","totty","touks","touns","tours","touse","tousy","touts","touze","touzy","towed","towie","towns","towny","towse","towsy","towts","towze","towzy","toyed","toyer","toyon","toyos","tozed","tozes","tozie","trabs","trads","tragi","traik","trams","trank","tranq","trans","trant","trape","traps","trapt","trass","trats","tratt","trave","trayf","trays","treck","treed","treen","trees","trefa","treif","treks","trema","trems","tress","trest","trets","trews","treyf","treys","triac","tride","trier","tries","triff","trigo","trigs","trike","trild","trill","trims","trine","trins","triol","trior","trios","trips","tripy","trist","troad","troak","troat","trock","trode","trods","trogs","trois","troke","tromp","trona","tronc","trone","tronk","trons","trooz","troth","trots","trows","troys","trued","trues","trugo","trugs","trull","tryer","tryke","tryma","tryps","tsade","tsadi","tsars","tsked","tsuba","tsubo","tuans","tuart","tuath","tubae","tubar","tubas","tubby","tubed","tubes","tucks","tufas","tuffe","tuffs","tufts","tufty","tugra","tuile","tuina","tuism","tuktu","tules","tulpa","tulsi","tumid","tummy","tumps","tumpy","tunas","tunds","tuned","tuner","tunes","tungs","tunny","tupek","tupik","tuple","tuque","turds","turfs","turfy","turks","turme","turms","turns","turnt","turps","turrs","tushy","tusks","tusky","tutee","tutti","tutty","tutus","tuxes","tuyer","twaes","twain","twals","twank","twats","tways","tweel","tween","tweep","tweer","twerk","twerp","twier","twigs","twill","twilt","twink","twins","twiny","twire","twirp","twite","twits","twoer","twyer","tyees","tyers","tyiyn","tykes","tyler","tymps","tynde","tyned","tynes","typal","typed","types","typey","typic","typos","typps","typto","tyran","tyred","tyres","tyros","tythe","tzars","udals","udons","ugali","ugged","uhlan","uhuru","ukase","ulama","ulans","ulema","ulmin","ulnad","ulnae","ulnar","ulnas","ulpan","ulvas","ulyie","ulzie","umami","umbel","umber","umble","umbos","umbre","umiac","umiak","umiaq","ummah","ummas","ummed","umped","umphs","umpie","umpty","umrah","umras","unais","unapt","unarm","unary","unaus","unbag","unban","unbar","unbed","unbid","unbox","uncap","unces","uncia","uncos","uncoy","uncus","undam","undee","undos","undug","uneth","unfix","ungag","unget","ungod","ungot","ungum","unhat","unhip","unica","units","unjam","unked","unket","unkid","unlaw","unlay","unled","unlet","unlid","unman","unmew","unmix","unpay","unpeg","unpen","unpin","unred","unrid","unrig","unrip","unsaw","unsay","unsee","unsew","unsex","unsod","untax","untin","unwet","unwit","unwon","upbow","upbye","updos","updry","upend","upjet","uplay","upled","uplit","upped","upran","uprun","upsee","upsey","uptak","upter","uptie","uraei","urali","uraos","urare","urari","urase","urate","urbex","urbia","urdee","ureal","ureas","uredo","ureic","urena","urent","urged","urger","urges","urial","urite","urman","urnal","urned","urped","ursae","ursid","urson","urubu","urvas","users","usnea","usque","usure","usury","uteri","uveal","uveas","uvula","vacua","vaded","vades","vagal","vagus","vails","vaire","vairs","vairy","vakas","vakil","vales","valis","valse","vamps","vampy","vanda","vaned","vanes","vangs","vants","vaped","vaper","vapes","varan","varas","vardy","varec","vares","varia","varix","varna","varus","varve","vasal","vases","vasts","vasty","vatic","vatus","vauch","vaute","vauts","vawte","vaxes","veale","veals","vealy","veena","veeps","veers","veery","vegas","veges","vegie","vegos","vehme","veils","veily","veins","veiny","velar","velds","veldt","veles","vells","velum","venae","venal","vends","vendu","veney","venge","venin","vents","venus","verbs","verra","verry","verst","verts","vertu","vespa","vesta","vests","vetch","vexed","vexer","vexes","vexil","vezir","vials","viand","vibes","vibex","vibey","viced","vices","vichy","viers","views","viewy","vifda","viffs","vigas","vigia","vilde","viler","villi","vills","vimen","vinal","vinas","vinca","vined","viner","vines","vinew","vinic","vinos","vints","viold","viols","vired","vireo","vires","virga","virge","virid","virls","virtu","visas","vised","vises","visie","visne","vison","visto","vitae","vitas","vitex","vitro","vitta","vivas","vivat","vivda","viver","vives","vizir","vizor","vleis","vlies","vlogs","voars","vocab","voces","voddy","vodou","vodun","voema","vogie","voids","voile","voips","volae","volar","voled","voles","volet","volks","volta","volte","volti","volts","volva","volve","vomer","voted","votes","vouge","voulu","vowed","vower","voxel","vozhd","vraic","vrils","vroom","vrous","vrouw","vrows","vuggs","vuggy","vughs","vughy","vulgo","vulns","vulva","vutty","waacs","wacke","wacko","wacks","wadds","waddy","waded","wader","wades","wadge","wadis","wadts","waffs","wafts","waged","wages","wagga","wagyu","wahoo","waide","waifs","waift","wails","wains","wairs","waite","waits","wakas","waked","waken","waker","wakes","wakfs","waldo","walds","waled","waler","wales","walie","walis","walks","walla","walls","wally","walty","wamed","wames","wamus","wands","waned","wanes","waney","wangs","wanks","wanky","wanle","wanly","wanna","wants","wanty","wanze","waqfs","warbs","warby","wards","wared","wares","warez","warks","warms","warns","warps","warre","warst","warts","wases","washy","wasms","wasps","waspy","wasts","watap","watts","wauff","waugh","wauks","waulk","wauls","waurs","waved","waves","wavey","wawas","wawes","wawls","waxed","waxer","waxes","wayed","wazir","wazoo","weald","weals","weamb","weans","wears","webby","weber","wecht","wedel","wedgy","weeds","weeke","weeks","weels","weems","weens","weeny","weeps","weepy","weest","weete","weets","wefte","wefts","weids","weils","weirs","weise","weize","wekas","welds","welke","welks","welkt","wells","welly","welts","wembs","wends","wenge","wenny","wents","weros","wersh","wests","wetas","wetly","wexed","wexes","whamo","whams","whang","whaps","whare","whata","whats","whaup","whaur","wheal","whear","wheen","wheep","wheft","whelk","whelm","whens","whets","whews","wheys","whids","whift","whigs","whilk","whims","whins","whios","whips","whipt","whirr","whirs","whish","whiss","whist","whits","whity","whizz","whomp","whoof","whoot","whops","whorl","whort","whoso","whows","whump","whups","whyda","wicca","wicks","wicky","widdy","wides","wiels","wifed","wifes","wifey","wifie","wifty","wigan","wigga","wiggy","wikis","wilco","wilds","wiled","wiles","wilga","wilis","wilja","wills","wilts","wimps","winds","wined","wines","winey","winge","wings","wingy","winks","winna","winns","winos","winze","wiped","wiper","wipes","wired","wirer","wires","wirra","wised","wises","wisha","wisht","wisps","wists","witan","wited","wites","withe","withs","withy","wived","wiver","wives","wizen","wizes","woads","woald","wocks","wodge","woful","wojus","woker","wokka","wolds","wolfs","wolly","wolve","wombs","womby","womyn","wonga","wongi","wonks","wonky","wonts","woods","wooed","woofs","woofy","woold","wools","woons","woops","woopy","woose","woosh","wootz","words","works","worms","wormy","worts","wowed","wowee","woxen","wrang","wraps","wrapt","wrast","wrate","wrawl","wrens","wrick","wried","wrier","wries","writs","wroke","wroot","wroth","wryer","wuddy","wudus","wulls","wurst","wuses","wushu","wussy","wuxia","wyled","wyles","wynds","wynns","wyted","wytes","xebec","xenia","xenic","xenon","xeric","xerox","xerus","xoana","xrays","xylan","xylem","xylic","xylol","xylyl","xysti","xysts","yaars","yabas","yabba","yabby","yacca","yacka","yacks","yaffs","yager","yages","yagis","yahoo","yaird","yakka","yakow","yales","yamen","yampy","yamun","yangs","yanks","yapok","yapon","yapps","yappy","yarak","yarco","yards","yarer","yarfa","yarks","yarns","yarrs","yarta","yarto","yates","yauds","yauld","yaups","yawed","yawey","yawls","yawns","yawny","yawps","ybore","yclad","ycled","ycond","ydrad","ydred","yeads","yeahs","yealm","yeans","yeard","years","yecch","yechs","yechy","yedes","yeeds","yeesh","yeggs","yelks","yells","yelms","yelps","yelts","yenta","yente","yerba","yerds","yerks","yeses","yesks","yests","yesty","yetis","yetts","yeuks","yeuky","yeven","yeves","yewen","yexed","yexes","yfere","yiked","yikes","yills","yince","yipes","yippy","yirds","yirks","yirrs","yirth","yites","yitie","ylems","ylike","ylkes","ymolt","ympes","yobbo","yobby","yocks","yodel","yodhs","yodle","yogas","yogee","yoghs","yogic","yogin","yogis","yoick","yojan","yoked","yokel","yoker","yokes","yokul","yolks","yolky","yomim","yomps","yonic","yonis","yonks","yoofs","yoops","yores","yorks","yorps","youks","yourn","yours","yourt","youse","yowed","yowes","yowie","yowls","yowza","yrapt","yrent","yrivd","yrneh","ysame","ytost","yuans","yucas","yucca","yucch","yucko","yucks","yucky","yufts","yugas","yuked","yukes","yukky","yukos","yulan","yules","yummo","yummy","yumps","yupon","yuppy","yurta","yurts","yuzus","zabra","zacks","zaida","zaidy","zaire","zakat","zaman","zambo","zamia","zanja","zante","zanza","zanze","zappy","zarfs","zaris","zatis","zaxes","zayin","zazen","zeals","zebec","zebub","zebus","zedas","zeins","zendo","zerda","zerks","zeros","zests","zetas","zexes","zezes","zhomo","zibet","ziffs","zigan","zilas","zilch","zilla","zills","zimbi","zimbs","zinco","zincs","zincy","zineb","zines","zings","zingy","zinke","zinky","zippo","zippy","ziram","zitis","zizel","zizit","zlote","zloty","zoaea","zobos","zobus","zocco","zoeae","zoeal","zoeas","zoism","zoist","zombi","zonae","zonda","zoned","zoner","zones","zonks","zooea","zooey","zooid","zooks","zooms","zoons","zooty","zoppa","zoppo","zoril","zoris","zorro","zouks","zowee","zowie","zulus","zupan","zupas","zuppa","zurfs","zuzim","zygal","zygon","zymes","zymic"];
+pub static ANSW_LIST: &[&str] = &["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve", "heath", "dwarf", "model", "karma", "stink", "grade", "quiet", "bench", "abate", "feign", "major", "death", "fresh", "crust", "stool", "colon", "abase", "marry", "react", "batty", "pride", "floss", "helix", "croak", "staff", "paper", "unfed", "whelp", "trawl", "outdo", "adobe", "crazy", "sower", "repay", "digit", "crate", "cluck", "spike", "mimic", "pound", "maxim", "linen", "unmet", "flesh", "booby", "forth", "first", "stand", "belly", "ivory", "seedy", "print", "yearn", "drain", "bribe", "stout", "panel", "crass", "flume", "offal", "agree", "error", "swirl", "argue", "bleed", "delta", "flick", "totem", "wooer", "front", "shrub", "parry", "biome", "lapel", "start", "greet", "goner", "golem", "lusty", "loopy", "round", "audit", "lying", "gamma", "labor", "islet", "civic", "forge", "corny", "moult", "basic", "salad", "agate", "spicy", "spray", "essay", "fjord", "spend", "kebab", "guild", "aback", "motor", "alone", "hatch", "hyper", "thumb", "dowry", "ought", "belch", "dutch", "pilot", "tweed", "comet", "jaunt", "enema", "steed", "abyss", "growl", "fling", "dozen", "boozy", "erode", "world", "gouge", "click", "briar", "great", "altar", "pulpy", "blurt", "coast", "duchy", "groin", "fixer", "group", "rogue", "badly", "smart", "pithy", "gaudy", "chill", "heron", "vodka", "finer", "surer", "radio", "rouge", "perch", "retch", "wrote", "clock", "tilde", "store", "prove", "bring", "solve", "cheat", "grime", "exult", "usher", "epoch", "triad", "break", "rhino", "viral", "conic", "masse", "sonic", "vital", "trace", "using", "peach", "champ", "baton", "brake", "pluck", "craze", "gripe", "weary", "picky", "acute", "ferry", "aside", "tapir", "troll", "unify", "rebus", "boost", "truss", "siege", "tiger", "banal", "slump", "crank", "gorge", "query", "drink", "favor", "abbey", "tangy", "panic", "solar", "shire", "proxy", "point", "robot", "prick", "wince", "crimp", "knoll", "sugar", "whack", "mount", "perky", "could", "wrung", "light", "those", "moist", "shard", "pleat", "aloft", "skill", "elder", "frame", "humor", "pause", "ulcer", "ultra", "robin", "cynic", "aroma", "caulk", "shake", "dodge", "swill", "tacit", "other", "thorn", "trove", "bloke", "vivid", "spill", "chant", "choke", "rupee", "nasty", "mourn", "ahead", "brine", "cloth", "hoard", "sweet", "month", "lapse", "watch", "today", "focus", "smelt", "tease", "cater", "movie", "saute", "allow", "renew", "their", "slosh", "purge", "chest", "depot", "epoxy", "nymph", "found", "shall", "harry", "stove", "lowly", "snout", "trope", "fewer", "shawl", "natal", "comma", "foray", "scare", "stair", "black", "squad", "royal", "chunk", "mince", "shame", "cheek", "ample", "flair", "foyer", "cargo", "oxide", "plant", "olive", "inert", "askew", "heist", "shown", "zesty", "hasty", "trash", "fella", "larva", "forgo", "story", "hairy", "train", "homer", "badge", "midst", "canny", "fetus", "butch", "farce", "slung", "tipsy", "metal", "yield", "delve", "being", "scour", "glass", "gamer", "scrap", "money", "hinge", "album", "vouch", "asset", "tiara", "crept", "bayou", "atoll", "manor", "creak", "showy", "phase", "froth", "depth", "gloom", "flood", "trait", "girth", "piety", "payer", "goose", "float", "donor", "atone", "primo", "apron", "blown", "cacao", "loser", "input", "gloat", "awful", "brink", "smite", "beady", "rusty", "retro", "droll", "gawky", "hutch", "pinto", "gaily", "egret", "lilac", "sever", "field", "fluff", "hydro", "flack", "agape", "voice", "stead", "stalk", "berth", "madam", "night", "bland", "liver", "wedge", "augur", "roomy", "wacky", "flock", "angry", "bobby", "trite", "aphid", "tryst", "midge", "power", "elope", "cinch", "motto", "stomp", "upset", "bluff", "cramp", "quart", "coyly", "youth", "rhyme", "buggy", "alien", "smear", "unfit", "patty", "cling", "glean", "label", "hunky", "khaki", "poker", "gruel", "twice", "twang", "shrug", "treat", "unlit", "waste", "merit", "woven", "octal", "needy", "clown", "widow", "irony", "ruder", "gauze", "chief", "onset", "prize", "fungi", "charm", "gully", "inter", "whoop", "taunt", "leery", "class", "theme", "lofty", "tibia", "booze", "alpha", "thyme", "eclat", "doubt", "parer", "chute", "stick", "trice", "alike", "sooth", "recap", "saint", "liege", "glory", "grate", "admit", "brisk", "soggy", "usurp", "scald", "scorn", "leave", "twine", "sting", "bough", "marsh", "sloth", "dandy", "vigor", "howdy", "enjoy", "valid", "ionic", "equal", "unset", "floor", "catch", "spade", "stein", "exist", "quirk", "denim", "grove", "spiel", "mummy", "fault", "foggy", "flout", "carry", "sneak", "libel", "waltz", "aptly", "piney", "inept", "aloud", "photo", "dream", "stale", "vomit", "ombre", "fanny", "unite", "snarl", "baker", "there", "glyph", "pooch", "hippy", "spell", "folly", "louse", "gulch", "vault", "godly", "threw", "fleet", "grave", "inane", "shock", "crave", "spite", "valve", "skimp", "claim", "rainy", "musty", "pique", "daddy", "quasi", "arise", "aging", "valet", "opium", "avert", "stuck", "recut", "mulch", "genre", "plume", "rifle", "count", "incur", "total", "wrest", "mocha", "deter", "study", "lover", "safer", "rivet", "funny", "smoke", "mound", "undue", "sedan", "pagan", "swine", "guile", "gusty", "equip", "tough", "canoe", "chaos", "covet", "human", "udder", "lunch", "blast", "stray", "manga", "melee", "lefty", "quick", "paste", "given", "octet", "risen", "groan", "leaky", "grind", "carve", "loose", "sadly", "spilt", "apple", "slack", "honey", "final", "sheen", "eerie", "minty", "slick", "derby", "wharf", "spelt", "coach", "erupt", "singe", "price", "spawn", "fairy", "jiffy", "filmy", "stack", "chose", "sleep", "ardor", "nanny", "niece", "woozy", "handy", "grace", "ditto", "stank", "cream", "usual", "diode", "valor", "angle", "ninja", "muddy", "chase", "reply", "prone", "spoil", "heart", "shade", "diner", "arson", "onion", "sleet", "dowel", "couch", "palsy", "bowel", "smile", "evoke", "creek", "lance", "eagle", "idiot", "siren", "built", "embed", "award", "dross", "annul", "goody", "frown", "patio", "laden", "humid", "elite", "lymph", "edify", "might", "reset", "visit", "gusto", "purse", "vapor", "crock", "write", "sunny", "loath", "chaff", "slide", "queer", "venom", "stamp", "sorry", "still", "acorn", "aping", "pushy", "tamer", "hater", "mania", "awoke", "brawn", "swift", "exile", "birch", "lucky", "freer", "risky", "ghost", "plier", "lunar", "winch", "snare", "nurse", "house", "borax", "nicer", "lurch", "exalt", "about", "savvy", "toxin", "tunic", "pried", "inlay", "chump", "lanky", "cress", "eater", "elude", "cycle", "kitty", "boule", "moron", "tenet", "place", "lobby", "plush", "vigil", "index", "blink", "clung", "qualm", "croup", "clink", "juicy", "stage", "decay", "nerve", "flier", "shaft", "crook", "clean", "china", "ridge", "vowel", "gnome", "snuck", "icing", "spiny", "rigor", "snail", "flown", "rabid", "prose", "thank", "poppy", "budge", "fiber", "moldy", "dowdy", "kneel", "track", "caddy", "quell", "dumpy", "paler", "swore", "rebar", "scuba", "splat", "flyer", "horny", "mason", "doing", "ozone", "amply", "molar", "ovary", "beset", "queue", "cliff", "magic", "truce", "sport", "fritz", "edict", "twirl", "verse", "llama", "eaten", "range", "whisk", "hovel", "rehab", "macaw", "sigma", "spout", "verve", "sushi", "dying", "fetid", "brain", "buddy", "thump", "scion", "candy", "chord", "basin", "march", "crowd", "arbor", "gayly", "musky", "stain", "dally", "bless", "bravo", "stung", "title", "ruler", "kiosk", "blond", "ennui", "layer", "fluid", "tatty", "score", "cutie", "zebra", "barge", "matey", "bluer", "aider", "shook", "river", "privy", "betel", "frisk", "bongo", "begun", "azure", "weave", "genie", "sound", "glove", "braid", "scope", "wryly", "rover", "assay", "ocean", "bloom", "irate", "later", "woken", "silky", "wreck", "dwelt", "slate", "smack", "solid", "amaze", "hazel", "wrist", "jolly", "globe", "flint", "rouse", "civil", "vista", "relax", "cover", "alive", "beech", "jetty", "bliss", "vocal", "often", "dolly", "eight", "joker", "since", "event", "ensue", "shunt", "diver", "poser", "worst", "sweep", "alley", "creed", "anime", "leafy", "bosom", "dunce", "stare", "pudgy", "waive", "choir", "stood", "spoke", "outgo", "delay", "bilge", "ideal", "clasp", "seize", "hotly", "laugh", "sieve", "block", "meant", "grape", "noose", "hardy", "shied", "drawl", "daisy", "putty", "strut", "burnt", "tulip", "crick", "idyll", "vixen", "furor", "geeky", "cough", "naive", "shoal", "stork", "bathe", "aunty", "check", "prime", "brass", "outer", "furry", "razor", "elect", "evict", "imply", "demur", "quota", "haven", "cavil", "swear", "crump", "dough", "gavel", "wagon", "salon", "nudge", "harem", "pitch", "sworn", "pupil", "excel", "stony", "cabin", "unzip", "queen", "trout", "polyp", "earth", "storm", "until", "taper", "enter", "child", "adopt", "minor", "fatty", "husky", "brave", "filet", "slime", "glint", "tread", "steal", "regal", "guest", "every", "murky", "share", "spore", "hoist", "buxom", "inner", "otter", "dimly", "level", "sumac", "donut", "stilt", "arena", "sheet", "scrub", "fancy", "slimy", "pearl", "silly", "porch", "dingo", "sepia", "amble", "shady", "bread", "friar", "reign", "dairy", "quill", "cross", "brood", "tuber", "shear", "posit", "blank", "villa", "shank", "piggy", "freak", "which", "among", "fecal", "shell", "would", "algae", "large", "rabbi", "agony", "amuse", "bushy", "copse", "swoon", "knife", "pouch", "ascot", "plane", "crown", "urban", "snide", "relay", "abide", "viola", "rajah", "straw", "dilly", "crash", "amass", "third", "trick", "tutor", "woody", "blurb", "grief", "disco", "where", "sassy", "beach", "sauna", "comic", "clued", "creep", "caste", "graze", "snuff", "frock", "gonad", "drunk", "prong", "lurid", "steel", "halve", "buyer", "vinyl", "utile", "smell", "adage", "worry", "tasty", "local", "trade", "finch", "ashen", "modal", "gaunt", "clove", "enact", "adorn", "roast", "speck", "sheik", "missy", "grunt", "snoop", "party", "touch", "mafia", "emcee", "array", "south", "vapid", "jelly", "skulk", "angst", "tubal", "lower", "crest", "sweat", "cyber", "adore", "tardy", "swami", "notch", "groom", "roach", "hitch", "young", "align", "ready", "frond", "strap", "puree", "realm", "venue", "swarm", "offer", "seven", "dryer", "diary", "dryly", "drank", "acrid", "heady", "theta", "junto", "pixie", "quoth", "bonus", "shalt", "penne", "amend", "datum", "build", "piano", "shelf", "lodge", "suing", "rearm", "coral", "ramen", "worth", "psalm", "infer", "overt", "mayor", "ovoid", "glide", "usage", "poise", "randy", "chuck", "prank", "fishy", "tooth", "ether", "drove", "idler", "swath", "stint", "while", "begat", "apply", "slang", "tarot", "radar", "credo", "aware", "canon", "shift", "timer", "bylaw", "serum", "three", "steak", "iliac", "shirk", "blunt", "puppy", "penal", "joist", "bunny", "shape", "beget", "wheel", "adept", "stunt", "stole", "topaz", "chore", "fluke", "afoot", "bloat", "bully", "dense", "caper", "sneer", "boxer", "jumbo", "lunge", "space", "avail", "short", "slurp", "loyal", "flirt", "pizza", "conch", "tempo", "droop", "plate", "bible", "plunk", "afoul", "savoy", "steep", "agile", "stake", "dwell", "knave", "beard", "arose", "motif", "smash", "broil", "glare", "shove", "baggy", "mammy", "swamp", "along", "rugby", "wager", "quack", "squat", "snaky", "debit", "mange", "skate", "ninth", "joust", "tramp", "spurn", "medal", "micro", "rebel", "flank", "learn", "nadir", "maple", "comfy", "remit", "gruff", "ester", "least", "mogul", "fetch", "cause", "oaken", "aglow", "meaty", "gaffe", "shyly", "racer", "prowl", "thief", "stern", "poesy", "rocky", "tweet", "waist", "spire", "grope", "havoc", "patsy", "truly", "forty", "deity", "uncle", "swish", "giver", "preen", "bevel", "lemur", "draft", "slope", "annoy", "lingo", "bleak", "ditty", "curly", "cedar", "dirge", "grown", "horde", "drool", "shuck", "crypt", "cumin", "stock", "gravy", "locus", "wider", "breed", "quite", "chafe", "cache", "blimp", "deign", "fiend", "logic", "cheap", "elide", "rigid", "false", "renal", "pence", "rowdy", "shoot", "blaze", "envoy", "posse", "brief", "never", "abort", "mouse", "mucky", "sulky", "fiery", "media", "trunk", "yeast", "clear", "skunk", "scalp", "bitty", "cider", "koala", "duvet", "segue", "creme", "super", "grill", "after", "owner", "ember", "reach", "nobly", "empty", "speed", "gipsy", "recur", "smock", "dread", "merge", "burst", "kappa", "amity", "shaky", "hover", "carol", "snort", "synod", "faint", "haunt", "flour", "chair", "detox", "shrew", "tense", "plied", "quark", "burly", "novel", "waxen", "stoic", "jerky", "blitz", "beefy", "lyric", "hussy", "towel", "quilt", "below", "bingo", "wispy", "brash", "scone", "toast", "easel", "saucy", "value", "spice", "honor", "route", "sharp", "bawdy", "radii", "skull", "phony", "issue", "lager", "swell", "urine", "gassy", "trial", "flora", "upper", "latch", "wight", "brick", "retry", "holly", "decal", "grass", "shack", "dogma", "mover", "defer", "sober", "optic", "crier", "vying", "nomad", "flute", "hippo", "shark", "drier", "obese", "bugle", "tawny", "chalk", "feast", "ruddy", "pedal", "scarf", "cruel", "bleat", "tidal", "slush", "semen", "windy", "dusty", "sally", "igloo", "nerdy", "jewel", "shone", "whale", "hymen", "abuse", "fugue", "elbow", "crumb", "pansy", "welsh", "syrup", "terse", "suave", "gamut", "swung", "drake", "freed", "afire", "shirt", "grout", "oddly", "tithe", "plaid", "dummy", "broom", "blind", "torch", "enemy", "again", "tying", "pesky", "alter", "gazer", "noble", "ethos", "bride", "extol", "decor", "hobby", "beast", "idiom", "utter", "these", "sixth", "alarm", "erase", "elegy", "spunk", "piper", "scaly", "scold", "hefty", "chick", "sooty", "canal", "whiny", "slash", "quake", "joint", "swept", "prude", "heavy", "wield", "femme", "lasso", "maize", "shale", "screw", "spree", "smoky", "whiff", "scent", "glade", "spent", "prism", "stoke", "riper", "orbit", "cocoa", "guilt", "humus", "shush", "table", "smirk", "wrong", "noisy", "alert", "shiny", "elate", "resin", "whole", "hunch", "pixel", "polar", "hotel", "sword", "cleat", "mango", "rumba", "puffy", "filly", "billy", "leash", "clout", "dance", "ovate", "facet", "chili", "paint", "liner", "curio", "salty", "audio", "snake", "fable", "cloak", "navel", "spurt", "pesto", "balmy", "flash", "unwed", "early", "churn", "weedy", "stump", "lease", "witty", "wimpy", "spoof", "saner", "blend", "salsa", "thick", "warty", "manic", "blare", "squib", "spoon", "probe", "crepe", "knack", "force", "debut", "order", "haste", "teeth", "agent", "widen", "icily", "slice", "ingot", "clash", "juror", "blood", "abode", "throw", "unity", "pivot", "slept", "troop", "spare", "sewer", "parse", "morph", "cacti", "tacky", "spool", "demon", "moody", "annex", "begin", "fuzzy", "patch", "water", "lumpy", "admin", "omega", "limit", "tabby", "macho", "aisle", "skiff", "basis", "plank", "verge", "botch", "crawl", "lousy", "slain", "cubic", "raise", "wrack", "guide", "foist", "cameo", "under", "actor", "revue", "fraud", "harpy", "scoop", "climb", "refer", "olden", "clerk", "debar", "tally", "ethic", "cairn", "tulle", "ghoul", "hilly", "crude", "apart", "scale", "older", "plain", "sperm", "briny", "abbot", "rerun", "quest", "crisp", "bound", "befit", "drawn", "suite", "itchy", "cheer", "bagel", "guess", "broad", "axiom", "chard", "caput", "leant", "harsh", "curse", "proud", "swing", "opine", "taste", "lupus", "gumbo", "miner", "green", "chasm", "lipid", "topic", "armor", "brush", "crane", "mural", "abled", "habit", "bossy", "maker", "dusky", "dizzy", "lithe", "brook", "jazzy", "fifty", "sense", "giant", "surly", "legal", "fatal", "flunk", "began", "prune", "small", "slant", "scoff", "torus", "ninny", "covey", "viper", "taken", "moral", "vogue", "owing", "token", "entry", "booth", "voter", "chide", "elfin", "ebony", "neigh", "minim", "melon", "kneed", "decoy", "voila", "ankle", "arrow", "mushy", "tribe", "cease", "eager", "birth", "graph", "odder", "terra", "weird", "tried", "clack", "color", "rough", "weigh", "uncut", "ladle", "strip", "craft", "minus", "dicey", "titan", "lucid", "vicar", "dress", "ditch", "gypsy", "pasta", "taffy", "flame", "swoop", "aloof", "sight", "broke", "teary", "chart", "sixty", "wordy", "sheer", "leper", "nosey", "bulge", "savor", "clamp", "funky", "foamy", "toxic", "brand", "plumb", "dingy", "butte", "drill", "tripe", "bicep", "tenor", "krill", "worse", "drama", "hyena", "think", "ratio", "cobra", "basil", "scrum", "bused", "phone", "court", "camel", "proof", "heard", "angel", "petal", "pouty", "throb", "maybe", "fetal", "sprig", "spine", "shout", "cadet", "macro", "dodgy", "satyr", "rarer", "binge", "trend", "nutty", "leapt", "amiss", "split", "myrrh", "width", "sonar", "tower", "baron", "fever", "waver", "spark", "belie", "sloop", "expel", "smote", "baler", "above", "north", "wafer", "scant", "frill", "awash", "snack", "scowl", "frail", "drift", "limbo", "fence", "motel", "ounce", "wreak", "revel", "talon", "prior", "knelt", "cello", "flake", "debug", "anode", "crime", "salve", "scout", "imbue", "pinky", "stave", "vague", "chock", "fight", "video", "stone", "teach", "cleft", "frost", "prawn", "booty", "twist", "apnea", "stiff", "plaza", "ledge", "tweak", "board", "grant", "medic", "bacon", "cable", "brawl", "slunk", "raspy", "forum", "drone", "women", "mucus", "boast", "toddy", "coven", "tumor", "truer", "wrath", "stall", "steam", "axial", "purer", "daily", "trail", "niche", "mealy", "juice", "nylon", "plump", "merry", "flail", "papal", "wheat", "berry", "cower", "erect", "brute", "leggy", "snipe", "sinew", "skier", "penny", "jumpy", "rally", "umbra", "scary", "modem", "gross", "avian", "greed", "satin", "tonic", "parka", "sniff", "livid", "stark", "trump", "giddy", "reuse", "taboo", "avoid", "quote", "devil", "liken", "gloss", "gayer", "beret", "noise", "gland", "dealt", "sling", "rumor", "opera", "thigh", "tonga", "flare", "wound", "white", "bulky", "etude", "horse", "circa", "paddy", "inbox", "fizzy", "grain", "exert", "surge", "gleam", "belle", "salvo", "crush", "fruit", "sappy", "taker", "tract", "ovine", "spiky", "frank", "reedy", "filth", "spasm", "heave", "mambo", "right", "clank", "trust", "lumen", "borne", "spook", "sauce", "amber", "lathe", "carat", "corer", "dirty", "slyly", "affix", "alloy", "taint", "sheep", "kinky", "wooly", "mauve", "flung", "yacht", "fried", "quail", "brunt", "grimy", "curvy", "cagey", "rinse", "deuce", "state", "grasp", "milky", "bison", "graft", "sandy", "baste", "flask", "hedge", "girly", "swash", "boney", "coupe", "endow", "abhor", "welch", "blade", "tight", "geese", "miser", "mirth", "cloud", "cabal", "leech", "close", "tenth", "pecan", "droit", "grail", "clone", "guise", "ralph", "tango", "biddy", "smith", "mower", "payee", "serif", "drape", "fifth", "spank", "glaze", "allot", "truck", "kayak", "virus", "testy", "tepee", "fully", "zonal", "metro", "curry", "grand", "banjo", "axion", "bezel", "occur", "chain", "nasal", "gooey", "filer", "brace", "allay", "pubic", "raven", "plead", "gnash", "flaky", "munch", "dully", "eking", "thing", "slink", "hurry", "theft", "shorn", "pygmy", "ranch", "wring", "lemon", "shore", "mamma", "froze", "newer", "style", "moose", "antic", "drown", "vegan", "chess", "guppy", "union", "lever", "lorry", "image", "cabby", "druid", "exact", "truth", "dopey", "spear", "cried", "chime", "crony", "stunk", "timid", "batch", "gauge", "rotor", "crack", "curve", "latte", "witch", "bunch", "repel", "anvil", "soapy", "meter", "broth", "madly", "dried", "scene", "known", "magma", "roost", "woman", "thong", "punch", "pasty", "downy", "knead", "whirl", "rapid", "clang", "anger", "drive", "goofy", "email", "music", "stuff", "bleep", "rider", "mecca", "folio", "setup", "verso", "quash", "fauna", "gummy", "happy", "newly", "fussy", "relic", "guava", "ratty", "fudge", "femur", "chirp", "forte", "alibi", "whine", "petty", "golly", "plait", "fleck", "felon", "gourd", "brown", "thrum", "ficus", "stash", "decry", "wiser", "junta", "visor", "daunt", "scree", "impel", "await", "press", "whose", "turbo", "stoop", "speak", "mangy", "eying", "inlet", "crone", "pulse", "mossy", "staid", "hence", "pinch", "teddy", "sully", "snore", "ripen", "snowy", "attic", "going", "leach", "mouth", "hound", "clump", "tonal", "bigot", "peril", "piece", "blame", "haute", "spied", "undid", "intro", "basal", "shine", "gecko", "rodeo", "guard", "steer", "loamy", "scamp", "scram", "manly", "hello", "vaunt", "organ", "feral", "knock", "extra", "condo", "adapt", "willy", "polka", "rayon", "skirt", "faith", "torso", "match", "mercy", "tepid", "sleek", "riser", "twixt", "peace", "flush", "catty", "login", "eject", "roger", "rival", "untie", "refit", "aorta", "adult", "judge", "rower", "artsy", "rural", "shave"];
+pub static GUESS_LIST: &[&str] = &["aahed", "aalii", "aargh", "aarti", "abaca", "abaci", "abacs", "abaft", "abaka", "abamp", "aband", "abash", "abask", "abaya", "abbas", "abbed", "abbes", "abcee", "abeam", "abear", "abele", "abers", "abets", "abies", "abler", "ables", "ablet", "ablow", "abmho", "abohm", "aboil", "aboma", "aboon", "abord", "abore", "abram", "abray", "abrim", "abrin", "abris", "absey", "absit", "abuna", "abune", "abuts", "abuzz", "abyes", "abysm", "acais", "acari", "accas", "accoy", "acerb", "acers", "aceta", "achar", "ached", "aches", "achoo", "acids", "acidy", "acing", "acini", "ackee", "acker", "acmes", "acmic", "acned", "acnes", "acock", "acold", "acred", "acres", "acros", "acted", "actin", "acton", "acyls", "adaws", "adays", "adbot", "addax", "added", "adder", "addio", "addle", "adeem", "adhan", "adieu", "adios", "adits", "adman", "admen", "admix", "adobo", "adown", "adoze", "adrad", "adred", "adsum", "aduki", "adunc", "adust", "advew", "adyta", "adzed", "adzes", "aecia", "aedes", "aegis", "aeons", "aerie", "aeros", "aesir", "afald", "afara", "afars", "afear", "aflaj", "afore", "afrit", "afros", "agama", "agami", "agars", "agast", "agave", "agaze", "agene", "agers", "agger", "aggie", "aggri", "aggro", "aggry", "aghas", "agila", "agios", "agism", "agist", "agita", "aglee", "aglet", "agley", "agloo", "aglus", "agmas", "agoge", "agone", "agons", "agood", "agria", "agrin", "agros", "agued", "agues", "aguna", "aguti", "aheap", "ahent", "ahigh", "ahind", "ahing", "ahint", "ahold", "ahull", "ahuru", "aidas", "aided", "aides", "aidoi", "aidos", "aiery", "aigas", "aight", "ailed", "aimed", "aimer", "ainee", "ainga", "aioli", "aired", "airer", "airns", "airth", "airts", "aitch", "aitus", "aiver", "aiyee", "aizle", "ajies", "ajiva", "ajuga", "ajwan", "akees", "akela", "akene", "aking", "akita", "akkas", "alaap", "alack", "alamo", "aland", "alane", "alang", "alans", "alant", "alapa", "alaps", "alary", "alate", "alays", "albas", "albee", "alcid", "alcos", "aldea", "alder", "aldol", "aleck", "alecs", "alefs", "aleft", "aleph", "alews", "aleye", "alfas", "algal", "algas", "algid", "algin", "algor", "algum", "alias", "alifs", "aline", "alist", "aliya", "alkie", "alkos", "alkyd", "alkyl", "allee", "allel", "allis", "allod", "allyl", "almah", "almas", "almeh", "almes", "almud", "almug", "alods", "aloed", "aloes", "aloha", "aloin", "aloos", "alowe", "altho", "altos", "alula", "alums", "alure", "alvar", "alway", "amahs", "amain", "amate", "amaut", "amban", "ambit", "ambos", "ambry", "ameba", "ameer", "amene", "amens", "ament", "amias", "amice", "amici", "amide", "amido", "amids", "amies", "amiga", "amigo", "amine", "amino", "amins", "amirs", "amlas", "amman", "ammon", "ammos", "amnia", "amnic", "amnio", "amoks", "amole", "amort", "amour", "amove", "amowt", "amped", "ampul", "amrit", "amuck", "amyls", "anana", "anata", "ancho", "ancle", "ancon", "andro", "anear", "anele", "anent", "angas", "anglo", "anigh", "anile", "anils", "anima", "animi", "anion", "anise", "anker", "ankhs", "ankus", "anlas", "annal", "annas", "annat", "anoas", "anole", "anomy", "ansae", "antae", "antar", "antas", "anted", "antes", "antis", "antra", "antre", "antsy", "anura", "anyon", "apace", "apage", "apaid", "apayd", "apays", "apeak", "apeek", "apers", "apert", "apery", "apgar", "aphis", "apian", "apiol", "apish", "apism", "apode", "apods", "apoop", "aport", "appal", "appay", "appel", "appro", "appui", "appuy", "apres", "apses", "apsis", "apsos", "apted", "apter", "aquae", "aquas", "araba", "araks", "arame", "arars", "arbas", "arced", "archi", "arcos", "arcus", "ardeb", "ardri", "aread", "areae", "areal", "arear", "areas", "areca", "aredd", "arede", "arefy", "areic", "arene", "arepa", "arere", "arete", "arets", "arett", "argal", "argan", "argil", "argle", "argol", "argon", "argot", "argus", "arhat", "arias", "ariel", "ariki", "arils", "ariot", "arish", "arked", "arled", "arles", "armed", "armer", "armet", "armil", "arnas", "arnut", "aroba", "aroha", "aroid", "arpas", "arpen", "arrah", "arras", "arret", "arris", "arroz", "arsed", "arses", "arsey", "arsis", "artal", "artel", "artic", "artis", "aruhe", "arums", "arval", "arvee", "arvos", "aryls", "asana", "ascon", "ascus", "asdic", "ashed", "ashes", "ashet", "asked", "asker", "askoi", "askos", "aspen", "asper", "aspic", "aspie", "aspis", "aspro", "assai", "assam", "asses", "assez", "assot", "aster", "astir", "astun", "asura", "asway", "aswim", "asyla", "ataps", "ataxy", "atigi", "atilt", "atimy", "atlas", "atman", "atmas", "atmos", "atocs", "atoke", "atoks", "atoms", "atomy", "atony", "atopy", "atria", "atrip", "attap", "attar", "atuas", "audad", "auger", "aught", "aulas", "aulic", "auloi", "aulos", "aumil", "aunes", "aunts", "aurae", "aural", "aurar", "auras", "aurei", "aures", "auric", "auris", "aurum", "autos", "auxin", "avale", "avant", "avast", "avels", "avens", "avers", "avgas", "avine", "avion", "avise", "aviso", "avize", "avows", "avyze", "awarn", "awato", "awave", "aways", "awdls", "aweel", "aweto", "awing", "awmry", "awned", "awner", "awols", "awork", "axels", "axile", "axils", "axing", "axite", "axled", "axles", "axman", "axmen", "axoid", "axone", "axons", "ayahs", "ayaya", "ayelp", "aygre", "ayins", "ayont", "ayres", "ayrie", "azans", "azide", "azido", "azine", "azlon", "azoic", "azole", "azons", "azote", "azoth", "azuki", "azurn", "azury", "azygy", "azyme", "azyms", "baaed", "baals", "babas", "babel", "babes", "babka", "baboo", "babul", "babus", "bacca", "bacco", "baccy", "bacha", "bachs", "backs", "baddy", "baels", "baffs", "baffy", "bafts", "baghs", "bagie", "bahts", "bahus", "bahut", "bails", "bairn", "baisa", "baith", "baits", "baiza", "baize", "bajan", "bajra", "bajri", "bajus", "baked", "baken", "bakes", "bakra", "balas", "balds", "baldy", "baled", "bales", "balks", "balky", "balls", "bally", "balms", "baloo", "balsa", "balti", "balun", "balus", "bambi", "banak", "banco", "bancs", "banda", "bandh", "bands", "bandy", "baned", "banes", "bangs", "bania", "banks", "banns", "bants", "bantu", "banty", "banya", "bapus", "barbe", "barbs", "barby", "barca", "barde", "bardo", "bards", "bardy", "bared", "barer", "bares", "barfi", "barfs", "baric", "barks", "barky", "barms", "barmy", "barns", "barny", "barps", "barra", "barre", "barro", "barry", "barye", "basan", "based", "basen", "baser", "bases", "basho", "basij", "basks", "bason", "basse", "bassi", "basso", "bassy", "basta", "basti", "basto", "basts", "bated", "bates", "baths", "batik", "batta", "batts", "battu", "bauds", "bauks", "baulk", "baurs", "bavin", "bawds", "bawks", "bawls", "bawns", "bawrs", "bawty", "bayed", "bayer", "bayes", "bayle", "bayts", "bazar", "bazoo", "beads", "beaks", "beaky", "beals", "beams", "beamy", "beano", "beans", "beany", "beare", "bears", "beath", "beats", "beaty", "beaus", "beaut", "beaux", "bebop", "becap", "becke", "becks", "bedad", "bedel", "bedes", "bedew", "bedim", "bedye", "beedi", "beefs", "beeps", "beers", "beery", "beets", "befog", "begad", "begar", "begem", "begot", "begum", "beige", "beigy", "beins", "bekah", "belah", "belar", "belay", "belee", "belga", "bells", "belon", "belts", "bemad", "bemas", "bemix", "bemud", "bends", "bendy", "benes", "benet", "benga", "benis", "benne", "benni", "benny", "bento", "bents", "benty", "bepat", "beray", "beres", "bergs", "berko", "berks", "berme", "berms", "berob", "beryl", "besat", "besaw", "besee", "beses", "besit", "besom", "besot", "besti", "bests", "betas", "beted", "betes", "beths", "betid", "beton", "betta", "betty", "bever", "bevor", "bevue", "bevvy", "bewet", "bewig", "bezes", "bezil", "bezzy", "bhais", "bhaji", "bhang", "bhats", "bhels", "bhoot", "bhuna", "bhuts", "biach", "biali", "bialy", "bibbs", "bibes", "biccy", "bices", "bided", "bider", "bides", "bidet", "bidis", "bidon", "bield", "biers", "biffo", "biffs", "biffy", "bifid", "bigae", "biggs", "biggy", "bigha", "bight", "bigly", "bigos", "bijou", "biked", "biker", "bikes", "bikie", "bilbo", "bilby", "biled", "biles", "bilgy", "bilks", "bills", "bimah", "bimas", "bimbo", "binal", "bindi", "binds", "biner", "bines", "bings", "bingy", "binit", "binks", "bints", "biogs", "biont", "biota", "biped", "bipod", "birds", "birks", "birle", "birls", "biros", "birrs", "birse", "birsy", "bises", "bisks", "bisom", "biter", "bites", "bitos", "bitou", "bitsy", "bitte", "bitts", "bivia", "bivvy", "bizes", "bizzo", "bizzy", "blabs", "blads", "blady", "blaer", "blaes", "blaff", "blags", "blahs", "blain", "blams", "blart", "blase", "blash", "blate", "blats", "blatt", "blaud", "blawn", "blaws", "blays", "blear", "blebs", "blech", "blees", "blent", "blert", "blest", "blets", "bleys", "blimy", "bling", "blini", "blins", "bliny", "blips", "blist", "blite", "blits", "blive", "blobs", "blocs", "blogs", "blook", "bloop", "blore", "blots", "blows", "blowy", "blubs", "blude", "bluds", "bludy", "blued", "blues", "bluet", "bluey", "bluid", "blume", "blunk", "blurs", "blype", "boabs", "boaks", "boars", "boart", "boats", "bobac", "bobak", "bobas", "bobol", "bobos", "bocca", "bocce", "bocci", "boche", "bocks", "boded", "bodes", "bodge", "bodhi", "bodle", "boeps", "boets", "boeuf", "boffo", "boffs", "bogan", "bogey", "boggy", "bogie", "bogle", "bogue", "bogus", "bohea", "bohos", "boils", "boing", "boink", "boite", "boked", "bokeh", "bokes", "bokos", "bolar", "bolas", "bolds", "boles", "bolix", "bolls", "bolos", "bolts", "bolus", "bomas", "bombe", "bombo", "bombs", "bonce", "bonds", "boned", "boner", "bones", "bongs", "bonie", "bonks", "bonne", "bonny", "bonza", "bonze", "booai", "booay", "boobs", "boody", "booed", "boofy", "boogy", "boohs", "books", "booky", "bools", "booms", "boomy", "boong", "boons", "boord", "boors", "boose", "boots", "boppy", "borak", "boral", "boras", "borde", "bords", "bored", "boree", "borel", "borer", "bores", "borgo", "boric", "borks", "borms", "borna", "boron", "borts", "borty", "bortz", "bosie", "bosks", "bosky", "boson", "bosun", "botas", "botel", "botes", "bothy", "botte", "botts", "botty", "bouge", "bouks", "boult", "bouns", "bourd", "bourg", "bourn", "bouse", "bousy", "bouts", "bovid", "bowat", "bowed", "bower", "bowes", "bowet", "bowie", "bowls", "bowne", "bowrs", "bowse", "boxed", "boxen", "boxes", "boxla", "boxty", "boyar", "boyau", "boyed", "boyfs", "boygs", "boyla", "boyos", "boysy", "bozos", "braai", "brach", "brack", "bract", "brads", "braes", "brags", "brail", "braks", "braky", "brame", "brane", "brank", "brans", "brant", "brast", "brats", "brava", "bravi", "braws", "braxy", "brays", "braza", "braze", "bream", "brede", "breds", "breem", "breer", "brees", "breid", "breis", "breme", "brens", "brent", "brere", "brers", "breve", "brews", "breys", "brier", "bries", "brigs", "briki", "briks", "brill", "brims", "brins", "brios", "brise", "briss", "brith", "brits", "britt", "brize", "broch", "brock", "brods", "brogh", "brogs", "brome", "bromo", "bronc", "brond", "brool", "broos", "brose", "brosy", "brows", "brugh", "bruin", "bruit", "brule", "brume", "brung", "brusk", "brust", "bruts", "buats", "buaze", "bubal", "bubas", "bubba", "bubbe", "bubby", "bubus", "buchu", "bucko", "bucks", "bucku", "budas", "budis", "budos", "buffa", "buffe", "buffi", "buffo", "buffs", "buffy", "bufos", "bufty", "buhls", "buhrs", "buiks", "buist", "bukes", "bulbs", "bulgy", "bulks", "bulla", "bulls", "bulse", "bumbo", "bumfs", "bumph", "bumps", "bumpy", "bunas", "bunce", "bunco", "bunde", "bundh", "bunds", "bundt", "bundu", "bundy", "bungs", "bungy", "bunia", "bunje", "bunjy", "bunko", "bunks", "bunns", "bunts", "bunty", "bunya", "buoys", "buppy", "buran", "buras", "burbs", "burds", "buret", "burfi", "burgh", "burgs", "burin", "burka", "burke", "burks", "burls", "burns", "buroo", "burps", "burqa", "burro", "burrs", "burry", "bursa", "burse", "busby", "buses", "busks", "busky", "bussu", "busti", "busts", "busty", "buteo", "butes", "butle", "butoh", "butts", "butty", "butut", "butyl", "buzzy", "bwana", "bwazi", "byded", "bydes", "byked", "bykes", "byres", "byrls", "byssi", "bytes", "byway", "caaed", "cabas", "caber", "cabob", "caboc", "cabre", "cacas", "cacks", "cacky", "cadee", "cades", "cadge", "cadgy", "cadie", "cadis", "cadre", "caeca", "caese", "cafes", "caffs", "caged", "cager", "cages", "cagot", "cahow", "caids", "cains", "caird", "cajon", "cajun", "caked", "cakes", "cakey", "calfs", "calid", "calif", "calix", "calks", "calla", "calls", "calms", "calmy", "calos", "calpa", "calps", "calve", "calyx", "caman", "camas", "cames", "camis", "camos", "campi", "campo", "camps", "campy", "camus", "caned", "caneh", "caner", "canes", "cangs", "canid", "canna", "canns", "canso", "canst", "canto", "cants", "canty", "capas", "caped", "capes", "capex", "caphs", "capiz", "caple", "capon", "capos", "capot", "capri", "capul", "carap", "carbo", "carbs", "carby", "cardi", "cards", "cardy", "cared", "carer", "cares", "caret", "carex", "carks", "carle", "carls", "carns", "carny", "carob", "carom", "caron", "carpi", "carps", "carrs", "carse", "carta", "carte", "carts", "carvy", "casas", "casco", "cased", "cases", "casks", "casky", "casts", "casus", "cates", "cauda", "cauks", "cauld", "cauls", "caums", "caups", "cauri", "causa", "cavas", "caved", "cavel", "caver", "caves", "cavie", "cawed", "cawks", "caxon", "ceaze", "cebid", "cecal", "cecum", "ceded", "ceder", "cedes", "cedis", "ceiba", "ceili", "ceils", "celeb", "cella", "celli", "cells", "celom", "celts", "cense", "cento", "cents", "centu", "ceorl", "cepes", "cerci", "cered", "ceres", "cerge", "ceria", "ceric", "cerne", "ceroc", "ceros", "certs", "certy", "cesse", "cesta", "cesti", "cetes", "cetyl", "cezve", "chace", "chack", "chaco", "chado", "chads", "chaft", "chais", "chals", "chams", "chana", "chang", "chank", "chape", "chaps", "chapt", "chara", "chare", "chark", "charr", "chars", "chary", "chats", "chave", "chavs", "chawk", "chaws", "chaya", "chays", "cheep", "chefs", "cheka", "chela", "chelp", "chemo", "chems", "chere", "chert", "cheth", "chevy", "chews", "chewy", "chiao", "chias", "chibs", "chica", "chich", "chico", "chics", "chiel", "chiks", "chile", "chimb", "chimo", "chimp", "chine", "ching", "chino", "chins", "chips", "chirk", "chirl", "chirm", "chiro", "chirr", "chirt", "chiru", "chits", "chive", "chivs", "chivy", "chizz", "choco", "chocs", "chode", "chogs", "choil", "choko", "choky", "chola", "choli", "cholo", "chomp", "chons", "choof", "chook", "choom", "choon", "chops", "chota", "chott", "chout", "choux", "chowk", "chows", "chubs", "chufa", "chuff", "chugs", "chums", "churl", "churr", "chuse", "chuts", "chyle", "chyme", "chynd", "cibol", "cided", "cides", "ciels", "ciggy", "cilia", "cills", "cimar", "cimex", "cinct", "cines", "cinqs", "cions", "cippi", "circs", "cires", "cirls", "cirri", "cisco", "cissy", "cists", "cital", "cited", "citer", "cites", "cives", "civet", "civie", "civvy", "clach", "clade", "clads", "claes", "clags", "clame", "clams", "clans", "claps", "clapt", "claro", "clart", "clary", "clast", "clats", "claut", "clave", "clavi", "claws", "clays", "cleck", "cleek", "cleep", "clefs", "clegs", "cleik", "clems", "clepe", "clept", "cleve", "clews", "clied", "clies", "clift", "clime", "cline", "clint", "clipe", "clips", "clipt", "clits", "cloam", "clods", "cloff", "clogs", "cloke", "clomb", "clomp", "clonk", "clons", "cloop", "cloot", "clops", "clote", "clots", "clour", "clous", "clows", "cloye", "cloys", "cloze", "clubs", "clues", "cluey", "clunk", "clype", "cnida", "coact", "coady", "coala", "coals", "coaly", "coapt", "coarb", "coate", "coati", "coats", "cobbs", "cobby", "cobia", "coble", "cobza", "cocas", "cocci", "cocco", "cocks", "cocky", "cocos", "codas", "codec", "coded", "coden", "coder", "codes", "codex", "codon", "coeds", "coffs", "cogie", "cogon", "cogue", "cohab", "cohen", "cohoe", "cohog", "cohos", "coifs", "coign", "coils", "coins", "coirs", "coits", "coked", "cokes", "colas", "colby", "colds", "coled", "coles", "coley", "colic", "colin", "colls", "colly", "colog", "colts", "colza", "comae", "comal", "comas", "combe", "combi", "combo", "combs", "comby", "comer", "comes", "comix", "commo", "comms", "commy", "compo", "comps", "compt", "comte", "comus", "coned", "cones", "coney", "confs", "conga", "conge", "congo", "conia", "conin", "conks", "conky", "conne", "conns", "conte", "conto", "conus", "convo", "cooch", "cooed", "cooee", "cooer", "cooey", "coofs", "cooks", "cooky", "cools", "cooly", "coomb", "cooms", "coomy", "coops", "coopt", "coost", "coots", "cooze", "copal", "copay", "coped", "copen", "coper", "copes", "coppy", "copra", "copsy", "coqui", "coram", "corbe", "corby", "cords", "cored", "cores", "corey", "corgi", "coria", "corks", "corky", "corms", "corni", "corno", "corns", "cornu", "corps", "corse", "corso", "cosec", "cosed", "coses", "coset", "cosey", "cosie", "costa", "coste", "costs", "cotan", "coted", "cotes", "coths", "cotta", "cotts", "coude", "coups", "courb", "courd", "coure", "cours", "couta", "couth", "coved", "coves", "covin", "cowal", "cowan", "cowed", "cowks", "cowls", "cowps", "cowry", "coxae", "coxal", "coxed", "coxes", "coxib", "coyau", "coyed", "coyer", "coypu", "cozed", "cozen", "cozes", "cozey", "cozie", "craal", "crabs", "crags", "craic", "craig", "crake", "crame", "crams", "crans", "crape", "craps", "crapy", "crare", "craws", "crays", "creds", "creel", "crees", "crems", "crena", "creps", "crepy", "crewe", "crews", "crias", "cribs", "cries", "crims", "crine", "crios", "cripe", "crips", "crise", "crith", "crits", "croci", "crocs", "croft", "crogs", "cromb", "crome", "cronk", "crons", "crool", "croon", "crops", "crore", "crost", "crout", "crows", "croze", "cruck", "crudo", "cruds", "crudy", "crues", "cruet", "cruft", "crunk", "cruor", "crura", "cruse", "crusy", "cruve", "crwth", "cryer", "ctene", "cubby", "cubeb", "cubed", "cuber", "cubes", "cubit", "cuddy", "cuffo", "cuffs", "cuifs", "cuing", "cuish", "cuits", "cukes", "culch", "culet", "culex", "culls", "cully", "culms", "culpa", "culti", "cults", "culty", "cumec", "cundy", "cunei", "cunit", "cunts", "cupel", "cupid", "cuppa", "cuppy", "curat", "curbs", "curch", "curds", "curdy", "cured", "curer", "cures", "curet", "curfs", "curia", "curie", "curli", "curls", "curns", "curny", "currs", "cursi", "curst", "cusec", "cushy", "cusks", "cusps", "cuspy", "cusso", "cusum", "cutch", "cuter", "cutes", "cutey", "cutin", "cutis", "cutto", "cutty", "cutup", "cuvee", "cuzes", "cwtch", "cyano", "cyans", "cycad", "cycas", "cyclo", "cyder", "cylix", "cymae", "cymar", "cymas", "cymes", "cymol", "cysts", "cytes", "cyton", "czars", "daals", "dabba", "daces", "dacha", "dacks", "dadah", "dadas", "dados", "daffs", "daffy", "dagga", "daggy", "dagos", "dahls", "daiko", "daine", "daint", "daker", "daled", "dales", "dalis", "dalle", "dalts", "daman", "damar", "dames", "damme", "damns", "damps", "dampy", "dancy", "dangs", "danio", "danks", "danny", "dants", "daraf", "darbs", "darcy", "dared", "darer", "dares", "darga", "dargs", "daric", "daris", "darks", "darns", "darre", "darts", "darzi", "dashi", "dashy", "datal", "dated", "dater", "dates", "datos", "datto", "daube", "daubs", "dauby", "dauds", "dault", "daurs", "dauts", "daven", "davit", "dawah", "dawds", "dawed", "dawen", "dawks", "dawns", "dawts", "dayan", "daych", "daynt", "dazed", "dazer", "dazes", "deads", "deair", "deals", "deans", "deare", "dearn", "dears", "deary", "deash", "deave", "deaws", "deawy", "debag", "debby", "debel", "debes", "debts", "debud", "debur", "debus", "debye", "decad", "decaf", "decan", "decko", "decks", "decos", "dedal", "deeds", "deedy", "deely", "deems", "deens", "deeps", "deere", "deers", "deets", "deeve", "deevs", "defat", "deffo", "defis", "defog", "degas", "degum", "degus", "deice", "deids", "deify", "deils", "deism", "deist", "deked", "dekes", "dekko", "deled", "deles", "delfs", "delft", "delis", "dells", "delly", "delos", "delph", "delts", "deman", "demes", "demic", "demit", "demob", "demoi", "demos", "dempt", "denar", "denay", "dench", "denes", "denet", "denis", "dents", "deoxy", "derat", "deray", "dered", "deres", "derig", "derma", "derms", "derns", "derny", "deros", "derro", "derry", "derth", "dervs", "desex", "deshi", "desis", "desks", "desse", "devas", "devel", "devis", "devon", "devos", "devot", "dewan", "dewar", "dewax", "dewed", "dexes", "dexie", "dhaba", "dhaks", "dhals", "dhikr", "dhobi", "dhole", "dholl", "dhols", "dhoti", "dhows", "dhuti", "diact", "dials", "diane", "diazo", "dibbs", "diced", "dicer", "dices", "dicht", "dicks", "dicky", "dicot", "dicta", "dicts", "dicty", "diddy", "didie", "didos", "didst", "diebs", "diels", "diene", "diets", "diffs", "dight", "dikas", "diked", "diker", "dikes", "dikey", "dildo", "dilli", "dills", "dimbo", "dimer", "dimes", "dimps", "dinar", "dined", "dines", "dinge", "dings", "dinic", "dinks", "dinky", "dinna", "dinos", "dints", "diols", "diota", "dippy", "dipso", "diram", "direr", "dirke", "dirks", "dirls", "dirts", "disas", "disci", "discs", "dishy", "disks", "disme", "dital", "ditas", "dited", "dites", "ditsy", "ditts", "ditzy", "divan", "divas", "dived", "dives", "divis", "divna", "divos", "divot", "divvy", "diwan", "dixie", "dixit", "diyas", "dizen", "djinn", "djins", "doabs", "doats", "dobby", "dobes", "dobie", "dobla", "dobra", "dobro", "docht", "docks", "docos", "docus", "doddy", "dodos", "doeks", "doers", "doest", "doeth", "doffs", "dogan", "doges", "dogey", "doggo", "doggy", "dogie", "dohyo", "doilt", "doily", "doits", "dojos", "dolce", "dolci", "doled", "doles", "dolia", "dolls", "dolma", "dolor", "dolos", "dolts", "domal", "domed", "domes", "domic", "donah", "donas", "donee", "doner", "donga", "dongs", "donko", "donna", "donne", "donny", "donsy", "doobs", "dooce", "doody", "dooks", "doole", "dools", "dooly", "dooms", "doomy", "doona", "doorn", "doors", "doozy", "dopas", "doped", "doper", "dopes", "dorad", "dorba", "dorbs", "doree", "dores", "doric", "doris", "dorks", "dorky", "dorms", "dormy", "dorps", "dorrs", "dorsa", "dorse", "dorts", "dorty", "dosai", "dosas", "dosed", "doseh", "doser", "doses", "dosha", "dotal", "doted", "doter", "dotes", "dotty", "douar", "douce", "doucs", "douks", "doula", "douma", "doums", "doups", "doura", "douse", "douts", "doved", "doven", "dover", "doves", "dovie", "dowar", "dowds", "dowed", "dower", "dowie", "dowle", "dowls", "dowly", "downa", "downs", "dowps", "dowse", "dowts", "doxed", "doxes", "doxie", "doyen", "doyly", "dozed", "dozer", "dozes", "drabs", "drack", "draco", "draff", "drags", "drail", "drams", "drant", "draps", "drats", "drave", "draws", "drays", "drear", "dreck", "dreed", "dreer", "drees", "dregs", "dreks", "drent", "drere", "drest", "dreys", "dribs", "drice", "dries", "drily", "drips", "dript", "droid", "droil", "droke", "drole", "drome", "drony", "droob", "droog", "drook", "drops", "dropt", "drouk", "drows", "drubs", "drugs", "drums", "drupe", "druse", "drusy", "druxy", "dryad", "dryas", "dsobo", "dsomo", "duads", "duals", "duans", "duars", "dubbo", "ducal", "ducat", "duces", "ducks", "ducky", "ducts", "duddy", "duded", "dudes", "duels", "duets", "duett", "duffs", "dufus", "duing", "duits", "dukas", "duked", "dukes", "dukka", "dulce", "dules", "dulia", "dulls", "dulse", "dumas", "dumbo", "dumbs", "dumka", "dumky", "dumps", "dunam", "dunch", "dunes", "dungs", "dungy", "dunks", "dunno", "dunny", "dunsh", "dunts", "duomi", "duomo", "duped", "duper", "dupes", "duple", "duply", "duppy", "dural", "duras", "dured", "dures", "durgy", "durns", "duroc", "duros", "duroy", "durra", "durrs", "durry", "durst", "durum", "durzi", "dusks", "dusts", "duxes", "dwaal", "dwale", "dwalm", "dwams", "dwang", "dwaum", "dweeb", "dwile", "dwine", "dyads", "dyers", "dykon", "dynel", "dynes", "dzhos", "eagre", "ealed", "eales", "eaned", "eards", "eared", "earls", "earns", "earnt", "earst", "eased", "easer", "eases", "easle", "easts", "eathe", "eaved", "eaves", "ebbed", "ebbet", "ebons", "ebook", "ecads", "eched", "eches", "echos", "ecrus", "edema", "edged", "edger", "edges", "edile", "edits", "educe", "educt", "eejit", "eensy", "eeven", "eevns", "effed", "egads", "egers", "egest", "eggar", "egged", "egger", "egmas", "ehing", "eider", "eidos", "eigne", "eiked", "eikon", "eilds", "eisel", "ejido", "ekkas", "elain", "eland", "elans", "elchi", "eldin", "elemi", "elfed", "eliad", "elint", "elmen", "eloge", "elogy", "eloin", "elops", "elpee", "elsin", "elute", "elvan", "elven", "elver", "elves", "emacs", "embar", "embay", "embog", "embow", "embox", "embus", "emeer", "emend", "emerg", "emery", "emeus", "emics", "emirs", "emits", "emmas", "emmer", "emmet", "emmew", "emmys", "emoji", "emong", "emote", "emove", "empts", "emule", "emure", "emyde", "emyds", "enarm", "enate", "ended", "ender", "endew", "endue", "enews", "enfix", "eniac", "enlit", "enmew", "ennog", "enoki", "enols", "enorm", "enows", "enrol", "ensew", "ensky", "entia", "enure", "enurn", "envoi", "enzym", "eorls", "eosin", "epact", "epees", "ephah", "ephas", "ephod", "ephor", "epics", "epode", "epopt", "epris", "eques", "equid", "erbia", "erevs", "ergon", "ergos", "ergot", "erhus", "erica", "erick", "erics", "ering", "erned", "ernes", "erose", "erred", "erses", "eruct", "erugo", "eruvs", "erven", "ervil", "escar", "escot", "esile", "eskar", "esker", "esnes", "esses", "estoc", "estop", "estro", "etage", "etape", "etats", "etens", "ethal", "ethne", "ethyl", "etics", "etnas", "ettin", "ettle", "etuis", "etwee", "etyma", "eughs", "euked", "eupad", "euros", "eusol", "evens", "evert", "evets", "evhoe", "evils", "evite", "evohe", "ewers", "ewest", "ewhow", "ewked", "exams", "exeat", "execs", "exeem", "exeme", "exfil", "exies", "exine", "exing", "exits", "exode", "exome", "exons", "expat", "expos", "exude", "exuls", "exurb", "eyass", "eyers", "eyots", "eyras", "eyres", "eyrie", "eyrir", "ezine", "fabby", "faced", "facer", "faces", "facia", "facta", "facts", "faddy", "faded", "fader", "fades", "fadge", "fados", "faena", "faery", "faffs", "faffy", "fagin", "faiks", "fails", "faine", "fains", "fairs", "faked", "faker", "fakes", "fakey", "fakie", "fakir", "falaj", "falls", "famed", "fames", "fanal", "fands", "fanes", "fanga", "fango", "fangs", "fanks", "fanon", "fanos", "fanum", "faqir", "farad", "farci", "farcy", "fards", "fared", "farer", "fares", "farle", "farls", "farms", "faros", "farro", "farse", "farts", "fasci", "fasti", "fasts", "fated", "fates", "fatly", "fatso", "fatwa", "faugh", "fauld", "fauns", "faurd", "fauts", "fauve", "favas", "favel", "faver", "faves", "favus", "fawns", "fawny", "faxed", "faxes", "fayed", "fayer", "fayne", "fayre", "fazed", "fazes", "feals", "feare", "fears", "feart", "fease", "feats", "feaze", "feces", "fecht", "fecit", "fecks", "fedex", "feebs", "feeds", "feels", "feens", "feers", "feese", "feeze", "fehme", "feint", "feist", "felch", "felid", "fells", "felly", "felts", "felty", "femal", "femes", "femmy", "fends", "fendy", "fenis", "fenks", "fenny", "fents", "feods", "feoff", "ferer", "feres", "feria", "ferly", "fermi", "ferms", "ferns", "ferny", "fesse", "festa", "fests", "festy", "fetas", "feted", "fetes", "fetor", "fetta", "fetts", "fetwa", "feuar", "feuds", "feued", "feyed", "feyer", "feyly", "fezes", "fezzy", "fiars", "fiats", "fibro", "fices", "fiche", "fichu", "ficin", "ficos", "fides", "fidge", "fidos", "fiefs", "fient", "fiere", "fiers", "fiest", "fifed", "fifer", "fifes", "fifis", "figgy", "figos", "fiked", "fikes", "filar", "filch", "filed", "files", "filii", "filks", "fille", "fillo", "fills", "filmi", "films", "filos", "filum", "finca", "finds", "fined", "fines", "finis", "finks", "finny", "finos", "fiord", "fiqhs", "fique", "fired", "firer", "fires", "firie", "firks", "firms", "firns", "firry", "firth", "fiscs", "fisks", "fists", "fisty", "fitch", "fitly", "fitna", "fitte", "fitts", "fiver", "fives", "fixed", "fixes", "fixit", "fjeld", "flabs", "flaff", "flags", "flaks", "flamm", "flams", "flamy", "flane", "flans", "flaps", "flary", "flats", "flava", "flawn", "flaws", "flawy", "flaxy", "flays", "fleam", "fleas", "fleek", "fleer", "flees", "flegs", "fleme", "fleur", "flews", "flexi", "flexo", "fleys", "flics", "flied", "flies", "flimp", "flims", "flips", "flirs", "flisk", "flite", "flits", "flitt", "flobs", "flocs", "floes", "flogs", "flong", "flops", "flors", "flory", "flosh", "flota", "flote", "flows", "flubs", "flued", "flues", "fluey", "fluky", "flump", "fluor", "flurr", "fluty", "fluyt", "flyby", "flype", "flyte", "foals", "foams", "foehn", "fogey", "fogie", "fogle", "fogou", "fohns", "foids", "foils", "foins", "folds", "foley", "folia", "folic", "folie", "folks", "folky", "fomes", "fonda", "fonds", "fondu", "fones", "fonly", "fonts", "foods", "foody", "fools", "foots", "footy", "foram", "forbs", "forby", "fordo", "fords", "forel", "fores", "forex", "forks", "forky", "forme", "forms", "forts", "forza", "forze", "fossa", "fosse", "fouat", "fouds", "fouer", "fouet", "foule", "fouls", "fount", "fours", "fouth", "fovea", "fowls", "fowth", "foxed", "foxes", "foxie", "foyle", "foyne", "frabs", "frack", "fract", "frags", "fraim", "franc", "frape", "fraps", "frass", "frate", "frati", "frats", "fraus", "frays", "frees", "freet", "freit", "fremd", "frena", "freon", "frere", "frets", "fribs", "frier", "fries", "frigs", "frise", "frist", "frith", "frits", "fritt", "frize", "frizz", "froes", "frogs", "frons", "frore", "frorn", "frory", "frosh", "frows", "frowy", "frugs", "frump", "frush", "frust", "fryer", "fubar", "fubby", "fubsy", "fucks", "fucus", "fuddy", "fudgy", "fuels", "fuero", "fuffs", "fuffy", "fugal", "fuggy", "fugie", "fugio", "fugle", "fugly", "fugus", "fujis", "fulls", "fumed", "fumer", "fumes", "fumet", "fundi", "funds", "fundy", "fungo", "fungs", "funks", "fural", "furan", "furca", "furls", "furol", "furrs", "furth", "furze", "furzy", "fused", "fusee", "fusel", "fuses", "fusil", "fusks", "fusts", "fusty", "futon", "fuzed", "fuzee", "fuzes", "fuzil", "fyces", "fyked", "fykes", "fyles", "fyrds", "fytte", "gabba", "gabby", "gable", "gaddi", "gades", "gadge", "gadid", "gadis", "gadje", "gadjo", "gadso", "gaffs", "gaged", "gager", "gages", "gaids", "gains", "gairs", "gaita", "gaits", "gaitt", "gajos", "galah", "galas", "galax", "galea", "galed", "gales", "galls", "gally", "galop", "galut", "galvo", "gamas", "gamay", "gamba", "gambe", "gambo", "gambs", "gamed", "games", "gamey", "gamic", "gamin", "gamme", "gammy", "gamps", "ganch", "gandy", "ganef", "ganev", "gangs", "ganja", "ganof", "gants", "gaols", "gaped", "gaper", "gapes", "gapos", "gappy", "garbe", "garbo", "garbs", "garda", "gares", "garis", "garms", "garni", "garre", "garth", "garum", "gases", "gasps", "gaspy", "gasts", "gatch", "gated", "gater", "gates", "gaths", "gator", "gauch", "gaucy", "gauds", "gauje", "gault", "gaums", "gaumy", "gaups", "gaurs", "gauss", "gauzy", "gavot", "gawcy", "gawds", "gawks", "gawps", "gawsy", "gayal", "gazal", "gazar", "gazed", "gazes", "gazon", "gazoo", "geals", "geans", "geare", "gears", "geats", "gebur", "gecks", "geeks", "geeps", "geest", "geist", "geits", "gelds", "gelee", "gelid", "gelly", "gelts", "gemel", "gemma", "gemmy", "gemot", "genal", "genas", "genes", "genet", "genic", "genii", "genip", "genny", "genoa", "genom", "genro", "gents", "genty", "genua", "genus", "geode", "geoid", "gerah", "gerbe", "geres", "gerle", "germs", "germy", "gerne", "gesse", "gesso", "geste", "gests", "getas", "getup", "geums", "geyan", "geyer", "ghast", "ghats", "ghaut", "ghazi", "ghees", "ghest", "ghyll", "gibed", "gibel", "giber", "gibes", "gibli", "gibus", "gifts", "gigas", "gighe", "gigot", "gigue", "gilas", "gilds", "gilet", "gills", "gilly", "gilpy", "gilts", "gimel", "gimme", "gimps", "gimpy", "ginch", "ginge", "gings", "ginks", "ginny", "ginzo", "gipon", "gippo", "gippy", "girds", "girls", "girns", "giron", "giros", "girrs", "girsh", "girts", "gismo", "gisms", "gists", "gitch", "gites", "giust", "gived", "gives", "gizmo", "glace", "glads", "glady", "glaik", "glair", "glams", "glans", "glary", "glaum", "glaur", "glazy", "gleba", "glebe", "gleby", "glede", "gleds", "gleed", "gleek", "glees", "gleet", "gleis", "glens", "glent", "gleys", "glial", "glias", "glibs", "gliff", "glift", "glike", "glime", "glims", "glisk", "glits", "glitz", "gloam", "globi", "globs", "globy", "glode", "glogg", "gloms", "gloop", "glops", "glost", "glout", "glows", "gloze", "glued", "gluer", "glues", "gluey", "glugs", "glume", "glums", "gluon", "glute", "gluts", "gnarl", "gnarr", "gnars", "gnats", "gnawn", "gnaws", "gnows", "goads", "goafs", "goals", "goary", "goats", "goaty", "goban", "gobar", "gobbi", "gobbo", "gobby", "gobis", "gobos", "godet", "godso", "goels", "goers", "goest", "goeth", "goety", "gofer", "goffs", "gogga", "gogos", "goier", "gojis", "golds", "goldy", "goles", "golfs", "golpe", "golps", "gombo", "gomer", "gompa", "gonch", "gonef", "gongs", "gonia", "gonif", "gonks", "gonna", "gonof", "gonys", "gonzo", "gooby", "goods", "goofs", "googs", "gooky", "goold", "gools", "gooly", "goons", "goony", "goops", "goopy", "goors", "goory", "goosy", "gopak", "gopik", "goral", "goras", "gored", "gores", "goris", "gorms", "gormy", "gorps", "gorse", "gorsy", "gosht", "gosse", "gotch", "goths", "gothy", "gotta", "gouch", "gouks", "goura", "gouts", "gouty", "gowan", "gowds", "gowfs", "gowks", "gowls", "gowns", "goxes", "goyim", "goyle", "graal", "grabs", "grads", "graff", "graip", "grama", "grame", "gramp", "grams", "grana", "grans", "grapy", "gravs", "grays", "grebe", "grebo", "grece", "greek", "grees", "grege", "grego", "grein", "grens", "grese", "greve", "grews", "greys", "grice", "gride", "grids", "griff", "grift", "grigs", "grike", "grins", "griot", "grips", "gript", "gripy", "grise", "grist", "grisy", "grith", "grits", "grize", "groat", "grody", "grogs", "groks", "groma", "grone", "groof", "grosz", "grots", "grouf", "grovy", "grows", "grrls", "grrrl", "grubs", "grued", "grues", "grufe", "grume", "grump", "grund", "gryce", "gryde", "gryke", "grype", "grypt", "guaco", "guana", "guano", "guans", "guars", "gucks", "gucky", "gudes", "guffs", "gugas", "guids", "guimp", "guiro", "gulag", "gular", "gulas", "gules", "gulet", "gulfs", "gulfy", "gulls", "gulph", "gulps", "gulpy", "gumma", "gummi", "gumps", "gundy", "gunge", "gungy", "gunks", "gunky", "gunny", "guqin", "gurdy", "gurge", "gurls", "gurly", "gurns", "gurry", "gursh", "gurus", "gushy", "gusla", "gusle", "gusli", "gussy", "gusts", "gutsy", "gutta", "gutty", "guyed", "guyle", "guyot", "guyse", "gwine", "gyals", "gyans", "gybed", "gybes", "gyeld", "gymps", "gynae", "gynie", "gynny", "gynos", "gyoza", "gypos", "gyppo", "gyppy", "gyral", "gyred", "gyres", "gyron", "gyros", "gyrus", "gytes", "gyved", "gyves", "haafs", "haars", "hable", "habus", "hacek", "hacks", "hadal", "haded", "hades", "hadji", "hadst", "haems", "haets", "haffs", "hafiz", "hafts", "haggs", "hahas", "haick", "haika", "haiks", "haiku", "hails", "haily", "hains", "haint", "hairs", "haith", "hajes", "hajis", "hajji", "hakam", "hakas", "hakea", "hakes", "hakim", "hakus", "halal", "haled", "haler", "hales", "halfa", "halfs", "halid", "hallo", "halls", "halma", "halms", "halon", "halos", "halse", "halts", "halva", "halwa", "hamal", "hamba", "hamed", "hames", "hammy", "hamza", "hanap", "hance", "hanch", "hands", "hangi", "hangs", "hanks", "hanky", "hansa", "hanse", "hants", "haole", "haoma", "hapax", "haply", "happi", "hapus", "haram", "hards", "hared", "hares", "harim", "harks", "harls", "harms", "harns", "haros", "harps", "harts", "hashy", "hasks", "hasps", "hasta", "hated", "hates", "hatha", "hauds", "haufs", "haugh", "hauld", "haulm", "hauls", "hault", "hauns", "hause", "haver", "haves", "hawed", "hawks", "hawms", "hawse", "hayed", "hayer", "hayey", "hayle", "hazan", "hazed", "hazer", "hazes", "heads", "heald", "heals", "heame", "heaps", "heapy", "heare", "hears", "heast", "heats", "heben", "hebes", "hecht", "hecks", "heder", "hedgy", "heeds", "heedy", "heels", "heeze", "hefte", "hefts", "heids", "heigh", "heils", "heirs", "hejab", "hejra", "heled", "heles", "helio", "hells", "helms", "helos", "helot", "helps", "helve", "hemal", "hemes", "hemic", "hemin", "hemps", "hempy", "hench", "hends", "henge", "henna", "henny", "henry", "hents", "hepar", "herbs", "herby", "herds", "heres", "herls", "herma", "herms", "herns", "heros", "herry", "herse", "hertz", "herye", "hesps", "hests", "hetes", "heths", "heuch", "heugh", "hevea", "hewed", "hewer", "hewgh", "hexad", "hexed", "hexer", "hexes", "hexyl", "heyed", "hiant", "hicks", "hided", "hider", "hides", "hiems", "highs", "hight", "hijab", "hijra", "hiked", "hiker", "hikes", "hikoi", "hilar", "hilch", "hillo", "hills", "hilts", "hilum", "hilus", "himbo", "hinau", "hinds", "hings", "hinky", "hinny", "hints", "hiois", "hiply", "hired", "hiree", "hirer", "hires", "hissy", "hists", "hithe", "hived", "hiver", "hives", "hizen", "hoaed", "hoagy", "hoars", "hoary", "hoast", "hobos", "hocks", "hocus", "hodad", "hodja", "hoers", "hogan", "hogen", "hoggs", "hoghs", "hohed", "hoick", "hoied", "hoiks", "hoing", "hoise", "hokas", "hoked", "hokes", "hokey", "hokis", "hokku", "hokum", "holds", "holed", "holes", "holey", "holks", "holla", "hollo", "holme", "holms", "holon", "holos", "holts", "homas", "homed", "homes", "homey", "homie", "homme", "honan", "honda", "honds", "honed", "honer", "hones", "hongi", "hongs", "honks", "honky", "hooch", "hoods", "hoody", "hooey", "hoofs", "hooka", "hooks", "hooky", "hooly", "hoons", "hoops", "hoord", "hoors", "hoosh", "hoots", "hooty", "hoove", "hopak", "hoped", "hoper", "hopes", "hoppy", "horah", "horal", "horas", "horis", "horks", "horme", "horns", "horst", "horsy", "hosed", "hosel", "hosen", "hoser", "hoses", "hosey", "hosta", "hosts", "hotch", "hoten", "hotty", "houff", "houfs", "hough", "houri", "hours", "houts", "hovea", "hoved", "hoven", "hoves", "howbe", "howes", "howff", "howfs", "howks", "howls", "howre", "howso", "hoxed", "hoxes", "hoyas", "hoyed", "hoyle", "hubby", "hucks", "hudna", "hudud", "huers", "huffs", "huffy", "huger", "huggy", "huhus", "huias", "hulas", "hules", "hulks", "hulky", "hullo", "hulls", "hully", "humas", "humfs", "humic", "humps", "humpy", "hunks", "hunts", "hurds", "hurls", "hurly", "hurra", "hurst", "hurts", "hushy", "husks", "husos", "hutia", "huzza", "huzzy", "hwyls", "hydra", "hyens", "hygge", "hying", "hykes", "hylas", "hyleg", "hyles", "hylic", "hymns", "hynde", "hyoid", "hyped", "hypes", "hypha", "hyphy", "hypos", "hyrax", "hyson", "hythe", "iambi", "iambs", "ibrik", "icers", "iched", "iches", "ichor", "icier", "icker", "ickle", "icons", "ictal", "ictic", "ictus", "idant", "ideas", "idees", "ident", "idled", "idles", "idola", "idols", "idyls", "iftar", "igapo", "igged", "iglus", "ihram", "ikans", "ikats", "ikons", "ileac", "ileal", "ileum", "ileus", "iliad", "ilial", "ilium", "iller", "illth", "imago", "imams", "imari", "imaum", "imbar", "imbed", "imide", "imido", "imids", "imine", "imino", "immew", "immit", "immix", "imped", "impis", "impot", "impro", "imshi", "imshy", "inapt", "inarm", "inbye", "incel", "incle", "incog", "incus", "incut", "indew", "india", "indie", "indol", "indow", "indri", "indue", "inerm", "infix", "infos", "infra", "ingan", "ingle", "inion", "inked", "inker", "inkle", "inned", "innit", "inorb", "inrun", "inset", "inspo", "intel", "intil", "intis", "intra", "inula", "inure", "inurn", "inust", "invar", "inwit", "iodic", "iodid", "iodin", "iotas", "ippon", "irade", "irids", "iring", "irked", "iroko", "irone", "irons", "isbas", "ishes", "isled", "isles", "isnae", "issei", "istle", "items", "ither", "ivied", "ivies", "ixias", "ixnay", "ixora", "ixtle", "izard", "izars", "izzat", "jaaps", "jabot", "jacal", "jacks", "jacky", "jaded", "jades", "jafas", "jaffa", "jagas", "jager", "jaggs", "jaggy", "jagir", "jagra", "jails", "jaker", "jakes", "jakey", "jalap", "jalop", "jambe", "jambo", "jambs", "jambu", "james", "jammy", "jamon", "janes", "janns", "janny", "janty", "japan", "japed", "japer", "japes", "jarks", "jarls", "jarps", "jarta", "jarul", "jasey", "jaspe", "jasps", "jatos", "jauks", "jaups", "javas", "javel", "jawan", "jawed", "jaxie", "jeans", "jeats", "jebel", "jedis", "jeels", "jeely", "jeeps", "jeers", "jeeze", "jefes", "jeffs", "jehad", "jehus", "jelab", "jello", "jells", "jembe", "jemmy", "jenny", "jeons", "jerid", "jerks", "jerry", "jesse", "jests", "jesus", "jetes", "jeton", "jeune", "jewed", "jewie", "jhala", "jiaos", "jibba", "jibbs", "jibed", "jiber", "jibes", "jiffs", "jiggy", "jigot", "jihad", "jills", "jilts", "jimmy", "jimpy", "jingo", "jinks", "jinne", "jinni", "jinns", "jirds", "jirga", "jirre", "jisms", "jived", "jiver", "jives", "jivey", "jnana", "jobed", "jobes", "jocko", "jocks", "jocky", "jocos", "jodel", "joeys", "johns", "joins", "joked", "jokes", "jokey", "jokol", "joled", "joles", "jolls", "jolts", "jolty", "jomon", "jomos", "jones", "jongs", "jonty", "jooks", "joram", "jorum", "jotas", "jotty", "jotun", "joual", "jougs", "jouks", "joule", "jours", "jowar", "jowed", "jowls", "jowly", "joyed", "jubas", "jubes", "jucos", "judas", "judgy", "judos", "jugal", "jugum", "jujus", "juked", "jukes", "jukus", "julep", "jumar", "jumby", "jumps", "junco", "junks", "junky", "jupes", "jupon", "jural", "jurat", "jurel", "jures", "justs", "jutes", "jutty", "juves", "juvie", "kaama", "kabab", "kabar", "kabob", "kacha", "kacks", "kadai", "kades", "kadis", "kafir", "kagos", "kagus", "kahal", "kaiak", "kaids", "kaies", "kaifs", "kaika", "kaiks", "kails", "kaims", "kaing", "kains", "kakas", "kakis", "kalam", "kales", "kalif", "kalis", "kalpa", "kamas", "kames", "kamik", "kamis", "kamme", "kanae", "kanas", "kandy", "kaneh", "kanes", "kanga", "kangs", "kanji", "kants", "kanzu", "kaons", "kapas", "kaphs", "kapok", "kapow", "kapus", "kaput", "karas", "karat", "karks", "karns", "karoo", "karos", "karri", "karst", "karsy", "karts", "karzy", "kasha", "kasme", "katal", "katas", "katis", "katti", "kaugh", "kauri", "kauru", "kaury", "kaval", "kavas", "kawas", "kawau", "kawed", "kayle", "kayos", "kazis", "kazoo", "kbars", "kebar", "kebob", "kecks", "kedge", "kedgy", "keech", "keefs", "keeks", "keels", "keema", "keeno", "keens", "keeps", "keets", "keeve", "kefir", "kehua", "keirs", "kelep", "kelim", "kells", "kelly", "kelps", "kelpy", "kelts", "kelty", "kembo", "kembs", "kemps", "kempt", "kempy", "kenaf", "kench", "kendo", "kenos", "kente", "kents", "kepis", "kerbs", "kerel", "kerfs", "kerky", "kerma", "kerne", "kerns", "keros", "kerry", "kerve", "kesar", "kests", "ketas", "ketch", "ketes", "ketol", "kevel", "kevil", "kexes", "keyed", "keyer", "khadi", "khafs", "khans", "khaph", "khats", "khaya", "khazi", "kheda", "kheth", "khets", "khoja", "khors", "khoum", "khuds", "kiaat", "kiack", "kiang", "kibbe", "kibbi", "kibei", "kibes", "kibla", "kicks", "kicky", "kiddo", "kiddy", "kidel", "kidge", "kiefs", "kiers", "kieve", "kievs", "kight", "kikoi", "kiley", "kilim", "kills", "kilns", "kilos", "kilps", "kilts", "kilty", "kimbo", "kinas", "kinda", "kinds", "kindy", "kines", "kings", "kinin", "kinks", "kinos", "kiore", "kipes", "kippa", "kipps", "kirby", "kirks", "kirns", "kirri", "kisan", "kissy", "kists", "kited", "kiter", "kites", "kithe", "kiths", "kitul", "kivas", "kiwis", "klang", "klaps", "klett", "klick", "klieg", "kliks", "klong", "kloof", "kluge", "klutz", "knags", "knaps", "knarl", "knars", "knaur", "knawe", "knees", "knell", "knish", "knits", "knive", "knobs", "knops", "knosp", "knots", "knout", "knowe", "knows", "knubs", "knurl", "knurr", "knurs", "knuts", "koans", "koaps", "koban", "kobos", "koels", "koffs", "kofta", "kogal", "kohas", "kohen", "kohls", "koine", "kojis", "kokam", "kokas", "koker", "kokra", "kokum", "kolas", "kolos", "kombu", "konbu", "kondo", "konks", "kooks", "kooky", "koori", "kopek", "kophs", "kopje", "koppa", "korai", "koras", "korat", "kores", "korma", "koros", "korun", "korus", "koses", "kotch", "kotos", "kotow", "koura", "kraal", "krabs", "kraft", "krais", "krait", "krang", "krans", "kranz", "kraut", "krays", "kreep", "kreng", "krewe", "krona", "krone", "kroon", "krubi", "krunk", "ksars", "kubie", "kudos", "kudus", "kudzu", "kufis", "kugel", "kuias", "kukri", "kukus", "kulak", "kulan", "kulas", "kulfi", "kumis", "kumys", "kuris", "kurre", "kurta", "kurus", "kusso", "kutas", "kutch", "kutis", "kutus", "kuzus", "kvass", "kvell", "kwela", "kyack", "kyaks", "kyang", "kyars", "kyats", "kybos", "kydst", "kyles", "kylie", "kylin", "kylix", "kyloe", "kynde", "kynds", "kypes", "kyrie", "kytes", "kythe", "laari", "labda", "labia", "labis", "labra", "laced", "lacer", "laces", "lacet", "lacey", "lacks", "laddy", "laded", "lader", "lades", "laers", "laevo", "lagan", "lahal", "lahar", "laich", "laics", "laids", "laigh", "laika", "laiks", "laird", "lairs", "lairy", "laith", "laity", "laked", "laker", "lakes", "lakhs", "lakin", "laksa", "laldy", "lalls", "lamas", "lambs", "lamby", "lamed", "lamer", "lames", "lamia", "lammy", "lamps", "lanai", "lanas", "lanch", "lande", "lands", "lanes", "lanks", "lants", "lapin", "lapis", "lapje", "larch", "lards", "lardy", "laree", "lares", "largo", "laris", "larks", "larky", "larns", "larnt", "larum", "lased", "laser", "lases", "lassi", "lassu", "lassy", "lasts", "latah", "lated", "laten", "latex", "lathi", "laths", "lathy", "latke", "latus", "lauan", "lauch", "lauds", "laufs", "laund", "laura", "laval", "lavas", "laved", "laver", "laves", "lavra", "lavvy", "lawed", "lawer", "lawin", "lawks", "lawns", "lawny", "laxed", "laxer", "laxes", "laxly", "layed", "layin", "layup", "lazar", "lazed", "lazes", "lazos", "lazzi", "lazzo", "leads", "leady", "leafs", "leaks", "leams", "leans", "leany", "leaps", "leare", "lears", "leary", "leats", "leavy", "leaze", "leben", "leccy", "ledes", "ledgy", "ledum", "leear", "leeks", "leeps", "leers", "leese", "leets", "leeze", "lefte", "lefts", "leger", "leges", "legge", "leggo", "legit", "lehrs", "lehua", "leirs", "leish", "leman", "lemed", "lemel", "lemes", "lemma", "lemme", "lends", "lenes", "lengs", "lenis", "lenos", "lense", "lenti", "lento", "leone", "lepid", "lepra", "lepta", "lered", "leres", "lerps", "leses", "lests", "letch", "lethe", "letup", "leuch", "leuco", "leuds", "leugh", "levas", "levee", "leves", "levin", "levis", "lewis", "lexes", "lexis", "lezes", "lezza", "lezzy", "liana", "liane", "liang", "liard", "liars", "liart", "liber", "libra", "libri", "lichi", "licht", "licit", "licks", "lidar", "lidos", "liefs", "liens", "liers", "lieus", "lieve", "lifer", "lifes", "lifts", "ligan", "liger", "ligge", "ligne", "liked", "liker", "likes", "likin", "lills", "lilos", "lilts", "liman", "limas", "limax", "limba", "limbi", "limbs", "limby", "limed", "limen", "limes", "limey", "limma", "limns", "limos", "limpa", "limps", "linac", "linch", "linds", "lindy", "lined", "lines", "liney", "linga", "lings", "lingy", "linin", "links", "linky", "linns", "linny", "linos", "lints", "linty", "linum", "linux", "lions", "lipas", "lipes", "lipin", "lipos", "lippy", "liras", "lirks", "lirot", "lisks", "lisle", "lisps", "lists", "litai", "litas", "lited", "liter", "lites", "litho", "liths", "litre", "lived", "liven", "lives", "livor", "livre", "llano", "loach", "loads", "loafs", "loams", "loans", "loast", "loave", "lobar", "lobed", "lobes", "lobos", "lobus", "loche", "lochs", "locie", "locis", "locks", "locos", "locum", "loden", "lodes", "loess", "lofts", "logan", "loges", "loggy", "logia", "logie", "logoi", "logon", "logos", "lohan", "loids", "loins", "loipe", "loirs", "lokes", "lolls", "lolly", "lolog", "lomas", "lomed", "lomes", "loner", "longa", "longe", "longs", "looby", "looed", "looey", "loofa", "loofs", "looie", "looks", "looky", "looms", "loons", "loony", "loops", "loord", "loots", "loped", "loper", "lopes", "loppy", "loral", "loran", "lords", "lordy", "lorel", "lores", "loric", "loris", "losed", "losel", "losen", "loses", "lossy", "lotah", "lotas", "lotes", "lotic", "lotos", "lotsa", "lotta", "lotte", "lotto", "lotus", "loued", "lough", "louie", "louis", "louma", "lound", "louns", "loupe", "loups", "loure", "lours", "loury", "louts", "lovat", "loved", "loves", "lovey", "lovie", "lowan", "lowed", "lowes", "lownd", "lowne", "lowns", "lowps", "lowry", "lowse", "lowts", "loxed", "loxes", "lozen", "luach", "luaus", "lubed", "lubes", "lubra", "luces", "lucks", "lucre", "ludes", "ludic", "ludos", "luffa", "luffs", "luged", "luger", "luges", "lulls", "lulus", "lumas", "lumbi", "lumme", "lummy", "lumps", "lunas", "lunes", "lunet", "lungi", "lungs", "lunks", "lunts", "lupin", "lured", "lurer", "lures", "lurex", "lurgi", "lurgy", "lurks", "lurry", "lurve", "luser", "lushy", "lusks", "lusts", "lusus", "lutea", "luted", "luter", "lutes", "luvvy", "luxed", "luxer", "luxes", "lweis", "lyams", "lyard", "lyart", "lyase", "lycea", "lycee", "lycra", "lymes", "lynes", "lyres", "lysed", "lyses", "lysin", "lysis", "lysol", "lyssa", "lyted", "lytes", "lythe", "lytic", "lytta", "maaed", "maare", "maars", "mabes", "macas", "maced", "macer", "maces", "mache", "machi", "machs", "macks", "macle", "macon", "madge", "madid", "madre", "maerl", "mafic", "mages", "maggs", "magot", "magus", "mahoe", "mahua", "mahwa", "maids", "maiko", "maiks", "maile", "maill", "mails", "maims", "mains", "maire", "mairs", "maise", "maist", "makar", "makes", "makis", "makos", "malam", "malar", "malas", "malax", "males", "malic", "malik", "malis", "malls", "malms", "malmy", "malts", "malty", "malus", "malva", "malwa", "mamas", "mamba", "mamee", "mamey", "mamie", "manas", "manat", "mandi", "maneb", "maned", "maneh", "manes", "manet", "mangs", "manis", "manky", "manna", "manos", "manse", "manta", "manto", "manty", "manul", "manus", "mapau", "maqui", "marae", "marah", "maras", "marcs", "mardy", "mares", "marge", "margs", "maria", "marid", "marka", "marks", "marle", "marls", "marly", "marms", "maron", "maror", "marra", "marri", "marse", "marts", "marvy", "masas", "mased", "maser", "mases", "mashy", "masks", "massa", "massy", "masts", "masty", "masus", "matai", "mated", "mater", "mates", "maths", "matin", "matlo", "matte", "matts", "matza", "matzo", "mauby", "mauds", "mauls", "maund", "mauri", "mausy", "mauts", "mauzy", "maven", "mavie", "mavin", "mavis", "mawed", "mawks", "mawky", "mawns", "mawrs", "maxed", "maxes", "maxis", "mayan", "mayas", "mayed", "mayos", "mayst", "mazed", "mazer", "mazes", "mazey", "mazut", "mbira", "meads", "meals", "meane", "means", "meany", "meare", "mease", "meath", "meats", "mebos", "mechs", "mecks", "medii", "medle", "meeds", "meers", "meets", "meffs", "meins", "meint", "meiny", "meith", "mekka", "melas", "melba", "melds", "melic", "melik", "mells", "melts", "melty", "memes", "memos", "menad", "mends", "mened", "menes", "menge", "mengs", "mensa", "mense", "mensh", "menta", "mento", "menus", "meous", "meows", "merch", "mercs", "merde", "mered", "merel", "merer", "meres", "meril", "meris", "merks", "merle", "merls", "merse", "mesal", "mesas", "mesel", "meses", "meshy", "mesic", "mesne", "meson", "messy", "mesto", "meted", "metes", "metho", "meths", "metic", "metif", "metis", "metol", "metre", "meuse", "meved", "meves", "mewed", "mewls", "meynt", "mezes", "mezze", "mezzo", "mhorr", "miaou", "miaow", "miasm", "miaul", "micas", "miche", "micht", "micks", "micky", "micos", "micra", "middy", "midgy", "midis", "miens", "mieve", "miffs", "miffy", "mifty", "miggs", "mihas", "mihis", "miked", "mikes", "mikra", "mikva", "milch", "milds", "miler", "miles", "milfs", "milia", "milko", "milks", "mille", "mills", "milor", "milos", "milpa", "milts", "milty", "miltz", "mimed", "mimeo", "mimer", "mimes", "mimsy", "minae", "minar", "minas", "mincy", "minds", "mined", "mines", "minge", "mings", "mingy", "minis", "minke", "minks", "minny", "minos", "mints", "mired", "mires", "mirex", "mirid", "mirin", "mirks", "mirky", "mirly", "miros", "mirvs", "mirza", "misch", "misdo", "mises", "misgo", "misos", "missa", "mists", "misty", "mitch", "miter", "mites", "mitis", "mitre", "mitts", "mixed", "mixen", "mixer", "mixes", "mixte", "mixup", "mizen", "mizzy", "mneme", "moans", "moats", "mobby", "mobes", "mobey", "mobie", "moble", "mochi", "mochs", "mochy", "mocks", "moder", "modes", "modge", "modii", "modus", "moers", "mofos", "moggy", "mohel", "mohos", "mohrs", "mohua", "mohur", "moile", "moils", "moira", "moire", "moits", "mojos", "mokes", "mokis", "mokos", "molal", "molas", "molds", "moled", "moles", "molla", "molls", "molly", "molto", "molts", "molys", "momes", "momma", "mommy", "momus", "monad", "monal", "monas", "monde", "mondo", "moner", "mongo", "mongs", "monic", "monie", "monks", "monos", "monte", "monty", "moobs", "mooch", "moods", "mooed", "mooks", "moola", "mooli", "mools", "mooly", "moong", "moons", "moony", "moops", "moors", "moory", "moots", "moove", "moped", "moper", "mopes", "mopey", "moppy", "mopsy", "mopus", "morae", "moras", "morat", "moray", "morel", "mores", "moria", "morne", "morns", "morra", "morro", "morse", "morts", "mosed", "moses", "mosey", "mosks", "mosso", "moste", "mosts", "moted", "moten", "motes", "motet", "motey", "moths", "mothy", "motis", "motte", "motts", "motty", "motus", "motza", "mouch", "moues", "mould", "mouls", "moups", "moust", "mousy", "moved", "moves", "mowas", "mowed", "mowra", "moxas", "moxie", "moyas", "moyle", "moyls", "mozed", "mozes", "mozos", "mpret", "mucho", "mucic", "mucid", "mucin", "mucks", "mucor", "mucro", "mudge", "mudir", "mudra", "muffs", "mufti", "mugga", "muggs", "muggy", "muhly", "muids", "muils", "muirs", "muist", "mujik", "mulct", "muled", "mules", "muley", "mulga", "mulie", "mulla", "mulls", "mulse", "mulsh", "mumms", "mumps", "mumsy", "mumus", "munga", "munge", "mungo", "mungs", "munis", "munts", "muntu", "muons", "muras", "mured", "mures", "murex", "murid", "murks", "murls", "murly", "murra", "murre", "murri", "murrs", "murry", "murti", "murva", "musar", "musca", "mused", "muser", "muses", "muset", "musha", "musit", "musks", "musos", "musse", "mussy", "musth", "musts", "mutch", "muted", "muter", "mutes", "mutha", "mutis", "muton", "mutts", "muxed", "muxes", "muzak", "muzzy", "mvule", "myall", "mylar", "mynah", "mynas", "myoid", "myoma", "myope", "myops", "myopy", "mysid", "mythi", "myths", "mythy", "myxos", "mzees", "naams", "naans", "nabes", "nabis", "nabks", "nabla", "nabob", "nache", "nacho", "nacre", "nadas", "naeve", "naevi", "naffs", "nagas", "naggy", "nagor", "nahal", "naiad", "naifs", "naiks", "nails", "naira", "nairu", "naked", "naker", "nakfa", "nalas", "naled", "nalla", "named", "namer", "names", "namma", "namus", "nanas", "nance", "nancy", "nandu", "nanna", "nanos", "nanua", "napas", "naped", "napes", "napoo", "nappa", "nappe", "nappy", "naras", "narco", "narcs", "nards", "nares", "naric", "naris", "narks", "narky", "narre", "nashi", "natch", "nates", "natis", "natty", "nauch", "naunt", "navar", "naves", "navew", "navvy", "nawab", "nazes", "nazir", "nazis", "nduja", "neafe", "neals", "neaps", "nears", "neath", "neats", "nebek", "nebel", "necks", "neddy", "needs", "neeld", "neele", "neemb", "neems", "neeps", "neese", "neeze", "negro", "negus", "neifs", "neist", "neive", "nelis", "nelly", "nemas", "nemns", "nempt", "nenes", "neons", "neper", "nepit", "neral", "nerds", "nerka", "nerks", "nerol", "nerts", "nertz", "nervy", "nests", "netes", "netop", "netts", "netty", "neuks", "neume", "neums", "nevel", "neves", "nevus", "newbs", "newed", "newel", "newie", "newsy", "newts", "nexts", "nexus", "ngaio", "ngana", "ngati", "ngoma", "ngwee", "nicad", "nicht", "nicks", "nicol", "nidal", "nided", "nides", "nidor", "nidus", "niefs", "nieve", "nifes", "niffs", "niffy", "nifty", "niger", "nighs", "nihil", "nikab", "nikah", "nikau", "nills", "nimbi", "nimbs", "nimps", "niner", "nines", "ninon", "nipas", "nippy", "niqab", "nirls", "nirly", "nisei", "nisse", "nisus", "niter", "nites", "nitid", "niton", "nitre", "nitro", "nitry", "nitty", "nival", "nixed", "nixer", "nixes", "nixie", "nizam", "nkosi", "noahs", "nobby", "nocks", "nodal", "noddy", "nodes", "nodus", "noels", "noggs", "nohow", "noils", "noily", "noint", "noirs", "noles", "nolls", "nolos", "nomas", "nomen", "nomes", "nomic", "nomoi", "nomos", "nonas", "nonce", "nones", "nonet", "nongs", "nonis", "nonny", "nonyl", "noobs", "nooit", "nooks", "nooky", "noons", "noops", "nopal", "noria", "noris", "norks", "norma", "norms", "nosed", "noser", "noses", "notal", "noted", "noter", "notes", "notum", "nould", "noule", "nouls", "nouns", "nouny", "noups", "novae", "novas", "novum", "noway", "nowed", "nowls", "nowts", "nowty", "noxal", "noxes", "noyau", "noyed", "noyes", "nubby", "nubia", "nucha", "nuddy", "nuder", "nudes", "nudie", "nudzh", "nuffs", "nugae", "nuked", "nukes", "nulla", "nulls", "numbs", "numen", "nummy", "nunny", "nurds", "nurdy", "nurls", "nurrs", "nutso", "nutsy", "nyaff", "nyala", "nying", "nyssa", "oaked", "oaker", "oakum", "oared", "oases", "oasis", "oasts", "oaten", "oater", "oaths", "oaves", "obang", "obeah", "obeli", "obeys", "obias", "obied", "obiit", "obits", "objet", "oboes", "obole", "oboli", "obols", "occam", "ocher", "oches", "ochre", "ochry", "ocker", "ocrea", "octad", "octan", "octas", "octyl", "oculi", "odahs", "odals", "odeon", "odeum", "odism", "odist", "odium", "odors", "odour", "odyle", "odyls", "ofays", "offed", "offie", "oflag", "ofter", "ogams", "ogeed", "ogees", "oggin", "ogham", "ogive", "ogled", "ogler", "ogles", "ogmic", "ogres", "ohias", "ohing", "ohmic", "ohone", "oidia", "oiled", "oiler", "oinks", "oints", "ojime", "okapi", "okays", "okehs", "okras", "oktas", "oldie", "oleic", "olein", "olent", "oleos", "oleum", "olios", "ollas", "ollav", "oller", "ollie", "ology", "olpae", "olpes", "omasa", "omber", "ombus", "omens", "omers", "omits", "omlah", "omovs", "omrah", "oncer", "onces", "oncet", "oncus", "onely", "oners", "onery", "onium", "onkus", "onlay", "onned", "ontic", "oobit", "oohed", "oomph", "oonts", "ooped", "oorie", "ooses", "ootid", "oozed", "oozes", "opahs", "opals", "opens", "opepe", "oping", "oppos", "opsin", "opted", "opter", "orach", "oracy", "orals", "orang", "orant", "orate", "orbed", "orcas", "orcin", "ordos", "oread", "orfes", "orgia", "orgic", "orgue", "oribi", "oriel", "orixa", "orles", "orlon", "orlop", "ormer", "ornis", "orpin", "orris", "ortho", "orval", "orzos", "oscar", "oshac", "osier", "osmic", "osmol", "ossia", "ostia", "otaku", "otary", "ottar", "ottos", "oubit", "oucht", "ouens", "ouija", "oulks", "oumas", "oundy", "oupas", "ouped", "ouphe", "ouphs", "ourie", "ousel", "ousts", "outby", "outed", "outre", "outro", "outta", "ouzel", "ouzos", "ovals", "ovels", "ovens", "overs", "ovist", "ovoli", "ovolo", "ovule", "owche", "owies", "owled", "owler", "owlet", "owned", "owres", "owrie", "owsen", "oxbow", "oxers", "oxeye", "oxids", "oxies", "oxime", "oxims", "oxlip", "oxter", "oyers", "ozeki", "ozzie", "paals", "paans", "pacas", "paced", "pacer", "paces", "pacey", "pacha", "packs", "pacos", "pacta", "pacts", "padis", "padle", "padma", "padre", "padri", "paean", "paedo", "paeon", "paged", "pager", "pages", "pagle", "pagod", "pagri", "paiks", "pails", "pains", "paire", "pairs", "paisa", "paise", "pakka", "palas", "palay", "palea", "paled", "pales", "palet", "palis", "palki", "palla", "palls", "pally", "palms", "palmy", "palpi", "palps", "palsa", "pampa", "panax", "pance", "panda", "pands", "pandy", "paned", "panes", "panga", "pangs", "panim", "panko", "panne", "panni", "panto", "pants", "panty", "paoli", "paolo", "papas", "papaw", "papes", "pappi", "pappy", "parae", "paras", "parch", "pardi", "pards", "pardy", "pared", "paren", "pareo", "pares", "pareu", "parev", "parge", "pargo", "paris", "parki", "parks", "parky", "parle", "parly", "parma", "parol", "parps", "parra", "parrs", "parti", "parts", "parve", "parvo", "paseo", "pases", "pasha", "pashm", "paska", "paspy", "passe", "pasts", "pated", "paten", "pater", "pates", "paths", "patin", "patka", "patly", "patte", "patus", "pauas", "pauls", "pavan", "paved", "paven", "paver", "paves", "pavid", "pavin", "pavis", "pawas", "pawaw", "pawed", "pawer", "pawks", "pawky", "pawls", "pawns", "paxes", "payed", "payor", "paysd", "peage", "peags", "peaks", "peaky", "peals", "peans", "peare", "pears", "peart", "pease", "peats", "peaty", "peavy", "peaze", "pebas", "pechs", "pecke", "pecks", "pecky", "pedes", "pedis", "pedro", "peece", "peeks", "peels", "peens", "peeoy", "peepe", "peeps", "peers", "peery", "peeve", "peggy", "peghs", "peins", "peise", "peize", "pekan", "pekes", "pekin", "pekoe", "pelas", "pelau", "peles", "pelfs", "pells", "pelma", "pelon", "pelta", "pelts", "pends", "pendu", "pened", "penes", "pengo", "penie", "penis", "penks", "penna", "penni", "pents", "peons", "peony", "pepla", "pepos", "peppy", "pepsi", "perai", "perce", "percs", "perdu", "perdy", "perea", "peres", "peris", "perks", "perms", "perns", "perog", "perps", "perry", "perse", "perst", "perts", "perve", "pervo", "pervs", "pervy", "pesos", "pests", "pesty", "petar", "peter", "petit", "petre", "petri", "petti", "petto", "pewee", "pewit", "peyse", "phage", "phang", "phare", "pharm", "pheer", "phene", "pheon", "phese", "phial", "phish", "phizz", "phlox", "phoca", "phono", "phons", "phots", "phpht", "phuts", "phyla", "phyle", "piani", "pians", "pibal", "pical", "picas", "piccy", "picks", "picot", "picra", "picul", "piend", "piers", "piert", "pieta", "piets", "piezo", "pight", "pigmy", "piing", "pikas", "pikau", "piked", "piker", "pikes", "pikey", "pikis", "pikul", "pilae", "pilaf", "pilao", "pilar", "pilau", "pilaw", "pilch", "pilea", "piled", "pilei", "piler", "piles", "pilis", "pills", "pilow", "pilum", "pilus", "pimas", "pimps", "pinas", "pined", "pines", "pingo", "pings", "pinko", "pinks", "pinna", "pinny", "pinon", "pinot", "pinta", "pints", "pinup", "pions", "piony", "pious", "pioye", "pioys", "pipal", "pipas", "piped", "pipes", "pipet", "pipis", "pipit", "pippy", "pipul", "pirai", "pirls", "pirns", "pirog", "pisco", "pises", "pisky", "pisos", "pissy", "piste", "pitas", "piths", "piton", "pitot", "pitta", "piums", "pixes", "pized", "pizes", "plaas", "plack", "plage", "plans", "plaps", "plash", "plasm", "plast", "plats", "platt", "platy", "playa", "plays", "pleas", "plebe", "plebs", "plena", "pleon", "plesh", "plews", "plica", "plies", "plims", "pling", "plink", "ploat", "plods", "plong", "plonk", "plook", "plops", "plots", "plotz", "plouk", "plows", "ploye", "ploys", "plues", "pluff", "plugs", "plums", "plumy", "pluot", "pluto", "plyer", "poach", "poaka", "poake", "poboy", "pocks", "pocky", "podal", "poddy", "podex", "podge", "podgy", "podia", "poems", "poeps", "poets", "pogey", "pogge", "pogos", "pohed", "poilu", "poind", "pokal", "poked", "pokes", "pokey", "pokie", "poled", "poler", "poles", "poley", "polio", "polis", "polje", "polks", "polls", "polly", "polos", "polts", "polys", "pombe", "pomes", "pommy", "pomos", "pomps", "ponce", "poncy", "ponds", "pones", "poney", "ponga", "pongo", "pongs", "pongy", "ponks", "ponts", "ponty", "ponzu", "poods", "pooed", "poofs", "poofy", "poohs", "pooja", "pooka", "pooks", "pools", "poons", "poops", "poopy", "poori", "poort", "poots", "poove", "poovy", "popes", "poppa", "popsy", "porae", "poral", "pored", "porer", "pores", "porge", "porgy", "porin", "porks", "porky", "porno", "porns", "porny", "porta", "ports", "porty", "posed", "poses", "posey", "posho", "posts", "potae", "potch", "poted", "potes", "potin", "potoo", "potsy", "potto", "potts", "potty", "pouff", "poufs", "pouke", "pouks", "poule", "poulp", "poult", "poupe", "poupt", "pours", "pouts", "powan", "powin", "pownd", "powns", "powny", "powre", "poxed", "poxes", "poynt", "poyou", "poyse", "pozzy", "praam", "prads", "prahu", "prams", "prana", "prang", "praos", "prase", "prate", "prats", "pratt", "praty", "praus", "prays", "predy", "preed", "prees", "preif", "prems", "premy", "prent", "preon", "preop", "preps", "presa", "prese", "prest", "preve", "prexy", "preys", "prial", "pricy", "prief", "prier", "pries", "prigs", "prill", "prima", "primi", "primp", "prims", "primy", "prink", "prion", "prise", "priss", "proas", "probs", "prods", "proem", "profs", "progs", "proin", "proke", "prole", "proll", "promo", "proms", "pronk", "props", "prore", "proso", "pross", "prost", "prosy", "proto", "proul", "prows", "proyn", "prunt", "pruta", "pryer", "pryse", "pseud", "pshaw", "psion", "psoae", "psoai", "psoas", "psora", "psych", "psyop", "pubco", "pubes", "pubis", "pucan", "pucer", "puces", "pucka", "pucks", "puddy", "pudge", "pudic", "pudor", "pudsy", "pudus", "puers", "puffa", "puffs", "puggy", "pugil", "puhas", "pujah", "pujas", "pukas", "puked", "puker", "pukes", "pukey", "pukka", "pukus", "pulao", "pulas", "puled", "puler", "pules", "pulik", "pulis", "pulka", "pulks", "pulli", "pulls", "pully", "pulmo", "pulps", "pulus", "pumas", "pumie", "pumps", "punas", "punce", "punga", "pungs", "punji", "punka", "punks", "punky", "punny", "punto", "punts", "punty", "pupae", "pupas", "pupus", "purda", "pured", "pures", "purin", "puris", "purls", "purpy", "purrs", "pursy", "purty", "puses", "pusle", "putid", "puton", "putti", "putto", "putts", "puzel", "pwned", "pyats", "pyets", "pygal", "pyins", "pylon", "pyned", "pynes", "pyoid", "pyots", "pyral", "pyran", "pyres", "pyrex", "pyric", "pyros", "pyxed", "pyxes", "pyxie", "pyxis", "pzazz", "qadis", "qaids", "qajaq", "qanat", "qapik", "qibla", "qophs", "qorma", "quads", "quaff", "quags", "quair", "quais", "quaky", "quale", "quant", "quare", "quass", "quate", "quats", "quayd", "quays", "qubit", "quean", "queme", "quena", "quern", "queyn", "queys", "quich", "quids", "quiff", "quims", "quina", "quine", "quino", "quins", "quint", "quipo", "quips", "quipu", "quire", "quirt", "quist", "quits", "quoad", "quods", "quoif", "quoin", "quoit", "quoll", "quonk", "quops", "qursh", "quyte", "rabat", "rabic", "rabis", "raced", "races", "rache", "racks", "racon", "radge", "radix", "radon", "raffs", "rafts", "ragas", "ragde", "raged", "ragee", "rager", "rages", "ragga", "raggs", "raggy", "ragis", "ragus", "rahed", "rahui", "raias", "raids", "raiks", "raile", "rails", "raine", "rains", "raird", "raita", "raits", "rajas", "rajes", "raked", "rakee", "raker", "rakes", "rakia", "rakis", "rakus", "rales", "ramal", "ramee", "ramet", "ramie", "ramin", "ramis", "rammy", "ramps", "ramus", "ranas", "rance", "rands", "ranee", "ranga", "rangi", "rangs", "rangy", "ranid", "ranis", "ranke", "ranks", "rants", "raped", "raper", "rapes", "raphe", "rappe", "rared", "raree", "rares", "rarks", "rased", "raser", "rases", "rasps", "rasse", "rasta", "ratal", "ratan", "ratas", "ratch", "rated", "ratel", "rater", "rates", "ratha", "rathe", "raths", "ratoo", "ratos", "ratus", "rauns", "raupo", "raved", "ravel", "raver", "raves", "ravey", "ravin", "rawer", "rawin", "rawly", "rawns", "raxed", "raxes", "rayah", "rayas", "rayed", "rayle", "rayne", "razed", "razee", "razer", "razes", "razoo", "readd", "reads", "reais", "reaks", "realo", "reals", "reame", "reams", "reamy", "reans", "reaps", "rears", "reast", "reata", "reate", "reave", "rebbe", "rebec", "rebid", "rebit", "rebop", "rebuy", "recal", "recce", "recco", "reccy", "recit", "recks", "recon", "recta", "recti", "recto", "redan", "redds", "reddy", "reded", "redes", "redia", "redid", "redip", "redly", "redon", "redos", "redox", "redry", "redub", "redux", "redye", "reech", "reede", "reeds", "reefs", "reefy", "reeks", "reeky", "reels", "reens", "reest", "reeve", "refed", "refel", "reffo", "refis", "refix", "refly", "refry", "regar", "reges", "reggo", "regie", "regma", "regna", "regos", "regur", "rehem", "reifs", "reify", "reiki", "reiks", "reink", "reins", "reird", "reist", "reive", "rejig", "rejon", "reked", "rekes", "rekey", "relet", "relie", "relit", "rello", "reman", "remap", "remen", "remet", "remex", "remix", "renay", "rends", "reney", "renga", "renig", "renin", "renne", "renos", "rente", "rents", "reoil", "reorg", "repeg", "repin", "repla", "repos", "repot", "repps", "repro", "reran", "rerig", "resat", "resaw", "resay", "resee", "reses", "resew", "resid", "resit", "resod", "resow", "resto", "rests", "resty", "resus", "retag", "retax", "retem", "retia", "retie", "retox", "revet", "revie", "rewan", "rewax", "rewed", "rewet", "rewin", "rewon", "rewth", "rexes", "rezes", "rheas", "rheme", "rheum", "rhies", "rhime", "rhine", "rhody", "rhomb", "rhone", "rhumb", "rhyne", "rhyta", "riads", "rials", "riant", "riata", "ribas", "ribby", "ribes", "riced", "ricer", "rices", "ricey", "richt", "ricin", "ricks", "rides", "ridgy", "ridic", "riels", "riems", "rieve", "rifer", "riffs", "rifte", "rifts", "rifty", "riggs", "rigol", "riled", "riles", "riley", "rille", "rills", "rimae", "rimed", "rimer", "rimes", "rimus", "rinds", "rindy", "rines", "rings", "rinks", "rioja", "riots", "riped", "ripes", "ripps", "rises", "rishi", "risks", "risps", "risus", "rites", "ritts", "ritzy", "rivas", "rived", "rivel", "riven", "rives", "riyal", "rizas", "roads", "roams", "roans", "roars", "roary", "roate", "robed", "robes", "roble", "rocks", "roded", "rodes", "roguy", "rohes", "roids", "roils", "roily", "roins", "roist", "rojak", "rojis", "roked", "roker", "rokes", "rolag", "roles", "rolfs", "rolls", "romal", "roman", "romeo", "romps", "ronde", "rondo", "roneo", "rones", "ronin", "ronne", "ronte", "ronts", "roods", "roofs", "roofy", "rooks", "rooky", "rooms", "roons", "roops", "roopy", "roosa", "roose", "roots", "rooty", "roped", "roper", "ropes", "ropey", "roque", "roral", "rores", "roric", "rorid", "rorie", "rorts", "rorty", "rosed", "roses", "roset", "roshi", "rosin", "rosit", "rosti", "rosts", "rotal", "rotan", "rotas", "rotch", "roted", "rotes", "rotis", "rotls", "roton", "rotos", "rotte", "rouen", "roues", "roule", "rouls", "roums", "roups", "roupy", "roust", "routh", "routs", "roved", "roven", "roves", "rowan", "rowed", "rowel", "rowen", "rowie", "rowme", "rownd", "rowth", "rowts", "royne", "royst", "rozet", "rozit", "ruana", "rubai", "rubby", "rubel", "rubes", "rubin", "ruble", "rubli", "rubus", "ruche", "rucks", "rudas", "rudds", "rudes", "rudie", "rudis", "rueda", "ruers", "ruffe", "ruffs", "rugae", "rugal", "ruggy", "ruing", "ruins", "rukhs", "ruled", "rules", "rumal", "rumbo", "rumen", "rumes", "rumly", "rummy", "rumpo", "rumps", "rumpy", "runch", "runds", "runed", "runes", "rungs", "runic", "runny", "runts", "runty", "rupia", "rurps", "rurus", "rusas", "ruses", "rushy", "rusks", "rusma", "russe", "rusts", "ruths", "rutin", "rutty", "ryals", "rybat", "ryked", "rykes", "rymme", "rynds", "ryots", "ryper", "saags", "sabal", "sabed", "saber", "sabes", "sabha", "sabin", "sabir", "sable", "sabot", "sabra", "sabre", "sacks", "sacra", "saddo", "sades", "sadhe", "sadhu", "sadis", "sados", "sadza", "safed", "safes", "sagas", "sager", "sages", "saggy", "sagos", "sagum", "saheb", "sahib", "saice", "saick", "saics", "saids", "saiga", "sails", "saims", "saine", "sains", "sairs", "saist", "saith", "sajou", "sakai", "saker", "sakes", "sakia", "sakis", "sakti", "salal", "salat", "salep", "sales", "salet", "salic", "salix", "salle", "salmi", "salol", "salop", "salpa", "salps", "salse", "salto", "salts", "salue", "salut", "saman", "samas", "samba", "sambo", "samek", "samel", "samen", "sames", "samey", "samfu", "sammy", "sampi", "samps", "sands", "saned", "sanes", "sanga", "sangh", "sango", "sangs", "sanko", "sansa", "santo", "sants", "saola", "sapan", "sapid", "sapor", "saran", "sards", "sared", "saree", "sarge", "sargo", "sarin", "saris", "sarks", "sarky", "sarod", "saros", "sarus", "saser", "sasin", "sasse", "satai", "satay", "sated", "satem", "sates", "satis", "sauba", "sauch", "saugh", "sauls", "sault", "saunt", "saury", "sauts", "saved", "saver", "saves", "savey", "savin", "sawah", "sawed", "sawer", "saxes", "sayed", "sayer", "sayid", "sayne", "sayon", "sayst", "sazes", "scabs", "scads", "scaff", "scags", "scail", "scala", "scall", "scams", "scand", "scans", "scapa", "scape", "scapi", "scarp", "scars", "scart", "scath", "scats", "scatt", "scaud", "scaup", "scaur", "scaws", "sceat", "scena", "scend", "schav", "schmo", "schul", "schwa", "sclim", "scody", "scogs", "scoog", "scoot", "scopa", "scops", "scots", "scoug", "scoup", "scowp", "scows", "scrab", "scrae", "scrag", "scran", "scrat", "scraw", "scray", "scrim", "scrip", "scrob", "scrod", "scrog", "scrow", "scudi", "scudo", "scuds", "scuff", "scuft", "scugs", "sculk", "scull", "sculp", "sculs", "scums", "scups", "scurf", "scurs", "scuse", "scuta", "scute", "scuts", "scuzz", "scyes", "sdayn", "sdein", "seals", "seame", "seams", "seamy", "seans", "seare", "sears", "sease", "seats", "seaze", "sebum", "secco", "sechs", "sects", "seder", "sedes", "sedge", "sedgy", "sedum", "seeds", "seeks", "seeld", "seels", "seely", "seems", "seeps", "seepy", "seers", "sefer", "segar", "segni", "segno", "segol", "segos", "sehri", "seifs", "seils", "seine", "seirs", "seise", "seism", "seity", "seiza", "sekos", "sekts", "selah", "seles", "selfs", "sella", "selle", "sells", "selva", "semee", "semes", "semie", "semis", "senas", "sends", "senes", "sengi", "senna", "senor", "sensa", "sensi", "sente", "senti", "sents", "senvy", "senza", "sepad", "sepal", "sepic", "sepoy", "septa", "septs", "serac", "serai", "seral", "sered", "serer", "seres", "serfs", "serge", "seric", "serin", "serks", "seron", "serow", "serra", "serre", "serrs", "serry", "servo", "sesey", "sessa", "setae", "setal", "seton", "setts", "sewan", "sewar", "sewed", "sewel", "sewen", "sewin", "sexed", "sexer", "sexes", "sexto", "sexts", "seyen", "shads", "shags", "shahs", "shako", "shakt", "shalm", "shaly", "shama", "shams", "shand", "shans", "shaps", "sharn", "shash", "shaul", "shawm", "shawn", "shaws", "shaya", "shays", "shchi", "sheaf", "sheal", "sheas", "sheds", "sheel", "shend", "shent", "sheol", "sherd", "shere", "shero", "shets", "sheva", "shewn", "shews", "shiai", "shiel", "shier", "shies", "shill", "shily", "shims", "shins", "ships", "shirr", "shirs", "shish", "shiso", "shist", "shite", "shits", "shiur", "shiva", "shive", "shivs", "shlep", "shlub", "shmek", "shmoe", "shoat", "shoed", "shoer", "shoes", "shogi", "shogs", "shoji", "shojo", "shola", "shool", "shoon", "shoos", "shope", "shops", "shorl", "shote", "shots", "shott", "showd", "shows", "shoyu", "shred", "shris", "shrow", "shtik", "shtum", "shtup", "shule", "shuln", "shuls", "shuns", "shura", "shute", "shuts", "shwas", "shyer", "sials", "sibbs", "sibyl", "sices", "sicht", "sicko", "sicks", "sicky", "sidas", "sided", "sider", "sides", "sidha", "sidhe", "sidle", "sield", "siens", "sient", "sieth", "sieur", "sifts", "sighs", "sigil", "sigla", "signa", "signs", "sijos", "sikas", "siker", "sikes", "silds", "siled", "silen", "siler", "siles", "silex", "silks", "sills", "silos", "silts", "silty", "silva", "simar", "simas", "simba", "simis", "simps", "simul", "sinds", "sined", "sines", "sings", "sinhs", "sinks", "sinky", "sinus", "siped", "sipes", "sippy", "sired", "siree", "sires", "sirih", "siris", "siroc", "sirra", "sirup", "sisal", "sises", "sista", "sists", "sitar", "sited", "sites", "sithe", "sitka", "situp", "situs", "siver", "sixer", "sixes", "sixmo", "sixte", "sizar", "sized", "sizel", "sizer", "sizes", "skags", "skail", "skald", "skank", "skart", "skats", "skatt", "skaws", "skean", "skear", "skeds", "skeed", "skeef", "skeen", "skeer", "skees", "skeet", "skegg", "skegs", "skein", "skelf", "skell", "skelm", "skelp", "skene", "skens", "skeos", "skeps", "skers", "skets", "skews", "skids", "skied", "skies", "skiey", "skimo", "skims", "skink", "skins", "skint", "skios", "skips", "skirl", "skirr", "skite", "skits", "skive", "skivy", "sklim", "skoal", "skody", "skoff", "skogs", "skols", "skool", "skort", "skosh", "skran", "skrik", "skuas", "skugs", "skyed", "skyer", "skyey", "skyfs", "skyre", "skyrs", "skyte", "slabs", "slade", "slaes", "slags", "slaid", "slake", "slams", "slane", "slank", "slaps", "slart", "slats", "slaty", "slaws", "slays", "slebs", "sleds", "sleer", "slews", "sleys", "slier", "slily", "slims", "slipe", "slips", "slipt", "slish", "slits", "slive", "sloan", "slobs", "sloes", "slogs", "sloid", "slojd", "slomo", "sloom", "sloot", "slops", "slopy", "slorm", "slots", "slove", "slows", "sloyd", "slubb", "slubs", "slued", "slues", "sluff", "slugs", "sluit", "slums", "slurb", "slurs", "sluse", "slyer", "slype", "smaak", "smaik", "smalm", "smalt", "smarm", "smaze", "smeek", "smees", "smeik", "smeke", "smerk", "smews", "smirr", "smirs", "smits", "smogs", "smoko", "smolt", "smoor", "smoot", "smore", "smorg", "smout", "smowt", "smugs", "smurs", "smush", "smuts", "snabs", "snafu", "snags", "snaps", "snarf", "snark", "snars", "snary", "snash", "snath", "snaws", "snead", "sneap", "snebs", "sneck", "sneds", "sneed", "snees", "snell", "snibs", "snick", "snies", "snift", "snigs", "snips", "snipy", "snirt", "snits", "snobs", "snods", "snoek", "snoep", "snogs", "snoke", "snood", "snook", "snool", "snoot", "snots", "snowk", "snows", "snubs", "snugs", "snush", "snyes", "soaks", "soaps", "soare", "soars", "soave", "sobas", "socas", "soces", "socko", "socks", "socle", "sodas", "soddy", "sodic", "sodom", "sofar", "sofas", "softa", "softs", "softy", "soger", "sohur", "soils", "soily", "sojas", "sojus", "sokah", "soken", "sokes", "sokol", "solah", "solan", "solas", "solde", "soldi", "soldo", "solds", "soled", "solei", "soler", "soles", "solon", "solos", "solum", "solus", "soman", "somas", "sonce", "sonde", "sones", "songs", "sonly", "sonne", "sonny", "sonse", "sonsy", "sooey", "sooks", "sooky", "soole", "sools", "sooms", "soops", "soote", "soots", "sophs", "sophy", "sopor", "soppy", "sopra", "soral", "soras", "sorbo", "sorbs", "sorda", "sordo", "sords", "sored", "soree", "sorel", "sorer", "sores", "sorex", "sorgo", "sorns", "sorra", "sorta", "sorts", "sorus", "soths", "sotol", "souce", "souct", "sough", "souks", "souls", "soums", "soups", "soupy", "sours", "souse", "souts", "sowar", "sowce", "sowed", "sowff", "sowfs", "sowle", "sowls", "sowms", "sownd", "sowne", "sowps", "sowse", "sowth", "soyas", "soyle", "soyuz", "sozin", "spacy", "spado", "spaed", "spaer", "spaes", "spags", "spahi", "spail", "spain", "spait", "spake", "spald", "spale", "spall", "spalt", "spams", "spane", "spang", "spans", "spard", "spars", "spart", "spate", "spats", "spaul", "spawl", "spaws", "spayd", "spays", "spaza", "spazz", "speal", "spean", "speat", "specs", "spect", "speel", "speer", "speil", "speir", "speks", "speld", "spelk", "speos", "spets", "speug", "spews", "spewy", "spial", "spica", "spide", "spier", "spies", "spiff", "spifs", "spile", "spims", "spina", "spink", "spins", "spirt", "spiry", "spits", "spitz", "spivs", "splay", "splog", "spode", "spods", "spoom", "spoor", "spoot", "spork", "sposh", "spots", "sprad", "sprag", "sprat", "spred", "sprew", "sprit", "sprod", "sprog", "sprue", "sprug", "spuds", "spued", "spuer", "spues", "spugs", "spule", "spume", "spumy", "spurs", "sputa", "spyal", "spyre", "squab", "squaw", "squeg", "squid", "squit", "squiz", "stabs", "stade", "stags", "stagy", "staig", "stane", "stang", "staph", "staps", "starn", "starr", "stars", "stats", "staun", "staws", "stays", "stean", "stear", "stedd", "stede", "steds", "steek", "steem", "steen", "steil", "stela", "stele", "stell", "steme", "stems", "stend", "steno", "stens", "stent", "steps", "stept", "stere", "stets", "stews", "stewy", "steys", "stich", "stied", "sties", "stilb", "stile", "stime", "stims", "stimy", "stipa", "stipe", "stire", "stirk", "stirp", "stirs", "stive", "stivy", "stoae", "stoai", "stoas", "stoat", "stobs", "stoep", "stogy", "stoit", "stoln", "stoma", "stond", "stong", "stonk", "stonn", "stook", "stoor", "stope", "stops", "stopt", "stoss", "stots", "stott", "stoun", "stoup", "stour", "stown", "stowp", "stows", "strad", "strae", "strag", "strak", "strep", "strew", "stria", "strig", "strim", "strop", "strow", "stroy", "strum", "stubs", "stude", "studs", "stull", "stulm", "stumm", "stums", "stuns", "stupa", "stupe", "sture", "sturt", "styed", "styes", "styli", "stylo", "styme", "stymy", "styre", "styte", "subah", "subas", "subby", "suber", "subha", "succi", "sucks", "sucky", "sucre", "sudds", "sudor", "sudsy", "suede", "suent", "suers", "suete", "suets", "suety", "sugan", "sughs", "sugos", "suhur", "suids", "suint", "suits", "sujee", "sukhs", "sukuk", "sulci", "sulfa", "sulfo", "sulks", "sulph", "sulus", "sumis", "summa", "sumos", "sumph", "sumps", "sunis", "sunks", "sunna", "sunns", "sunup", "supes", "supra", "surah", "sural", "suras", "surat", "surds", "sured", "sures", "surfs", "surfy", "surgy", "surra", "sused", "suses", "susus", "sutor", "sutra", "sutta", "swabs", "swack", "swads", "swage", "swags", "swail", "swain", "swale", "swaly", "swamy", "swang", "swank", "swans", "swaps", "swapt", "sward", "sware", "swarf", "swart", "swats", "swayl", "sways", "sweal", "swede", "sweed", "sweel", "sweer", "swees", "sweir", "swelt", "swerf", "sweys", "swies", "swigs", "swile", "swims", "swink", "swipe", "swire", "swiss", "swith", "swits", "swive", "swizz", "swobs", "swole", "swoln", "swops", "swopt", "swots", "swoun", "sybbe", "sybil", "syboe", "sybow", "sycee", "syces", "sycon", "syens", "syker", "sykes", "sylis", "sylph", "sylva", "symar", "synch", "syncs", "synds", "syned", "synes", "synth", "syped", "sypes", "syphs", "syrah", "syren", "sysop", "sythe", "syver", "taals", "taata", "taber", "tabes", "tabid", "tabis", "tabla", "tabor", "tabun", "tabus", "tacan", "taces", "tacet", "tache", "tacho", "tachs", "tacks", "tacos", "tacts", "taels", "tafia", "taggy", "tagma", "tahas", "tahrs", "taiga", "taigs", "taiko", "tails", "tains", "taira", "taish", "taits", "tajes", "takas", "takes", "takhi", "takin", "takis", "takky", "talak", "talaq", "talar", "talas", "talcs", "talcy", "talea", "taler", "tales", "talks", "talky", "talls", "talma", "talpa", "taluk", "talus", "tamal", "tamed", "tames", "tamin", "tamis", "tammy", "tamps", "tanas", "tanga", "tangi", "tangs", "tanhs", "tanka", "tanks", "tanky", "tanna", "tansy", "tanti", "tanto", "tanty", "tapas", "taped", "tapen", "tapes", "tapet", "tapis", "tappa", "tapus", "taras", "tardo", "tared", "tares", "targa", "targe", "tarns", "taroc", "tarok", "taros", "tarps", "tarre", "tarry", "tarsi", "tarts", "tarty", "tasar", "tased", "taser", "tases", "tasks", "tassa", "tasse", "tasso", "tatar", "tater", "tates", "taths", "tatie", "tatou", "tatts", "tatus", "taube", "tauld", "tauon", "taupe", "tauts", "tavah", "tavas", "taver", "tawai", "tawas", "tawed", "tawer", "tawie", "tawse", "tawts", "taxed", "taxer", "taxes", "taxis", "taxol", "taxon", "taxor", "taxus", "tayra", "tazza", "tazze", "teade", "teads", "teaed", "teaks", "teals", "teams", "tears", "teats", "teaze", "techs", "techy", "tecta", "teels", "teems", "teend", "teene", "teens", "teeny", "teers", "teffs", "teggs", "tegua", "tegus", "tehrs", "teiid", "teils", "teind", "teins", "telae", "telco", "teles", "telex", "telia", "telic", "tells", "telly", "teloi", "telos", "temed", "temes", "tempi", "temps", "tempt", "temse", "tench", "tends", "tendu", "tenes", "tenge", "tenia", "tenne", "tenno", "tenny", "tenon", "tents", "tenty", "tenue", "tepal", "tepas", "tepoy", "terai", "teras", "terce", "terek", "teres", "terfe", "terfs", "terga", "terms", "terne", "terns", "terry", "terts", "tesla", "testa", "teste", "tests", "tetes", "teths", "tetra", "tetri", "teuch", "teugh", "tewed", "tewel", "tewit", "texas", "texes", "texts", "thack", "thagi", "thaim", "thale", "thali", "thana", "thane", "thang", "thans", "thanx", "tharm", "thars", "thaws", "thawy", "thebe", "theca", "theed", "theek", "thees", "thegn", "theic", "thein", "thelf", "thema", "thens", "theow", "therm", "thesp", "thete", "thews", "thewy", "thigs", "thilk", "thill", "thine", "thins", "thiol", "thirl", "thoft", "thole", "tholi", "thoro", "thorp", "thous", "thowl", "thrae", "thraw", "thrid", "thrip", "throe", "thuds", "thugs", "thuja", "thunk", "thurl", "thuya", "thymi", "thymy", "tians", "tiars", "tical", "ticca", "ticed", "tices", "tichy", "ticks", "ticky", "tiddy", "tided", "tides", "tiers", "tiffs", "tifos", "tifts", "tiges", "tigon", "tikas", "tikes", "tikis", "tikka", "tilak", "tiled", "tiler", "tiles", "tills", "tilly", "tilth", "tilts", "timbo", "timed", "times", "timon", "timps", "tinas", "tinct", "tinds", "tinea", "tined", "tines", "tinge", "tings", "tinks", "tinny", "tints", "tinty", "tipis", "tippy", "tired", "tires", "tirls", "tiros", "tirrs", "titch", "titer", "titis", "titre", "titty", "titup", "tiyin", "tiyns", "tizes", "tizzy", "toads", "toady", "toaze", "tocks", "tocky", "tocos", "todde", "toeas", "toffs", "toffy", "tofts", "tofus", "togae", "togas", "toged", "toges", "togue", "tohos", "toile", "toils", "toing", "toise", "toits", "tokay", "toked", "toker", "tokes", "tokos", "tolan", "tolar", "tolas", "toled", "toles", "tolls", "tolly", "tolts", "tolus", "tolyl", "toman", "tombs", "tomes", "tomia", "tommy", "tomos", "tondi", "tondo", "toned", "toner", "tones", "toney", "tongs", "tonka", "tonks", "tonne", "tonus", "tools", "tooms", "toons", "toots", "toped", "topee", "topek", "toper", "topes", "tophe", "tophi", "tophs", "topis", "topoi", "topos", "toppy", "toque", "torah", "toran", "toras", "torcs", "tores", "toric", "torii", "toros", "torot", "torrs", "torse", "torsi", "torsk", "torta", "torte", "torts", "tosas", "tosed", "toses", "toshy", "tossy", "toted", "toter", "totes", "totty", "touks", "touns", "tours", "touse", "tousy", "touts", "touze", "touzy", "towed", "towie", "towns", "towny", "towse", "towsy", "towts", "towze", "towzy", "toyed", "toyer", "toyon", "toyos", "tozed", "tozes", "tozie", "trabs", "trads", "tragi", "traik", "trams", "trank", "tranq", "trans", "trant", "trape", "traps", "trapt", "trass", "trats", "tratt", "trave", "trayf", "trays", "treck", "treed", "treen", "trees", "trefa", "treif", "treks", "trema", "trems", "tress", "trest", "trets", "trews", "treyf", "treys", "triac", "tride", "trier", "tries", "triff", "trigo", "trigs", "trike", "trild", "trill", "trims", "trine", "trins", "triol", "trior", "trios", "trips", "tripy", "trist", "troad", "troak", "troat", "trock", "trode", "trods", "trogs", "trois", "troke", "tromp", "trona", "tronc", "trone", "tronk", "trons", "trooz", "troth", "trots", "trows", "troys", "trued", "trues", "trugo", "trugs", "trull", "tryer", "tryke", "tryma", "tryps", "tsade", "tsadi", "tsars", "tsked", "tsuba", "tsubo", "tuans", "tuart", "tuath", "tubae", "tubar", "tubas", "tubby", "tubed", "tubes", "tucks", "tufas", "tuffe", "tuffs", "tufts", "tufty", "tugra", "tuile", "tuina", "tuism", "tuktu", "tules", "tulpa", "tulsi", "tumid", "tummy", "tumps", "tumpy", "tunas", "tunds", "tuned", "tuner", "tunes", "tungs", "tunny", "tupek", "tupik", "tuple", "tuque", "turds", "turfs", "turfy", "turks", "turme", "turms", "turns", "turnt", "turps", "turrs", "tushy", "tusks", "tusky", "tutee", "tutti", "tutty", "tutus", "tuxes", "tuyer", "twaes", "twain", "twals", "twank", "twats", "tways", "tweel", "tween", "tweep", "tweer", "twerk", "twerp", "twier", "twigs", "twill", "twilt", "twink", "twins", "twiny", "twire", "twirp", "twite", "twits", "twoer", "twyer", "tyees", "tyers", "tyiyn", "tykes", "tyler", "tymps", "tynde", "tyned", "tynes", "typal", "typed", "types", "typey", "typic", "typos", "typps", "typto", "tyran", "tyred", "tyres", "tyros", "tythe", "tzars", "udals", "udons", "ugali", "ugged", "uhlan", "uhuru", "ukase", "ulama", "ulans", "ulema", "ulmin", "ulnad", "ulnae", "ulnar", "ulnas", "ulpan", "ulvas", "ulyie", "ulzie", "umami", "umbel", "umber", "umble", "umbos", "umbre", "umiac", "umiak", "umiaq", "ummah", "ummas", "ummed", "umped", "umphs", "umpie", "umpty", "umrah", "umras", "unais", "unapt", "unarm", "unary", "unaus", "unbag", "unban", "unbar", "unbed", "unbid", "unbox", "uncap", "unces", "uncia", "uncos", "uncoy", "uncus", "undam", "undee", "undos", "undug", "uneth", "unfix", "ungag", "unget", "ungod", "ungot", "ungum", "unhat", "unhip", "unica", "units", "unjam", "unked", "unket", "unkid", "unlaw", "unlay", "unled", "unlet", "unlid", "unman", "unmew", "unmix", "unpay", "unpeg", "unpen", "unpin", "unred", "unrid", "unrig", "unrip", "unsaw", "unsay", "unsee", "unsew", "unsex", "unsod", "untax", "untin", "unwet", "unwit", "unwon", "upbow", "upbye", "updos", "updry", "upend", "upjet", "uplay", "upled", "uplit", "upped", "upran", "uprun", "upsee", "upsey", "uptak", "upter", "uptie", "uraei", "urali", "uraos", "urare", "urari", "urase", "urate", "urbex", "urbia", "urdee", "ureal", "ureas", "uredo", "ureic", "urena", "urent", "urged", "urger", "urges", "urial", "urite", "urman", "urnal", "urned", "urped", "ursae", "ursid", "urson", "urubu", "urvas", "users", "usnea", "usque", "usure", "usury", "uteri", "uveal", "uveas", "uvula", "vacua", "vaded", "vades", "vagal", "vagus", "vails", "vaire", "vairs", "vairy", "vakas", "vakil", "vales", "valis", "valse", "vamps", "vampy", "vanda", "vaned", "vanes", "vangs", "vants", "vaped", "vaper", "vapes", "varan", "varas", "vardy", "varec", "vares", "varia", "varix", "varna", "varus", "varve", "vasal", "vases", "vasts", "vasty", "vatic", "vatus", "vauch", "vaute", "vauts", "vawte", "vaxes", "veale", "veals", "vealy", "veena", "veeps", "veers", "veery", "vegas", "veges", "vegie", "vegos", "vehme", "veils", "veily", "veins", "veiny", "velar", "velds", "veldt", "veles", "vells", "velum", "venae", "venal", "vends", "vendu", "veney", "venge", "venin", "vents", "venus", "verbs", "verra", "verry", "verst", "verts", "vertu", "vespa", "vesta", "vests", "vetch", "vexed", "vexer", "vexes", "vexil", "vezir", "vials", "viand", "vibes", "vibex", "vibey", "viced", "vices", "vichy", "viers", "views", "viewy", "vifda", "viffs", "vigas", "vigia", "vilde", "viler", "villi", "vills", "vimen", "vinal", "vinas", "vinca", "vined", "viner", "vines", "vinew", "vinic", "vinos", "vints", "viold", "viols", "vired", "vireo", "vires", "virga", "virge", "virid", "virls", "virtu", "visas", "vised", "vises", "visie", "visne", "vison", "visto", "vitae", "vitas", "vitex", "vitro", "vitta", "vivas", "vivat", "vivda", "viver", "vives", "vizir", "vizor", "vleis", "vlies", "vlogs", "voars", "vocab", "voces", "voddy", "vodou", "vodun", "voema", "vogie", "voids", "voile", "voips", "volae", "volar", "voled", "voles", "volet", "volks", "volta", "volte", "volti", "volts", "volva", "volve", "vomer", "voted", "votes", "vouge", "voulu", "vowed", "vower", "voxel", "vozhd", "vraic", "vrils", "vroom", "vrous", "vrouw", "vrows", "vuggs", "vuggy", "vughs", "vughy", "vulgo", "vulns", "vulva", "vutty", "waacs", "wacke", "wacko", "wacks", "wadds", "waddy", "waded", "wader", "wades", "wadge", "wadis", "wadts", "waffs", "wafts", "waged", "wages", "wagga", "wagyu", "wahoo", "waide", "waifs", "waift", "wails", "wains", "wairs", "waite", "waits", "wakas", "waked", "waken", "waker", "wakes", "wakfs", "waldo", "walds", "waled", "waler", "wales", "walie", "walis", "walks", "walla", "walls", "wally", "walty", "wamed", "wames", "wamus", "wands", "waned", "wanes", "waney", "wangs", "wanks", "wanky", "wanle", "wanly", "wanna", "wants", "wanty", "wanze", "waqfs", "warbs", "warby", "wards", "wared", "wares", "warez", "warks", "warms", "warns", "warps", "warre", "warst", "warts", "wases", "washy", "wasms", "wasps", "waspy", "wasts", "watap", "watts", "wauff", "waugh", "wauks", "waulk", "wauls", "waurs", "waved", "waves", "wavey", "wawas", "wawes", "wawls", "waxed", "waxer", "waxes", "wayed", "wazir", "wazoo", "weald", "weals", "weamb", "weans", "wears", "webby", "weber", "wecht", "wedel", "wedgy", "weeds", "weeke", "weeks", "weels", "weems", "weens", "weeny", "weeps", "weepy", "weest", "weete", "weets", "wefte", "wefts", "weids", "weils", "weirs", "weise", "weize", "wekas", "welds", "welke", "welks", "welkt", "wells", "welly", "welts", "wembs", "wends", "wenge", "wenny", "wents", "weros", "wersh", "wests", "wetas", "wetly", "wexed", "wexes", "whamo", "whams", "whang", "whaps", "whare", "whata", "whats", "whaup", "whaur", "wheal", "whear", "wheen", "wheep", "wheft", "whelk", "whelm", "whens", "whets", "whews", "wheys", "whids", "whift", "whigs", "whilk", "whims", "whins", "whios", "whips", "whipt", "whirr", "whirs", "whish", "whiss", "whist", "whits", "whity", "whizz", "whomp", "whoof", "whoot", "whops", "whorl", "whort", "whoso", "whows", "whump", "whups", "whyda", "wicca", "wicks", "wicky", "widdy", "wides", "wiels", "wifed", "wifes", "wifey", "wifie", "wifty", "wigan", "wigga", "wiggy", "wikis", "wilco", "wilds", "wiled", "wiles", "wilga", "wilis", "wilja", "wills", "wilts", "wimps", "winds", "wined", "wines", "winey", "winge", "wings", "wingy", "winks", "winna", "winns", "winos", "winze", "wiped", "wiper", "wipes", "wired", "wirer", "wires", "wirra", "wised", "wises", "wisha", "wisht", "wisps", "wists", "witan", "wited", "wites", "withe", "withs", "withy", "wived", "wiver", "wives", "wizen", "wizes", "woads", "woald", "wocks", "wodge", "woful", "wojus", "woker", "wokka", "wolds", "wolfs", "wolly", "wolve", "wombs", "womby", "womyn", "wonga", "wongi", "wonks", "wonky", "wonts", "woods", "wooed", "woofs", "woofy", "woold", "wools", "woons", "woops", "woopy", "woose", "woosh", "wootz", "words", "works", "worms", "wormy", "worts", "wowed", "wowee", "woxen", "wrang", "wraps", "wrapt", "wrast", "wrate", "wrawl", "wrens", "wrick", "wried", "wrier", "wries", "writs", "wroke", "wroot", "wroth", "wryer", "wuddy", "wudus", "wulls", "wurst", "wuses", "wushu", "wussy", "wuxia", "wyled", "wyles", "wynds", "wynns", "wyted", "wytes", "xebec", "xenia", "xenic", "xenon", "xeric", "xerox", "xerus", "xoana", "xrays", "xylan", "xylem", "xylic", "xylol", "xylyl", "xysti", "xysts", "yaars", "yabas", "yabba", "yabby", "yacca", "yacka", "yacks", "yaffs", "yager", "yages", "yagis", "yahoo", "yaird", "yakka", "yakow", "yales", "yamen", "yampy", "yamun", "yangs", "yanks", "yapok", "yapon", "yapps", "yappy", "yarak", "yarco", "yards", "yarer", "yarfa", "yarks", "yarns", "yarrs", "yarta", "yarto", "yates", "yauds", "yauld", "yaups", "yawed", "yawey", "yawls", "yawns", "yawny", "yawps", "ybore", "yclad", "ycled", "ycond", "ydrad", "ydred", "yeads", "yeahs", "yealm", "yeans", "yeard", "years", "yecch", "yechs", "yechy", "yedes", "yeeds", "yeesh", "yeggs", "yelks", "yells", "yelms", "yelps", "yelts", "yenta", "yente", "yerba", "yerds", "yerks", "yeses", "yesks", "yests", "yesty", "yetis", "yetts", "yeuks", "yeuky", "yeven", "yeves", "yewen", "yexed", "yexes", "yfere", "yiked", "yikes", "yills", "yince", "yipes", "yippy", "yirds", "yirks", "yirrs", "yirth", "yites", "yitie", "ylems", "ylike", "ylkes", "ymolt", "ympes", "yobbo", "yobby", "yocks", "yodel", "yodhs", "yodle", "yogas", "yogee", "yoghs", "yogic", "yogin", "yogis", "yoick", "yojan", "yoked", "yokel", "yoker", "yokes", "yokul", "yolks", "yolky", "yomim", "yomps", "yonic", "yonis", "yonks", "yoofs", "yoops", "yores", "yorks", "yorps", "youks", "yourn", "yours", "yourt", "youse", "yowed", "yowes", "yowie", "yowls", "yowza", "yrapt", "yrent", "yrivd", "yrneh", "ysame", "ytost", "yuans", "yucas", "yucca", "yucch", "yucko", "yucks", "yucky", "yufts", "yugas", "yuked", "yukes", "yukky", "yukos", "yulan", "yules", "yummo", "yummy", "yumps", "yupon", "yuppy", "yurta", "yurts", "yuzus", "zabra", "zacks", "zaida", "zaidy", "zaire", "zakat", "zaman", "zambo", "zamia", "zanja", "zante", "zanza", "zanze", "zappy", "zarfs", "zaris", "zatis", "zaxes", "zayin", "zazen", "zeals", "zebec", "zebub", "zebus", "zedas", "zeins", "zendo", "zerda", "zerks", "zeros", "zests", "zetas", "zexes", "zezes", "zhomo", "zibet", "ziffs", "zigan", "zilas", "zilch", "zilla", "zills", "zimbi", "zimbs", "zinco", "zincs", "zincy", "zineb", "zines", "zings", "zingy", "zinke", "zinky", "zippo", "zippy", "ziram", "zitis", "zizel", "zizit", "zlote", "zloty", "zoaea", "zobos", "zobus", "zocco", "zoeae", "zoeal", "zoeas", "zoism", "zoist", "zombi", "zonae", "zonda", "zoned", "zoner", "zones", "zonks", "zooea", "zooey", "zooid", "zooks", "zooms", "zoons", "zooty", "zoppa", "zoppo", "zoril", "zoris", "zorro", "zouks", "zowee", "zowie", "zulus", "zupan", "zupas", "zuppa", "zurfs", "zuzim", "zygal", "zygon", "zymes", "zymic"];
+pub static ANSW_WORDS: &[[u8; 5]] = &[*b"cigar", *b"rebut", *b"sissy", *b"humph", *b"awake", *b"blush", *b"focal", *b"evade", *b"naval", *b"serve", *b"heath", *b"dwarf", *b"model", *b"karma", *b"stink", *b"grade", *b"quiet", *b"bench", *b"abate", *b"feign", *b"major", *b"death", *b"fresh", *b"crust", *b"stool", *b"colon", *b"abase", *b"marry", *b"react", *b"batty", *b"pride", *b"floss", *b"helix", *b"croak", *b"staff", *b"paper", *b"unfed", *b"whelp", *b"trawl", *b"outdo", *b"adobe", *b"crazy", *b"sower", *b"repay", *b"digit", *b"crate", *b"cluck", *b"spike", *b"mimic", *b"pound", *b"maxim", *b"linen", *b"unmet", *b"flesh", *b"booby", *b"forth", *b"first", *b"stand", *b"belly", *b"ivory", *b"seedy", *b"print", *b"yearn", *b"drain", *b"bribe", *b"stout", *b"panel", *b"crass", *b"flume", *b"offal", *b"agree", *b"error", *b"swirl", *b"argue", *b"bleed", *b"delta", *b"flick", *b"totem", *b"wooer", *b"front", *b"shrub", *b"parry", *b"biome", *b"lapel", *b"start", *b"greet", *b"goner", *b"golem", *b"lusty", *b"loopy", *b"round", *b"audit", *b"lying", *b"gamma", *b"labor", *b"islet", *b"civic", *b"forge", *b"corny", *b"moult", *b"basic", *b"salad", *b"agate", *b"spicy", *b"spray", *b"essay", *b"fjord", *b"spend", *b"kebab", *b"guild", *b"aback", *b"motor", *b"alone", *b"hatch", *b"hyper", *b"thumb", *b"dowry", *b"ought", *b"belch", *b"dutch", *b"pilot", *b"tweed", *b"comet", *b"jaunt", *b"enema", *b"steed", *b"abyss", *b"growl", *b"fling", *b"dozen", *b"boozy", *b"erode", *b"world", *b"gouge", *b"click", *b"briar", *b"great", *b"altar", *b"pulpy", *b"blurt", *b"coast", *b"duchy", *b"groin", *b"fixer", *b"group", *b"rogue", *b"badly", *b"smart", *b"pithy", *b"gaudy", *b"chill", *b"heron", *b"vodka", *b"finer", *b"surer", *b"radio", *b"rouge", *b"perch", *b"retch", *b"wrote", *b"clock", *b"tilde", *b"store", *b"prove", *b"bring", *b"solve", *b"cheat", *b"grime", *b"exult", *b"usher", *b"epoch", *b"triad", *b"break", *b"rhino", *b"viral", *b"conic", *b"masse", *b"sonic", *b"vital", *b"trace", *b"using", *b"peach", *b"champ", *b"baton", *b"brake", *b"pluck", *b"craze", *b"gripe", *b"weary", *b"picky", *b"acute", *b"ferry", *b"aside", *b"tapir", *b"troll", *b"unify", *b"rebus", *b"boost", *b"truss", *b"siege", *b"tiger", *b"banal", *b"slump", *b"crank", *b"gorge", *b"query", *b"drink", *b"favor", *b"abbey", *b"tangy", *b"panic", *b"solar", *b"shire", *b"proxy", *b"point", *b"robot", *b"prick", *b"wince", *b"crimp", *b"knoll", *b"sugar", *b"whack", *b"mount", *b"perky", *b"could", *b"wrung", *b"light", *b"those", *b"moist", *b"shard", *b"pleat", *b"aloft", *b"skill", *b"elder", *b"frame", *b"humor", *b"pause", *b"ulcer", *b"ultra", *b"robin", *b"cynic", *b"aroma", *b"caulk", *b"shake", *b"dodge", *b"swill", *b"tacit", *b"other", *b"thorn", *b"trove", *b"bloke", *b"vivid", *b"spill", *b"chant", *b"choke", *b"rupee", *b"nasty", *b"mourn", *b"ahead", *b"brine", *b"cloth", *b"hoard", *b"sweet", *b"month", *b"lapse", *b"watch", *b"today", *b"focus", *b"smelt", *b"tease", *b"cater", *b"movie", *b"saute", *b"allow", *b"renew", *b"their", *b"slosh", *b"purge", *b"chest", *b"depot", *b"epoxy", *b"nymph", *b"found", *b"shall", *b"harry", *b"stove", *b"lowly", *b"snout", *b"trope", *b"fewer", *b"shawl", *b"natal", *b"comma", *b"foray", *b"scare", *b"stair", *b"black", *b"squad", *b"royal", *b"chunk", *b"mince", *b"shame", *b"cheek", *b"ample", *b"flair", *b"foyer", *b"cargo", *b"oxide", *b"plant", *b"olive", *b"inert", *b"askew", *b"heist", *b"shown", *b"zesty", *b"hasty", *b"trash", *b"fella", *b"larva", *b"forgo", *b"story", *b"hairy", *b"train", *b"homer", *b"badge", *b"midst", *b"canny", *b"fetus", *b"butch", *b"farce", *b"slung", *b"tipsy", *b"metal", *b"yield", *b"delve", *b"being", *b"scour", *b"glass", *b"gamer", *b"scrap", *b"money", *b"hinge", *b"album", *b"vouch", *b"asset", *b"tiara", *b"crept", *b"bayou", *b"atoll", *b"manor", *b"creak", *b"showy", *b"phase", *b"froth", *b"depth", *b"gloom", *b"flood", *b"trait", *b"girth", *b"piety", *b"payer", *b"goose", *b"float", *b"donor", *b"atone", *b"primo", *b"apron", *b"blown", *b"cacao", *b"loser", *b"input", *b"gloat", *b"awful", *b"brink", *b"smite", *b"beady", *b"rusty", *b"retro", *b"droll", *b"gawky", *b"hutch", *b"pinto", *b"gaily", *b"egret", *b"lilac", *b"sever", *b"field", *b"fluff", *b"hydro", *b"flack", *b"agape", *b"voice", *b"stead", *b"stalk", *b"berth", *b"madam", *b"night", *b"bland", *b"liver", *b"wedge", *b"augur", *b"roomy", *b"wacky", *b"flock", *b"angry", *b"bobby", *b"trite", *b"aphid", *b"tryst", *b"midge", *b"power", *b"elope", *b"cinch", *b"motto", *b"stomp", *b"upset", *b"bluff", *b"cramp", *b"quart", *b"coyly", *b"youth", *b"rhyme", *b"buggy", *b"alien", *b"smear", *b"unfit", *b"patty", *b"cling", *b"glean", *b"label", *b"hunky", *b"khaki", *b"poker", *b"gruel", *b"twice", *b"twang", *b"shrug", *b"treat", *b"unlit", *b"waste", *b"merit", *b"woven", *b"octal", *b"needy", *b"clown", *b"widow", *b"irony", *b"ruder", *b"gauze", *b"chief", *b"onset", *b"prize", *b"fungi", *b"charm", *b"gully", *b"inter", *b"whoop", *b"taunt", *b"leery", *b"class", *b"theme", *b"lofty", *b"tibia", *b"booze", *b"alpha", *b"thyme", *b"eclat", *b"doubt", *b"parer", *b"chute", *b"stick", *b"trice", *b"alike", *b"sooth", *b"recap", *b"saint", *b"liege", *b"glory", *b"grate", *b"admit", *b"brisk", *b"soggy", *b"usurp", *b"scald", *b"scorn", *b"leave", *b"twine", *b"sting", *b"bough", *b"marsh", *b"sloth", *b"dandy", *b"vigor", *b"howdy", *b"enjoy", *b"valid", *b"ionic", *b"equal", *b"unset", *b"floor", *b"catch", *b"spade", *b"stein", *b"exist", *b"quirk", *b"denim", *b"grove", *b"spiel", *b"mummy", *b"fault", *b"foggy", *b"flout", *b"carry", *b"sneak", *b"libel", *b"waltz", *b"aptly", *b"piney", *b"inept", *b"aloud", *b"photo", *b"dream", *b"stale", *b"vomit", *b"ombre", *b"fanny", *b"unite", *b"snarl", *b"baker", *b"there", *b"glyph", *b"pooch", *b"hippy", *b"spell", *b"folly", *b"louse", *b"gulch", *b"vault", *b"godly", *b"threw", *b"fleet", *b"grave", *b"inane", *b"shock", *b"crave", *b"spite", *b"valve", *b"skimp", *b"claim", *b"rainy", *b"musty", *b"pique", *b"daddy", *b"quasi", *b"arise", *b"aging", *b"valet", *b"opium", *b"avert", *b"stuck", *b"recut", *b"mulch", *b"genre", *b"plume", *b"rifle", *b"count", *b"incur", *b"total", *b"wrest", *b"mocha", *b"deter", *b"study", *b"lover", *b"safer", *b"rivet", *b"funny", *b"smoke", *b"mound", *b"undue", *b"sedan", *b"pagan", *b"swine", *b"guile", *b"gusty", *b"equip", *b"tough", *b"canoe", *b"chaos", *b"covet", *b"human", *b"udder", *b"lunch", *b"blast", *b"stray", *b"manga", *b"melee", *b"lefty", *b"quick", *b"paste", *b"given", *b"octet", *b"risen", *b"groan", *b"leaky", *b"grind", *b"carve", *b"loose", *b"sadly", *b"spilt", *b"apple", *b"slack", *b"honey", *b"final", *b"sheen", *b"eerie", *b"minty", *b"slick", *b"derby", *b"wharf", *b"spelt", *b"coach", *b"erupt", *b"singe", *b"price", *b"spawn", *b"fairy", *b"jiffy", *b"filmy", *b"stack", *b"chose", *b"sleep", *b"ardor", *b"nanny", *b"niece", *b"woozy", *b"handy", *b"grace", *b"ditto", *b"stank", *b"cream", *b"usual", *b"diode", *b"valor", *b"angle", *b"ninja", *b"muddy", *b"chase", *b"reply", *b"prone", *b"spoil", *b"heart", *b"shade", *b"diner", *b"arson", *b"onion", *b"sleet", *b"dowel", *b"couch", *b"palsy", *b"bowel", *b"smile", *b"evoke", *b"creek", *b"lance", *b"eagle", *b"idiot", *b"siren", *b"built", *b"embed", *b"award", *b"dross", *b"annul", *b"goody", *b"frown", *b"patio", *b"laden", *b"humid", *b"elite", *b"lymph", *b"edify", *b"might", *b"reset", *b"visit", *b"gusto", *b"purse", *b"vapor", *b"crock", *b"write", *b"sunny", *b"loath", *b"chaff", *b"slide", *b"queer", *b"venom", *b"stamp", *b"sorry", *b"still", *b"acorn", *b"aping", *b"pushy", *b"tamer", *b"hater", *b"mania", *b"awoke", *b"brawn", *b"swift", *b"exile", *b"birch", *b"lucky", *b"freer", *b"risky", *b"ghost", *b"plier", *b"lunar", *b"winch", *b"snare", *b"nurse", *b"house", *b"borax", *b"nicer", *b"lurch", *b"exalt", *b"about", *b"savvy", *b"toxin", *b"tunic", *b"pried", *b"inlay", *b"chump", *b"lanky", *b"cress", *b"eater", *b"elude", *b"cycle", *b"kitty", *b"boule", *b"moron", *b"tenet", *b"place", *b"lobby", *b"plush", *b"vigil", *b"index", *b"blink", *b"clung", *b"qualm", *b"croup", *b"clink", *b"juicy", *b"stage", *b"decay", *b"nerve", *b"flier", *b"shaft", *b"crook", *b"clean", *b"china", *b"ridge", *b"vowel", *b"gnome", *b"snuck", *b"icing", *b"spiny", *b"rigor", *b"snail", *b"flown", *b"rabid", *b"prose", *b"thank", *b"poppy", *b"budge", *b"fiber", *b"moldy", *b"dowdy", *b"kneel", *b"track", *b"caddy", *b"quell", *b"dumpy", *b"paler", *b"swore", *b"rebar", *b"scuba", *b"splat", *b"flyer", *b"horny", *b"mason", *b"doing", *b"ozone", *b"amply", *b"molar", *b"ovary", *b"beset", *b"queue", *b"cliff", *b"magic", *b"truce", *b"sport", *b"fritz", *b"edict", *b"twirl", *b"verse", *b"llama", *b"eaten", *b"range", *b"whisk", *b"hovel", *b"rehab", *b"macaw", *b"sigma", *b"spout", *b"verve", *b"sushi", *b"dying", *b"fetid", *b"brain", *b"buddy", *b"thump", *b"scion", *b"candy", *b"chord", *b"basin", *b"march", *b"crowd", *b"arbor", *b"gayly", *b"musky", *b"stain", *b"dally", *b"bless", *b"bravo", *b"stung", *b"title", *b"ruler", *b"kiosk", *b"blond", *b"ennui", *b"layer", *b"fluid", *b"tatty", *b"score", *b"cutie", *b"zebra", *b"barge", *b"matey", *b"bluer", *b"aider", *b"shook", *b"river", *b"privy", *b"betel", *b"frisk", *b"bongo", *b"begun", *b"azure", *b"weave", *b"genie", *b"sound", *b"glove", *b"braid", *b"scope", *b"wryly", *b"rover", *b"assay", *b"ocean", *b"bloom", *b"irate", *b"later", *b"woken", *b"silky", *b"wreck", *b"dwelt", *b"slate", *b"smack", *b"solid", *b"amaze", *b"hazel", *b"wrist", *b"jolly", *b"globe", *b"flint", *b"rouse", *b"civil", *b"vista", *b"relax", *b"cover", *b"alive", *b"beech", *b"jetty", *b"bliss", *b"vocal", *b"often", *b"dolly", *b"eight", *b"joker", *b"since", *b"event", *b"ensue", *b"shunt", *b"diver", *b"poser", *b"worst", *b"sweep", *b"alley", *b"creed", *b"anime", *b"leafy", *b"bosom", *b"dunce", *b"stare", *b"pudgy", *b"waive", *b"choir", *b"stood", *b"spoke", *b"outgo", *b"delay", *b"bilge", *b"ideal", *b"clasp", *b"seize", *b"hotly", *b"laugh", *b"sieve", *b"block", *b"meant", *b"grape", *b"noose", *b"hardy", *b"shied", *b"drawl", *b"daisy", *b"putty", *b"strut", *b"burnt", *b"tulip", *b"crick", *b"idyll", *b"vixen", *b"furor", *b"geeky", *b"cough", *b"naive", *b"shoal", *b"stork", *b"bathe", *b"aunty", *b"check", *b"prime", *b"brass", *b"outer", *b"furry", *b"razor", *b"elect", *b"evict", *b"imply", *b"demur", *b"quota", *b"haven", *b"cavil", *b"swear", *b"crump", *b"dough", *b"gavel", *b"wagon", *b"salon", *b"nudge", *b"harem", *b"pitch", *b"sworn", *b"pupil", *b"excel", *b"stony", *b"cabin", *b"unzip", *b"queen", *b"trout", *b"polyp", *b"earth", *b"storm", *b"until", *b"taper", *b"enter", *b"child", *b"adopt", *b"minor", *b"fatty", *b"husky", *b"brave", *b"filet", *b"slime", *b"glint", *b"tread", *b"steal", *b"regal", *b"guest", *b"every", *b"murky", *b"share", *b"spore", *b"hoist", *b"buxom", *b"inner", *b"otter", *b"dimly", *b"level", *b"sumac", *b"donut", *b"stilt", *b"arena", *b"sheet", *b"scrub", *b"fancy", *b"slimy", *b"pearl", *b"silly", *b"porch", *b"dingo", *b"sepia", *b"amble", *b"shady", *b"bread", *b"friar", *b"reign", *b"dairy", *b"quill", *b"cross", *b"brood", *b"tuber", *b"shear", *b"posit", *b"blank", *b"villa", *b"shank", *b"piggy", *b"freak", *b"which", *b"among", *b"fecal", *b"shell", *b"would", *b"algae", *b"large", *b"rabbi", *b"agony", *b"amuse", *b"bushy", *b"copse", *b"swoon", *b"knife", *b"pouch", *b"ascot", *b"plane", *b"crown", *b"urban", *b"snide", *b"relay", *b"abide", *b"viola", *b"rajah", *b"straw", *b"dilly", *b"crash", *b"amass", *b"third", *b"trick", *b"tutor", *b"woody", *b"blurb", *b"grief", *b"disco", *b"where", *b"sassy", *b"beach", *b"sauna", *b"comic", *b"clued", *b"creep", *b"caste", *b"graze", *b"snuff", *b"frock", *b"gonad", *b"drunk", *b"prong", *b"lurid", *b"steel", *b"halve", *b"buyer", *b"vinyl", *b"utile", *b"smell", *b"adage", *b"worry", *b"tasty", *b"local", *b"trade", *b"finch", *b"ashen", *b"modal", *b"gaunt", *b"clove", *b"enact", *b"adorn", *b"roast", *b"speck", *b"sheik", *b"missy", *b"grunt", *b"snoop", *b"party", *b"touch", *b"mafia", *b"emcee", *b"array", *b"south", *b"vapid", *b"jelly", *b"skulk", *b"angst", *b"tubal", *b"lower", *b"crest", *b"sweat", *b"cyber", *b"adore", *b"tardy", *b"swami", *b"notch", *b"groom", *b"roach", *b"hitch", *b"young", *b"align", *b"ready", *b"frond", *b"strap", *b"puree", *b"realm", *b"venue", *b"swarm", *b"offer", *b"seven", *b"dryer", *b"diary", *b"dryly", *b"drank", *b"acrid", *b"heady", *b"theta", *b"junto", *b"pixie", *b"quoth", *b"bonus", *b"shalt", *b"penne", *b"amend", *b"datum", *b"build", *b"piano", *b"shelf", *b"lodge", *b"suing", *b"rearm", *b"coral", *b"ramen", *b"worth", *b"psalm", *b"infer", *b"overt", *b"mayor", *b"ovoid", *b"glide", *b"usage", *b"poise", *b"randy", *b"chuck", *b"prank", *b"fishy", *b"tooth", *b"ether", *b"drove", *b"idler", *b"swath", *b"stint", *b"while", *b"begat", *b"apply", *b"slang", *b"tarot", *b"radar", *b"credo", *b"aware", *b"canon", *b"shift", *b"timer", *b"bylaw", *b"serum", *b"three", *b"steak", *b"iliac", *b"shirk", *b"blunt", *b"puppy", *b"penal", *b"joist", *b"bunny", *b"shape", *b"beget", *b"wheel", *b"adept", *b"stunt", *b"stole", *b"topaz", *b"chore", *b"fluke", *b"afoot", *b"bloat", *b"bully", *b"dense", *b"caper", *b"sneer", *b"boxer", *b"jumbo", *b"lunge", *b"space", *b"avail", *b"short", *b"slurp", *b"loyal", *b"flirt", *b"pizza", *b"conch", *b"tempo", *b"droop", *b"plate", *b"bible", *b"plunk", *b"afoul", *b"savoy", *b"steep", *b"agile", *b"stake", *b"dwell", *b"knave", *b"beard", *b"arose", *b"motif", *b"smash", *b"broil", *b"glare", *b"shove", *b"baggy", *b"mammy", *b"swamp", *b"along", *b"rugby", *b"wager", *b"quack", *b"squat", *b"snaky", *b"debit", *b"mange", *b"skate", *b"ninth", *b"joust", *b"tramp", *b"spurn", *b"medal", *b"micro", *b"rebel", *b"flank", *b"learn", *b"nadir", *b"maple", *b"comfy", *b"remit", *b"gruff", *b"ester", *b"least", *b"mogul", *b"fetch", *b"cause", *b"oaken", *b"aglow", *b"meaty", *b"gaffe", *b"shyly", *b"racer", *b"prowl", *b"thief", *b"stern", *b"poesy", *b"rocky", *b"tweet", *b"waist", *b"spire", *b"grope", *b"havoc", *b"patsy", *b"truly", *b"forty", *b"deity", *b"uncle", *b"swish", *b"giver", *b"preen", *b"bevel", *b"lemur", *b"draft", *b"slope", *b"annoy", *b"lingo", *b"bleak", *b"ditty", *b"curly", *b"cedar", *b"dirge", *b"grown", *b"horde", *b"drool", *b"shuck", *b"crypt", *b"cumin", *b"stock", *b"gravy", *b"locus", *b"wider", *b"breed", *b"quite", *b"chafe", *b"cache", *b"blimp", *b"deign", *b"fiend", *b"logic", *b"cheap", *b"elide", *b"rigid", *b"false", *b"renal", *b"pence", *b"rowdy", *b"shoot", *b"blaze", *b"envoy", *b"posse", *b"brief", *b"never", *b"abort", *b"mouse", *b"mucky", *b"sulky", *b"fiery", *b"media", *b"trunk", *b"yeast", *b"clear", *b"skunk", *b"scalp", *b"bitty", *b"cider", *b"koala", *b"duvet", *b"segue", *b"creme", *b"super", *b"grill", *b"after", *b"owner", *b"ember", *b"reach", *b"nobly", *b"empty", *b"speed", *b"gipsy", *b"recur", *b"smock", *b"dread", *b"merge", *b"burst", *b"kappa", *b"amity", *b"shaky", *b"hover", *b"carol", *b"snort", *b"synod", *b"faint", *b"haunt", *b"flour", *b"chair", *b"detox", *b"shrew", *b"tense", *b"plied", *b"quark", *b"burly", *b"novel", *b"waxen", *b"stoic", *b"jerky", *b"blitz", *b"beefy", *b"lyric", *b"hussy", *b"towel", *b"quilt", *b"below", *b"bingo", *b"wispy", *b"brash", *b"scone", *b"toast", *b"easel", *b"saucy", *b"value", *b"spice", *b"honor", *b"route", *b"sharp", *b"bawdy", *b"radii", *b"skull", *b"phony", *b"issue", *b"lager", *b"swell", *b"urine", *b"gassy", *b"trial", *b"flora", *b"upper", *b"latch", *b"wight", *b"brick", *b"retry", *b"holly", *b"decal", *b"grass", *b"shack", *b"dogma", *b"mover", *b"defer", *b"sober", *b"optic", *b"crier", *b"vying", *b"nomad", *b"flute", *b"hippo", *b"shark", *b"drier", *b"obese", *b"bugle", *b"tawny", *b"chalk", *b"feast", *b"ruddy", *b"pedal", *b"scarf", *b"cruel", *b"bleat", *b"tidal", *b"slush", *b"semen", *b"windy", *b"dusty", *b"sally", *b"igloo", *b"nerdy", *b"jewel", *b"shone", *b"whale", *b"hymen", *b"abuse", *b"fugue", *b"elbow", *b"crumb", *b"pansy", *b"welsh", *b"syrup", *b"terse", *b"suave", *b"gamut", *b"swung", *b"drake", *b"freed", *b"afire", *b"shirt", *b"grout", *b"oddly", *b"tithe", *b"plaid", *b"dummy", *b"broom", *b"blind", *b"torch", *b"enemy", *b"again", *b"tying", *b"pesky", *b"alter", *b"gazer", *b"noble", *b"ethos", *b"bride", *b"extol", *b"decor", *b"hobby", *b"beast", *b"idiom", *b"utter", *b"these", *b"sixth", *b"alarm", *b"erase", *b"elegy", *b"spunk", *b"piper", *b"scaly", *b"scold", *b"hefty", *b"chick", *b"sooty", *b"canal", *b"whiny", *b"slash", *b"quake", *b"joint", *b"swept", *b"prude", *b"heavy", *b"wield", *b"femme", *b"lasso", *b"maize", *b"shale", *b"screw", *b"spree", *b"smoky", *b"whiff", *b"scent", *b"glade", *b"spent", *b"prism", *b"stoke", *b"riper", *b"orbit", *b"cocoa", *b"guilt", *b"humus", *b"shush", *b"table", *b"smirk", *b"wrong", *b"noisy", *b"alert", *b"shiny", *b"elate", *b"resin", *b"whole", *b"hunch", *b"pixel", *b"polar", *b"hotel", *b"sword", *b"cleat", *b"mango", *b"rumba", *b"puffy", *b"filly", *b"billy", *b"leash", *b"clout", *b"dance", *b"ovate", *b"facet", *b"chili", *b"paint", *b"liner", *b"curio", *b"salty", *b"audio", *b"snake", *b"fable", *b"cloak", *b"navel", *b"spurt", *b"pesto", *b"balmy", *b"flash", *b"unwed", *b"early", *b"churn", *b"weedy", *b"stump", *b"lease", *b"witty", *b"wimpy", *b"spoof", *b"saner", *b"blend", *b"salsa", *b"thick", *b"warty", *b"manic", *b"blare", *b"squib", *b"spoon", *b"probe", *b"crepe", *b"knack", *b"force", *b"debut", *b"order", *b"haste", *b"teeth", *b"agent", *b"widen", *b"icily", *b"slice", *b"ingot", *b"clash", *b"juror", *b"blood", *b"abode", *b"throw", *b"unity", *b"pivot", *b"slept", *b"troop", *b"spare", *b"sewer", *b"parse", *b"morph", *b"cacti", *b"tacky", *b"spool", *b"demon", *b"moody", *b"annex", *b"begin", *b"fuzzy", *b"patch", *b"water", *b"lumpy", *b"admin", *b"omega", *b"limit", *b"tabby", *b"macho", *b"aisle", *b"skiff", *b"basis", *b"plank", *b"verge", *b"botch", *b"crawl", *b"lousy", *b"slain", *b"cubic", *b"raise", *b"wrack", *b"guide", *b"foist", *b"cameo", *b"under", *b"actor", *b"revue", *b"fraud", *b"harpy", *b"scoop", *b"climb", *b"refer", *b"olden", *b"clerk", *b"debar", *b"tally", *b"ethic", *b"cairn", *b"tulle", *b"ghoul", *b"hilly", *b"crude", *b"apart", *b"scale", *b"older", *b"plain", *b"sperm", *b"briny", *b"abbot", *b"rerun", *b"quest", *b"crisp", *b"bound", *b"befit", *b"drawn", *b"suite", *b"itchy", *b"cheer", *b"bagel", *b"guess", *b"broad", *b"axiom", *b"chard", *b"caput", *b"leant", *b"harsh", *b"curse", *b"proud", *b"swing", *b"opine", *b"taste", *b"lupus", *b"gumbo", *b"miner", *b"green", *b"chasm", *b"lipid", *b"topic", *b"armor", *b"brush", *b"crane", *b"mural", *b"abled", *b"habit", *b"bossy", *b"maker", *b"dusky", *b"dizzy", *b"lithe", *b"brook", *b"jazzy", *b"fifty", *b"sense", *b"giant", *b"surly", *b"legal", *b"fatal", *b"flunk", *b"began", *b"prune", *b"small", *b"slant", *b"scoff", *b"torus", *b"ninny", *b"covey", *b"viper", *b"taken", *b"moral", *b"vogue", *b"owing", *b"token", *b"entry", *b"booth", *b"voter", *b"chide", *b"elfin", *b"ebony", *b"neigh", *b"minim", *b"melon", *b"kneed", *b"decoy", *b"voila", *b"ankle", *b"arrow", *b"mushy", *b"tribe", *b"cease", *b"eager", *b"birth", *b"graph", *b"odder", *b"terra", *b"weird", *b"tried", *b"clack", *b"color", *b"rough", *b"weigh", *b"uncut", *b"ladle", *b"strip", *b"craft", *b"minus", *b"dicey", *b"titan", *b"lucid", *b"vicar", *b"dress", *b"ditch", *b"gypsy", *b"pasta", *b"taffy", *b"flame", *b"swoop", *b"aloof", *b"sight", *b"broke", *b"teary", *b"chart", *b"sixty", *b"wordy", *b"sheer", *b"leper", *b"nosey", *b"bulge", *b"savor", *b"clamp", *b"funky", *b"foamy", *b"toxic", *b"brand", *b"plumb", *b"dingy", *b"butte", *b"drill", *b"tripe", *b"bicep", *b"tenor", *b"krill", *b"worse", *b"drama", *b"hyena", *b"think", *b"ratio", *b"cobra", *b"basil", *b"scrum", *b"bused", *b"phone", *b"court", *b"camel", *b"proof", *b"heard", *b"angel", *b"petal", *b"pouty", *b"throb", *b"maybe", *b"fetal", *b"sprig", *b"spine", *b"shout", *b"cadet", *b"macro", *b"dodgy", *b"satyr", *b"rarer", *b"binge", *b"trend", *b"nutty", *b"leapt", *b"amiss", *b"split", *b"myrrh", *b"width", *b"sonar", *b"tower", *b"baron", *b"fever", *b"waver", *b"spark", *b"belie", *b"sloop", *b"expel", *b"smote", *b"baler", *b"above", *b"north", *b"wafer", *b"scant", *b"frill", *b"awash", *b"snack", *b"scowl", *b"frail", *b"drift", *b"limbo", *b"fence", *b"motel", *b"ounce", *b"wreak", *b"revel", *b"talon", *b"prior", *b"knelt", *b"cello", *b"flake", *b"debug", *b"anode", *b"crime", *b"salve", *b"scout", *b"imbue", *b"pinky", *b"stave", *b"vague", *b"chock", *b"fight", *b"video", *b"stone", *b"teach", *b"cleft", *b"frost", *b"prawn", *b"booty", *b"twist", *b"apnea", *b"stiff", *b"plaza", *b"ledge", *b"tweak", *b"board", *b"grant", *b"medic", *b"bacon", *b"cable", *b"brawl", *b"slunk", *b"raspy", *b"forum", *b"drone", *b"women", *b"mucus", *b"boast", *b"toddy", *b"coven", *b"tumor", *b"truer", *b"wrath", *b"stall", *b"steam", *b"axial", *b"purer", *b"daily", *b"trail", *b"niche", *b"mealy", *b"juice", *b"nylon", *b"plump", *b"merry", *b"flail", *b"papal", *b"wheat", *b"berry", *b"cower", *b"erect", *b"brute", *b"leggy", *b"snipe", *b"sinew", *b"skier", *b"penny", *b"jumpy", *b"rally", *b"umbra", *b"scary", *b"modem", *b"gross", *b"avian", *b"greed", *b"satin", *b"tonic", *b"parka", *b"sniff", *b"livid", *b"stark", *b"trump", *b"giddy", *b"reuse", *b"taboo", *b"avoid", *b"quote", *b"devil", *b"liken", *b"gloss", *b"gayer", *b"beret", *b"noise", *b"gland", *b"dealt", *b"sling", *b"rumor", *b"opera", *b"thigh", *b"tonga", *b"flare", *b"wound", *b"white", *b"bulky", *b"etude", *b"horse", *b"circa", *b"paddy", *b"inbox", *b"fizzy", *b"grain", *b"exert", *b"surge", *b"gleam", *b"belle", *b"salvo", *b"crush", *b"fruit", *b"sappy", *b"taker", *b"tract", *b"ovine", *b"spiky", *b"frank", *b"reedy", *b"filth", *b"spasm", *b"heave", *b"mambo", *b"right", *b"clank", *b"trust", *b"lumen", *b"borne", *b"spook", *b"sauce", *b"amber", *b"lathe", *b"carat", *b"corer", *b"dirty", *b"slyly", *b"affix", *b"alloy", *b"taint", *b"sheep", *b"kinky", *b"wooly", *b"mauve", *b"flung", *b"yacht", *b"fried", *b"quail", *b"brunt", *b"grimy", *b"curvy", *b"cagey", *b"rinse", *b"deuce", *b"state", *b"grasp", *b"milky", *b"bison", *b"graft", *b"sandy", *b"baste", *b"flask", *b"hedge", *b"girly", *b"swash", *b"boney", *b"coupe", *b"endow", *b"abhor", *b"welch", *b"blade", *b"tight", *b"geese", *b"miser", *b"mirth", *b"cloud", *b"cabal", *b"leech", *b"close", *b"tenth", *b"pecan", *b"droit", *b"grail", *b"clone", *b"guise", *b"ralph", *b"tango", *b"biddy", *b"smith", *b"mower", *b"payee", *b"serif", *b"drape", *b"fifth", *b"spank", *b"glaze", *b"allot", *b"truck", *b"kayak", *b"virus", *b"testy", *b"tepee", *b"fully", *b"zonal", *b"metro", *b"curry", *b"grand", *b"banjo", *b"axion", *b"bezel", *b"occur", *b"chain", *b"nasal", *b"gooey", *b"filer", *b"brace", *b"allay", *b"pubic", *b"raven", *b"plead", *b"gnash", *b"flaky", *b"munch", *b"dully", *b"eking", *b"thing", *b"slink", *b"hurry", *b"theft", *b"shorn", *b"pygmy", *b"ranch", *b"wring", *b"lemon", *b"shore", *b"mamma", *b"froze", *b"newer", *b"style", *b"moose", *b"antic", *b"drown", *b"vegan", *b"chess", *b"guppy", *b"union", *b"lever", *b"lorry", *b"image", *b"cabby", *b"druid", *b"exact", *b"truth", *b"dopey", *b"spear", *b"cried", *b"chime", *b"crony", *b"stunk", *b"timid", *b"batch", *b"gauge", *b"rotor", *b"crack", *b"curve", *b"latte", *b"witch", *b"bunch", *b"repel", *b"anvil", *b"soapy", *b"meter", *b"broth", *b"madly", *b"dried", *b"scene", *b"known", *b"magma", *b"roost", *b"woman", *b"thong", *b"punch", *b"pasty", *b"downy", *b"knead", *b"whirl", *b"rapid", *b"clang", *b"anger", *b"drive", *b"goofy", *b"email", *b"music", *b"stuff", *b"bleep", *b"rider", *b"mecca", *b"folio", *b"setup", *b"verso", *b"quash", *b"fauna", *b"gummy", *b"happy", *b"newly", *b"fussy", *b"relic", *b"guava", *b"ratty", *b"fudge", *b"femur", *b"chirp", *b"forte", *b"alibi", *b"whine", *b"petty", *b"golly", *b"plait", *b"fleck", *b"felon", *b"gourd", *b"brown", *b"thrum", *b"ficus", *b"stash", *b"decry", *b"wiser", *b"junta", *b"visor", *b"daunt", *b"scree", *b"impel", *b"await", *b"press", *b"whose", *b"turbo", *b"stoop", *b"speak", *b"mangy", *b"eying", *b"inlet", *b"crone", *b"pulse", *b"mossy", *b"staid", *b"hence", *b"pinch", *b"teddy", *b"sully", *b"snore", *b"ripen", *b"snowy", *b"attic", *b"going", *b"leach", *b"mouth", *b"hound", *b"clump", *b"tonal", *b"bigot", *b"peril", *b"piece", *b"blame", *b"haute", *b"spied", *b"undid", *b"intro", *b"basal", *b"shine", *b"gecko", *b"rodeo", *b"guard", *b"steer", *b"loamy", *b"scamp", *b"scram", *b"manly", *b"hello", *b"vaunt", *b"organ", *b"feral", *b"knock", *b"extra", *b"condo", *b"adapt", *b"willy", *b"polka", *b"rayon", *b"skirt", *b"faith", *b"torso", *b"match", *b"mercy", *b"tepid", *b"sleek", *b"riser", *b"twixt", *b"peace", *b"flush", *b"catty", *b"login", *b"eject", *b"roger", *b"rival", *b"untie", *b"refit", *b"aorta", *b"adult", *b"judge", *b"rower", *b"artsy", *b"rural", *b"shave"];
+pub static GUESS_WORDS: &[[u8; 5]] = &[*b"aahed", *b"aalii", *b"aargh", *b"aarti", *b"abaca", *b"abaci", *b"abacs", *b"abaft", *b"abaka", *b"abamp", *b"aband", *b"abash", *b"abask", *b"abaya", *b"abbas", *b"abbed", *b"abbes", *b"abcee", *b"abeam", *b"abear", *b"abele", *b"abers", *b"abets", *b"abies", *b"abler", *b"ables", *b"ablet", *b"ablow", *b"abmho", *b"abohm", *b"aboil", *b"aboma", *b"aboon", *b"abord", *b"abore", *b"abram", *b"abray", *b"abrim", *b"abrin", *b"abris", *b"absey", *b"absit", *b"abuna", *b"abune", *b"abuts", *b"abuzz", *b"abyes", *b"abysm", *b"acais", *b"acari", *b"accas", *b"accoy", *b"acerb", *b"acers", *b"aceta", *b"achar", *b"ached", *b"aches", *b"achoo", *b"acids", *b"acidy", *b"acing", *b"acini", *b"ackee", *b"acker", *b"acmes", *b"acmic", *b"acned", *b"acnes", *b"acock", *b"acold", *b"acred", *b"acres", *b"acros", *b"acted", *b"actin", *b"acton", *b"acyls", *b"adaws", *b"adays", *b"adbot", *b"addax", *b"added", *b"adder", *b"addio", *b"addle", *b"adeem", *b"adhan", *b"adieu", *b"adios", *b"adits", *b"adman", *b"admen", *b"admix", *b"adobo", *b"adown", *b"adoze", *b"adrad", *b"adred", *b"adsum", *b"aduki", *b"adunc", *b"adust", *b"advew", *b"adyta", *b"adzed", *b"adzes", *b"aecia", *b"aedes", *b"aegis", *b"aeons", *b"aerie", *b"aeros", *b"aesir", *b"afald", *b"afara", *b"afars", *b"afear", *b"aflaj", *b"afore", *b"afrit", *b"afros", *b"agama", *b"agami", *b"agars", *b"agast", *b"agave", *b"agaze", *b"agene", *b"agers", *b"agger", *b"aggie", *b"aggri", *b"aggro", *b"aggry", *b"aghas", *b"agila", *b"agios", *b"agism", *b"agist", *b"agita", *b"aglee", *b"aglet", *b"agley", *b"agloo", *b"aglus", *b"agmas", *b"agoge", *b"agone", *b"agons", *b"agood", *b"agria", *b"agrin", *b"agros", *b"agued", *b"agues", *b"aguna", *b"aguti", *b"aheap", *b"ahent", *b"ahigh", *b"ahind", *b"ahing", *b"ahint", *b"ahold", *b"ahull", *b"ahuru", *b"aidas", *b"aided", *b"aides", *b"aidoi", *b"aidos", *b"aiery", *b"aigas", *b"aight", *b"ailed", *b"aimed", *b"aimer", *b"ainee", *b"ainga", *b"aioli", *b"aired", *b"airer", *b"airns", *b"airth", *b"airts", *b"aitch", *b"aitus", *b"aiver", *b"aiyee", *b"aizle", *b"ajies", *b"ajiva", *b"ajuga", *b"ajwan", *b"akees", *b"akela", *b"akene", *b"aking", *b"akita", *b"akkas", *b"alaap", *b"alack", *b"alamo", *b"aland", *b"alane", *b"alang", *b"alans", *b"alant", *b"alapa", *b"alaps", *b"alary", *b"alate", *b"alays", *b"albas", *b"albee", *b"alcid", *b"alcos", *b"aldea", *b"alder", *b"aldol", *b"aleck", *b"alecs", *b"alefs", *b"aleft", *b"aleph", *b"alews", *b"aleye", *b"alfas", *b"algal", *b"algas", *b"algid", *b"algin", *b"algor", *b"algum", *b"alias", *b"alifs", *b"aline", *b"alist", *b"aliya", *b"alkie", *b"alkos", *b"alkyd", *b"alkyl", *b"allee", *b"allel", *b"allis", *b"allod", *b"allyl", *b"almah", *b"almas", *b"almeh", *b"almes", *b"almud", *b"almug", *b"alods", *b"aloed", *b"aloes", *b"aloha", *b"aloin", *b"aloos", *b"alowe", *b"altho", *b"altos", *b"alula", *b"alums", *b"alure", *b"alvar", *b"alway", *b"amahs", *b"amain", *b"amate", *b"amaut", *b"amban", *b"ambit", *b"ambos", *b"ambry", *b"ameba", *b"ameer", *b"amene", *b"amens", *b"ament", *b"amias", *b"amice", *b"amici", *b"amide", *b"amido", *b"amids", *b"amies", *b"amiga", *b"amigo", *b"amine", *b"amino", *b"amins", *b"amirs", *b"amlas", *b"amman", *b"ammon", *b"ammos", *b"amnia", *b"amnic", *b"amnio", *b"amoks", *b"amole", *b"amort", *b"amour", *b"amove", *b"amowt", *b"amped", *b"ampul", *b"amrit", *b"amuck", *b"amyls", *b"anana", *b"anata", *b"ancho", *b"ancle", *b"ancon", *b"andro", *b"anear", *b"anele", *b"anent", *b"angas", *b"anglo", *b"anigh", *b"anile", *b"anils", *b"anima", *b"animi", *b"anion", *b"anise", *b"anker", *b"ankhs", *b"ankus", *b"anlas", *b"annal", *b"annas", *b"annat", *b"anoas", *b"anole", *b"anomy", *b"ansae", *b"antae", *b"antar", *b"antas", *b"anted", *b"antes", *b"antis", *b"antra", *b"antre", *b"antsy", *b"anura", *b"anyon", *b"apace", *b"apage", *b"apaid", *b"apayd", *b"apays", *b"apeak", *b"apeek", *b"apers", *b"apert", *b"apery", *b"apgar", *b"aphis", *b"apian", *b"apiol", *b"apish", *b"apism", *b"apode", *b"apods", *b"apoop", *b"aport", *b"appal", *b"appay", *b"appel", *b"appro", *b"appui", *b"appuy", *b"apres", *b"apses", *b"apsis", *b"apsos", *b"apted", *b"apter", *b"aquae", *b"aquas", *b"araba", *b"araks", *b"arame", *b"arars", *b"arbas", *b"arced", *b"archi", *b"arcos", *b"arcus", *b"ardeb", *b"ardri", *b"aread", *b"areae", *b"areal", *b"arear", *b"areas", *b"areca", *b"aredd", *b"arede", *b"arefy", *b"areic", *b"arene", *b"arepa", *b"arere", *b"arete", *b"arets", *b"arett", *b"argal", *b"argan", *b"argil", *b"argle", *b"argol", *b"argon", *b"argot", *b"argus", *b"arhat", *b"arias", *b"ariel", *b"ariki", *b"arils", *b"ariot", *b"arish", *b"arked", *b"arled", *b"arles", *b"armed", *b"armer", *b"armet", *b"armil", *b"arnas", *b"arnut", *b"aroba", *b"aroha", *b"aroid", *b"arpas", *b"arpen", *b"arrah", *b"arras", *b"arret", *b"arris", *b"arroz", *b"arsed", *b"arses", *b"arsey", *b"arsis", *b"artal", *b"artel", *b"artic", *b"artis", *b"aruhe", *b"arums", *b"arval", *b"arvee", *b"arvos", *b"aryls", *b"asana", *b"ascon", *b"ascus", *b"asdic", *b"ashed", *b"ashes", *b"ashet", *b"asked", *b"asker", *b"askoi", *b"askos", *b"aspen", *b"asper", *b"aspic", *b"aspie", *b"aspis", *b"aspro", *b"assai", *b"assam", *b"asses", *b"assez", *b"assot", *b"aster", *b"astir", *b"astun", *b"asura", *b"asway", *b"aswim", *b"asyla", *b"ataps", *b"ataxy", *b"atigi", *b"atilt", *b"atimy", *b"atlas", *b"atman", *b"atmas", *b"atmos", *b"atocs", *b"atoke", *b"atoks", *b"atoms", *b"atomy", *b"atony", *b"atopy", *b"atria", *b"atrip", *b"attap", *b"attar", *b"atuas", *b"audad", *b"auger", *b"aught", *b"aulas", *b"aulic", *b"auloi", *b"aulos", *b"aumil", *b"aunes", *b"aunts", *b"aurae", *b"aural", *b"aurar", *b"auras", *b"aurei", *b"aures", *b"auric", *b"auris", *b"aurum", *b"autos", *b"auxin", *b"avale", *b"avant", *b"avast", *b"avels", *b"avens", *b"avers", *b"avgas", *b"avine", *b"avion", *b"avise", *b"aviso", *b"avize", *b"avows", *b"avyze", *b"awarn", *b"awato", *b"awave", *b"aways", *b"awdls", *b"aweel", *b"aweto", *b"awing", *b"awmry", *b"awned", *b"awner", *b"awols", *b"awork", *b"axels", *b"axile", *b"axils", *b"axing", *b"axite", *b"axled", *b"axles", *b"axman", *b"axmen", *b"axoid", *b"axone", *b"axons", *b"ayahs", *b"ayaya", *b"ayelp", *b"aygre", *b"ayins", *b"ayont", *b"ayres", *b"ayrie", *b"azans", *b"azide", *b"azido", *b"azine", *b"azlon", *b"azoic", *b"azole", *b"azons", *b"azote", *b"azoth", *b"azuki", *b"azurn", *b"azury", *b"azygy", *b"azyme", *b"azyms", *b"baaed", *b"baals", *b"babas", *b"babel", *b"babes", *b"babka", *b"baboo", *b"babul", *b"babus", *b"bacca", *b"bacco", *b"baccy", *b"bacha", *b"bachs", *b"backs", *b"baddy", *b"baels", *b"baffs", *b"baffy", *b"bafts", *b"baghs", *b"bagie", *b"bahts", *b"bahus", *b"bahut", *b"bails", *b"bairn", *b"baisa", *b"baith", *b"baits", *b"baiza", *b"baize", *b"bajan", *b"bajra", *b"bajri", *b"bajus", *b"baked", *b"baken", *b"bakes", *b"bakra", *b"balas", *b"balds", *b"baldy", *b"baled", *b"bales", *b"balks", *b"balky", *b"balls", *b"bally", *b"balms", *b"baloo", *b"balsa", *b"balti", *b"balun", *b"balus", *b"bambi", *b"banak", *b"banco", *b"bancs", *b"banda", *b"bandh", *b"bands", *b"bandy", *b"baned", *b"banes", *b"bangs", *b"bania", *b"banks", *b"banns", *b"bants", *b"bantu", *b"banty", *b"banya", *b"bapus", *b"barbe", *b"barbs", *b"barby", *b"barca", *b"barde", *b"bardo", *b"bards", *b"bardy", *b"bared", *b"barer", *b"bares", *b"barfi", *b"barfs", *b"baric", *b"barks", *b"barky", *b"barms", *b"barmy", *b"barns", *b"barny", *b"barps", *b"barra", *b"barre", *b"barro", *b"barry", *b"barye", *b"basan", *b"based", *b"basen", *b"baser", *b"bases", *b"basho", *b"basij", *b"basks", *b"bason", *b"basse", *b"bassi", *b"basso", *b"bassy", *b"basta", *b"basti", *b"basto", *b"basts", *b"bated", *b"bates", *b"baths", *b"batik", *b"batta", *b"batts", *b"battu", *b"bauds", *b"bauks", *b"baulk", *b"baurs", *b"bavin", *b"bawds", *b"bawks", *b"bawls", *b"bawns", *b"bawrs", *b"bawty", *b"bayed", *b"bayer", *b"bayes", *b"bayle", *b"bayts", *b"bazar", *b"bazoo", *b"beads", *b"beaks", *b"beaky", *b"beals", *b"beams", *b"beamy", *b"beano", *b"beans", *b"beany", *b"beare", *b"bears", *b"beath", *b"beats", *b"beaty", *b"beaus", *b"beaut", *b"beaux", *b"bebop", *b"becap", *b"becke", *b"becks", *b"bedad", *b"bedel", *b"bedes", *b"bedew", *b"bedim", *b"bedye", *b"beedi", *b"beefs", *b"beeps", *b"beers", *b"beery", *b"beets", *b"befog", *b"begad", *b"begar", *b"begem", *b"begot", *b"begum", *b"beige", *b"beigy", *b"beins", *b"bekah", *b"belah", *b"belar", *b"belay", *b"belee", *b"belga", *b"bells", *b"belon", *b"belts", *b"bemad", *b"bemas", *b"bemix", *b"bemud", *b"bends", *b"bendy", *b"benes", *b"benet", *b"benga", *b"benis", *b"benne", *b"benni", *b"benny", *b"bento", *b"bents", *b"benty", *b"bepat", *b"beray", *b"beres", *b"bergs", *b"berko", *b"berks", *b"berme", *b"berms", *b"berob", *b"beryl", *b"besat", *b"besaw", *b"besee", *b"beses", *b"besit", *b"besom", *b"besot", *b"besti", *b"bests", *b"betas", *b"beted", *b"betes", *b"beths", *b"betid", *b"beton", *b"betta", *b"betty", *b"bever", *b"bevor", *b"bevue", *b"bevvy", *b"bewet", *b"bewig", *b"bezes", *b"bezil", *b"bezzy", *b"bhais", *b"bhaji", *b"bhang", *b"bhats", *b"bhels", *b"bhoot", *b"bhuna", *b"bhuts", *b"biach", *b"biali", *b"bialy", *b"bibbs", *b"bibes", *b"biccy", *b"bices", *b"bided", *b"bider", *b"bides", *b"bidet", *b"bidis", *b"bidon", *b"bield", *b"biers", *b"biffo", *b"biffs", *b"biffy", *b"bifid", *b"bigae", *b"biggs", *b"biggy", *b"bigha", *b"bight", *b"bigly", *b"bigos", *b"bijou", *b"biked", *b"biker", *b"bikes", *b"bikie", *b"bilbo", *b"bilby", *b"biled", *b"biles", *b"bilgy", *b"bilks", *b"bills", *b"bimah", *b"bimas", *b"bimbo", *b"binal", *b"bindi", *b"binds", *b"biner", *b"bines", *b"bings", *b"bingy", *b"binit", *b"binks", *b"bints", *b"biogs", *b"biont", *b"biota", *b"biped", *b"bipod", *b"birds", *b"birks", *b"birle", *b"birls", *b"biros", *b"birrs", *b"birse", *b"birsy", *b"bises", *b"bisks", *b"bisom", *b"biter", *b"bites", *b"bitos", *b"bitou", *b"bitsy", *b"bitte", *b"bitts", *b"bivia", *b"bivvy", *b"bizes", *b"bizzo", *b"bizzy", *b"blabs", *b"blads", *b"blady", *b"blaer", *b"blaes", *b"blaff", *b"blags", *b"blahs", *b"blain", *b"blams", *b"blart", *b"blase", *b"blash", *b"blate", *b"blats", *b"blatt", *b"blaud", *b"blawn", *b"blaws", *b"blays", *b"blear", *b"blebs", *b"blech", *b"blees", *b"blent", *b"blert", *b"blest", *b"blets", *b"bleys", *b"blimy", *b"bling", *b"blini", *b"blins", *b"bliny", *b"blips", *b"blist", *b"blite", *b"blits", *b"blive", *b"blobs", *b"blocs", *b"blogs", *b"blook", *b"bloop", *b"blore", *b"blots", *b"blows", *b"blowy", *b"blubs", *b"blude", *b"bluds", *b"bludy", *b"blued", *b"blues", *b"bluet", *b"bluey", *b"bluid", *b"blume", *b"blunk", *b"blurs", *b"blype", *b"boabs", *b"boaks", *b"boars", *b"boart", *b"boats", *b"bobac", *b"bobak", *b"bobas", *b"bobol", *b"bobos", *b"bocca", *b"bocce", *b"bocci", *b"boche", *b"bocks", *b"boded", *b"bodes", *b"bodge", *b"bodhi", *b"bodle", *b"boeps", *b"boets", *b"boeuf", *b"boffo", *b"boffs", *b"bogan", *b"bogey", *b"boggy", *b"bogie", *b"bogle", *b"bogue", *b"bogus", *b"bohea", *b"bohos", *b"boils", *b"boing", *b"boink", *b"boite", *b"boked", *b"bokeh", *b"bokes", *b"bokos", *b"bolar", *b"bolas", *b"bolds", *b"boles", *b"bolix", *b"bolls", *b"bolos", *b"bolts", *b"bolus", *b"bomas", *b"bombe", *b"bombo", *b"bombs", *b"bonce", *b"bonds", *b"boned", *b"boner", *b"bones", *b"bongs", *b"bonie", *b"bonks", *b"bonne", *b"bonny", *b"bonza", *b"bonze", *b"booai", *b"booay", *b"boobs", *b"boody", *b"booed", *b"boofy", *b"boogy", *b"boohs", *b"books", *b"booky", *b"bools", *b"booms", *b"boomy", *b"boong", *b"boons", *b"boord", *b"boors", *b"boose", *b"boots", *b"boppy", *b"borak", *b"boral", *b"boras", *b"borde", *b"bords", *b"bored", *b"boree", *b"borel", *b"borer", *b"bores", *b"borgo", *b"boric", *b"borks", *b"borms", *b"borna", *b"boron", *b"borts", *b"borty", *b"bortz", *b"bosie", *b"bosks", *b"bosky", *b"boson", *b"bosun", *b"botas", *b"botel", *b"botes", *b"bothy", *b"botte", *b"botts", *b"botty", *b"bouge", *b"bouks", *b"boult", *b"bouns", *b"bourd", *b"bourg", *b"bourn", *b"bouse", *b"bousy", *b"bouts", *b"bovid", *b"bowat", *b"bowed", *b"bower", *b"bowes", *b"bowet", *b"bowie", *b"bowls", *b"bowne", *b"bowrs", *b"bowse", *b"boxed", *b"boxen", *b"boxes", *b"boxla", *b"boxty", *b"boyar", *b"boyau", *b"boyed", *b"boyfs", *b"boygs", *b"boyla", *b"boyos", *b"boysy", *b"bozos", *b"braai", *b"brach", *b"brack", *b"bract", *b"brads", *b"braes", *b"brags", *b"brail", *b"braks", *b"braky", *b"brame", *b"brane", *b"brank", *b"brans", *b"brant", *b"brast", *b"brats", *b"brava", *b"bravi", *b"braws", *b"braxy", *b"brays", *b"braza", *b"braze", *b"bream", *b"brede", *b"breds", *b"breem", *b"breer", *b"brees", *b"breid", *b"breis", *b"breme", *b"brens", *b"brent", *b"brere", *b"brers", *b"breve", *b"brews", *b"breys", *b"brier", *b"bries", *b"brigs", *b"briki", *b"briks", *b"brill", *b"brims", *b"brins", *b"brios", *b"brise", *b"briss", *b"brith", *b"brits", *b"britt", *b"brize", *b"broch", *b"brock", *b"brods", *b"brogh", *b"brogs", *b"brome", *b"bromo", *b"bronc", *b"brond", *b"brool", *b"broos", *b"brose", *b"brosy", *b"brows", *b"brugh", *b"bruin", *b"bruit", *b"brule", *b"brume", *b"brung", *b"brusk", *b"brust", *b"bruts", *b"buats", *b"buaze", *b"bubal", *b"bubas", *b"bubba", *b"bubbe", *b"bubby", *b"bubus", *b"buchu", *b"bucko", *b"bucks", *b"bucku", *b"budas", *b"budis", *b"budos", *b"buffa", *b"buffe", *b"buffi", *b"buffo", *b"buffs", *b"buffy", *b"bufos", *b"bufty", *b"buhls", *b"buhrs", *b"buiks", *b"buist", *b"bukes", *b"bulbs", *b"bulgy", *b"bulks", *b"bulla", *b"bulls", *b"bulse", *b"bumbo", *b"bumfs", *b"bumph", *b"bumps", *b"bumpy", *b"bunas", *b"bunce", *b"bunco", *b"bunde", *b"bundh", *b"bunds", *b"bundt", *b"bundu", *b"bundy", *b"bungs", *b"bungy", *b"bunia", *b"bunje", *b"bunjy", *b"bunko", *b"bunks", *b"bunns", *b"bunts", *b"bunty", *b"bunya", *b"buoys", *b"buppy", *b"buran", *b"buras", *b"burbs", *b"burds", *b"buret", *b"burfi", *b"burgh", *b"burgs", *b"burin", *b"burka", *b"burke", *b"burks", *b"burls", *b"burns", *b"buroo", *b"burps", *b"burqa", *b"burro", *b"burrs", *b"burry", *b"bursa", *b"burse", *b"busby", *b"buses", *b"busks", *b"busky", *b"bussu", *b"busti", *b"busts", *b"busty", *b"buteo", *b"butes", *b"butle", *b"butoh", *b"butts", *b"butty", *b"butut", *b"butyl", *b"buzzy", *b"bwana", *b"bwazi", *b"byded", *b"bydes", *b"byked", *b"bykes", *b"byres", *b"byrls", *b"byssi", *b"bytes", *b"byway", *b"caaed", *b"cabas", *b"caber", *b"cabob", *b"caboc", *b"cabre", *b"cacas", *b"cacks", *b"cacky", *b"cadee", *b"cades", *b"cadge", *b"cadgy", *b"cadie", *b"cadis", *b"cadre", *b"caeca", *b"caese", *b"cafes", *b"caffs", *b"caged", *b"cager", *b"cages", *b"cagot", *b"cahow", *b"caids", *b"cains", *b"caird", *b"cajon", *b"cajun", *b"caked", *b"cakes", *b"cakey", *b"calfs", *b"calid", *b"calif", *b"calix", *b"calks", *b"calla", *b"calls", *b"calms", *b"calmy", *b"calos", *b"calpa", *b"calps", *b"calve", *b"calyx", *b"caman", *b"camas", *b"cames", *b"camis", *b"camos", *b"campi", *b"campo", *b"camps", *b"campy", *b"camus", *b"caned", *b"caneh", *b"caner", *b"canes", *b"cangs", *b"canid", *b"canna", *b"canns", *b"canso", *b"canst", *b"canto", *b"cants", *b"canty", *b"capas", *b"caped", *b"capes", *b"capex", *b"caphs", *b"capiz", *b"caple", *b"capon", *b"capos", *b"capot", *b"capri", *b"capul", *b"carap", *b"carbo", *b"carbs", *b"carby", *b"cardi", *b"cards", *b"cardy", *b"cared", *b"carer", *b"cares", *b"caret", *b"carex", *b"carks", *b"carle", *b"carls", *b"carns", *b"carny", *b"carob", *b"carom", *b"caron", *b"carpi", *b"carps", *b"carrs", *b"carse", *b"carta", *b"carte", *b"carts", *b"carvy", *b"casas", *b"casco", *b"cased", *b"cases", *b"casks", *b"casky", *b"casts", *b"casus", *b"cates", *b"cauda", *b"cauks", *b"cauld", *b"cauls", *b"caums", *b"caups", *b"cauri", *b"causa", *b"cavas", *b"caved", *b"cavel", *b"caver", *b"caves", *b"cavie", *b"cawed", *b"cawks", *b"caxon", *b"ceaze", *b"cebid", *b"cecal", *b"cecum", *b"ceded", *b"ceder", *b"cedes", *b"cedis", *b"ceiba", *b"ceili", *b"ceils", *b"celeb", *b"cella", *b"celli", *b"cells", *b"celom", *b"celts", *b"cense", *b"cento", *b"cents", *b"centu", *b"ceorl", *b"cepes", *b"cerci", *b"cered", *b"ceres", *b"cerge", *b"ceria", *b"ceric", *b"cerne", *b"ceroc", *b"ceros", *b"certs", *b"certy", *b"cesse", *b"cesta", *b"cesti", *b"cetes", *b"cetyl", *b"cezve", *b"chace", *b"chack", *b"chaco", *b"chado", *b"chads", *b"chaft", *b"chais", *b"chals", *b"chams", *b"chana", *b"chang", *b"chank", *b"chape", *b"chaps", *b"chapt", *b"chara", *b"chare", *b"chark", *b"charr", *b"chars", *b"chary", *b"chats", *b"chave", *b"chavs", *b"chawk", *b"chaws", *b"chaya", *b"chays", *b"cheep", *b"chefs", *b"cheka", *b"chela", *b"chelp", *b"chemo", *b"chems", *b"chere", *b"chert", *b"cheth", *b"chevy", *b"chews", *b"chewy", *b"chiao", *b"chias", *b"chibs", *b"chica", *b"chich", *b"chico", *b"chics", *b"chiel", *b"chiks", *b"chile", *b"chimb", *b"chimo", *b"chimp", *b"chine", *b"ching", *b"chino", *b"chins", *b"chips", *b"chirk", *b"chirl", *b"chirm", *b"chiro", *b"chirr", *b"chirt", *b"chiru", *b"chits", *b"chive", *b"chivs", *b"chivy", *b"chizz", *b"choco", *b"chocs", *b"chode", *b"chogs", *b"choil", *b"choko", *b"choky", *b"chola", *b"choli", *b"cholo", *b"chomp", *b"chons", *b"choof", *b"chook", *b"choom", *b"choon", *b"chops", *b"chota", *b"chott", *b"chout", *b"choux", *b"chowk", *b"chows", *b"chubs", *b"chufa", *b"chuff", *b"chugs", *b"chums", *b"churl", *b"churr", *b"chuse", *b"chuts", *b"chyle", *b"chyme", *b"chynd", *b"cibol", *b"cided", *b"cides", *b"ciels", *b"ciggy", *b"cilia", *b"cills", *b"cimar", *b"cimex", *b"cinct", *b"cines", *b"cinqs", *b"cions", *b"cippi", *b"circs", *b"cires", *b"cirls", *b"cirri", *b"cisco", *b"cissy", *b"cists", *b"cital", *b"cited", *b"citer", *b"cites", *b"cives", *b"civet", *b"civie", *b"civvy", *b"clach", *b"clade", *b"clads", *b"claes", *b"clags", *b"clame", *b"clams", *b"clans", *b"claps", *b"clapt", *b"claro", *b"clart", *b"clary", *b"clast", *b"clats", *b"claut", *b"clave", *b"clavi", *b"claws", *b"clays", *b"cleck", *b"cleek", *b"cleep", *b"clefs", *b"clegs", *b"cleik", *b"clems", *b"clepe", *b"clept", *b"cleve", *b"clews", *b"clied", *b"clies", *b"clift", *b"clime", *b"cline", *b"clint", *b"clipe", *b"clips", *b"clipt", *b"clits", *b"cloam", *b"clods", *b"cloff", *b"clogs", *b"cloke", *b"clomb", *b"clomp", *b"clonk", *b"clons", *b"cloop", *b"cloot", *b"clops", *b"clote", *b"clots", *b"clour", *b"clous", *b"clows", *b"cloye", *b"cloys", *b"cloze", *b"clubs", *b"clues", *b"cluey", *b"clunk", *b"clype", *b"cnida", *b"coact", *b"coady", *b"coala", *b"coals", *b"coaly", *b"coapt", *b"coarb", *b"coate", *b"coati", *b"coats", *b"cobbs", *b"cobby", *b"cobia", *b"coble", *b"cobza", *b"cocas", *b"cocci", *b"cocco", *b"cocks", *b"cocky", *b"cocos", *b"codas", *b"codec", *b"coded", *b"coden", *b"coder", *b"codes", *b"codex", *b"codon", *b"coeds", *b"coffs", *b"cogie", *b"cogon", *b"cogue", *b"cohab", *b"cohen", *b"cohoe", *b"cohog", *b"cohos", *b"coifs", *b"coign", *b"coils", *b"coins", *b"coirs", *b"coits", *b"coked", *b"cokes", *b"colas", *b"colby", *b"colds", *b"coled", *b"coles", *b"coley", *b"colic", *b"colin", *b"colls", *b"colly", *b"colog", *b"colts", *b"colza", *b"comae", *b"comal", *b"comas", *b"combe", *b"combi", *b"combo", *b"combs", *b"comby", *b"comer", *b"comes", *b"comix", *b"commo", *b"comms", *b"commy", *b"compo", *b"comps", *b"compt", *b"comte", *b"comus", *b"coned", *b"cones", *b"coney", *b"confs", *b"conga", *b"conge", *b"congo", *b"conia", *b"conin", *b"conks", *b"conky", *b"conne", *b"conns", *b"conte", *b"conto", *b"conus", *b"convo", *b"cooch", *b"cooed", *b"cooee", *b"cooer", *b"cooey", *b"coofs", *b"cooks", *b"cooky", *b"cools", *b"cooly", *b"coomb", *b"cooms", *b"coomy", *b"coops", *b"coopt", *b"coost", *b"coots", *b"cooze", *b"copal", *b"copay", *b"coped", *b"copen", *b"coper", *b"copes", *b"coppy", *b"copra", *b"copsy", *b"coqui", *b"coram", *b"corbe", *b"corby", *b"cords", *b"cored", *b"cores", *b"corey", *b"corgi", *b"coria", *b"corks", *b"corky", *b"corms", *b"corni", *b"corno", *b"corns", *b"cornu", *b"corps", *b"corse", *b"corso", *b"cosec", *b"cosed", *b"coses", *b"coset", *b"cosey", *b"cosie", *b"costa", *b"coste", *b"costs", *b"cotan", *b"coted", *b"cotes", *b"coths", *b"cotta", *b"cotts", *b"coude", *b"coups", *b"courb", *b"courd", *b"coure", *b"cours", *b"couta", *b"couth", *b"coved", *b"coves", *b"covin", *b"cowal", *b"cowan", *b"cowed", *b"cowks", *b"cowls", *b"cowps", *b"cowry", *b"coxae", *b"coxal", *b"coxed", *b"coxes", *b"coxib", *b"coyau", *b"coyed", *b"coyer", *b"coypu", *b"cozed", *b"cozen", *b"cozes", *b"cozey", *b"cozie", *b"craal", *b"crabs", *b"crags", *b"craic", *b"craig", *b"crake", *b"crame", *b"crams", *b"crans", *b"crape", *b"craps", *b"crapy", *b"crare", *b"craws", *b"crays", *b"creds", *b"creel", *b"crees", *b"crems", *b"crena", *b"creps", *b"crepy", *b"crewe", *b"crews", *b"crias", *b"cribs", *b"cries", *b"crims", *b"crine", *b"crios", *b"cripe", *b"crips", *b"crise", *b"crith", *b"crits", *b"croci", *b"crocs", *b"croft", *b"crogs", *b"cromb", *b"crome", *b"cronk", *b"crons", *b"crool", *b"croon", *b"crops", *b"crore", *b"crost", *b"crout", *b"crows", *b"croze", *b"cruck", *b"crudo", *b"cruds", *b"crudy", *b"crues", *b"cruet", *b"cruft", *b"crunk", *b"cruor", *b"crura", *b"cruse", *b"crusy", *b"cruve", *b"crwth", *b"cryer", *b"ctene", *b"cubby", *b"cubeb", *b"cubed", *b"cuber", *b"cubes", *b"cubit", *b"cuddy", *b"cuffo", *b"cuffs", *b"cuifs", *b"cuing", *b"cuish", *b"cuits", *b"cukes", *b"culch", *b"culet", *b"culex", *b"culls", *b"cully", *b"culms", *b"culpa", *b"culti", *b"cults", *b"culty", *b"cumec", *b"cundy", *b"cunei", *b"cunit", *b"cunts", *b"cupel", *b"cupid", *b"cuppa", *b"cuppy", *b"curat", *b"curbs", *b"curch", *b"curds", *b"curdy", *b"cured", *b"curer", *b"cures", *b"curet", *b"curfs", *b"curia", *b"curie", *b"curli", *b"curls", *b"curns", *b"curny", *b"currs", *b"cursi", *b"curst", *b"cusec", *b"cushy", *b"cusks", *b"cusps", *b"cuspy", *b"cusso", *b"cusum", *b"cutch", *b"cuter", *b"cutes", *b"cutey", *b"cutin", *b"cutis", *b"cutto", *b"cutty", *b"cutup", *b"cuvee", *b"cuzes", *b"cwtch", *b"cyano", *b"cyans", *b"cycad", *b"cycas", *b"cyclo", *b"cyder", *b"cylix", *b"cymae", *b"cymar", *b"cymas", *b"cymes", *b"cymol", *b"cysts", *b"cytes", *b"cyton", *b"czars", *b"daals", *b"dabba", *b"daces", *b"dacha", *b"dacks", *b"dadah", *b"dadas", *b"dados", *b"daffs", *b"daffy", *b"dagga", *b"daggy", *b"dagos", *b"dahls", *b"daiko", *b"daine", *b"daint", *b"daker", *b"daled", *b"dales", *b"dalis", *b"dalle", *b"dalts", *b"daman", *b"damar", *b"dames", *b"damme", *b"damns", *b"damps", *b"dampy", *b"dancy", *b"dangs", *b"danio", *b"danks", *b"danny", *b"dants", *b"daraf", *b"darbs", *b"darcy", *b"dared", *b"darer", *b"dares", *b"darga", *b"dargs", *b"daric", *b"daris", *b"darks", *b"darns", *b"darre", *b"darts", *b"darzi", *b"dashi", *b"dashy", *b"datal", *b"dated", *b"dater", *b"dates", *b"datos", *b"datto", *b"daube", *b"daubs", *b"dauby", *b"dauds", *b"dault", *b"daurs", *b"dauts", *b"daven", *b"davit", *b"dawah", *b"dawds", *b"dawed", *b"dawen", *b"dawks", *b"dawns", *b"dawts", *b"dayan", *b"daych", *b"daynt", *b"dazed", *b"dazer", *b"dazes", *b"deads", *b"deair", *b"deals", *b"deans", *b"deare", *b"dearn", *b"dears", *b"deary", *b"deash", *b"deave", *b"deaws", *b"deawy", *b"debag", *b"debby", *b"debel", *b"debes", *b"debts", *b"debud", *b"debur", *b"debus", *b"debye", *b"decad", *b"decaf", *b"decan", *b"decko", *b"decks", *b"decos", *b"dedal", *b"deeds", *b"deedy", *b"deely", *b"deems", *b"deens", *b"deeps", *b"deere", *b"deers", *b"deets", *b"deeve", *b"deevs", *b"defat", *b"deffo", *b"defis", *b"defog", *b"degas", *b"degum", *b"degus", *b"deice", *b"deids", *b"deify", *b"deils", *b"deism", *b"deist", *b"deked", *b"dekes", *b"dekko", *b"deled", *b"deles", *b"delfs", *b"delft", *b"delis", *b"dells", *b"delly", *b"delos", *b"delph", *b"delts", *b"deman", *b"demes", *b"demic", *b"demit", *b"demob", *b"demoi", *b"demos", *b"dempt", *b"denar", *b"denay", *b"dench", *b"denes", *b"denet", *b"denis", *b"dents", *b"deoxy", *b"derat", *b"deray", *b"dered", *b"deres", *b"derig", *b"derma", *b"derms", *b"derns", *b"derny", *b"deros", *b"derro", *b"derry", *b"derth", *b"dervs", *b"desex", *b"deshi", *b"desis", *b"desks", *b"desse", *b"devas", *b"devel", *b"devis", *b"devon", *b"devos", *b"devot", *b"dewan", *b"dewar", *b"dewax", *b"dewed", *b"dexes", *b"dexie", *b"dhaba", *b"dhaks", *b"dhals", *b"dhikr", *b"dhobi", *b"dhole", *b"dholl", *b"dhols", *b"dhoti", *b"dhows", *b"dhuti", *b"diact", *b"dials", *b"diane", *b"diazo", *b"dibbs", *b"diced", *b"dicer", *b"dices", *b"dicht", *b"dicks", *b"dicky", *b"dicot", *b"dicta", *b"dicts", *b"dicty", *b"diddy", *b"didie", *b"didos", *b"didst", *b"diebs", *b"diels", *b"diene", *b"diets", *b"diffs", *b"dight", *b"dikas", *b"diked", *b"diker", *b"dikes", *b"dikey", *b"dildo", *b"dilli", *b"dills", *b"dimbo", *b"dimer", *b"dimes", *b"dimps", *b"dinar", *b"dined", *b"dines", *b"dinge", *b"dings", *b"dinic", *b"dinks", *b"dinky", *b"dinna", *b"dinos", *b"dints", *b"diols", *b"diota", *b"dippy", *b"dipso", *b"diram", *b"direr", *b"dirke", *b"dirks", *b"dirls", *b"dirts", *b"disas", *b"disci", *b"discs", *b"dishy", *b"disks", *b"disme", *b"dital", *b"ditas", *b"dited", *b"dites", *b"ditsy", *b"ditts", *b"ditzy", *b"divan", *b"divas", *b"dived", *b"dives", *b"divis", *b"divna", *b"divos", *b"divot", *b"divvy", *b"diwan", *b"dixie", *b"dixit", *b"diyas", *b"dizen", *b"djinn", *b"djins", *b"doabs", *b"doats", *b"dobby", *b"dobes", *b"dobie", *b"dobla", *b"dobra", *b"dobro", *b"docht", *b"docks", *b"docos", *b"docus", *b"doddy", *b"dodos", *b"doeks", *b"doers", *b"doest", *b"doeth", *b"doffs", *b"dogan", *b"doges", *b"dogey", *b"doggo", *b"doggy", *b"dogie", *b"dohyo", *b"doilt", *b"doily", *b"doits", *b"dojos", *b"dolce", *b"dolci", *b"doled", *b"doles", *b"dolia", *b"dolls", *b"dolma", *b"dolor", *b"dolos", *b"dolts", *b"domal", *b"domed", *b"domes", *b"domic", *b"donah", *b"donas", *b"donee", *b"doner", *b"donga", *b"dongs", *b"donko", *b"donna", *b"donne", *b"donny", *b"donsy", *b"doobs", *b"dooce", *b"doody", *b"dooks", *b"doole", *b"dools", *b"dooly", *b"dooms", *b"doomy", *b"doona", *b"doorn", *b"doors", *b"doozy", *b"dopas", *b"doped", *b"doper", *b"dopes", *b"dorad", *b"dorba", *b"dorbs", *b"doree", *b"dores", *b"doric", *b"doris", *b"dorks", *b"dorky", *b"dorms", *b"dormy", *b"dorps", *b"dorrs", *b"dorsa", *b"dorse", *b"dorts", *b"dorty", *b"dosai", *b"dosas", *b"dosed", *b"doseh", *b"doser", *b"doses", *b"dosha", *b"dotal", *b"doted", *b"doter", *b"dotes", *b"dotty", *b"douar", *b"douce", *b"doucs", *b"douks", *b"doula", *b"douma", *b"doums", *b"doups", *b"doura", *b"douse", *b"douts", *b"doved", *b"doven", *b"dover", *b"doves", *b"dovie", *b"dowar", *b"dowds", *b"dowed", *b"dower", *b"dowie", *b"dowle", *b"dowls", *b"dowly", *b"downa", *b"downs", *b"dowps", *b"dowse", *b"dowts", *b"doxed", *b"doxes", *b"doxie", *b"doyen", *b"doyly", *b"dozed", *b"dozer", *b"dozes", *b"drabs", *b"drack", *b"draco", *b"draff", *b"drags", *b"drail", *b"drams", *b"drant", *b"draps", *b"drats", *b"drave", *b"draws", *b"drays", *b"drear", *b"dreck", *b"dreed", *b"dreer", *b"drees", *b"dregs", *b"dreks", *b"drent", *b"drere", *b"drest", *b"dreys", *b"dribs", *b"drice", *b"dries", *b"drily", *b"drips", *b"dript", *b"droid", *b"droil", *b"droke", *b"drole", *b"drome", *b"drony", *b"droob", *b"droog", *b"drook", *b"drops", *b"dropt", *b"drouk", *b"drows", *b"drubs", *b"drugs", *b"drums", *b"drupe", *b"druse", *b"drusy", *b"druxy", *b"dryad", *b"dryas", *b"dsobo", *b"dsomo", *b"duads", *b"duals", *b"duans", *b"duars", *b"dubbo", *b"ducal", *b"ducat", *b"duces", *b"ducks", *b"ducky", *b"ducts", *b"duddy", *b"duded", *b"dudes", *b"duels", *b"duets", *b"duett", *b"duffs", *b"dufus", *b"duing", *b"duits", *b"dukas", *b"duked", *b"dukes", *b"dukka", *b"dulce", *b"dules", *b"dulia", *b"dulls", *b"dulse", *b"dumas", *b"dumbo", *b"dumbs", *b"dumka", *b"dumky", *b"dumps", *b"dunam", *b"dunch", *b"dunes", *b"dungs", *b"dungy", *b"dunks", *b"dunno", *b"dunny", *b"dunsh", *b"dunts", *b"duomi", *b"duomo", *b"duped", *b"duper", *b"dupes", *b"duple", *b"duply", *b"duppy", *b"dural", *b"duras", *b"dured", *b"dures", *b"durgy", *b"durns", *b"duroc", *b"duros", *b"duroy", *b"durra", *b"durrs", *b"durry", *b"durst", *b"durum", *b"durzi", *b"dusks", *b"dusts", *b"duxes", *b"dwaal", *b"dwale", *b"dwalm", *b"dwams", *b"dwang", *b"dwaum", *b"dweeb", *b"dwile", *b"dwine", *b"dyads", *b"dyers", *b"dykon", *b"dynel", *b"dynes", *b"dzhos", *b"eagre", *b"ealed", *b"eales", *b"eaned", *b"eards", *b"eared", *b"earls", *b"earns", *b"earnt", *b"earst", *b"eased", *b"easer", *b"eases", *b"easle", *b"easts", *b"eathe", *b"eaved", *b"eaves", *b"ebbed", *b"ebbet", *b"ebons", *b"ebook", *b"ecads", *b"eched", *b"eches", *b"echos", *b"ecrus", *b"edema", *b"edged", *b"edger", *b"edges", *b"edile", *b"edits", *b"educe", *b"educt", *b"eejit", *b"eensy", *b"eeven", *b"eevns", *b"effed", *b"egads", *b"egers", *b"egest", *b"eggar", *b"egged", *b"egger", *b"egmas", *b"ehing", *b"eider", *b"eidos", *b"eigne", *b"eiked", *b"eikon", *b"eilds", *b"eisel", *b"ejido", *b"ekkas", *b"elain", *b"eland", *b"elans", *b"elchi", *b"eldin", *b"elemi", *b"elfed", *b"eliad", *b"elint", *b"elmen", *b"eloge", *b"elogy", *b"eloin", *b"elops", *b"elpee", *b"elsin", *b"elute", *b"elvan", *b"elven", *b"elver", *b"elves", *b"emacs", *b"embar", *b"embay", *b"embog", *b"embow", *b"embox", *b"embus", *b"emeer", *b"emend", *b"emerg", *b"emery", *b"emeus", *b"emics", *b"emirs", *b"emits", *b"emmas", *b"emmer", *b"emmet", *b"emmew", *b"emmys", *b"emoji", *b"emong", *b"emote", *b"emove", *b"empts", *b"emule", *b"emure", *b"emyde", *b"emyds", *b"enarm", *b"enate", *b"ended", *b"ender", *b"endew", *b"endue", *b"enews", *b"enfix", *b"eniac", *b"enlit", *b"enmew", *b"ennog", *b"enoki", *b"enols", *b"enorm", *b"enows", *b"enrol", *b"ensew", *b"ensky", *b"entia", *b"enure", *b"enurn", *b"envoi", *b"enzym", *b"eorls", *b"eosin", *b"epact", *b"epees", *b"ephah", *b"ephas", *b"ephod", *b"ephor", *b"epics", *b"epode", *b"epopt", *b"epris", *b"eques", *b"equid", *b"erbia", *b"erevs", *b"ergon", *b"ergos", *b"ergot", *b"erhus", *b"erica", *b"erick", *b"erics", *b"ering", *b"erned", *b"ernes", *b"erose", *b"erred", *b"erses", *b"eruct", *b"erugo", *b"eruvs", *b"erven", *b"ervil", *b"escar", *b"escot", *b"esile", *b"eskar", *b"esker", *b"esnes", *b"esses", *b"estoc", *b"estop", *b"estro", *b"etage", *b"etape", *b"etats", *b"etens", *b"ethal", *b"ethne", *b"ethyl", *b"etics", *b"etnas", *b"ettin", *b"ettle", *b"etuis", *b"etwee", *b"etyma", *b"eughs", *b"euked", *b"eupad", *b"euros", *b"eusol", *b"evens", *b"evert", *b"evets", *b"evhoe", *b"evils", *b"evite", *b"evohe", *b"ewers", *b"ewest", *b"ewhow", *b"ewked", *b"exams", *b"exeat", *b"execs", *b"exeem", *b"exeme", *b"exfil", *b"exies", *b"exine", *b"exing", *b"exits", *b"exode", *b"exome", *b"exons", *b"expat", *b"expos", *b"exude", *b"exuls", *b"exurb", *b"eyass", *b"eyers", *b"eyots", *b"eyras", *b"eyres", *b"eyrie", *b"eyrir", *b"ezine", *b"fabby", *b"faced", *b"facer", *b"faces", *b"facia", *b"facta", *b"facts", *b"faddy", *b"faded", *b"fader", *b"fades", *b"fadge", *b"fados", *b"faena", *b"faery", *b"faffs", *b"faffy", *b"fagin", *b"faiks", *b"fails", *b"faine", *b"fains", *b"fairs", *b"faked", *b"faker", *b"fakes", *b"fakey", *b"fakie", *b"fakir", *b"falaj", *b"falls", *b"famed", *b"fames", *b"fanal", *b"fands", *b"fanes", *b"fanga", *b"fango", *b"fangs", *b"fanks", *b"fanon", *b"fanos", *b"fanum", *b"faqir", *b"farad", *b"farci", *b"farcy", *b"fards", *b"fared", *b"farer", *b"fares", *b"farle", *b"farls", *b"farms", *b"faros", *b"farro", *b"farse", *b"farts", *b"fasci", *b"fasti", *b"fasts", *b"fated", *b"fates", *b"fatly", *b"fatso", *b"fatwa", *b"faugh", *b"fauld", *b"fauns", *b"faurd", *b"fauts", *b"fauve", *b"favas", *b"favel", *b"faver", *b"faves", *b"favus", *b"fawns", *b"fawny", *b"faxed", *b"faxes", *b"fayed", *b"fayer", *b"fayne", *b"fayre", *b"fazed", *b"fazes", *b"feals", *b"feare", *b"fears", *b"feart", *b"fease", *b"feats", *b"feaze", *b"feces", *b"fecht", *b"fecit", *b"fecks", *b"fedex", *b"feebs", *b"feeds", *b"feels", *b"feens", *b"feers", *b"feese", *b"feeze", *b"fehme", *b"feint", *b"feist", *b"felch", *b"felid", *b"fells", *b"felly", *b"felts", *b"felty", *b"femal", *b"femes", *b"femmy", *b"fends", *b"fendy", *b"fenis", *b"fenks", *b"fenny", *b"fents", *b"feods", *b"feoff", *b"ferer", *b"feres", *b"feria", *b"ferly", *b"fermi", *b"ferms", *b"ferns", *b"ferny", *b"fesse", *b"festa", *b"fests", *b"festy", *b"fetas", *b"feted", *b"fetes", *b"fetor", *b"fetta", *b"fetts", *b"fetwa", *b"feuar", *b"feuds", *b"feued", *b"feyed", *b"feyer", *b"feyly", *b"fezes", *b"fezzy", *b"fiars", *b"fiats", *b"fibro", *b"fices", *b"fiche", *b"fichu", *b"ficin", *b"ficos", *b"fides", *b"fidge", *b"fidos", *b"fiefs", *b"fient", *b"fiere", *b"fiers", *b"fiest", *b"fifed", *b"fifer", *b"fifes", *b"fifis", *b"figgy", *b"figos", *b"fiked", *b"fikes", *b"filar", *b"filch", *b"filed", *b"files", *b"filii", *b"filks", *b"fille", *b"fillo", *b"fills", *b"filmi", *b"films", *b"filos", *b"filum", *b"finca", *b"finds", *b"fined", *b"fines", *b"finis", *b"finks", *b"finny", *b"finos", *b"fiord", *b"fiqhs", *b"fique", *b"fired", *b"firer", *b"fires", *b"firie", *b"firks", *b"firms", *b"firns", *b"firry", *b"firth", *b"fiscs", *b"fisks", *b"fists", *b"fisty", *b"fitch", *b"fitly", *b"fitna", *b"fitte", *b"fitts", *b"fiver", *b"fives", *b"fixed", *b"fixes", *b"fixit", *b"fjeld", *b"flabs", *b"flaff", *b"flags", *b"flaks", *b"flamm", *b"flams", *b"flamy", *b"flane", *b"flans", *b"flaps", *b"flary", *b"flats", *b"flava", *b"flawn", *b"flaws", *b"flawy", *b"flaxy", *b"flays", *b"fleam", *b"fleas", *b"fleek", *b"fleer", *b"flees", *b"flegs", *b"fleme", *b"fleur", *b"flews", *b"flexi", *b"flexo", *b"fleys", *b"flics", *b"flied", *b"flies", *b"flimp", *b"flims", *b"flips", *b"flirs", *b"flisk", *b"flite", *b"flits", *b"flitt", *b"flobs", *b"flocs", *b"floes", *b"flogs", *b"flong", *b"flops", *b"flors", *b"flory", *b"flosh", *b"flota", *b"flote", *b"flows", *b"flubs", *b"flued", *b"flues", *b"fluey", *b"fluky", *b"flump", *b"fluor", *b"flurr", *b"fluty", *b"fluyt", *b"flyby", *b"flype", *b"flyte", *b"foals", *b"foams", *b"foehn", *b"fogey", *b"fogie", *b"fogle", *b"fogou", *b"fohns", *b"foids", *b"foils", *b"foins", *b"folds", *b"foley", *b"folia", *b"folic", *b"folie", *b"folks", *b"folky", *b"fomes", *b"fonda", *b"fonds", *b"fondu", *b"fones", *b"fonly", *b"fonts", *b"foods", *b"foody", *b"fools", *b"foots", *b"footy", *b"foram", *b"forbs", *b"forby", *b"fordo", *b"fords", *b"forel", *b"fores", *b"forex", *b"forks", *b"forky", *b"forme", *b"forms", *b"forts", *b"forza", *b"forze", *b"fossa", *b"fosse", *b"fouat", *b"fouds", *b"fouer", *b"fouet", *b"foule", *b"fouls", *b"fount", *b"fours", *b"fouth", *b"fovea", *b"fowls", *b"fowth", *b"foxed", *b"foxes", *b"foxie", *b"foyle", *b"foyne", *b"frabs", *b"frack", *b"fract", *b"frags", *b"fraim", *b"franc", *b"frape", *b"fraps", *b"frass", *b"frate", *b"frati", *b"frats", *b"fraus", *b"frays", *b"frees", *b"freet", *b"freit", *b"fremd", *b"frena", *b"freon", *b"frere", *b"frets", *b"fribs", *b"frier", *b"fries", *b"frigs", *b"frise", *b"frist", *b"frith", *b"frits", *b"fritt", *b"frize", *b"frizz", *b"froes", *b"frogs", *b"frons", *b"frore", *b"frorn", *b"frory", *b"frosh", *b"frows", *b"frowy", *b"frugs", *b"frump", *b"frush", *b"frust", *b"fryer", *b"fubar", *b"fubby", *b"fubsy", *b"fucks", *b"fucus", *b"fuddy", *b"fudgy", *b"fuels", *b"fuero", *b"fuffs", *b"fuffy", *b"fugal", *b"fuggy", *b"fugie", *b"fugio", *b"fugle", *b"fugly", *b"fugus", *b"fujis", *b"fulls", *b"fumed", *b"fumer", *b"fumes", *b"fumet", *b"fundi", *b"funds", *b"fundy", *b"fungo", *b"fungs", *b"funks", *b"fural", *b"furan", *b"furca", *b"furls", *b"furol", *b"furrs", *b"furth", *b"furze", *b"furzy", *b"fused", *b"fusee", *b"fusel", *b"fuses", *b"fusil", *b"fusks", *b"fusts", *b"fusty", *b"futon", *b"fuzed", *b"fuzee", *b"fuzes", *b"fuzil", *b"fyces", *b"fyked", *b"fykes", *b"fyles", *b"fyrds", *b"fytte", *b"gabba", *b"gabby", *b"gable", *b"gaddi", *b"gades", *b"gadge", *b"gadid", *b"gadis", *b"gadje", *b"gadjo", *b"gadso", *b"gaffs", *b"gaged", *b"gager", *b"gages", *b"gaids", *b"gains", *b"gairs", *b"gaita", *b"gaits", *b"gaitt", *b"gajos", *b"galah", *b"galas", *b"galax", *b"galea", *b"galed", *b"gales", *b"galls", *b"gally", *b"galop", *b"galut", *b"galvo", *b"gamas", *b"gamay", *b"gamba", *b"gambe", *b"gambo", *b"gambs", *b"gamed", *b"games", *b"gamey", *b"gamic", *b"gamin", *b"gamme", *b"gammy", *b"gamps", *b"ganch", *b"gandy", *b"ganef", *b"ganev", *b"gangs", *b"ganja", *b"ganof", *b"gants", *b"gaols", *b"gaped", *b"gaper", *b"gapes", *b"gapos", *b"gappy", *b"garbe", *b"garbo", *b"garbs", *b"garda", *b"gares", *b"garis", *b"garms", *b"garni", *b"garre", *b"garth", *b"garum", *b"gases", *b"gasps", *b"gaspy", *b"gasts", *b"gatch", *b"gated", *b"gater", *b"gates", *b"gaths", *b"gator", *b"gauch", *b"gaucy", *b"gauds", *b"gauje", *b"gault", *b"gaums", *b"gaumy", *b"gaups", *b"gaurs", *b"gauss", *b"gauzy", *b"gavot", *b"gawcy", *b"gawds", *b"gawks", *b"gawps", *b"gawsy", *b"gayal", *b"gazal", *b"gazar", *b"gazed", *b"gazes", *b"gazon", *b"gazoo", *b"geals", *b"geans", *b"geare", *b"gears", *b"geats", *b"gebur", *b"gecks", *b"geeks", *b"geeps", *b"geest", *b"geist", *b"geits", *b"gelds", *b"gelee", *b"gelid", *b"gelly", *b"gelts", *b"gemel", *b"gemma", *b"gemmy", *b"gemot", *b"genal", *b"genas", *b"genes", *b"genet", *b"genic", *b"genii", *b"genip", *b"genny", *b"genoa", *b"genom", *b"genro", *b"gents", *b"genty", *b"genua", *b"genus", *b"geode", *b"geoid", *b"gerah", *b"gerbe", *b"geres", *b"gerle", *b"germs", *b"germy", *b"gerne", *b"gesse", *b"gesso", *b"geste", *b"gests", *b"getas", *b"getup", *b"geums", *b"geyan", *b"geyer", *b"ghast", *b"ghats", *b"ghaut", *b"ghazi", *b"ghees", *b"ghest", *b"ghyll", *b"gibed", *b"gibel", *b"giber", *b"gibes", *b"gibli", *b"gibus", *b"gifts", *b"gigas", *b"gighe", *b"gigot", *b"gigue", *b"gilas", *b"gilds", *b"gilet", *b"gills", *b"gilly", *b"gilpy", *b"gilts", *b"gimel", *b"gimme", *b"gimps", *b"gimpy", *b"ginch", *b"ginge", *b"gings", *b"ginks", *b"ginny", *b"ginzo", *b"gipon", *b"gippo", *b"gippy", *b"girds", *b"girls", *b"girns", *b"giron", *b"giros", *b"girrs", *b"girsh", *b"girts", *b"gismo", *b"gisms", *b"gists", *b"gitch", *b"gites", *b"giust", *b"gived", *b"gives", *b"gizmo", *b"glace", *b"glads", *b"glady", *b"glaik", *b"glair", *b"glams", *b"glans", *b"glary", *b"glaum", *b"glaur", *b"glazy", *b"gleba", *b"glebe", *b"gleby", *b"glede", *b"gleds", *b"gleed", *b"gleek", *b"glees", *b"gleet", *b"gleis", *b"glens", *b"glent", *b"gleys", *b"glial", *b"glias", *b"glibs", *b"gliff", *b"glift", *b"glike", *b"glime", *b"glims", *b"glisk", *b"glits", *b"glitz", *b"gloam", *b"globi", *b"globs", *b"globy", *b"glode", *b"glogg", *b"gloms", *b"gloop", *b"glops", *b"glost", *b"glout", *b"glows", *b"gloze", *b"glued", *b"gluer", *b"glues", *b"gluey", *b"glugs", *b"glume", *b"glums", *b"gluon", *b"glute", *b"gluts", *b"gnarl", *b"gnarr", *b"gnars", *b"gnats", *b"gnawn", *b"gnaws", *b"gnows", *b"goads", *b"goafs", *b"goals", *b"goary", *b"goats", *b"goaty", *b"goban", *b"gobar", *b"gobbi", *b"gobbo", *b"gobby", *b"gobis", *b"gobos", *b"godet", *b"godso", *b"goels", *b"goers", *b"goest", *b"goeth", *b"goety", *b"gofer", *b"goffs", *b"gogga", *b"gogos", *b"goier", *b"gojis", *b"golds", *b"goldy", *b"goles", *b"golfs", *b"golpe", *b"golps", *b"gombo", *b"gomer", *b"gompa", *b"gonch", *b"gonef", *b"gongs", *b"gonia", *b"gonif", *b"gonks", *b"gonna", *b"gonof", *b"gonys", *b"gonzo", *b"gooby", *b"goods", *b"goofs", *b"googs", *b"gooky", *b"goold", *b"gools", *b"gooly", *b"goons", *b"goony", *b"goops", *b"goopy", *b"goors", *b"goory", *b"goosy", *b"gopak", *b"gopik", *b"goral", *b"goras", *b"gored", *b"gores", *b"goris", *b"gorms", *b"gormy", *b"gorps", *b"gorse", *b"gorsy", *b"gosht", *b"gosse", *b"gotch", *b"goths", *b"gothy", *b"gotta", *b"gouch", *b"gouks", *b"goura", *b"gouts", *b"gouty", *b"gowan", *b"gowds", *b"gowfs", *b"gowks", *b"gowls", *b"gowns", *b"goxes", *b"goyim", *b"goyle", *b"graal", *b"grabs", *b"grads", *b"graff", *b"graip", *b"grama", *b"grame", *b"gramp", *b"grams", *b"grana", *b"grans", *b"grapy", *b"gravs", *b"grays", *b"grebe", *b"grebo", *b"grece", *b"greek", *b"grees", *b"grege", *b"grego", *b"grein", *b"grens", *b"grese", *b"greve", *b"grews", *b"greys", *b"grice", *b"gride", *b"grids", *b"griff", *b"grift", *b"grigs", *b"grike", *b"grins", *b"griot", *b"grips", *b"gript", *b"gripy", *b"grise", *b"grist", *b"grisy", *b"grith", *b"grits", *b"grize", *b"groat", *b"grody", *b"grogs", *b"groks", *b"groma", *b"grone", *b"groof", *b"grosz", *b"grots", *b"grouf", *b"grovy", *b"grows", *b"grrls", *b"grrrl", *b"grubs", *b"grued", *b"grues", *b"grufe", *b"grume", *b"grump", *b"grund", *b"gryce", *b"gryde", *b"gryke", *b"grype", *b"grypt", *b"guaco", *b"guana", *b"guano", *b"guans", *b"guars", *b"gucks", *b"gucky", *b"gudes", *b"guffs", *b"gugas", *b"guids", *b"guimp", *b"guiro", *b"gulag", *b"gular", *b"gulas", *b"gules", *b"gulet", *b"gulfs", *b"gulfy", *b"gulls", *b"gulph", *b"gulps", *b"gulpy", *b"gumma", *b"gummi", *b"gumps", *b"gundy", *b"gunge", *b"gungy", *b"gunks", *b"gunky", *b"gunny", *b"guqin", *b"gurdy", *b"gurge", *b"gurls", *b"gurly", *b"gurns", *b"gurry", *b"gursh", *b"gurus", *b"gushy", *b"gusla", *b"gusle", *b"gusli", *b"gussy", *b"gusts", *b"gutsy", *b"gutta", *b"gutty", *b"guyed", *b"guyle", *b"guyot", *b"guyse", *b"gwine", *b"gyals", *b"gyans", *b"gybed", *b"gybes", *b"gyeld", *b"gymps", *b"gynae", *b"gynie", *b"gynny", *b"gynos", *b"gyoza", *b"gypos", *b"gyppo", *b"gyppy", *b"gyral", *b"gyred", *b"gyres", *b"gyron", *b"gyros", *b"gyrus", *b"gytes", *b"gyved", *b"gyves", *b"haafs", *b"haars", *b"hable", *b"habus", *b"hacek", *b"hacks", *b"hadal", *b"haded", *b"hades", *b"hadji", *b"hadst", *b"haems", *b"haets", *b"haffs", *b"hafiz", *b"hafts", *b"haggs", *b"hahas", *b"haick", *b"haika", *b"haiks", *b"haiku", *b"hails", *b"haily", *b"hains", *b"haint", *b"hairs", *b"haith", *b"hajes", *b"hajis", *b"hajji", *b"hakam", *b"hakas", *b"hakea", *b"hakes", *b"hakim", *b"hakus", *b"halal", *b"haled", *b"haler", *b"hales", *b"halfa", *b"halfs", *b"halid", *b"hallo", *b"halls", *b"halma", *b"halms", *b"halon", *b"halos", *b"halse", *b"halts", *b"halva", *b"halwa", *b"hamal", *b"hamba", *b"hamed", *b"hames", *b"hammy", *b"hamza", *b"hanap", *b"hance", *b"hanch", *b"hands", *b"hangi", *b"hangs", *b"hanks", *b"hanky", *b"hansa", *b"hanse", *b"hants", *b"haole", *b"haoma", *b"hapax", *b"haply", *b"happi", *b"hapus", *b"haram", *b"hards", *b"hared", *b"hares", *b"harim", *b"harks", *b"harls", *b"harms", *b"harns", *b"haros", *b"harps", *b"harts", *b"hashy", *b"hasks", *b"hasps", *b"hasta", *b"hated", *b"hates", *b"hatha", *b"hauds", *b"haufs", *b"haugh", *b"hauld", *b"haulm", *b"hauls", *b"hault", *b"hauns", *b"hause", *b"haver", *b"haves", *b"hawed", *b"hawks", *b"hawms", *b"hawse", *b"hayed", *b"hayer", *b"hayey", *b"hayle", *b"hazan", *b"hazed", *b"hazer", *b"hazes", *b"heads", *b"heald", *b"heals", *b"heame", *b"heaps", *b"heapy", *b"heare", *b"hears", *b"heast", *b"heats", *b"heben", *b"hebes", *b"hecht", *b"hecks", *b"heder", *b"hedgy", *b"heeds", *b"heedy", *b"heels", *b"heeze", *b"hefte", *b"hefts", *b"heids", *b"heigh", *b"heils", *b"heirs", *b"hejab", *b"hejra", *b"heled", *b"heles", *b"helio", *b"hells", *b"helms", *b"helos", *b"helot", *b"helps", *b"helve", *b"hemal", *b"hemes", *b"hemic", *b"hemin", *b"hemps", *b"hempy", *b"hench", *b"hends", *b"henge", *b"henna", *b"henny", *b"henry", *b"hents", *b"hepar", *b"herbs", *b"herby", *b"herds", *b"heres", *b"herls", *b"herma", *b"herms", *b"herns", *b"heros", *b"herry", *b"herse", *b"hertz", *b"herye", *b"hesps", *b"hests", *b"hetes", *b"heths", *b"heuch", *b"heugh", *b"hevea", *b"hewed", *b"hewer", *b"hewgh", *b"hexad", *b"hexed", *b"hexer", *b"hexes", *b"hexyl", *b"heyed", *b"hiant", *b"hicks", *b"hided", *b"hider", *b"hides", *b"hiems", *b"highs", *b"hight", *b"hijab", *b"hijra", *b"hiked", *b"hiker", *b"hikes", *b"hikoi", *b"hilar", *b"hilch", *b"hillo", *b"hills", *b"hilts", *b"hilum", *b"hilus", *b"himbo", *b"hinau", *b"hinds", *b"hings", *b"hinky", *b"hinny", *b"hints", *b"hiois", *b"hiply", *b"hired", *b"hiree", *b"hirer", *b"hires", *b"hissy", *b"hists", *b"hithe", *b"hived", *b"hiver", *b"hives", *b"hizen", *b"hoaed", *b"hoagy", *b"hoars", *b"hoary", *b"hoast", *b"hobos", *b"hocks", *b"hocus", *b"hodad", *b"hodja", *b"hoers", *b"hogan", *b"hogen", *b"hoggs", *b"hoghs", *b"hohed", *b"hoick", *b"hoied", *b"hoiks", *b"hoing", *b"hoise", *b"hokas", *b"hoked", *b"hokes", *b"hokey", *b"hokis", *b"hokku", *b"hokum", *b"holds", *b"holed", *b"holes", *b"holey", *b"holks", *b"holla", *b"hollo", *b"holme", *b"holms", *b"holon", *b"holos", *b"holts", *b"homas", *b"homed", *b"homes", *b"homey", *b"homie", *b"homme", *b"honan", *b"honda", *b"honds", *b"honed", *b"honer", *b"hones", *b"hongi", *b"hongs", *b"honks", *b"honky", *b"hooch", *b"hoods", *b"hoody", *b"hooey", *b"hoofs", *b"hooka", *b"hooks", *b"hooky", *b"hooly", *b"hoons", *b"hoops", *b"hoord", *b"hoors", *b"hoosh", *b"hoots", *b"hooty", *b"hoove", *b"hopak", *b"hoped", *b"hoper", *b"hopes", *b"hoppy", *b"horah", *b"horal", *b"horas", *b"horis", *b"horks", *b"horme", *b"horns", *b"horst", *b"horsy", *b"hosed", *b"hosel", *b"hosen", *b"hoser", *b"hoses", *b"hosey", *b"hosta", *b"hosts", *b"hotch", *b"hoten", *b"hotty", *b"houff", *b"houfs", *b"hough", *b"houri", *b"hours", *b"houts", *b"hovea", *b"hoved", *b"hoven", *b"hoves", *b"howbe", *b"howes", *b"howff", *b"howfs", *b"howks", *b"howls", *b"howre", *b"howso", *b"hoxed", *b"hoxes", *b"hoyas", *b"hoyed", *b"hoyle", *b"hubby", *b"hucks", *b"hudna", *b"hudud", *b"huers", *b"huffs", *b"huffy", *b"huger", *b"huggy", *b"huhus", *b"huias", *b"hulas", *b"hules", *b"hulks", *b"hulky", *b"hullo", *b"hulls", *b"hully", *b"humas", *b"humfs", *b"humic", *b"humps", *b"humpy", *b"hunks", *b"hunts", *b"hurds", *b"hurls", *b"hurly", *b"hurra", *b"hurst", *b"hurts", *b"hushy", *b"husks", *b"husos", *b"hutia", *b"huzza", *b"huzzy", *b"hwyls", *b"hydra", *b"hyens", *b"hygge", *b"hying", *b"hykes", *b"hylas", *b"hyleg", *b"hyles", *b"hylic", *b"hymns", *b"hynde", *b"hyoid", *b"hyped", *b"hypes", *b"hypha", *b"hyphy", *b"hypos", *b"hyrax", *b"hyson", *b"hythe", *b"iambi", *b"iambs", *b"ibrik", *b"icers", *b"iched", *b"iches", *b"ichor", *b"icier", *b"icker", *b"ickle", *b"icons", *b"ictal", *b"ictic", *b"ictus", *b"idant", *b"ideas", *b"idees", *b"ident", *b"idled", *b"idles", *b"idola", *b"idols", *b"idyls", *b"iftar", *b"igapo", *b"igged", *b"iglus", *b"ihram", *b"ikans", *b"ikats", *b"ikons", *b"ileac", *b"ileal", *b"ileum", *b"ileus", *b"iliad", *b"ilial", *b"ilium", *b"iller", *b"illth", *b"imago", *b"imams", *b"imari", *b"imaum", *b"imbar", *b"imbed", *b"imide", *b"imido", *b"imids", *b"imine", *b"imino", *b"immew", *b"immit", *b"immix", *b"imped", *b"impis", *b"impot", *b"impro", *b"imshi", *b"imshy", *b"inapt", *b"inarm", *b"inbye", *b"incel", *b"incle", *b"incog", *b"incus", *b"incut", *b"indew", *b"india", *b"indie", *b"indol", *b"indow", *b"indri", *b"indue", *b"inerm", *b"infix", *b"infos", *b"infra", *b"ingan", *b"ingle", *b"inion", *b"inked", *b"inker", *b"inkle", *b"inned", *b"innit", *b"inorb", *b"inrun", *b"inset", *b"inspo", *b"intel", *b"intil", *b"intis", *b"intra", *b"inula", *b"inure", *b"inurn", *b"inust", *b"invar", *b"inwit", *b"iodic", *b"iodid", *b"iodin", *b"iotas", *b"ippon", *b"irade", *b"irids", *b"iring", *b"irked", *b"iroko", *b"irone", *b"irons", *b"isbas", *b"ishes", *b"isled", *b"isles", *b"isnae", *b"issei", *b"istle", *b"items", *b"ither", *b"ivied", *b"ivies", *b"ixias", *b"ixnay", *b"ixora", *b"ixtle", *b"izard", *b"izars", *b"izzat", *b"jaaps", *b"jabot", *b"jacal", *b"jacks", *b"jacky", *b"jaded", *b"jades", *b"jafas", *b"jaffa", *b"jagas", *b"jager", *b"jaggs", *b"jaggy", *b"jagir", *b"jagra", *b"jails", *b"jaker", *b"jakes", *b"jakey", *b"jalap", *b"jalop", *b"jambe", *b"jambo", *b"jambs", *b"jambu", *b"james", *b"jammy", *b"jamon", *b"janes", *b"janns", *b"janny", *b"janty", *b"japan", *b"japed", *b"japer", *b"japes", *b"jarks", *b"jarls", *b"jarps", *b"jarta", *b"jarul", *b"jasey", *b"jaspe", *b"jasps", *b"jatos", *b"jauks", *b"jaups", *b"javas", *b"javel", *b"jawan", *b"jawed", *b"jaxie", *b"jeans", *b"jeats", *b"jebel", *b"jedis", *b"jeels", *b"jeely", *b"jeeps", *b"jeers", *b"jeeze", *b"jefes", *b"jeffs", *b"jehad", *b"jehus", *b"jelab", *b"jello", *b"jells", *b"jembe", *b"jemmy", *b"jenny", *b"jeons", *b"jerid", *b"jerks", *b"jerry", *b"jesse", *b"jests", *b"jesus", *b"jetes", *b"jeton", *b"jeune", *b"jewed", *b"jewie", *b"jhala", *b"jiaos", *b"jibba", *b"jibbs", *b"jibed", *b"jiber", *b"jibes", *b"jiffs", *b"jiggy", *b"jigot", *b"jihad", *b"jills", *b"jilts", *b"jimmy", *b"jimpy", *b"jingo", *b"jinks", *b"jinne", *b"jinni", *b"jinns", *b"jirds", *b"jirga", *b"jirre", *b"jisms", *b"jived", *b"jiver", *b"jives", *b"jivey", *b"jnana", *b"jobed", *b"jobes", *b"jocko", *b"jocks", *b"jocky", *b"jocos", *b"jodel", *b"joeys", *b"johns", *b"joins", *b"joked", *b"jokes", *b"jokey", *b"jokol", *b"joled", *b"joles", *b"jolls", *b"jolts", *b"jolty", *b"jomon", *b"jomos", *b"jones", *b"jongs", *b"jonty", *b"jooks", *b"joram", *b"jorum", *b"jotas", *b"jotty", *b"jotun", *b"joual", *b"jougs", *b"jouks", *b"joule", *b"jours", *b"jowar", *b"jowed", *b"jowls", *b"jowly", *b"joyed", *b"jubas", *b"jubes", *b"jucos", *b"judas", *b"judgy", *b"judos", *b"jugal", *b"jugum", *b"jujus", *b"juked", *b"jukes", *b"jukus", *b"julep", *b"jumar", *b"jumby", *b"jumps", *b"junco", *b"junks", *b"junky", *b"jupes", *b"jupon", *b"jural", *b"jurat", *b"jurel", *b"jures", *b"justs", *b"jutes", *b"jutty", *b"juves", *b"juvie", *b"kaama", *b"kabab", *b"kabar", *b"kabob", *b"kacha", *b"kacks", *b"kadai", *b"kades", *b"kadis", *b"kafir", *b"kagos", *b"kagus", *b"kahal", *b"kaiak", *b"kaids", *b"kaies", *b"kaifs", *b"kaika", *b"kaiks", *b"kails", *b"kaims", *b"kaing", *b"kains", *b"kakas", *b"kakis", *b"kalam", *b"kales", *b"kalif", *b"kalis", *b"kalpa", *b"kamas", *b"kames", *b"kamik", *b"kamis", *b"kamme", *b"kanae", *b"kanas", *b"kandy", *b"kaneh", *b"kanes", *b"kanga", *b"kangs", *b"kanji", *b"kants", *b"kanzu", *b"kaons", *b"kapas", *b"kaphs", *b"kapok", *b"kapow", *b"kapus", *b"kaput", *b"karas", *b"karat", *b"karks", *b"karns", *b"karoo", *b"karos", *b"karri", *b"karst", *b"karsy", *b"karts", *b"karzy", *b"kasha", *b"kasme", *b"katal", *b"katas", *b"katis", *b"katti", *b"kaugh", *b"kauri", *b"kauru", *b"kaury", *b"kaval", *b"kavas", *b"kawas", *b"kawau", *b"kawed", *b"kayle", *b"kayos", *b"kazis", *b"kazoo", *b"kbars", *b"kebar", *b"kebob", *b"kecks", *b"kedge", *b"kedgy", *b"keech", *b"keefs", *b"keeks", *b"keels", *b"keema", *b"keeno", *b"keens", *b"keeps", *b"keets", *b"keeve", *b"kefir", *b"kehua", *b"keirs", *b"kelep", *b"kelim", *b"kells", *b"kelly", *b"kelps", *b"kelpy", *b"kelts", *b"kelty", *b"kembo", *b"kembs", *b"kemps", *b"kempt", *b"kempy", *b"kenaf", *b"kench", *b"kendo", *b"kenos", *b"kente", *b"kents", *b"kepis", *b"kerbs", *b"kerel", *b"kerfs", *b"kerky", *b"kerma", *b"kerne", *b"kerns", *b"keros", *b"kerry", *b"kerve", *b"kesar", *b"kests", *b"ketas", *b"ketch", *b"ketes", *b"ketol", *b"kevel", *b"kevil", *b"kexes", *b"keyed", *b"keyer", *b"khadi", *b"khafs", *b"khans", *b"khaph", *b"khats", *b"khaya", *b"khazi", *b"kheda", *b"kheth", *b"khets", *b"khoja", *b"khors", *b"khoum", *b"khuds", *b"kiaat", *b"kiack", *b"kiang", *b"kibbe", *b"kibbi", *b"kibei", *b"kibes", *b"kibla", *b"kicks", *b"kicky", *b"kiddo", *b"kiddy", *b"kidel", *b"kidge", *b"kiefs", *b"kiers", *b"kieve", *b"kievs", *b"kight", *b"kikoi", *b"kiley", *b"kilim", *b"kills", *b"kilns", *b"kilos", *b"kilps", *b"kilts", *b"kilty", *b"kimbo", *b"kinas", *b"kinda", *b"kinds", *b"kindy", *b"kines", *b"kings", *b"kinin", *b"kinks", *b"kinos", *b"kiore", *b"kipes", *b"kippa", *b"kipps", *b"kirby", *b"kirks", *b"kirns", *b"kirri", *b"kisan", *b"kissy", *b"kists", *b"kited", *b"kiter", *b"kites", *b"kithe", *b"kiths", *b"kitul", *b"kivas", *b"kiwis", *b"klang", *b"klaps", *b"klett", *b"klick", *b"klieg", *b"kliks", *b"klong", *b"kloof", *b"kluge", *b"klutz", *b"knags", *b"knaps", *b"knarl", *b"knars", *b"knaur", *b"knawe", *b"knees", *b"knell", *b"knish", *b"knits", *b"knive", *b"knobs", *b"knops", *b"knosp", *b"knots", *b"knout", *b"knowe", *b"knows", *b"knubs", *b"knurl", *b"knurr", *b"knurs", *b"knuts", *b"koans", *b"koaps", *b"koban", *b"kobos", *b"koels", *b"koffs", *b"kofta", *b"kogal", *b"kohas", *b"kohen", *b"kohls", *b"koine", *b"kojis", *b"kokam", *b"kokas", *b"koker", *b"kokra", *b"kokum", *b"kolas", *b"kolos", *b"kombu", *b"konbu", *b"kondo", *b"konks", *b"kooks", *b"kooky", *b"koori", *b"kopek", *b"kophs", *b"kopje", *b"koppa", *b"korai", *b"koras", *b"korat", *b"kores", *b"korma", *b"koros", *b"korun", *b"korus", *b"koses", *b"kotch", *b"kotos", *b"kotow", *b"koura", *b"kraal", *b"krabs", *b"kraft", *b"krais", *b"krait", *b"krang", *b"krans", *b"kranz", *b"kraut", *b"krays", *b"kreep", *b"kreng", *b"krewe", *b"krona", *b"krone", *b"kroon", *b"krubi", *b"krunk", *b"ksars", *b"kubie", *b"kudos", *b"kudus", *b"kudzu", *b"kufis", *b"kugel", *b"kuias", *b"kukri", *b"kukus", *b"kulak", *b"kulan", *b"kulas", *b"kulfi", *b"kumis", *b"kumys", *b"kuris", *b"kurre", *b"kurta", *b"kurus", *b"kusso", *b"kutas", *b"kutch", *b"kutis", *b"kutus", *b"kuzus", *b"kvass", *b"kvell", *b"kwela", *b"kyack", *b"kyaks", *b"kyang", *b"kyars", *b"kyats", *b"kybos", *b"kydst", *b"kyles", *b"kylie", *b"kylin", *b"kylix", *b"kyloe", *b"kynde", *b"kynds", *b"kypes", *b"kyrie", *b"kytes", *b"kythe", *b"laari", *b"labda", *b"labia", *b"labis", *b"labra", *b"laced", *b"lacer", *b"laces", *b"lacet", *b"lacey", *b"lacks", *b"laddy", *b"laded", *b"lader", *b"lades", *b"laers", *b"laevo", *b"lagan", *b"lahal", *b"lahar", *b"laich", *b"laics", *b"laids", *b"laigh", *b"laika", *b"laiks", *b"laird", *b"lairs", *b"lairy", *b"laith", *b"laity", *b"laked", *b"laker", *b"lakes", *b"lakhs", *b"lakin", *b"laksa", *b"laldy", *b"lalls", *b"lamas", *b"lambs", *b"lamby", *b"lamed", *b"lamer", *b"lames", *b"lamia", *b"lammy", *b"lamps", *b"lanai", *b"lanas", *b"lanch", *b"lande", *b"lands", *b"lanes", *b"lanks", *b"lants", *b"lapin", *b"lapis", *b"lapje", *b"larch", *b"lards", *b"lardy", *b"laree", *b"lares", *b"largo", *b"laris", *b"larks", *b"larky", *b"larns", *b"larnt", *b"larum", *b"lased", *b"laser", *b"lases", *b"lassi", *b"lassu", *b"lassy", *b"lasts", *b"latah", *b"lated", *b"laten", *b"latex", *b"lathi", *b"laths", *b"lathy", *b"latke", *b"latus", *b"lauan", *b"lauch", *b"lauds", *b"laufs", *b"laund", *b"laura", *b"laval", *b"lavas", *b"laved", *b"laver", *b"laves", *b"lavra", *b"lavvy", *b"lawed", *b"lawer", *b"lawin", *b"lawks", *b"lawns", *b"lawny", *b"laxed", *b"laxer", *b"laxes", *b"laxly", *b"layed", *b"layin", *b"layup", *b"lazar", *b"lazed", *b"lazes", *b"lazos", *b"lazzi", *b"lazzo", *b"leads", *b"leady", *b"leafs", *b"leaks", *b"leams", *b"leans", *b"leany", *b"leaps", *b"leare", *b"lears", *b"leary", *b"leats", *b"leavy", *b"leaze", *b"leben", *b"leccy", *b"ledes", *b"ledgy", *b"ledum", *b"leear", *b"leeks", *b"leeps", *b"leers", *b"leese", *b"leets", *b"leeze", *b"lefte", *b"lefts", *b"leger", *b"leges", *b"legge", *b"leggo", *b"legit", *b"lehrs", *b"lehua", *b"leirs", *b"leish", *b"leman", *b"lemed", *b"lemel", *b"lemes", *b"lemma", *b"lemme", *b"lends", *b"lenes", *b"lengs", *b"lenis", *b"lenos", *b"lense", *b"lenti", *b"lento", *b"leone", *b"lepid", *b"lepra", *b"lepta", *b"lered", *b"leres", *b"lerps", *b"leses", *b"lests", *b"letch", *b"lethe", *b"letup", *b"leuch", *b"leuco", *b"leuds", *b"leugh", *b"levas", *b"levee", *b"leves", *b"levin", *b"levis", *b"lewis", *b"lexes", *b"lexis", *b"lezes", *b"lezza", *b"lezzy", *b"liana", *b"liane", *b"liang", *b"liard", *b"liars", *b"liart", *b"liber", *b"libra", *b"libri", *b"lichi", *b"licht", *b"licit", *b"licks", *b"lidar", *b"lidos", *b"liefs", *b"liens", *b"liers", *b"lieus", *b"lieve", *b"lifer", *b"lifes", *b"lifts", *b"ligan", *b"liger", *b"ligge", *b"ligne", *b"liked", *b"liker", *b"likes", *b"likin", *b"lills", *b"lilos", *b"lilts", *b"liman", *b"limas", *b"limax", *b"limba", *b"limbi", *b"limbs", *b"limby", *b"limed", *b"limen", *b"limes", *b"limey", *b"limma", *b"limns", *b"limos", *b"limpa", *b"limps", *b"linac", *b"linch", *b"linds", *b"lindy", *b"lined", *b"lines", *b"liney", *b"linga", *b"lings", *b"lingy", *b"linin", *b"links", *b"linky", *b"linns", *b"linny", *b"linos", *b"lints", *b"linty", *b"linum", *b"linux", *b"lions", *b"lipas", *b"lipes", *b"lipin", *b"lipos", *b"lippy", *b"liras", *b"lirks", *b"lirot", *b"lisks", *b"lisle", *b"lisps", *b"lists", *b"litai", *b"litas", *b"lited", *b"liter", *b"lites", *b"litho", *b"liths", *b"litre", *b"lived", *b"liven", *b"lives", *b"livor", *b"livre", *b"llano", *b"loach", *b"loads", *b"loafs", *b"loams", *b"loans", *b"loast", *b"loave", *b"lobar", *b"lobed", *b"lobes", *b"lobos", *b"lobus", *b"loche", *b"lochs", *b"locie", *b"locis", *b"locks", *b"locos", *b"locum", *b"loden", *b"lodes", *b"loess", *b"lofts", *b"logan", *b"loges", *b"loggy", *b"logia", *b"logie", *b"logoi", *b"logon", *b"logos", *b"lohan", *b"loids", *b"loins", *b"loipe", *b"loirs", *b"lokes", *b"lolls", *b"lolly", *b"lolog", *b"lomas", *b"lomed", *b"lomes", *b"loner", *b"longa", *b"longe", *b"longs", *b"looby", *b"looed", *b"looey", *b"loofa", *b"loofs", *b"looie", *b"looks", *b"looky", *b"looms", *b"loons", *b"loony", *b"loops", *b"loord", *b"loots", *b"loped", *b"loper", *b"lopes", *b"loppy", *b"loral", *b"loran", *b"lords", *b"lordy", *b"lorel", *b"lores", *b"loric", *b"loris", *b"losed", *b"losel", *b"losen", *b"loses", *b"lossy", *b"lotah", *b"lotas", *b"lotes", *b"lotic", *b"lotos", *b"lotsa", *b"lotta", *b"lotte", *b"lotto", *b"lotus", *b"loued", *b"lough", *b"louie", *b"louis", *b"louma", *b"lound", *b"louns", *b"loupe", *b"loups", *b"loure", *b"lours", *b"loury", *b"louts", *b"lovat", *b"loved", *b"loves", *b"lovey", *b"lovie", *b"lowan", *b"lowed", *b"lowes", *b"lownd", *b"lowne", *b"lowns", *b"lowps", *b"lowry", *b"lowse", *b"lowts", *b"loxed", *b"loxes", *b"lozen", *b"luach", *b"luaus", *b"lubed", *b"lubes", *b"lubra", *b"luces", *b"lucks", *b"lucre", *b"ludes", *b"ludic", *b"ludos", *b"luffa", *b"luffs", *b"luged", *b"luger", *b"luges", *b"lulls", *b"lulus", *b"lumas", *b"lumbi", *b"lumme", *b"lummy", *b"lumps", *b"lunas", *b"lunes", *b"lunet", *b"lungi", *b"lungs", *b"lunks", *b"lunts", *b"lupin", *b"lured", *b"lurer", *b"lures", *b"lurex", *b"lurgi", *b"lurgy", *b"lurks", *b"lurry", *b"lurve", *b"luser", *b"lushy", *b"lusks", *b"lusts", *b"lusus", *b"lutea", *b"luted", *b"luter", *b"lutes", *b"luvvy", *b"luxed", *b"luxer", *b"luxes", *b"lweis", *b"lyams", *b"lyard", *b"lyart", *b"lyase", *b"lycea", *b"lycee", *b"lycra", *b"lymes", *b"lynes", *b"lyres", *b"lysed", *b"lyses", *b"lysin", *b"lysis", *b"lysol", *b"lyssa", *b"lyted", *b"lytes", *b"lythe", *b"lytic", *b"lytta", *b"maaed", *b"maare", *b"maars", *b"mabes", *b"macas", *b"maced", *b"macer", *b"maces", *b"mache", *b"machi", *b"machs", *b"macks", *b"macle", *b"macon", *b"madge", *b"madid", *b"madre", *b"maerl", *b"mafic", *b"mages", *b"maggs", *b"magot", *b"magus", *b"mahoe", *b"mahua", *b"mahwa", *b"maids", *b"maiko", *b"maiks", *b"maile", *b"maill", *b"mails", *b"maims", *b"mains", *b"maire", *b"mairs", *b"maise", *b"maist", *b"makar", *b"makes", *b"makis", *b"makos", *b"malam", *b"malar", *b"malas", *b"malax", *b"males", *b"malic", *b"malik", *b"malis", *b"malls", *b"malms", *b"malmy", *b"malts", *b"malty", *b"malus", *b"malva", *b"malwa", *b"mamas", *b"mamba", *b"mamee", *b"mamey", *b"mamie", *b"manas", *b"manat", *b"mandi", *b"maneb", *b"maned", *b"maneh", *b"manes", *b"manet", *b"mangs", *b"manis", *b"manky", *b"manna", *b"manos", *b"manse", *b"manta", *b"manto", *b"manty", *b"manul", *b"manus", *b"mapau", *b"maqui", *b"marae", *b"marah", *b"maras", *b"marcs", *b"mardy", *b"mares", *b"marge", *b"margs", *b"maria", *b"marid", *b"marka", *b"marks", *b"marle", *b"marls", *b"marly", *b"marms", *b"maron", *b"maror", *b"marra", *b"marri", *b"marse", *b"marts", *b"marvy", *b"masas", *b"mased", *b"maser", *b"mases", *b"mashy", *b"masks", *b"massa", *b"massy", *b"masts", *b"masty", *b"masus", *b"matai", *b"mated", *b"mater", *b"mates", *b"maths", *b"matin", *b"matlo", *b"matte", *b"matts", *b"matza", *b"matzo", *b"mauby", *b"mauds", *b"mauls", *b"maund", *b"mauri", *b"mausy", *b"mauts", *b"mauzy", *b"maven", *b"mavie", *b"mavin", *b"mavis", *b"mawed", *b"mawks", *b"mawky", *b"mawns", *b"mawrs", *b"maxed", *b"maxes", *b"maxis", *b"mayan", *b"mayas", *b"mayed", *b"mayos", *b"mayst", *b"mazed", *b"mazer", *b"mazes", *b"mazey", *b"mazut", *b"mbira", *b"meads", *b"meals", *b"meane", *b"means", *b"meany", *b"meare", *b"mease", *b"meath", *b"meats", *b"mebos", *b"mechs", *b"mecks", *b"medii", *b"medle", *b"meeds", *b"meers", *b"meets", *b"meffs", *b"meins", *b"meint", *b"meiny", *b"meith", *b"mekka", *b"melas", *b"melba", *b"melds", *b"melic", *b"melik", *b"mells", *b"melts", *b"melty", *b"memes", *b"memos", *b"menad", *b"mends", *b"mened", *b"menes", *b"menge", *b"mengs", *b"mensa", *b"mense", *b"mensh", *b"menta", *b"mento", *b"menus", *b"meous", *b"meows", *b"merch", *b"mercs", *b"merde", *b"mered", *b"merel", *b"merer", *b"meres", *b"meril", *b"meris", *b"merks", *b"merle", *b"merls", *b"merse", *b"mesal", *b"mesas", *b"mesel", *b"meses", *b"meshy", *b"mesic", *b"mesne", *b"meson", *b"messy", *b"mesto", *b"meted", *b"metes", *b"metho", *b"meths", *b"metic", *b"metif", *b"metis", *b"metol", *b"metre", *b"meuse", *b"meved", *b"meves", *b"mewed", *b"mewls", *b"meynt", *b"mezes", *b"mezze", *b"mezzo", *b"mhorr", *b"miaou", *b"miaow", *b"miasm", *b"miaul", *b"micas", *b"miche", *b"micht", *b"micks", *b"micky", *b"micos", *b"micra", *b"middy", *b"midgy", *b"midis", *b"miens", *b"mieve", *b"miffs", *b"miffy", *b"mifty", *b"miggs", *b"mihas", *b"mihis", *b"miked", *b"mikes", *b"mikra", *b"mikva", *b"milch", *b"milds", *b"miler", *b"miles", *b"milfs", *b"milia", *b"milko", *b"milks", *b"mille", *b"mills", *b"milor", *b"milos", *b"milpa", *b"milts", *b"milty", *b"miltz", *b"mimed", *b"mimeo", *b"mimer", *b"mimes", *b"mimsy", *b"minae", *b"minar", *b"minas", *b"mincy", *b"minds", *b"mined", *b"mines", *b"minge", *b"mings", *b"mingy", *b"minis", *b"minke", *b"minks", *b"minny", *b"minos", *b"mints", *b"mired", *b"mires", *b"mirex", *b"mirid", *b"mirin", *b"mirks", *b"mirky", *b"mirly", *b"miros", *b"mirvs", *b"mirza", *b"misch", *b"misdo", *b"mises", *b"misgo", *b"misos", *b"missa", *b"mists", *b"misty", *b"mitch", *b"miter", *b"mites", *b"mitis", *b"mitre", *b"mitts", *b"mixed", *b"mixen", *b"mixer", *b"mixes", *b"mixte", *b"mixup", *b"mizen", *b"mizzy", *b"mneme", *b"moans", *b"moats", *b"mobby", *b"mobes", *b"mobey", *b"mobie", *b"moble", *b"mochi", *b"mochs", *b"mochy", *b"mocks", *b"moder", *b"modes", *b"modge", *b"modii", *b"modus", *b"moers", *b"mofos", *b"moggy", *b"mohel", *b"mohos", *b"mohrs", *b"mohua", *b"mohur", *b"moile", *b"moils", *b"moira", *b"moire", *b"moits", *b"mojos", *b"mokes", *b"mokis", *b"mokos", *b"molal", *b"molas", *b"molds", *b"moled", *b"moles", *b"molla", *b"molls", *b"molly", *b"molto", *b"molts", *b"molys", *b"momes", *b"momma", *b"mommy", *b"momus", *b"monad", *b"monal", *b"monas", *b"monde", *b"mondo", *b"moner", *b"mongo", *b"mongs", *b"monic", *b"monie", *b"monks", *b"monos", *b"monte", *b"monty", *b"moobs", *b"mooch", *b"moods", *b"mooed", *b"mooks", *b"moola", *b"mooli", *b"mools", *b"mooly", *b"moong", *b"moons", *b"moony", *b"moops", *b"moors", *b"moory", *b"moots", *b"moove", *b"moped", *b"moper", *b"mopes", *b"mopey", *b"moppy", *b"mopsy", *b"mopus", *b"morae", *b"moras", *b"morat", *b"moray", *b"morel", *b"mores", *b"moria", *b"morne", *b"morns", *b"morra", *b"morro", *b"morse", *b"morts", *b"mosed", *b"moses", *b"mosey", *b"mosks", *b"mosso", *b"moste", *b"mosts", *b"moted", *b"moten", *b"motes", *b"motet", *b"motey", *b"moths", *b"mothy", *b"motis", *b"motte", *b"motts", *b"motty", *b"motus", *b"motza", *b"mouch", *b"moues", *b"mould", *b"mouls", *b"moups", *b"moust", *b"mousy", *b"moved", *b"moves", *b"mowas", *b"mowed", *b"mowra", *b"moxas", *b"moxie", *b"moyas", *b"moyle", *b"moyls", *b"mozed", *b"mozes", *b"mozos", *b"mpret", *b"mucho", *b"mucic", *b"mucid", *b"mucin", *b"mucks", *b"mucor", *b"mucro", *b"mudge", *b"mudir", *b"mudra", *b"muffs", *b"mufti", *b"mugga", *b"muggs", *b"muggy", *b"muhly", *b"muids", *b"muils", *b"muirs", *b"muist", *b"mujik", *b"mulct", *b"muled", *b"mules", *b"muley", *b"mulga", *b"mulie", *b"mulla", *b"mulls", *b"mulse", *b"mulsh", *b"mumms", *b"mumps", *b"mumsy", *b"mumus", *b"munga", *b"munge", *b"mungo", *b"mungs", *b"munis", *b"munts", *b"muntu", *b"muons", *b"muras", *b"mured", *b"mures", *b"murex", *b"murid", *b"murks", *b"murls", *b"murly", *b"murra", *b"murre", *b"murri", *b"murrs", *b"murry", *b"murti", *b"murva", *b"musar", *b"musca", *b"mused", *b"muser", *b"muses", *b"muset", *b"musha", *b"musit", *b"musks", *b"musos", *b"musse", *b"mussy", *b"musth", *b"musts", *b"mutch", *b"muted", *b"muter", *b"mutes", *b"mutha", *b"mutis", *b"muton", *b"mutts", *b"muxed", *b"muxes", *b"muzak", *b"muzzy", *b"mvule", *b"myall", *b"mylar", *b"mynah", *b"mynas", *b"myoid", *b"myoma", *b"myope", *b"myops", *b"myopy", *b"mysid", *b"mythi", *b"myths", *b"mythy", *b"myxos", *b"mzees", *b"naams", *b"naans", *b"nabes", *b"nabis", *b"nabks", *b"nabla", *b"nabob", *b"nache", *b"nacho", *b"nacre", *b"nadas", *b"naeve", *b"naevi", *b"naffs", *b"nagas", *b"naggy", *b"nagor", *b"nahal", *b"naiad", *b"naifs", *b"naiks", *b"nails", *b"naira", *b"nairu", *b"naked", *b"naker", *b"nakfa", *b"nalas", *b"naled", *b"nalla", *b"named", *b"namer", *b"names", *b"namma", *b"namus", *b"nanas", *b"nance", *b"nancy", *b"nandu", *b"nanna", *b"nanos", *b"nanua", *b"napas", *b"naped", *b"napes", *b"napoo", *b"nappa", *b"nappe", *b"nappy", *b"naras", *b"narco", *b"narcs", *b"nards", *b"nares", *b"naric", *b"naris", *b"narks", *b"narky", *b"narre", *b"nashi", *b"natch", *b"nates", *b"natis", *b"natty", *b"nauch", *b"naunt", *b"navar", *b"naves", *b"navew", *b"navvy", *b"nawab", *b"nazes", *b"nazir", *b"nazis", *b"nduja", *b"neafe", *b"neals", *b"neaps", *b"nears", *b"neath", *b"neats", *b"nebek", *b"nebel", *b"necks", *b"neddy", *b"needs", *b"neeld", *b"neele", *b"neemb", *b"neems", *b"neeps", *b"neese", *b"neeze", *b"negro", *b"negus", *b"neifs", *b"neist", *b"neive", *b"nelis", *b"nelly", *b"nemas", *b"nemns", *b"nempt", *b"nenes", *b"neons", *b"neper", *b"nepit", *b"neral", *b"nerds", *b"nerka", *b"nerks", *b"nerol", *b"nerts", *b"nertz", *b"nervy", *b"nests", *b"netes", *b"netop", *b"netts", *b"netty", *b"neuks", *b"neume", *b"neums", *b"nevel", *b"neves", *b"nevus", *b"newbs", *b"newed", *b"newel", *b"newie", *b"newsy", *b"newts", *b"nexts", *b"nexus", *b"ngaio", *b"ngana", *b"ngati", *b"ngoma", *b"ngwee", *b"nicad", *b"nicht", *b"nicks", *b"nicol", *b"nidal", *b"nided", *b"nides", *b"nidor", *b"nidus", *b"niefs", *b"nieve", *b"nifes", *b"niffs", *b"niffy", *b"nifty", *b"niger", *b"nighs", *b"nihil", *b"nikab", *b"nikah", *b"nikau", *b"nills", *b"nimbi", *b"nimbs", *b"nimps", *b"niner", *b"nines", *b"ninon", *b"nipas", *b"nippy", *b"niqab", *b"nirls", *b"nirly", *b"nisei", *b"nisse", *b"nisus", *b"niter", *b"nites", *b"nitid", *b"niton", *b"nitre", *b"nitro", *b"nitry", *b"nitty", *b"nival", *b"nixed", *b"nixer", *b"nixes", *b"nixie", *b"nizam", *b"nkosi", *b"noahs", *b"nobby", *b"nocks", *b"nodal", *b"noddy", *b"nodes", *b"nodus", *b"noels", *b"noggs", *b"nohow", *b"noils", *b"noily", *b"noint", *b"noirs", *b"noles", *b"nolls", *b"nolos", *b"nomas", *b"nomen", *b"nomes", *b"nomic", *b"nomoi", *b"nomos", *b"nonas", *b"nonce", *b"nones", *b"nonet", *b"nongs", *b"nonis", *b"nonny", *b"nonyl", *b"noobs", *b"nooit", *b"nooks", *b"nooky", *b"noons", *b"noops", *b"nopal", *b"noria", *b"noris", *b"norks", *b"norma", *b"norms", *b"nosed", *b"noser", *b"noses", *b"notal", *b"noted", *b"noter", *b"notes", *b"notum", *b"nould", *b"noule", *b"nouls", *b"nouns", *b"nouny", *b"noups", *b"novae", *b"novas", *b"novum", *b"noway", *b"nowed", *b"nowls", *b"nowts", *b"nowty", *b"noxal", *b"noxes", *b"noyau", *b"noyed", *b"noyes", *b"nubby", *b"nubia", *b"nucha", *b"nuddy", *b"nuder", *b"nudes", *b"nudie", *b"nudzh", *b"nuffs", *b"nugae", *b"nuked", *b"nukes", *b"nulla", *b"nulls", *b"numbs", *b"numen", *b"nummy", *b"nunny", *b"nurds", *b"nurdy", *b"nurls", *b"nurrs", *b"nutso", *b"nutsy", *b"nyaff", *b"nyala", *b"nying", *b"nyssa", *b"oaked", *b"oaker", *b"oakum", *b"oared", *b"oases", *b"oasis", *b"oasts", *b"oaten", *b"oater", *b"oaths", *b"oaves", *b"obang", *b"obeah", *b"obeli", *b"obeys", *b"obias", *b"obied", *b"obiit", *b"obits", *b"objet", *b"oboes", *b"obole", *b"oboli", *b"obols", *b"occam", *b"ocher", *b"oches", *b"ochre", *b"ochry", *b"ocker", *b"ocrea", *b"octad", *b"octan", *b"octas", *b"octyl", *b"oculi", *b"odahs", *b"odals", *b"odeon", *b"odeum", *b"odism", *b"odist", *b"odium", *b"odors", *b"odour", *b"odyle", *b"odyls", *b"ofays", *b"offed", *b"offie", *b"oflag", *b"ofter", *b"ogams", *b"ogeed", *b"ogees", *b"oggin", *b"ogham", *b"ogive", *b"ogled", *b"ogler", *b"ogles", *b"ogmic", *b"ogres", *b"ohias", *b"ohing", *b"ohmic", *b"ohone", *b"oidia", *b"oiled", *b"oiler", *b"oinks", *b"oints", *b"ojime", *b"okapi", *b"okays", *b"okehs", *b"okras", *b"oktas", *b"oldie", *b"oleic", *b"olein", *b"olent", *b"oleos", *b"oleum", *b"olios", *b"ollas", *b"ollav", *b"oller", *b"ollie", *b"ology", *b"olpae", *b"olpes", *b"omasa", *b"omber", *b"ombus", *b"omens", *b"omers", *b"omits", *b"omlah", *b"omovs", *b"omrah", *b"oncer", *b"onces", *b"oncet", *b"oncus", *b"onely", *b"oners", *b"onery", *b"onium", *b"onkus", *b"onlay", *b"onned", *b"ontic", *b"oobit", *b"oohed", *b"oomph", *b"oonts", *b"ooped", *b"oorie", *b"ooses", *b"ootid", *b"oozed", *b"oozes", *b"opahs", *b"opals", *b"opens", *b"opepe", *b"oping", *b"oppos", *b"opsin", *b"opted", *b"opter", *b"orach", *b"oracy", *b"orals", *b"orang", *b"orant", *b"orate", *b"orbed", *b"orcas", *b"orcin", *b"ordos", *b"oread", *b"orfes", *b"orgia", *b"orgic", *b"orgue", *b"oribi", *b"oriel", *b"orixa", *b"orles", *b"orlon", *b"orlop", *b"ormer", *b"ornis", *b"orpin", *b"orris", *b"ortho", *b"orval", *b"orzos", *b"oscar", *b"oshac", *b"osier", *b"osmic", *b"osmol", *b"ossia", *b"ostia", *b"otaku", *b"otary", *b"ottar", *b"ottos", *b"oubit", *b"oucht", *b"ouens", *b"ouija", *b"oulks", *b"oumas", *b"oundy", *b"oupas", *b"ouped", *b"ouphe", *b"ouphs", *b"ourie", *b"ousel", *b"ousts", *b"outby", *b"outed", *b"outre", *b"outro", *b"outta", *b"ouzel", *b"ouzos", *b"ovals", *b"ovels", *b"ovens", *b"overs", *b"ovist", *b"ovoli", *b"ovolo", *b"ovule", *b"owche", *b"owies", *b"owled", *b"owler", *b"owlet", *b"owned", *b"owres", *b"owrie", *b"owsen", *b"oxbow", *b"oxers", *b"oxeye", *b"oxids", *b"oxies", *b"oxime", *b"oxims", *b"oxlip", *b"oxter", *b"oyers", *b"ozeki", *b"ozzie", *b"paals", *b"paans", *b"pacas", *b"paced", *b"pacer", *b"paces", *b"pacey", *b"pacha", *b"packs", *b"pacos", *b"pacta", *b"pacts", *b"padis", *b"padle", *b"padma", *b"padre", *b"padri", *b"paean", *b"paedo", *b"paeon", *b"paged", *b"pager", *b"pages", *b"pagle", *b"pagod", *b"pagri", *b"paiks", *b"pails", *b"pains", *b"paire", *b"pairs", *b"paisa", *b"paise", *b"pakka", *b"palas", *b"palay", *b"palea", *b"paled", *b"pales", *b"palet", *b"palis", *b"palki", *b"palla", *b"palls", *b"pally", *b"palms", *b"palmy", *b"palpi", *b"palps", *b"palsa", *b"pampa", *b"panax", *b"pance", *b"panda", *b"pands", *b"pandy", *b"paned", *b"panes", *b"panga", *b"pangs", *b"panim", *b"panko", *b"panne", *b"panni", *b"panto", *b"pants", *b"panty", *b"paoli", *b"paolo", *b"papas", *b"papaw", *b"papes", *b"pappi", *b"pappy", *b"parae", *b"paras", *b"parch", *b"pardi", *b"pards", *b"pardy", *b"pared", *b"paren", *b"pareo", *b"pares", *b"pareu", *b"parev", *b"parge", *b"pargo", *b"paris", *b"parki", *b"parks", *b"parky", *b"parle", *b"parly", *b"parma", *b"parol", *b"parps", *b"parra", *b"parrs", *b"parti", *b"parts", *b"parve", *b"parvo", *b"paseo", *b"pases", *b"pasha", *b"pashm", *b"paska", *b"paspy", *b"passe", *b"pasts", *b"pated", *b"paten", *b"pater", *b"pates", *b"paths", *b"patin", *b"patka", *b"patly", *b"patte", *b"patus", *b"pauas", *b"pauls", *b"pavan", *b"paved", *b"paven", *b"paver", *b"paves", *b"pavid", *b"pavin", *b"pavis", *b"pawas", *b"pawaw", *b"pawed", *b"pawer", *b"pawks", *b"pawky", *b"pawls", *b"pawns", *b"paxes", *b"payed", *b"payor", *b"paysd", *b"peage", *b"peags", *b"peaks", *b"peaky", *b"peals", *b"peans", *b"peare", *b"pears", *b"peart", *b"pease", *b"peats", *b"peaty", *b"peavy", *b"peaze", *b"pebas", *b"pechs", *b"pecke", *b"pecks", *b"pecky", *b"pedes", *b"pedis", *b"pedro", *b"peece", *b"peeks", *b"peels", *b"peens", *b"peeoy", *b"peepe", *b"peeps", *b"peers", *b"peery", *b"peeve", *b"peggy", *b"peghs", *b"peins", *b"peise", *b"peize", *b"pekan", *b"pekes", *b"pekin", *b"pekoe", *b"pelas", *b"pelau", *b"peles", *b"pelfs", *b"pells", *b"pelma", *b"pelon", *b"pelta", *b"pelts", *b"pends", *b"pendu", *b"pened", *b"penes", *b"pengo", *b"penie", *b"penis", *b"penks", *b"penna", *b"penni", *b"pents", *b"peons", *b"peony", *b"pepla", *b"pepos", *b"peppy", *b"pepsi", *b"perai", *b"perce", *b"percs", *b"perdu", *b"perdy", *b"perea", *b"peres", *b"peris", *b"perks", *b"perms", *b"perns", *b"perog", *b"perps", *b"perry", *b"perse", *b"perst", *b"perts", *b"perve", *b"pervo", *b"pervs", *b"pervy", *b"pesos", *b"pests", *b"pesty", *b"petar", *b"peter", *b"petit", *b"petre", *b"petri", *b"petti", *b"petto", *b"pewee", *b"pewit", *b"peyse", *b"phage", *b"phang", *b"phare", *b"pharm", *b"pheer", *b"phene", *b"pheon", *b"phese", *b"phial", *b"phish", *b"phizz", *b"phlox", *b"phoca", *b"phono", *b"phons", *b"phots", *b"phpht", *b"phuts", *b"phyla", *b"phyle", *b"piani", *b"pians", *b"pibal", *b"pical", *b"picas", *b"piccy", *b"picks", *b"picot", *b"picra", *b"picul", *b"piend", *b"piers", *b"piert", *b"pieta", *b"piets", *b"piezo", *b"pight", *b"pigmy", *b"piing", *b"pikas", *b"pikau", *b"piked", *b"piker", *b"pikes", *b"pikey", *b"pikis", *b"pikul", *b"pilae", *b"pilaf", *b"pilao", *b"pilar", *b"pilau", *b"pilaw", *b"pilch", *b"pilea", *b"piled", *b"pilei", *b"piler", *b"piles", *b"pilis", *b"pills", *b"pilow", *b"pilum", *b"pilus", *b"pimas", *b"pimps", *b"pinas", *b"pined", *b"pines", *b"pingo", *b"pings", *b"pinko", *b"pinks", *b"pinna", *b"pinny", *b"pinon", *b"pinot", *b"pinta", *b"pints", *b"pinup", *b"pions", *b"piony", *b"pious", *b"pioye", *b"pioys", *b"pipal", *b"pipas", *b"piped", *b"pipes", *b"pipet", *b"pipis", *b"pipit", *b"pippy", *b"pipul", *b"pirai", *b"pirls", *b"pirns", *b"pirog", *b"pisco", *b"pises", *b"pisky", *b"pisos", *b"pissy", *b"piste", *b"pitas", *b"piths", *b"piton", *b"pitot", *b"pitta", *b"piums", *b"pixes", *b"pized", *b"pizes", *b"plaas", *b"plack", *b"plage", *b"plans", *b"plaps", *b"plash", *b"plasm", *b"plast", *b"plats", *b"platt", *b"platy", *b"playa", *b"plays", *b"pleas", *b"plebe", *b"plebs", *b"plena", *b"pleon", *b"plesh", *b"plews", *b"plica", *b"plies", *b"plims", *b"pling", *b"plink", *b"ploat", *b"plods", *b"plong", *b"plonk", *b"plook", *b"plops", *b"plots", *b"plotz", *b"plouk", *b"plows", *b"ploye", *b"ploys", *b"plues", *b"pluff", *b"plugs", *b"plums", *b"plumy", *b"pluot", *b"pluto", *b"plyer", *b"poach", *b"poaka", *b"poake", *b"poboy", *b"pocks", *b"pocky", *b"podal", *b"poddy", *b"podex", *b"podge", *b"podgy", *b"podia", *b"poems", *b"poeps", *b"poets", *b"pogey", *b"pogge", *b"pogos", *b"pohed", *b"poilu", *b"poind", *b"pokal", *b"poked", *b"pokes", *b"pokey", *b"pokie", *b"poled", *b"poler", *b"poles", *b"poley", *b"polio", *b"polis", *b"polje", *b"polks", *b"polls", *b"polly", *b"polos", *b"polts", *b"polys", *b"pombe", *b"pomes", *b"pommy", *b"pomos", *b"pomps", *b"ponce", *b"poncy", *b"ponds", *b"pones", *b"poney", *b"ponga", *b"pongo", *b"pongs", *b"pongy", *b"ponks", *b"ponts", *b"ponty", *b"ponzu", *b"poods", *b"pooed", *b"poofs", *b"poofy", *b"poohs", *b"pooja", *b"pooka", *b"pooks", *b"pools", *b"poons", *b"poops", *b"poopy", *b"poori", *b"poort", *b"poots", *b"poove", *b"poovy", *b"popes", *b"poppa", *b"popsy", *b"porae", *b"poral", *b"pored", *b"porer", *b"pores", *b"porge", *b"porgy", *b"porin", *b"porks", *b"porky", *b"porno", *b"porns", *b"porny", *b"porta", *b"ports", *b"porty", *b"posed", *b"poses", *b"posey", *b"posho", *b"posts", *b"potae", *b"potch", *b"poted", *b"potes", *b"potin", *b"potoo", *b"potsy", *b"potto", *b"potts", *b"potty", *b"pouff", *b"poufs", *b"pouke", *b"pouks", *b"poule", *b"poulp", *b"poult", *b"poupe", *b"poupt", *b"pours", *b"pouts", *b"powan", *b"powin", *b"pownd", *b"powns", *b"powny", *b"powre", *b"poxed", *b"poxes", *b"poynt", *b"poyou", *b"poyse", *b"pozzy", *b"praam", *b"prads", *b"prahu", *b"prams", *b"prana", *b"prang", *b"praos", *b"prase", *b"prate", *b"prats", *b"pratt", *b"praty", *b"praus", *b"prays", *b"predy", *b"preed", *b"prees", *b"preif", *b"prems", *b"premy", *b"prent", *b"preon", *b"preop", *b"preps", *b"presa", *b"prese", *b"prest", *b"preve", *b"prexy", *b"preys", *b"prial", *b"pricy", *b"prief", *b"prier", *b"pries", *b"prigs", *b"prill", *b"prima", *b"primi", *b"primp", *b"prims", *b"primy", *b"prink", *b"prion", *b"prise", *b"priss", *b"proas", *b"probs", *b"prods", *b"proem", *b"profs", *b"progs", *b"proin", *b"proke", *b"prole", *b"proll", *b"promo", *b"proms", *b"pronk", *b"props", *b"prore", *b"proso", *b"pross", *b"prost", *b"prosy", *b"proto", *b"proul", *b"prows", *b"proyn", *b"prunt", *b"pruta", *b"pryer", *b"pryse", *b"pseud", *b"pshaw", *b"psion", *b"psoae", *b"psoai", *b"psoas", *b"psora", *b"psych", *b"psyop", *b"pubco", *b"pubes", *b"pubis", *b"pucan", *b"pucer", *b"puces", *b"pucka", *b"pucks", *b"puddy", *b"pudge", *b"pudic", *b"pudor", *b"pudsy", *b"pudus", *b"puers", *b"puffa", *b"puffs", *b"puggy", *b"pugil", *b"puhas", *b"pujah", *b"pujas", *b"pukas", *b"puked", *b"puker", *b"pukes", *b"pukey", *b"pukka", *b"pukus", *b"pulao", *b"pulas", *b"puled", *b"puler", *b"pules", *b"pulik", *b"pulis", *b"pulka", *b"pulks", *b"pulli", *b"pulls", *b"pully", *b"pulmo", *b"pulps", *b"pulus", *b"pumas", *b"pumie", *b"pumps", *b"punas", *b"punce", *b"punga", *b"pungs", *b"punji", *b"punka", *b"punks", *b"punky", *b"punny", *b"punto", *b"punts", *b"punty", *b"pupae", *b"pupas", *b"pupus", *b"purda", *b"pured", *b"pures", *b"purin", *b"puris", *b"purls", *b"purpy", *b"purrs", *b"pursy", *b"purty", *b"puses", *b"pusle", *b"putid", *b"puton", *b"putti", *b"putto", *b"putts", *b"puzel", *b"pwned", *b"pyats", *b"pyets", *b"pygal", *b"pyins", *b"pylon", *b"pyned", *b"pynes", *b"pyoid", *b"pyots", *b"pyral", *b"pyran", *b"pyres", *b"pyrex", *b"pyric", *b"pyros", *b"pyxed", *b"pyxes", *b"pyxie", *b"pyxis", *b"pzazz", *b"qadis", *b"qaids", *b"qajaq", *b"qanat", *b"qapik", *b"qibla", *b"qophs", *b"qorma", *b"quads", *b"quaff", *b"quags", *b"quair", *b"quais", *b"quaky", *b"quale", *b"quant", *b"quare", *b"quass", *b"quate", *b"quats", *b"quayd", *b"quays", *b"qubit", *b"quean", *b"queme", *b"quena", *b"quern", *b"queyn", *b"queys", *b"quich", *b"quids", *b"quiff", *b"quims", *b"quina", *b"quine", *b"quino", *b"quins", *b"quint", *b"quipo", *b"quips", *b"quipu", *b"quire", *b"quirt", *b"quist", *b"quits", *b"quoad", *b"quods", *b"quoif", *b"quoin", *b"quoit", *b"quoll", *b"quonk", *b"quops", *b"qursh", *b"quyte", *b"rabat", *b"rabic", *b"rabis", *b"raced", *b"races", *b"rache", *b"racks", *b"racon", *b"radge", *b"radix", *b"radon", *b"raffs", *b"rafts", *b"ragas", *b"ragde", *b"raged", *b"ragee", *b"rager", *b"rages", *b"ragga", *b"raggs", *b"raggy", *b"ragis", *b"ragus", *b"rahed", *b"rahui", *b"raias", *b"raids", *b"raiks", *b"raile", *b"rails", *b"raine", *b"rains", *b"raird", *b"raita", *b"raits", *b"rajas", *b"rajes", *b"raked", *b"rakee", *b"raker", *b"rakes", *b"rakia", *b"rakis", *b"rakus", *b"rales", *b"ramal", *b"ramee", *b"ramet", *b"ramie", *b"ramin", *b"ramis", *b"rammy", *b"ramps", *b"ramus", *b"ranas", *b"rance", *b"rands", *b"ranee", *b"ranga", *b"rangi", *b"rangs", *b"rangy", *b"ranid", *b"ranis", *b"ranke", *b"ranks", *b"rants", *b"raped", *b"raper", *b"rapes", *b"raphe", *b"rappe", *b"rared", *b"raree", *b"rares", *b"rarks", *b"rased", *b"raser", *b"rases", *b"rasps", *b"rasse", *b"rasta", *b"ratal", *b"ratan", *b"ratas", *b"ratch", *b"rated", *b"ratel", *b"rater", *b"rates", *b"ratha", *b"rathe", *b"raths", *b"ratoo", *b"ratos", *b"ratus", *b"rauns", *b"raupo", *b"raved", *b"ravel", *b"raver", *b"raves", *b"ravey", *b"ravin", *b"rawer", *b"rawin", *b"rawly", *b"rawns", *b"raxed", *b"raxes", *b"rayah", *b"rayas", *b"rayed", *b"rayle", *b"rayne", *b"razed", *b"razee", *b"razer", *b"razes", *b"razoo", *b"readd", *b"reads", *b"reais", *b"reaks", *b"realo", *b"reals", *b"reame", *b"reams", *b"reamy", *b"reans", *b"reaps", *b"rears", *b"reast", *b"reata", *b"reate", *b"reave", *b"rebbe", *b"rebec", *b"rebid", *b"rebit", *b"rebop", *b"rebuy", *b"recal", *b"recce", *b"recco", *b"reccy", *b"recit", *b"recks", *b"recon", *b"recta", *b"recti", *b"recto", *b"redan", *b"redds", *b"reddy", *b"reded", *b"redes", *b"redia", *b"redid", *b"redip", *b"redly", *b"redon", *b"redos", *b"redox", *b"redry", *b"redub", *b"redux", *b"redye", *b"reech", *b"reede", *b"reeds", *b"reefs", *b"reefy", *b"reeks", *b"reeky", *b"reels", *b"reens", *b"reest", *b"reeve", *b"refed", *b"refel", *b"reffo", *b"refis", *b"refix", *b"refly", *b"refry", *b"regar", *b"reges", *b"reggo", *b"regie", *b"regma", *b"regna", *b"regos", *b"regur", *b"rehem", *b"reifs", *b"reify", *b"reiki", *b"reiks", *b"reink", *b"reins", *b"reird", *b"reist", *b"reive", *b"rejig", *b"rejon", *b"reked", *b"rekes", *b"rekey", *b"relet", *b"relie", *b"relit", *b"rello", *b"reman", *b"remap", *b"remen", *b"remet", *b"remex", *b"remix", *b"renay", *b"rends", *b"reney", *b"renga", *b"renig", *b"renin", *b"renne", *b"renos", *b"rente", *b"rents", *b"reoil", *b"reorg", *b"repeg", *b"repin", *b"repla", *b"repos", *b"repot", *b"repps", *b"repro", *b"reran", *b"rerig", *b"resat", *b"resaw", *b"resay", *b"resee", *b"reses", *b"resew", *b"resid", *b"resit", *b"resod", *b"resow", *b"resto", *b"rests", *b"resty", *b"resus", *b"retag", *b"retax", *b"retem", *b"retia", *b"retie", *b"retox", *b"revet", *b"revie", *b"rewan", *b"rewax", *b"rewed", *b"rewet", *b"rewin", *b"rewon", *b"rewth", *b"rexes", *b"rezes", *b"rheas", *b"rheme", *b"rheum", *b"rhies", *b"rhime", *b"rhine", *b"rhody", *b"rhomb", *b"rhone", *b"rhumb", *b"rhyne", *b"rhyta", *b"riads", *b"rials", *b"riant", *b"riata", *b"ribas", *b"ribby", *b"ribes", *b"riced", *b"ricer", *b"rices", *b"ricey", *b"richt", *b"ricin", *b"ricks", *b"rides", *b"ridgy", *b"ridic", *b"riels", *b"riems", *b"rieve", *b"rifer", *b"riffs", *b"rifte", *b"rifts", *b"rifty", *b"riggs", *b"rigol", *b"riled", *b"riles", *b"riley", *b"rille", *b"rills", *b"rimae", *b"rimed", *b"rimer", *b"rimes", *b"rimus", *b"rinds", *b"rindy", *b"rines", *b"rings", *b"rinks", *b"rioja", *b"riots", *b"riped", *b"ripes", *b"ripps", *b"rises", *b"rishi", *b"risks", *b"risps", *b"risus", *b"rites", *b"ritts", *b"ritzy", *b"rivas", *b"rived", *b"rivel", *b"riven", *b"rives", *b"riyal", *b"rizas", *b"roads", *b"roams", *b"roans", *b"roars", *b"roary", *b"roate", *b"robed", *b"robes", *b"roble", *b"rocks", *b"roded", *b"rodes", *b"roguy", *b"rohes", *b"roids", *b"roils", *b"roily", *b"roins", *b"roist", *b"rojak", *b"rojis", *b"roked", *b"roker", *b"rokes", *b"rolag", *b"roles", *b"rolfs", *b"rolls", *b"romal", *b"roman", *b"romeo", *b"romps", *b"ronde", *b"rondo", *b"roneo", *b"rones", *b"ronin", *b"ronne", *b"ronte", *b"ronts", *b"roods", *b"roofs", *b"roofy", *b"rooks", *b"rooky", *b"rooms", *b"roons", *b"roops", *b"roopy", *b"roosa", *b"roose", *b"roots", *b"rooty", *b"roped", *b"roper", *b"ropes", *b"ropey", *b"roque", *b"roral", *b"rores", *b"roric", *b"rorid", *b"rorie", *b"rorts", *b"rorty", *b"rosed", *b"roses", *b"roset", *b"roshi", *b"rosin", *b"rosit", *b"rosti", *b"rosts", *b"rotal", *b"rotan", *b"rotas", *b"rotch", *b"roted", *b"rotes", *b"rotis", *b"rotls", *b"roton", *b"rotos", *b"rotte", *b"rouen", *b"roues", *b"roule", *b"rouls", *b"roums", *b"roups", *b"roupy", *b"roust", *b"routh", *b"routs", *b"roved", *b"roven", *b"roves", *b"rowan", *b"rowed", *b"rowel", *b"rowen", *b"rowie", *b"rowme", *b"rownd", *b"rowth", *b"rowts", *b"royne", *b"royst", *b"rozet", *b"rozit", *b"ruana", *b"rubai", *b"rubby", *b"rubel", *b"rubes", *b"rubin", *b"ruble", *b"rubli", *b"rubus", *b"ruche", *b"rucks", *b"rudas", *b"rudds", *b"rudes", *b"rudie", *b"rudis", *b"rueda", *b"ruers", *b"ruffe", *b"ruffs", *b"rugae", *b"rugal", *b"ruggy", *b"ruing", *b"ruins", *b"rukhs", *b"ruled", *b"rules", *b"rumal", *b"rumbo", *b"rumen", *b"rumes", *b"rumly", *b"rummy", *b"rumpo", *b"rumps", *b"rumpy", *b"runch", *b"runds", *b"runed", *b"runes", *b"rungs", *b"runic", *b"runny", *b"runts", *b"runty", *b"rupia", *b"rurps", *b"rurus", *b"rusas", *b"ruses", *b"rushy", *b"rusks", *b"rusma", *b"russe", *b"rusts", *b"ruths", *b"rutin", *b"rutty", *b"ryals", *b"rybat", *b"ryked", *b"rykes", *b"rymme", *b"rynds", *b"ryots", *b"ryper", *b"saags", *b"sabal", *b"sabed", *b"saber", *b"sabes", *b"sabha", *b"sabin", *b"sabir", *b"sable", *b"sabot", *b"sabra", *b"sabre", *b"sacks", *b"sacra", *b"saddo", *b"sades", *b"sadhe", *b"sadhu", *b"sadis", *b"sados", *b"sadza", *b"safed", *b"safes", *b"sagas", *b"sager", *b"sages", *b"saggy", *b"sagos", *b"sagum", *b"saheb", *b"sahib", *b"saice", *b"saick", *b"saics", *b"saids", *b"saiga", *b"sails", *b"saims", *b"saine", *b"sains", *b"sairs", *b"saist", *b"saith", *b"sajou", *b"sakai", *b"saker", *b"sakes", *b"sakia", *b"sakis", *b"sakti", *b"salal", *b"salat", *b"salep", *b"sales", *b"salet", *b"salic", *b"salix", *b"salle", *b"salmi", *b"salol", *b"salop", *b"salpa", *b"salps", *b"salse", *b"salto", *b"salts", *b"salue", *b"salut", *b"saman", *b"samas", *b"samba", *b"sambo", *b"samek", *b"samel", *b"samen", *b"sames", *b"samey", *b"samfu", *b"sammy", *b"sampi", *b"samps", *b"sands", *b"saned", *b"sanes", *b"sanga", *b"sangh", *b"sango", *b"sangs", *b"sanko", *b"sansa", *b"santo", *b"sants", *b"saola", *b"sapan", *b"sapid", *b"sapor", *b"saran", *b"sards", *b"sared", *b"saree", *b"sarge", *b"sargo", *b"sarin", *b"saris", *b"sarks", *b"sarky", *b"sarod", *b"saros", *b"sarus", *b"saser", *b"sasin", *b"sasse", *b"satai", *b"satay", *b"sated", *b"satem", *b"sates", *b"satis", *b"sauba", *b"sauch", *b"saugh", *b"sauls", *b"sault", *b"saunt", *b"saury", *b"sauts", *b"saved", *b"saver", *b"saves", *b"savey", *b"savin", *b"sawah", *b"sawed", *b"sawer", *b"saxes", *b"sayed", *b"sayer", *b"sayid", *b"sayne", *b"sayon", *b"sayst", *b"sazes", *b"scabs", *b"scads", *b"scaff", *b"scags", *b"scail", *b"scala", *b"scall", *b"scams", *b"scand", *b"scans", *b"scapa", *b"scape", *b"scapi", *b"scarp", *b"scars", *b"scart", *b"scath", *b"scats", *b"scatt", *b"scaud", *b"scaup", *b"scaur", *b"scaws", *b"sceat", *b"scena", *b"scend", *b"schav", *b"schmo", *b"schul", *b"schwa", *b"sclim", *b"scody", *b"scogs", *b"scoog", *b"scoot", *b"scopa", *b"scops", *b"scots", *b"scoug", *b"scoup", *b"scowp", *b"scows", *b"scrab", *b"scrae", *b"scrag", *b"scran", *b"scrat", *b"scraw", *b"scray", *b"scrim", *b"scrip", *b"scrob", *b"scrod", *b"scrog", *b"scrow", *b"scudi", *b"scudo", *b"scuds", *b"scuff", *b"scuft", *b"scugs", *b"sculk", *b"scull", *b"sculp", *b"sculs", *b"scums", *b"scups", *b"scurf", *b"scurs", *b"scuse", *b"scuta", *b"scute", *b"scuts", *b"scuzz", *b"scyes", *b"sdayn", *b"sdein", *b"seals", *b"seame", *b"seams", *b"seamy", *b"seans", *b"seare", *b"sears", *b"sease", *b"seats", *b"seaze", *b"sebum", *b"secco", *b"sechs", *b"sects", *b"seder", *b"sedes", *b"sedge", *b"sedgy", *b"sedum", *b"seeds", *b"seeks", *b"seeld", *b"seels", *b"seely", *b"seems", *b"seeps", *b"seepy", *b"seers", *b"sefer", *b"segar", *b"segni", *b"segno", *b"segol", *b"segos", *b"sehri", *b"seifs", *b"seils", *b"seine", *b"seirs", *b"seise", *b"seism", *b"seity", *b"seiza", *b"sekos", *b"sekts", *b"selah", *b"seles", *b"selfs", *b"sella", *b"selle", *b"sells", *b"selva", *b"semee", *b"semes", *b"semie", *b"semis", *b"senas", *b"sends", *b"senes", *b"sengi", *b"senna", *b"senor", *b"sensa", *b"sensi", *b"sente", *b"senti", *b"sents", *b"senvy", *b"senza", *b"sepad", *b"sepal", *b"sepic", *b"sepoy", *b"septa", *b"septs", *b"serac", *b"serai", *b"seral", *b"sered", *b"serer", *b"seres", *b"serfs", *b"serge", *b"seric", *b"serin", *b"serks", *b"seron", *b"serow", *b"serra", *b"serre", *b"serrs", *b"serry", *b"servo", *b"sesey", *b"sessa", *b"setae", *b"setal", *b"seton", *b"setts", *b"sewan", *b"sewar", *b"sewed", *b"sewel", *b"sewen", *b"sewin", *b"sexed", *b"sexer", *b"sexes", *b"sexto", *b"sexts", *b"seyen", *b"shads", *b"shags", *b"shahs", *b"shako", *b"shakt", *b"shalm", *b"shaly", *b"shama", *b"shams", *b"shand", *b"shans", *b"shaps", *b"sharn", *b"shash", *b"shaul", *b"shawm", *b"shawn", *b"shaws", *b"shaya", *b"shays", *b"shchi", *b"sheaf", *b"sheal", *b"sheas", *b"sheds", *b"sheel", *b"shend", *b"shent", *b"sheol", *b"sherd", *b"shere", *b"shero", *b"shets", *b"sheva", *b"shewn", *b"shews", *b"shiai", *b"shiel", *b"shier", *b"shies", *b"shill", *b"shily", *b"shims", *b"shins", *b"ships", *b"shirr", *b"shirs", *b"shish", *b"shiso", *b"shist", *b"shite", *b"shits", *b"shiur", *b"shiva", *b"shive", *b"shivs", *b"shlep", *b"shlub", *b"shmek", *b"shmoe", *b"shoat", *b"shoed", *b"shoer", *b"shoes", *b"shogi", *b"shogs", *b"shoji", *b"shojo", *b"shola", *b"shool", *b"shoon", *b"shoos", *b"shope", *b"shops", *b"shorl", *b"shote", *b"shots", *b"shott", *b"showd", *b"shows", *b"shoyu", *b"shred", *b"shris", *b"shrow", *b"shtik", *b"shtum", *b"shtup", *b"shule", *b"shuln", *b"shuls", *b"shuns", *b"shura", *b"shute", *b"shuts", *b"shwas", *b"shyer", *b"sials", *b"sibbs", *b"sibyl", *b"sices", *b"sicht", *b"sicko", *b"sicks", *b"sicky", *b"sidas", *b"sided", *b"sider", *b"sides", *b"sidha", *b"sidhe", *b"sidle", *b"sield", *b"siens", *b"sient", *b"sieth", *b"sieur", *b"sifts", *b"sighs", *b"sigil", *b"sigla", *b"signa", *b"signs", *b"sijos", *b"sikas", *b"siker", *b"sikes", *b"silds", *b"siled", *b"silen", *b"siler", *b"siles", *b"silex", *b"silks", *b"sills", *b"silos", *b"silts", *b"silty", *b"silva", *b"simar", *b"simas", *b"simba", *b"simis", *b"simps", *b"simul", *b"sinds", *b"sined", *b"sines", *b"sings", *b"sinhs", *b"sinks", *b"sinky", *b"sinus", *b"siped", *b"sipes", *b"sippy", *b"sired", *b"siree", *b"sires", *b"sirih", *b"siris", *b"siroc", *b"sirra", *b"sirup", *b"sisal", *b"sises", *b"sista", *b"sists", *b"sitar", *b"sited", *b"sites", *b"sithe", *b"sitka", *b"situp", *b"situs", *b"siver", *b"sixer", *b"sixes", *b"sixmo", *b"sixte", *b"sizar", *b"sized", *b"sizel", *b"sizer", *b"sizes", *b"skags", *b"skail", *b"skald", *b"skank", *b"skart", *b"skats", *b"skatt", *b"skaws", *b"skean", *b"skear", *b"skeds", *b"skeed", *b"skeef", *b"skeen", *b"skeer", *b"skees", *b"skeet", *b"skegg", *b"skegs", *b"skein", *b"skelf", *b"skell", *b"skelm", *b"skelp", *b"skene", *b"skens", *b"skeos", *b"skeps", *b"skers", *b"skets", *b"skews", *b"skids", *b"skied", *b"skies", *b"skiey", *b"skimo", *b"skims", *b"skink", *b"skins", *b"skint", *b"skios", *b"skips", *b"skirl", *b"skirr", *b"skite", *b"skits", *b"skive", *b"skivy", *b"sklim", *b"skoal", *b"skody", *b"skoff", *b"skogs", *b"skols", *b"skool", *b"skort", *b"skosh", *b"skran", *b"skrik", *b"skuas", *b"skugs", *b"skyed", *b"skyer", *b"skyey", *b"skyfs", *b"skyre", *b"skyrs", *b"skyte", *b"slabs", *b"slade", *b"slaes", *b"slags", *b"slaid", *b"slake", *b"slams", *b"slane", *b"slank", *b"slaps", *b"slart", *b"slats", *b"slaty", *b"slaws", *b"slays", *b"slebs", *b"sleds", *b"sleer", *b"slews", *b"sleys", *b"slier", *b"slily", *b"slims", *b"slipe", *b"slips", *b"slipt", *b"slish", *b"slits", *b"slive", *b"sloan", *b"slobs", *b"sloes", *b"slogs", *b"sloid", *b"slojd", *b"slomo", *b"sloom", *b"sloot", *b"slops", *b"slopy", *b"slorm", *b"slots", *b"slove", *b"slows", *b"sloyd", *b"slubb", *b"slubs", *b"slued", *b"slues", *b"sluff", *b"slugs", *b"sluit", *b"slums", *b"slurb", *b"slurs", *b"sluse", *b"slyer", *b"slype", *b"smaak", *b"smaik", *b"smalm", *b"smalt", *b"smarm", *b"smaze", *b"smeek", *b"smees", *b"smeik", *b"smeke", *b"smerk", *b"smews", *b"smirr", *b"smirs", *b"smits", *b"smogs", *b"smoko", *b"smolt", *b"smoor", *b"smoot", *b"smore", *b"smorg", *b"smout", *b"smowt", *b"smugs", *b"smurs", *b"smush", *b"smuts", *b"snabs", *b"snafu", *b"snags", *b"snaps", *b"snarf", *b"snark", *b"snars", *b"snary", *b"snash", *b"snath", *b"snaws", *b"snead", *b"sneap", *b"snebs", *b"sneck", *b"sneds", *b"sneed", *b"snees", *b"snell", *b"snibs", *b"snick", *b"snies", *b"snift", *b"snigs", *b"snips", *b"snipy", *b"snirt", *b"snits", *b"snobs", *b"snods", *b"snoek", *b"snoep", *b"snogs", *b"snoke", *b"snood", *b"snook", *b"snool", *b"snoot", *b"snots", *b"snowk", *b"snows", *b"snubs", *b"snugs", *b"snush", *b"snyes", *b"soaks", *b"soaps", *b"soare", *b"soars", *b"soave", *b"sobas", *b"socas", *b"soces", *b"socko", *b"socks", *b"socle", *b"sodas", *b"soddy", *b"sodic", *b"sodom", *b"sofar", *b"sofas", *b"softa", *b"softs", *b"softy", *b"soger", *b"sohur", *b"soils", *b"soily", *b"sojas", *b"sojus", *b"sokah", *b"soken", *b"sokes", *b"sokol", *b"solah", *b"solan", *b"solas", *b"solde", *b"soldi", *b"soldo", *b"solds", *b"soled", *b"solei", *b"soler", *b"soles", *b"solon", *b"solos", *b"solum", *b"solus", *b"soman", *b"somas", *b"sonce", *b"sonde", *b"sones", *b"songs", *b"sonly", *b"sonne", *b"sonny", *b"sonse", *b"sonsy", *b"sooey", *b"sooks", *b"sooky", *b"soole", *b"sools", *b"sooms", *b"soops", *b"soote", *b"soots", *b"sophs", *b"sophy", *b"sopor", *b"soppy", *b"sopra", *b"soral", *b"soras", *b"sorbo", *b"sorbs", *b"sorda", *b"sordo", *b"sords", *b"sored", *b"soree", *b"sorel", *b"sorer", *b"sores", *b"sorex", *b"sorgo", *b"sorns", *b"sorra", *b"sorta", *b"sorts", *b"sorus", *b"soths", *b"sotol", *b"souce", *b"souct", *b"sough", *b"souks", *b"souls", *b"soums", *b"soups", *b"soupy", *b"sours", *b"souse", *b"souts", *b"sowar", *b"sowce", *b"sowed", *b"sowff", *b"sowfs", *b"sowle", *b"sowls", *b"sowms", *b"sownd", *b"sowne", *b"sowps", *b"sowse", *b"sowth", *b"soyas", *b"soyle", *b"soyuz", *b"sozin", *b"spacy", *b"spado", *b"spaed", *b"spaer", *b"spaes", *b"spags", *b"spahi", *b"spail", *b"spain", *b"spait", *b"spake", *b"spald", *b"spale", *b"spall", *b"spalt", *b"spams", *b"spane", *b"spang", *b"spans", *b"spard", *b"spars", *b"spart", *b"spate", *b"spats", *b"spaul", *b"spawl", *b"spaws", *b"spayd", *b"spays", *b"spaza", *b"spazz", *b"speal", *b"spean", *b"speat", *b"specs", *b"spect", *b"speel", *b"speer", *b"speil", *b"speir", *b"speks", *b"speld", *b"spelk", *b"speos", *b"spets", *b"speug", *b"spews", *b"spewy", *b"spial", *b"spica", *b"spide", *b"spier", *b"spies", *b"spiff", *b"spifs", *b"spile", *b"spims", *b"spina", *b"spink", *b"spins", *b"spirt", *b"spiry", *b"spits", *b"spitz", *b"spivs", *b"splay", *b"splog", *b"spode", *b"spods", *b"spoom", *b"spoor", *b"spoot", *b"spork", *b"sposh", *b"spots", *b"sprad", *b"sprag", *b"sprat", *b"spred", *b"sprew", *b"sprit", *b"sprod", *b"sprog", *b"sprue", *b"sprug", *b"spuds", *b"spued", *b"spuer", *b"spues", *b"spugs", *b"spule", *b"spume", *b"spumy", *b"spurs", *b"sputa", *b"spyal", *b"spyre", *b"squab", *b"squaw", *b"squeg", *b"squid", *b"squit", *b"squiz", *b"stabs", *b"stade", *b"stags", *b"stagy", *b"staig", *b"stane", *b"stang", *b"staph", *b"staps", *b"starn", *b"starr", *b"stars", *b"stats", *b"staun", *b"staws", *b"stays", *b"stean", *b"stear", *b"stedd", *b"stede", *b"steds", *b"steek", *b"steem", *b"steen", *b"steil", *b"stela", *b"stele", *b"stell", *b"steme", *b"stems", *b"stend", *b"steno", *b"stens", *b"stent", *b"steps", *b"stept", *b"stere", *b"stets", *b"stews", *b"stewy", *b"steys", *b"stich", *b"stied", *b"sties", *b"stilb", *b"stile", *b"stime", *b"stims", *b"stimy", *b"stipa", *b"stipe", *b"stire", *b"stirk", *b"stirp", *b"stirs", *b"stive", *b"stivy", *b"stoae", *b"stoai", *b"stoas", *b"stoat", *b"stobs", *b"stoep", *b"stogy", *b"stoit", *b"stoln", *b"stoma", *b"stond", *b"stong", *b"stonk", *b"stonn", *b"stook", *b"stoor", *b"stope", *b"stops", *b"stopt", *b"stoss", *b"stots", *b"stott", *b"stoun", *b"stoup", *b"stour", *b"stown", *b"stowp", *b"stows", *b"strad", *b"strae", *b"strag", *b"strak", *b"strep", *b"strew", *b"stria", *b"strig", *b"strim", *b"strop", *b"strow", *b"stroy", *b"strum", *b"stubs", *b"stude", *b"studs", *b"stull", *b"stulm", *b"stumm", *b"stums", *b"stuns", *b"stupa", *b"stupe", *b"sture", *b"sturt", *b"styed", *b"styes", *b"styli", *b"stylo", *b"styme", *b"stymy", *b"styre", *b"styte", *b"subah", *b"subas", *b"subby", *b"suber", *b"subha", *b"succi", *b"sucks", *b"sucky", *b"sucre", *b"sudds", *b"sudor", *b"sudsy", *b"suede", *b"suent", *b"suers", *b"suete", *b"suets", *b"suety", *b"sugan", *b"sughs", *b"sugos", *b"suhur", *b"suids", *b"suint", *b"suits", *b"sujee", *b"sukhs", *b"sukuk", *b"sulci", *b"sulfa", *b"sulfo", *b"sulks", *b"sulph", *b"sulus", *b"sumis", *b"summa", *b"sumos", *b"sumph", *b"sumps", *b"sunis", *b"sunks", *b"sunna", *b"sunns", *b"sunup", *b"supes", *b"supra", *b"surah", *b"sural", *b"suras", *b"surat", *b"surds", *b"sured", *b"sures", *b"surfs", *b"surfy", *b"surgy", *b"surra", *b"sused", *b"suses", *b"susus", *b"sutor", *b"sutra", *b"sutta", *b"swabs", *b"swack", *b"swads", *b"swage", *b"swags", *b"swail", *b"swain", *b"swale", *b"swaly", *b"swamy", *b"swang", *b"swank", *b"swans", *b"swaps", *b"swapt", *b"sward", *b"sware", *b"swarf", *b"swart", *b"swats", *b"swayl", *b"sways", *b"sweal", *b"swede", *b"sweed", *b"sweel", *b"sweer", *b"swees", *b"sweir", *b"swelt", *b"swerf", *b"sweys", *b"swies", *b"swigs", *b"swile", *b"swims", *b"swink", *b"swipe", *b"swire", *b"swiss", *b"swith", *b"swits", *b"swive", *b"swizz", *b"swobs", *b"swole", *b"swoln", *b"swops", *b"swopt", *b"swots", *b"swoun", *b"sybbe", *b"sybil", *b"syboe", *b"sybow", *b"sycee", *b"syces", *b"sycon", *b"syens", *b"syker", *b"sykes", *b"sylis", *b"sylph", *b"sylva", *b"symar", *b"synch", *b"syncs", *b"synds", *b"syned", *b"synes", *b"synth", *b"syped", *b"sypes", *b"syphs", *b"syrah", *b"syren", *b"sysop", *b"sythe", *b"syver", *b"taals", *b"taata", *b"taber", *b"tabes", *b"tabid", *b"tabis", *b"tabla", *b"tabor", *b"tabun", *b"tabus", *b"tacan", *b"taces", *b"tacet", *b"tache", *b"tacho", *b"tachs", *b"tacks", *b"tacos", *b"tacts", *b"taels", *b"tafia", *b"taggy", *b"tagma", *b"tahas", *b"tahrs", *b"taiga", *b"taigs", *b"taiko", *b"tails", *b"tains", *b"taira", *b"taish", *b"taits", *b"tajes", *b"takas", *b"takes", *b"takhi", *b"takin", *b"takis", *b"takky", *b"talak", *b"talaq", *b"talar", *b"talas", *b"talcs", *b"talcy", *b"talea", *b"taler", *b"tales", *b"talks", *b"talky", *b"talls", *b"talma", *b"talpa", *b"taluk", *b"talus", *b"tamal", *b"tamed", *b"tames", *b"tamin", *b"tamis", *b"tammy", *b"tamps", *b"tanas", *b"tanga", *b"tangi", *b"tangs", *b"tanhs", *b"tanka", *b"tanks", *b"tanky", *b"tanna", *b"tansy", *b"tanti", *b"tanto", *b"tanty", *b"tapas", *b"taped", *b"tapen", *b"tapes", *b"tapet", *b"tapis", *b"tappa", *b"tapus", *b"taras", *b"tardo", *b"tared", *b"tares", *b"targa", *b"targe", *b"tarns", *b"taroc", *b"tarok", *b"taros", *b"tarps", *b"tarre", *b"tarry", *b"tarsi", *b"tarts", *b"tarty", *b"tasar", *b"tased", *b"taser", *b"tases", *b"tasks", *b"tassa", *b"tasse", *b"tasso", *b"tatar", *b"tater", *b"tates", *b"taths", *b"tatie", *b"tatou", *b"tatts", *b"tatus", *b"taube", *b"tauld", *b"tauon", *b"taupe", *b"tauts", *b"tavah", *b"tavas", *b"taver", *b"tawai", *b"tawas", *b"tawed", *b"tawer", *b"tawie", *b"tawse", *b"tawts", *b"taxed", *b"taxer", *b"taxes", *b"taxis", *b"taxol", *b"taxon", *b"taxor", *b"taxus", *b"tayra", *b"tazza", *b"tazze", *b"teade", *b"teads", *b"teaed", *b"teaks", *b"teals", *b"teams", *b"tears", *b"teats", *b"teaze", *b"techs", *b"techy", *b"tecta", *b"teels", *b"teems", *b"teend", *b"teene", *b"teens", *b"teeny", *b"teers", *b"teffs", *b"teggs", *b"tegua", *b"tegus", *b"tehrs", *b"teiid", *b"teils", *b"teind", *b"teins", *b"telae", *b"telco", *b"teles", *b"telex", *b"telia", *b"telic", *b"tells", *b"telly", *b"teloi", *b"telos", *b"temed", *b"temes", *b"tempi", *b"temps", *b"tempt", *b"temse", *b"tench", *b"tends", *b"tendu", *b"tenes", *b"tenge", *b"tenia", *b"tenne", *b"tenno", *b"tenny", *b"tenon", *b"tents", *b"tenty", *b"tenue", *b"tepal", *b"tepas", *b"tepoy", *b"terai", *b"teras", *b"terce", *b"terek", *b"teres", *b"terfe", *b"terfs", *b"terga", *b"terms", *b"terne", *b"terns", *b"terry", *b"terts", *b"tesla", *b"testa", *b"teste", *b"tests", *b"tetes", *b"teths", *b"tetra", *b"tetri", *b"teuch", *b"teugh", *b"tewed", *b"tewel", *b"tewit", *b"texas", *b"texes", *b"texts", *b"thack", *b"thagi", *b"thaim", *b"thale", *b"thali", *b"thana", *b"thane", *b"thang", *b"thans", *b"thanx", *b"tharm", *b"thars", *b"thaws", *b"thawy", *b"thebe", *b"theca", *b"theed", *b"theek", *b"thees", *b"thegn", *b"theic", *b"thein", *b"thelf", *b"thema", *b"thens", *b"theow", *b"therm", *b"thesp", *b"thete", *b"thews", *b"thewy", *b"thigs", *b"thilk", *b"thill", *b"thine", *b"thins", *b"thiol", *b"thirl", *b"thoft", *b"thole", *b"tholi", *b"thoro", *b"thorp", *b"thous", *b"thowl", *b"thrae", *b"thraw", *b"thrid", *b"thrip", *b"throe", *b"thuds", *b"thugs", *b"thuja", *b"thunk", *b"thurl", *b"thuya", *b"thymi", *b"thymy", *b"tians", *b"tiars", *b"tical", *b"ticca", *b"ticed", *b"tices", *b"tichy", *b"ticks", *b"ticky", *b"tiddy", *b"tided", *b"tides", *b"tiers", *b"tiffs", *b"tifos", *b"tifts", *b"tiges", *b"tigon", *b"tikas", *b"tikes", *b"tikis", *b"tikka", *b"tilak", *b"tiled", *b"tiler", *b"tiles", *b"tills", *b"tilly", *b"tilth", *b"tilts", *b"timbo", *b"timed", *b"times", *b"timon", *b"timps", *b"tinas", *b"tinct", *b"tinds", *b"tinea", *b"tined", *b"tines", *b"tinge", *b"tings", *b"tinks", *b"tinny", *b"tints", *b"tinty", *b"tipis", *b"tippy", *b"tired", *b"tires", *b"tirls", *b"tiros", *b"tirrs", *b"titch", *b"titer", *b"titis", *b"titre", *b"titty", *b"titup", *b"tiyin", *b"tiyns", *b"tizes", *b"tizzy", *b"toads", *b"toady", *b"toaze", *b"tocks", *b"tocky", *b"tocos", *b"todde", *b"toeas", *b"toffs", *b"toffy", *b"tofts", *b"tofus", *b"togae", *b"togas", *b"toged", *b"toges", *b"togue", *b"tohos", *b"toile", *b"toils", *b"toing", *b"toise", *b"toits", *b"tokay", *b"toked", *b"toker", *b"tokes", *b"tokos", *b"tolan", *b"tolar", *b"tolas", *b"toled", *b"toles", *b"tolls", *b"tolly", *b"tolts", *b"tolus", *b"tolyl", *b"toman", *b"tombs", *b"tomes", *b"tomia", *b"tommy", *b"tomos", *b"tondi", *b"tondo", *b"toned", *b"toner", *b"tones", *b"toney", *b"tongs", *b"tonka", *b"tonks", *b"tonne", *b"tonus", *b"tools", *b"tooms", *b"toons", *b"toots", *b"toped", *b"topee", *b"topek", *b"toper", *b"topes", *b"tophe", *b"tophi", *b"tophs", *b"topis", *b"topoi", *b"topos", *b"toppy", *b"toque", *b"torah", *b"toran", *b"toras", *b"torcs", *b"tores", *b"toric", *b"torii", *b"toros", *b"torot", *b"torrs", *b"torse", *b"torsi", *b"torsk", *b"torta", *b"torte", *b"torts", *b"tosas", *b"tosed", *b"toses", *b"toshy", *b"tossy", *b"toted", *b"toter", *b"totes", *b"totty", *b"touks", *b"touns", *b"tours", *b"touse", *b"tousy", *b"touts", *b"touze", *b"touzy", *b"towed", *b"towie", *b"towns", *b"towny", *b"towse", *b"towsy", *b"towts", *b"towze", *b"towzy", *b"toyed", *b"toyer", *b"toyon", *b"toyos", *b"tozed", *b"tozes", *b"tozie", *b"trabs", *b"trads", *b"tragi", *b"traik", *b"trams", *b"trank", *b"tranq", *b"trans", *b"trant", *b"trape", *b"traps", *b"trapt", *b"trass", *b"trats", *b"tratt", *b"trave", *b"trayf", *b"trays", *b"treck", *b"treed", *b"treen", *b"trees", *b"trefa", *b"treif", *b"treks", *b"trema", *b"trems", *b"tress", *b"trest", *b"trets", *b"trews", *b"treyf", *b"treys", *b"triac", *b"tride", *b"trier", *b"tries", *b"triff", *b"trigo", *b"trigs", *b"trike", *b"trild", *b"trill", *b"trims", *b"trine", *b"trins", *b"triol", *b"trior", *b"trios", *b"trips", *b"tripy", *b"trist", *b"troad", *b"troak", *b"troat", *b"trock", *b"trode", *b"trods", *b"trogs", *b"trois", *b"troke", *b"tromp", *b"trona", *b"tronc", *b"trone", *b"tronk", *b"trons", *b"trooz", *b"troth", *b"trots", *b"trows", *b"troys", *b"trued", *b"trues", *b"trugo", *b"trugs", *b"trull", *b"tryer", *b"tryke", *b"tryma", *b"tryps", *b"tsade", *b"tsadi", *b"tsars", *b"tsked", *b"tsuba", *b"tsubo", *b"tuans", *b"tuart", *b"tuath", *b"tubae", *b"tubar", *b"tubas", *b"tubby", *b"tubed", *b"tubes", *b"tucks", *b"tufas", *b"tuffe", *b"tuffs", *b"tufts", *b"tufty", *b"tugra", *b"tuile", *b"tuina", *b"tuism", *b"tuktu", *b"tules", *b"tulpa", *b"tulsi", *b"tumid", *b"tummy", *b"tumps", *b"tumpy", *b"tunas", *b"tunds", *b"tuned", *b"tuner", *b"tunes", *b"tungs", *b"tunny", *b"tupek", *b"tupik", *b"tuple", *b"tuque", *b"turds", *b"turfs", *b"turfy", *b"turks", *b"turme", *b"turms", *b"turns", *b"turnt", *b"turps", *b"turrs", *b"tushy", *b"tusks", *b"tusky", *b"tutee", *b"tutti", *b"tutty", *b"tutus", *b"tuxes", *b"tuyer", *b"twaes", *b"twain", *b"twals", *b"twank", *b"twats", *b"tways", *b"tweel", *b"tween", *b"tweep", *b"tweer", *b"twerk", *b"twerp", *b"twier", *b"twigs", *b"twill", *b"twilt", *b"twink", *b"twins", *b"twiny", *b"twire", *b"twirp", *b"twite", *b"twits", *b"twoer", *b"twyer", *b"tyees", *b"tyers", *b"tyiyn", *b"tykes", *b"tyler", *b"tymps", *b"tynde", *b"tyned", *b"tynes", *b"typal", *b"typed", *b"types", *b"typey", *b"typic", *b"typos", *b"typps", *b"typto", *b"tyran", *b"tyred", *b"tyres", *b"tyros", *b"tythe", *b"tzars", *b"udals", *b"udons", *b"ugali", *b"ugged", *b"uhlan", *b"uhuru", *b"ukase", *b"ulama", *b"ulans", *b"ulema", *b"ulmin", *b"ulnad", *b"ulnae", *b"ulnar", *b"ulnas", *b"ulpan", *b"ulvas", *b"ulyie", *b"ulzie", *b"umami", *b"umbel", *b"umber", *b"umble", *b"umbos", *b"umbre", *b"umiac", *b"umiak", *b"umiaq", *b"ummah", *b"ummas", *b"ummed", *b"umped", *b"umphs", *b"umpie", *b"umpty", *b"umrah", *b"umras", *b"unais", *b"unapt", *b"unarm", *b"unary", *b"unaus", *b"unbag", *b"unban", *b"unbar", *b"unbed", *b"unbid", *b"unbox", *b"uncap", *b"unces", *b"uncia", *b"uncos", *b"uncoy", *b"uncus", *b"undam", *b"undee", *b"undos", *b"undug", *b"uneth", *b"unfix", *b"ungag", *b"unget", *b"ungod", *b"ungot", *b"ungum", *b"unhat", *b"unhip", *b"unica", *b"units", *b"unjam", *b"unked", *b"unket", *b"unkid", *b"unlaw", *b"unlay", *b"unled", *b"unlet", *b"unlid", *b"unman", *b"unmew", *b"unmix", *b"unpay", *b"unpeg", *b"unpen", *b"unpin", *b"unred", *b"unrid", *b"unrig", *b"unrip", *b"unsaw", *b"unsay", *b"unsee", *b"unsew", *b"unsex", *b"unsod", *b"untax", *b"untin", *b"unwet", *b"unwit", *b"unwon", *b"upbow", *b"upbye", *b"updos", *b"updry", *b"upend", *b"upjet", *b"uplay", *b"upled", *b"uplit", *b"upped", *b"upran", *b"uprun", *b"upsee", *b"upsey", *b"uptak", *b"upter", *b"uptie", *b"uraei", *b"urali", *b"uraos", *b"urare", *b"urari", *b"urase", *b"urate", *b"urbex", *b"urbia", *b"urdee", *b"ureal", *b"ureas", *b"uredo", *b"ureic", *b"urena", *b"urent", *b"urged", *b"urger", *b"urges", *b"urial", *b"urite", *b"urman", *b"urnal", *b"urned", *b"urped", *b"ursae", *b"ursid", *b"urson", *b"urubu", *b"urvas", *b"users", *b"usnea", *b"usque", *b"usure", *b"usury", *b"uteri", *b"uveal", *b"uveas", *b"uvula", *b"vacua", *b"vaded", *b"vades", *b"vagal", *b"vagus", *b"vails", *b"vaire", *b"vairs", *b"vairy", *b"vakas", *b"vakil", *b"vales", *b"valis", *b"valse", *b"vamps", *b"vampy", *b"vanda", *b"vaned", *b"vanes", *b"vangs", *b"vants", *b"vaped", *b"vaper", *b"vapes", *b"varan", *b"varas", *b"vardy", *b"varec", *b"vares", *b"varia", *b"varix", *b"varna", *b"varus", *b"varve", *b"vasal", *b"vases", *b"vasts", *b"vasty", *b"vatic", *b"vatus", *b"vauch", *b"vaute", *b"vauts", *b"vawte", *b"vaxes", *b"veale", *b"veals", *b"vealy", *b"veena", *b"veeps", *b"veers", *b"veery", *b"vegas", *b"veges", *b"vegie", *b"vegos", *b"vehme", *b"veils", *b"veily", *b"veins", *b"veiny", *b"velar", *b"velds", *b"veldt", *b"veles", *b"vells", *b"velum", *b"venae", *b"venal", *b"vends", *b"vendu", *b"veney", *b"venge", *b"venin", *b"vents", *b"venus", *b"verbs", *b"verra", *b"verry", *b"verst", *b"verts", *b"vertu", *b"vespa", *b"vesta", *b"vests", *b"vetch", *b"vexed", *b"vexer", *b"vexes", *b"vexil", *b"vezir", *b"vials", *b"viand", *b"vibes", *b"vibex", *b"vibey", *b"viced", *b"vices", *b"vichy", *b"viers", *b"views", *b"viewy", *b"vifda", *b"viffs", *b"vigas", *b"vigia", *b"vilde", *b"viler", *b"villi", *b"vills", *b"vimen", *b"vinal", *b"vinas", *b"vinca", *b"vined", *b"viner", *b"vines", *b"vinew", *b"vinic", *b"vinos", *b"vints", *b"viold", *b"viols", *b"vired", *b"vireo", *b"vires", *b"virga", *b"virge", *b"virid", *b"virls", *b"virtu", *b"visas", *b"vised", *b"vises", *b"visie", *b"visne", *b"vison", *b"visto", *b"vitae", *b"vitas", *b"vitex", *b"vitro", *b"vitta", *b"vivas", *b"vivat", *b"vivda", *b"viver", *b"vives", *b"vizir", *b"vizor", *b"vleis", *b"vlies", *b"vlogs", *b"voars", *b"vocab", *b"voces", *b"voddy", *b"vodou", *b"vodun", *b"voema", *b"vogie", *b"voids", *b"voile", *b"voips", *b"volae", *b"volar", *b"voled", *b"voles", *b"volet", *b"volks", *b"volta", *b"volte", *b"volti", *b"volts", *b"volva", *b"volve", *b"vomer", *b"voted", *b"votes", *b"vouge", *b"voulu", *b"vowed", *b"vower", *b"voxel", *b"vozhd", *b"vraic", *b"vrils", *b"vroom", *b"vrous", *b"vrouw", *b"vrows", *b"vuggs", *b"vuggy", *b"vughs", *b"vughy", *b"vulgo", *b"vulns", *b"vulva", *b"vutty", *b"waacs", *b"wacke", *b"wacko", *b"wacks", *b"wadds", *b"waddy", *b"waded", *b"wader", *b"wades", *b"wadge", *b"wadis", *b"wadts", *b"waffs", *b"wafts", *b"waged", *b"wages", *b"wagga", *b"wagyu", *b"wahoo", *b"waide", *b"waifs", *b"waift", *b"wails", *b"wains", *b"wairs", *b"waite", *b"waits", *b"wakas", *b"waked", *b"waken", *b"waker", *b"wakes", *b"wakfs", *b"waldo", *b"walds", *b"waled", *b"waler", *b"wales", *b"walie", *b"walis", *b"walks", *b"walla", *b"walls", *b"wally", *b"walty", *b"wamed", *b"wames", *b"wamus", *b"wands", *b"waned", *b"wanes", *b"waney", *b"wangs", *b"wanks", *b"wanky", *b"wanle", *b"wanly", *b"wanna", *b"wants", *b"wanty", *b"wanze", *b"waqfs", *b"warbs", *b"warby", *b"wards", *b"wared", *b"wares", *b"warez", *b"warks", *b"warms", *b"warns", *b"warps", *b"warre", *b"warst", *b"warts", *b"wases", *b"washy", *b"wasms", *b"wasps", *b"waspy", *b"wasts", *b"watap", *b"watts", *b"wauff", *b"waugh", *b"wauks", *b"waulk", *b"wauls", *b"waurs", *b"waved", *b"waves", *b"wavey", *b"wawas", *b"wawes", *b"wawls", *b"waxed", *b"waxer", *b"waxes", *b"wayed", *b"wazir", *b"wazoo", *b"weald", *b"weals", *b"weamb", *b"weans", *b"wears", *b"webby", *b"weber", *b"wecht", *b"wedel", *b"wedgy", *b"weeds", *b"weeke", *b"weeks", *b"weels", *b"weems", *b"weens", *b"weeny", *b"weeps", *b"weepy", *b"weest", *b"weete", *b"weets", *b"wefte", *b"wefts", *b"weids", *b"weils", *b"weirs", *b"weise", *b"weize", *b"wekas", *b"welds", *b"welke", *b"welks", *b"welkt", *b"wells", *b"welly", *b"welts", *b"wembs", *b"wends", *b"wenge", *b"wenny", *b"wents", *b"weros", *b"wersh", *b"wests", *b"wetas", *b"wetly", *b"wexed", *b"wexes", *b"whamo", *b"whams", *b"whang", *b"whaps", *b"whare", *b"whata", *b"whats", *b"whaup", *b"whaur", *b"wheal", *b"whear", *b"wheen", *b"wheep", *b"wheft", *b"whelk", *b"whelm", *b"whens", *b"whets", *b"whews", *b"wheys", *b"whids", *b"whift", *b"whigs", *b"whilk", *b"whims", *b"whins", *b"whios", *b"whips", *b"whipt", *b"whirr", *b"whirs", *b"whish", *b"whiss", *b"whist", *b"whits", *b"whity", *b"whizz", *b"whomp", *b"whoof", *b"whoot", *b"whops", *b"whorl", *b"whort", *b"whoso", *b"whows", *b"whump", *b"whups", *b"whyda", *b"wicca", *b"wicks", *b"wicky", *b"widdy", *b"wides", *b"wiels", *b"wifed", *b"wifes", *b"wifey", *b"wifie", *b"wifty", *b"wigan", *b"wigga", *b"wiggy", *b"wikis", *b"wilco", *b"wilds", *b"wiled", *b"wiles", *b"wilga", *b"wilis", *b"wilja", *b"wills", *b"wilts", *b"wimps", *b"winds", *b"wined", *b"wines", *b"winey", *b"winge", *b"wings", *b"wingy", *b"winks", *b"winna", *b"winns", *b"winos", *b"winze", *b"wiped", *b"wiper", *b"wipes", *b"wired", *b"wirer", *b"wires", *b"wirra", *b"wised", *b"wises", *b"wisha", *b"wisht", *b"wisps", *b"wists", *b"witan", *b"wited", *b"wites", *b"withe", *b"withs", *b"withy", *b"wived", *b"wiver", *b"wives", *b"wizen", *b"wizes", *b"woads", *b"woald", *b"wocks", *b"wodge", *b"woful", *b"wojus", *b"woker", *b"wokka", *b"wolds", *b"wolfs", *b"wolly", *b"wolve", *b"wombs", *b"womby", *b"womyn", *b"wonga", *b"wongi", *b"wonks", *b"wonky", *b"wonts", *b"woods", *b"wooed", *b"woofs", *b"woofy", *b"woold", *b"wools", *b"woons", *b"woops", *b"woopy", *b"woose", *b"woosh", *b"wootz", *b"words", *b"works", *b"worms", *b"wormy", *b"worts", *b"wowed", *b"wowee", *b"woxen", *b"wrang", *b"wraps", *b"wrapt", *b"wrast", *b"wrate", *b"wrawl", *b"wrens", *b"wrick", *b"wried", *b"wrier", *b"wries", *b"writs", *b"wroke", *b"wroot", *b"wroth", *b"wryer", *b"wuddy", *b"wudus", *b"wulls", *b"wurst", *b"wuses", *b"wushu", *b"wussy", *b"wuxia", *b"wyled", *b"wyles", *b"wynds", *b"wynns", *b"wyted", *b"wytes", *b"xebec", *b"xenia", *b"xenic", *b"xenon", *b"xeric", *b"xerox", *b"xerus", *b"xoana", *b"xrays", *b"xylan", *b"xylem", *b"xylic", *b"xylol", *b"xylyl", *b"xysti", *b"xysts", *b"yaars", *b"yabas", *b"yabba", *b"yabby", *b"yacca", *b"yacka", *b"yacks", *b"yaffs", *b"yager", *b"yages", *b"yagis", *b"yahoo", *b"yaird", *b"yakka", *b"yakow", *b"yales", *b"yamen", *b"yampy", *b"yamun", *b"yangs", *b"yanks", *b"yapok", *b"yapon", *b"yapps", *b"yappy", *b"yarak", *b"yarco", *b"yards", *b"yarer", *b"yarfa", *b"yarks", *b"yarns", *b"yarrs", *b"yarta", *b"yarto", *b"yates", *b"yauds", *b"yauld", *b"yaups", *b"yawed", *b"yawey", *b"yawls", *b"yawns", *b"yawny", *b"yawps", *b"ybore", *b"yclad", *b"ycled", *b"ycond", *b"ydrad", *b"ydred", *b"yeads", *b"yeahs", *b"yealm", *b"yeans", *b"yeard", *b"years", *b"yecch", *b"yechs", *b"yechy", *b"yedes", *b"yeeds", *b"yeesh", *b"yeggs", *b"yelks", *b"yells", *b"yelms", *b"yelps", *b"yelts", *b"yenta", *b"yente", *b"yerba", *b"yerds", *b"yerks", *b"yeses", *b"yesks", *b"yests", *b"yesty", *b"yetis", *b"yetts", *b"yeuks", *b"yeuky", *b"yeven", *b"yeves", *b"yewen", *b"yexed", *b"yexes", *b"yfere", *b"yiked", *b"yikes", *b"yills", *b"yince", *b"yipes", *b"yippy", *b"yirds", *b"yirks", *b"yirrs", *b"yirth", *b"yites", *b"yitie", *b"ylems", *b"ylike", *b"ylkes", *b"ymolt", *b"ympes", *b"yobbo", *b"yobby", *b"yocks", *b"yodel", *b"yodhs", *b"yodle", *b"yogas", *b"yogee", *b"yoghs", *b"yogic", *b"yogin", *b"yogis", *b"yoick", *b"yojan", *b"yoked", *b"yokel", *b"yoker", *b"yokes", *b"yokul", *b"yolks", *b"yolky", *b"yomim", *b"yomps", *b"yonic", *b"yonis", *b"yonks", *b"yoofs", *b"yoops", *b"yores", *b"yorks", *b"yorps", *b"youks", *b"yourn", *b"yours", *b"yourt", *b"youse", *b"yowed", *b"yowes", *b"yowie", *b"yowls", *b"yowza", *b"yrapt", *b"yrent", *b"yrivd", *b"yrneh", *b"ysame", *b"ytost", *b"yuans", *b"yucas", *b"yucca", *b"yucch", *b"yucko", *b"yucks", *b"yucky", *b"yufts", *b"yugas", *b"yuked", *b"yukes", *b"yukky", *b"yukos", *b"yulan", *b"yules", *b"yummo", *b"yummy", *b"yumps", *b"yupon", *b"yuppy", *b"yurta", *b"yurts", *b"yuzus", *b"zabra", *b"zacks", *b"zaida", *b"zaidy", *b"zaire", *b"zakat", *b"zaman", *b"zambo", *b"zamia", *b"zanja", *b"zante", *b"zanza", *b"zanze", *b"zappy", *b"zarfs", *b"zaris", *b"zatis", *b"zaxes", *b"zayin", *b"zazen", *b"zeals", *b"zebec", *b"zebub", *b"zebus", *b"zedas", *b"zeins", *b"zendo", *b"zerda", *b"zerks", *b"zeros", *b"zests", *b"zetas", *b"zexes", *b"zezes", *b"zhomo", *b"zibet", *b"ziffs", *b"zigan", *b"zilas", *b"zilch", *b"zilla", *b"zills", *b"zimbi", *b"zimbs", *b"zinco", *b"zincs", *b"zincy", *b"zineb", *b"zines", *b"zings", *b"zingy", *b"zinke", *b"zinky", *b"zippo", *b"zippy", *b"ziram", *b"zitis", *b"zizel", *b"zizit", *b"zlote", *b"zloty", *b"zoaea", *b"zobos", *b"zobus", *b"zocco", *b"zoeae", *b"zoeal", *b"zoeas", *b"zoism", *b"zoist", *b"zombi", *b"zonae", *b"zonda", *b"zoned", *b"zoner", *b"zones", *b"zonks", *b"zooea", *b"zooey", *b"zooid", *b"zooks", *b"zooms", *b"zoons", *b"zooty", *b"zoppa", *b"zoppo", *b"zoril", *b"zoris", *b"zorro", *b"zouks", *b"zowee", *b"zowie", *b"zulus", *b"zupan", *b"zupas", *b"zuppa", *b"zurfs", *b"zuzim", *b"zygal", *b"zygon", *b"zymes", *b"zymic"];